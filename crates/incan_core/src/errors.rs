@@ -29,6 +29,12 @@ pub enum ErrorKind {
     IndexError,
     KeyError,
     JsonDecodeError,
+    /// A user-declared exception kind (e.g. `class MyError(Exception): ...`), identified by its
+    /// interned canonical spelling (its class name).
+    ///
+    /// Unlike the builtin variants above, there is no static [`crate::lang::errors::EXCEPTIONS`]
+    /// entry for these; `as_str`/`description` special-case `Custom` directly.
+    Custom(&'static str),
 }
 
 /// Arguments used to format an [`IncanError`].
@@ -55,6 +61,32 @@ pub enum ErrorArgs<'a> {
     ///
     /// Mirrors Python's `json.dumps(...)` error.
     JsonNotSerializable { type_name: &'a str },
+    /// `JSONDecodeError: {msg}: line {line} column {col} (char {pos})`
+    ///
+    /// Mirrors CPython's `json.JSONDecodeError`, whose `.msg`, `.pos`, `.lineno`, and `.colno`
+    /// attributes are all counted in characters (not bytes).
+    JsonDecode { msg: &'a str, pos: usize, line: usize, col: usize },
+}
+
+/// A lightweight, allocation-free source location: a 1-based `line`/`col` plus a `len` in columns.
+///
+/// Unlike `incan_syntax::ast::Span` (byte offsets into the parsed source), this is the small,
+/// `Copy` shape `IncanError` carries so the `no_std` core never needs to depend on the parser.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Span {
+    /// 1-based source line.
+    pub line: usize,
+    /// 1-based source column (in chars).
+    pub col: usize,
+    /// Width of the underlined region, in chars. `0` renders as a single caret.
+    pub len: usize,
+}
+
+impl Span {
+    #[inline]
+    pub const fn new(line: usize, col: usize, len: usize) -> Self {
+        Self { line, col, len }
+    }
 }
 
 /// A typed, canonical Incan error (Python-like).
@@ -62,12 +94,13 @@ pub enum ErrorArgs<'a> {
 pub struct IncanError<'a> {
     kind: ErrorKind,
     args: ErrorArgs<'a>,
+    span: Option<Span>,
 }
 
 impl<'a> IncanError<'a> {
     #[inline]
     pub const fn new(kind: ErrorKind, args: ErrorArgs<'a>) -> Self {
-        Self { kind, args }
+        Self { kind, args, span: None }
     }
 
     /// Return the exception kind.
@@ -76,6 +109,28 @@ impl<'a> IncanError<'a> {
         self.kind
     }
 
+    /// Return the error's source span, if one was attached via [`IncanError::at`].
+    #[inline]
+    pub const fn span(&self) -> Option<Span> {
+        self.span
+    }
+
+    /// Attach a source span (e.g. from the AST node a codegen call site is lowering).
+    #[inline]
+    pub const fn at(mut self, span: Span) -> Self {
+        self.span = Some(span);
+        self
+    }
+
+    /// Render this error as a CPython-style traceback frame anchored to `source`, opt-in so the
+    /// terse [`fmt::Display`] impl (the only thing `no_std` callers need) stays a pure one-liner.
+    ///
+    /// Falls back to the terse one-line form when no [`Span`] was attached.
+    #[inline]
+    pub fn traceback<'b>(&'b self, file: &'b str, source: &'b str) -> Traceback<'a, 'b> {
+        Traceback { err: self, file, source }
+    }
+
     /// `IndexError: string index out of range`
     #[inline]
     pub const fn string_index_out_of_range() -> Self {
@@ -145,11 +200,38 @@ impl<'a> IncanError<'a> {
         Self::new(ErrorKind::JsonDecodeError, ErrorArgs::Message(message))
     }
 
+    /// `JSONDecodeError: {msg}: line {line} column {col} (char {pos})`
+    ///
+    /// Mirrors CPython's `json.JSONDecodeError`. `byte_pos` is the failing offset into `doc` as
+    /// reported by the scanner (a byte offset, per `str` indexing); it is converted to a char
+    /// offset so `line`/`col`/`pos` agree with Python's char-counted attributes even when `doc`
+    /// contains multibyte UTF-8. `byte_pos == doc.len()` (an EOF error) is handled by clamping.
+    pub fn json_decode_at(msg: &'a str, doc: &str, byte_pos: usize) -> Self {
+        let byte_pos = byte_pos.min(doc.len());
+        let prefix = &doc[..byte_pos];
+        let pos = prefix.chars().count();
+        let line = 1 + prefix.matches('\n').count();
+        let col = match prefix.rfind('\n') {
+            Some(nl_byte) => pos - doc[..nl_byte].chars().count(),
+            None => pos + 1,
+        };
+        Self::new(ErrorKind::JsonDecodeError, ErrorArgs::JsonDecode { msg, pos, line, col })
+    }
+
     /// Generic message helper (keeps kind typed, avoids allocating for the message body).
     #[inline]
     pub const fn with_message(kind: ErrorKind, message: &'a str) -> Self {
         Self::new(kind, ErrorArgs::Message(message))
     }
+
+    /// `{name}: {message}`
+    ///
+    /// Raise a user-declared exception kind (`class {name}(Exception): ...`), e.g. one minted by
+    /// a frontend lowering a user's `class` declaration to [`ErrorKind::Custom`].
+    #[inline]
+    pub const fn custom(name: &'static str, message: &'a str) -> Self {
+        Self::new(ErrorKind::Custom(name), ErrorArgs::Message(message))
+    }
 }
 
 impl fmt::Display for IncanError<'_> {
@@ -165,7 +247,44 @@ impl fmt::Display for IncanError<'_> {
             ErrorArgs::JsonNotSerializable { type_name } => {
                 write!(f, "{kind}: Object of type {type_name} is not JSON serializable")
             }
+            ErrorArgs::JsonDecode { msg, pos, line, col } => {
+                write!(f, "{kind}: {msg}: line {line} column {col} (char {pos})")
+            }
+        }
+    }
+}
+
+/// Opt-in, Python-`traceback`-style rendering of an [`IncanError`], anchored to its [`Span`] (if
+/// any) via [`IncanError::traceback`].
+///
+/// Renders:
+/// ```text
+/// File "<name>", line N
+///     <source line>
+///     <caret run under the offending columns>
+/// Kind: message
+/// ```
+///
+/// Falls back to the terse `Kind: message` form when the error has no attached span.
+#[derive(Debug, Clone, Copy)]
+pub struct Traceback<'a, 'b> {
+    err: &'b IncanError<'a>,
+    file: &'b str,
+    source: &'b str,
+}
+
+impl fmt::Display for Traceback<'_, '_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(span) = self.err.span {
+            writeln!(f, "File \"{}\", line {}", self.file, span.line)?;
+            if let Some(line_text) = self.source.lines().nth(span.line.saturating_sub(1)) {
+                writeln!(f, "    {line_text}")?;
+                let indent = " ".repeat(span.col.saturating_sub(1));
+                let carets = "^".repeat(span.len.max(1));
+                writeln!(f, "    {indent}{carets}")?;
+            }
         }
+        write!(f, "{}", self.err)
     }
 }
 
@@ -258,6 +377,73 @@ mod tests {
         );
     }
 
+    #[test]
+    fn json_decode_at_matches_cpython_linecol() {
+        let doc = r#"{"a": }"#;
+        assert_eq!(
+            IncanError::json_decode_at("Expecting value", doc, 6).to_string(),
+            "JSONDecodeError: Expecting value: line 1 column 7 (char 6)"
+        );
+
+        let doc = "{\n  \"a\": }";
+        assert_eq!(
+            IncanError::json_decode_at("Expecting value", doc, 9).to_string(),
+            "JSONDecodeError: Expecting value: line 2 column 8 (char 9)"
+        );
+    }
+
+    #[test]
+    fn json_decode_at_handles_eof_and_multibyte() {
+        let doc = "{";
+        assert_eq!(
+            IncanError::json_decode_at("Expecting property name enclosed in double quotes", doc, doc.len())
+                .to_string(),
+            "JSONDecodeError: Expecting property name enclosed in double quotes: line 1 column 2 (char 1)"
+        );
+
+        // `"café` is 6 bytes but 5 chars; the string is never closed, so the failure sits at the end of the document.
+        let doc = "\"café";
+        assert_eq!(
+            IncanError::json_decode_at("Unterminated string starting at", doc, doc.len()).to_string(),
+            "JSONDecodeError: Unterminated string starting at: line 1 column 6 (char 5)"
+        );
+    }
+
+    #[test]
+    fn custom_exception_kinds_use_their_declared_name() {
+        let err = IncanError::custom("InsufficientFundsError", "balance too low");
+        assert_eq!(err.to_string(), "InsufficientFundsError: balance too low");
+        assert_eq!(err.kind(), ErrorKind::Custom("InsufficientFundsError"));
+    }
+
+    #[test]
+    fn terse_display_ignores_attached_span() {
+        let err = IncanError::zero_division().at(Span::new(3, 5, 3));
+        assert_eq!(err.to_string(), "ZeroDivisionError: float division by zero");
+        assert_eq!(err.span(), Some(Span::new(3, 5, 3)));
+    }
+
+    #[test]
+    fn traceback_renders_file_line_and_carets() {
+        let source = "def main() -> None:\n    print(1 / 0)\n";
+        let err = IncanError::zero_division().at(Span::new(2, 11, 5));
+
+        let rendered = err.traceback("main.incan", source).to_string();
+        assert_eq!(
+            rendered,
+            "File \"main.incan\", line 2\n        print(1 / 0)\n              ^^^^^\nZeroDivisionError: float division by zero"
+        );
+    }
+
+    #[test]
+    fn traceback_without_span_falls_back_to_terse_form() {
+        let err = IncanError::zero_division();
+        assert_eq!(
+            err.traceback("main.incan", "1 / 0").to_string(),
+            "ZeroDivisionError: float division by zero"
+        );
+    }
+
     #[test]
     fn numeric_parse_errors_are_canonical() {
         assert_eq!(
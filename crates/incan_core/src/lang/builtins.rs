@@ -36,6 +36,15 @@ pub enum BuiltinFnId {
     WriteFile,
     JsonStringify,
     Sleep,
+    Min,
+    Max,
+    Sorted,
+    Reversed,
+    Round,
+    Map,
+    Filter,
+    Any,
+    All,
 }
 
 /// Metadata for a builtin function.
@@ -127,6 +136,69 @@ pub const BUILTIN_FUNCTIONS: &[BuiltinFnInfo] = &[
         RFC::_000,
     ),
     info(BuiltinFnId::Sleep, "sleep", &[], "Sleep for a duration.", RFC::_000),
+    info(
+        BuiltinFnId::Min,
+        "min",
+        &[],
+        "Return the smallest of an iterable, or of two or more arguments.",
+        RFC::_000,
+    ),
+    info(
+        BuiltinFnId::Max,
+        "max",
+        &[],
+        "Return the largest of an iterable, or of two or more arguments.",
+        RFC::_000,
+    ),
+    info(
+        BuiltinFnId::Sorted,
+        "sorted",
+        &[],
+        "Return a new sorted list from the items in an iterable.",
+        RFC::_000,
+    ),
+    info(
+        BuiltinFnId::Reversed,
+        "reversed",
+        &[],
+        "Return a reversed copy of a list.",
+        RFC::_000,
+    ),
+    info(
+        BuiltinFnId::Round,
+        "round",
+        &[],
+        "Round a number to the nearest integer.",
+        RFC::_000,
+    ),
+    info(
+        BuiltinFnId::Map,
+        "map",
+        &[],
+        "Apply a function to every item of an iterable.",
+        RFC::_000,
+    ),
+    info(
+        BuiltinFnId::Filter,
+        "filter",
+        &[],
+        "Keep only the items of an iterable for which a function returns true.",
+        RFC::_000,
+    ),
+    info(
+        BuiltinFnId::Any,
+        "any",
+        &[],
+        "Return true if any element of an iterable is truthy.",
+        RFC::_000,
+    ),
+    info(
+        BuiltinFnId::All,
+        "all",
+        &[],
+        "Return true if all elements of an iterable are truthy.",
+        RFC::_000,
+    ),
 ];
 
 /// Return the canonical spelling for a builtin function.
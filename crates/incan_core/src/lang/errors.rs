@@ -129,26 +129,45 @@ def main() -> None:
 ];
 
 /// Return the canonical spelling for an exception kind (e.g. `"ValueError"`).
+///
+/// For [`ErrorKind::Custom`], the canonical spelling *is* the user-declared class name, so no
+/// registry lookup is needed.
 #[inline]
 pub fn as_str(kind: ErrorKind) -> &'static str {
-    info_for(kind).canonical
+    match kind {
+        ErrorKind::Custom(name) => name,
+        _ => info_for(kind).canonical,
+    }
 }
 
 /// Return the user-facing description for an exception kind.
+///
+/// [`ErrorKind::Custom`] kinds have no static registry entry (their docstring, if any, lives with
+/// the user's `class` declaration), so this returns a generic placeholder for them.
 #[inline]
 pub fn description(kind: ErrorKind) -> &'static str {
-    info_for(kind).description
+    match kind {
+        ErrorKind::Custom(_) => "A user-declared exception.",
+        _ => info_for(kind).description,
+    }
 }
 
 /// Return the documentation examples for an exception kind.
+///
+/// [`ErrorKind::Custom`] kinds have no static registry entry, so this returns an empty slice.
 #[inline]
 pub fn examples(kind: ErrorKind) -> &'static [Example] {
-    info_for(kind).examples
+    match kind {
+        ErrorKind::Custom(_) => &[],
+        _ => info_for(kind).examples,
+    }
 }
 
-/// Resolve a spelling to an exception kind.
+/// Resolve a spelling to a builtin exception kind.
 ///
-/// Matching is case-sensitive.
+/// Matching is case-sensitive. This only resolves the fixed builtin vocabulary; a user-declared
+/// `class MyError(Exception)` becomes `ErrorKind::Custom("MyError")` directly at the lowering
+/// site rather than through this lookup.
 pub fn from_str(name: &str) -> Option<ErrorKind> {
     if let Some(e) = EXCEPTIONS.iter().find(|e| e.canonical == name) {
         return Some(e.id);
@@ -156,10 +175,12 @@ pub fn from_str(name: &str) -> Option<ErrorKind> {
     EXCEPTIONS.iter().find(|e| e.aliases.contains(&name)).map(|e| e.id)
 }
 
-/// Return full metadata for an exception kind.
+/// Return full metadata for a builtin exception kind.
 ///
 /// ## Panics
 /// - If the registry is missing an entry for `kind` (programming error).
+/// - If `kind` is [`ErrorKind::Custom`]; use [`as_str`]/[`description`]/[`examples`] instead, which
+///   special-case it.
 pub fn info_for(kind: ErrorKind) -> &'static ExceptionInfo {
     EXCEPTIONS
         .iter()
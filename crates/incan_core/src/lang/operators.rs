@@ -74,6 +74,9 @@ pub enum OperatorId {
     Not,
     In,
     Is,
+
+    // Type-level
+    Pipe,
 }
 
 /// Metadata for an operator.
@@ -346,6 +349,16 @@ pub const OPERATORS: &[OperatorInfo] = &[
         true,
         RFC::_000,
     ),
+    // Type-level (union types in annotations, e.g. `int | str`)
+    op(
+        OperatorId::Pipe,
+        &["|"],
+        20,
+        Associativity::Left,
+        Fixity::Infix,
+        false,
+        RFC::_000,
+    ),
 ];
 
 /// Return the full metadata entry for an operator.
@@ -46,17 +46,31 @@ pub mod string_methods {
     pub enum StringMethodId {
         Upper,
         Lower,
+        Title,
+        Capitalize,
+        Casefold,
         Strip,
+        Lstrip,
+        Rstrip,
         Replace,
         Join,
         ToString,
         SplitWhitespace,
         Split,
+        Rsplit,
+        Splitlines,
+        Partition,
+        Rpartition,
         Contains,
         StartsWith,
         EndsWith,
         Len,
         IsEmpty,
+        Find,
+        Rfind,
+        Index,
+        Rindex,
+        Count,
     }
 
     pub type StringMethodInfo = LangItemInfo<StringMethodId>;
@@ -79,11 +93,51 @@ pub mod string_methods {
             RFC::_009,
             Since(0, 1),
         ),
+        info(
+            StringMethodId::Title,
+            "title",
+            &[],
+            "Upper-case the first cased scalar of each word, lower-case the rest.",
+            RFC::_009,
+            Since(0, 1),
+        ),
+        info(
+            StringMethodId::Capitalize,
+            "capitalize",
+            &[],
+            "Upper-case the first scalar, lower-case the remainder.",
+            RFC::_009,
+            Since(0, 1),
+        ),
+        info(
+            StringMethodId::Casefold,
+            "casefold",
+            &[],
+            "Casefold for aggressive case-insensitive matching.",
+            RFC::_009,
+            Since(0, 1),
+        ),
         info(
             StringMethodId::Strip,
             "strip",
             &[],
-            "Strip leading and trailing whitespace.",
+            "Strip leading and trailing whitespace, or an optional set of characters.",
+            RFC::_009,
+            Since(0, 1),
+        ),
+        info(
+            StringMethodId::Lstrip,
+            "lstrip",
+            &[],
+            "Strip leading whitespace, or an optional set of characters.",
+            RFC::_009,
+            Since(0, 1),
+        ),
+        info(
+            StringMethodId::Rstrip,
+            "rstrip",
+            &[],
+            "Strip trailing whitespace, or an optional set of characters.",
             RFC::_009,
             Since(0, 1),
         ),
@@ -123,7 +177,39 @@ pub mod string_methods {
             StringMethodId::Split,
             "split",
             &[],
-            "Split on a separator substring.",
+            "Split on a separator substring, from the left, with an optional maxsplit.",
+            RFC::_009,
+            Since(0, 1),
+        ),
+        info(
+            StringMethodId::Rsplit,
+            "rsplit",
+            &[],
+            "Split on a separator substring, from the right, with an optional maxsplit.",
+            RFC::_009,
+            Since(0, 1),
+        ),
+        info(
+            StringMethodId::Splitlines,
+            "splitlines",
+            &[],
+            "Split on Unicode line boundaries, with an optional keepends flag.",
+            RFC::_009,
+            Since(0, 1),
+        ),
+        info(
+            StringMethodId::Partition,
+            "partition",
+            &[],
+            "Split on the first occurrence of a separator into a (before, sep, after) triple.",
+            RFC::_009,
+            Since(0, 1),
+        ),
+        info(
+            StringMethodId::Rpartition,
+            "rpartition",
+            &[],
+            "Split on the last occurrence of a separator into a (before, sep, after) triple.",
             RFC::_009,
             Since(0, 1),
         ),
@@ -167,6 +253,46 @@ pub mod string_methods {
             RFC::_009,
             Since(0, 1),
         ),
+        info(
+            StringMethodId::Find,
+            "find",
+            &[],
+            "Return the scalar index of the first occurrence of a substring, or -1 if absent.",
+            RFC::_009,
+            Since(0, 1),
+        ),
+        info(
+            StringMethodId::Rfind,
+            "rfind",
+            &[],
+            "Return the scalar index of the last occurrence of a substring, or -1 if absent.",
+            RFC::_009,
+            Since(0, 1),
+        ),
+        info(
+            StringMethodId::Index,
+            "index",
+            &[],
+            "Return the scalar index of the first occurrence of a substring, raising ValueError if absent.",
+            RFC::_009,
+            Since(0, 1),
+        ),
+        info(
+            StringMethodId::Rindex,
+            "rindex",
+            &[],
+            "Return the scalar index of the last occurrence of a substring, raising ValueError if absent.",
+            RFC::_009,
+            Since(0, 1),
+        ),
+        info(
+            StringMethodId::Count,
+            "count",
+            &[],
+            "Count non-overlapping occurrences of a substring.",
+            RFC::_009,
+            Since(0, 1),
+        ),
     ];
 
     /// Resolve a string method spelling to its stable id.
@@ -31,6 +31,8 @@ pub enum CollectionTypeId {
     FrozenList,
     FrozenDict,
     FrozenSet,
+    /// Lazy iterator type produced by a generator expression (`(expr for x in it)`).
+    Iterator,
 }
 
 /// Metadata for a collection/generic-base builtin type.
@@ -105,6 +107,13 @@ pub const COLLECTION_TYPES: &[CollectionTypeInfo] = &[
         "Immutable/const-friendly set type.",
         RFC::_009,
     ),
+    info(
+        CollectionTypeId::Iterator,
+        "Iterator",
+        &["generator"],
+        "Lazy iterator type produced by a generator expression.",
+        RFC::_000,
+    ),
 ];
 
 /// Resolve a type name to a [`CollectionTypeId`].
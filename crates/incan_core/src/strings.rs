@@ -23,6 +23,16 @@ pub const STRING_INDEX_OUT_OF_RANGE_MSG: &str = "IndexError: string index out of
 /// ## Notes
 /// - This is intended to be shared between compiler diagnostics and runtime panics.
 pub const STRING_SLICE_STEP_ZERO_MSG: &str = "ValueError: slice step cannot be zero";
+/// Describe a substring-not-found error message (mirrors Python's `str.index`/`str.rindex`).
+///
+/// ## Notes
+/// - This is intended to be shared between compiler diagnostics and runtime panics.
+pub const SUBSTRING_NOT_FOUND_MSG: &str = "ValueError: substring not found";
+/// Describe an invalid-UTF-8 decode error message (mirrors Python's `UnicodeDecodeError`).
+///
+/// ## Notes
+/// - This is intended to be shared between compiler diagnostics and runtime panics.
+pub const STRING_INVALID_UTF8_MSG: &str = "UnicodeDecodeError: invalid utf-8 sequence";
 
 /// Represent string access errors produced by semantic-core helpers.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -44,6 +54,140 @@ impl StringAccessError {
     }
 }
 
+/// Represent a UTF-8 decode error produced by [`str_from_utf8`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Utf8DecodeError {
+    /// Number of leading bytes of the input that formed valid UTF-8 before the first error.
+    pub valid_up_to: usize,
+}
+
+impl Utf8DecodeError {
+    /// Return the canonical error message for this decode error.
+    ///
+    /// ## Returns
+    /// - (`&'static str`): the shared error message string.
+    pub fn message(self) -> &'static str {
+        STRING_INVALID_UTF8_MSG
+    }
+}
+
+/// Relative per-byte frequency scores (higher = more common), used by
+/// [`find_rare_byte_accelerated`] to pick the rarest byte in a needle to anchor its scan on.
+///
+/// Modeled on typical English/UTF-8 text (ASCII letters, space, and common punctuation score
+/// highest; everything else — including UTF-8 continuation bytes — sits at a low baseline), not
+/// measured from a real corpus. The scoring only needs to be roughly right: picking *a* rare byte
+/// in the needle is what makes the scan cheap, not picking the statistically rarest one.
+static BYTE_FREQUENCY: [u8; 256] = build_byte_frequency_table();
+
+const fn build_byte_frequency_table() -> [u8; 256] {
+    let mut table = [40u8; 256];
+
+    table[b' ' as usize] = 255;
+
+    let common_lower: [u8; 12] = [b'e', b't', b'a', b'o', b'i', b'n', b's', b'h', b'r', b'd', b'l', b'u'];
+    let mut i = 0;
+    while i < common_lower.len() {
+        table[common_lower[i] as usize] = 200 - (i as u8) * 8;
+        i += 1;
+    }
+
+    let rest_lower: [u8; 14] = [
+        b'c', b'm', b'w', b'f', b'g', b'y', b'p', b'b', b'v', b'k', b'j', b'x', b'q', b'z',
+    ];
+    let mut i = 0;
+    while i < rest_lower.len() {
+        table[rest_lower[i] as usize] = 100 - (i as u8) * 4;
+        i += 1;
+    }
+
+    let mut c = b'A';
+    while c <= b'Z' {
+        table[c as usize] = 90;
+        c += 1;
+    }
+    let mut c = b'0';
+    while c <= b'9' {
+        table[c as usize] = 70;
+        c += 1;
+    }
+
+    let common_punct: [u8; 6] = [b'.', b',', b'\n', b'\'', b'-', b'_'];
+    let mut i = 0;
+    while i < common_punct.len() {
+        table[common_punct[i] as usize] = 150;
+        i += 1;
+    }
+
+    table
+}
+
+/// Find the offset within `needle` of its rarest byte per [`BYTE_FREQUENCY`] (ties broken by
+/// earliest occurrence).
+fn rarest_byte_offset(needle: &[u8]) -> usize {
+    let mut best_offset = 0;
+    let mut best_score = u8::MAX;
+    for (i, &b) in needle.iter().enumerate() {
+        let score = BYTE_FREQUENCY[b as usize];
+        if score < best_score {
+            best_score = score;
+            best_offset = i;
+        }
+    }
+    best_offset
+}
+
+/// Search for `needle` (at least 2 bytes) in `haystack` using a rare-byte heuristic.
+///
+/// Anchors the scan on the needle's rarest byte (by [`BYTE_FREQUENCY`]): scans `haystack` with a
+/// `memchr`-style byte scan for just that byte to generate candidate start positions, then does a
+/// full bytewise comparison of `needle` at each candidate. On a miss this does one scan for the
+/// rare byte's occurrences instead of a naive `O(len(haystack) * len(needle))` scan.
+///
+/// The rare byte may land mid-multibyte-sequence in `haystack`; that candidate is simply rejected
+/// by the full bytewise comparison (which only succeeds when every byte of `needle` lines up
+/// exactly), so the returned offset is always the start of a genuine, correctly-aligned match —
+/// the same guarantee `str::find` provides.
+///
+/// ## Returns
+/// - `Some(pos)`: byte offset of the first match.
+/// - `None`: `needle` does not occur in `haystack`.
+fn find_rare_byte_accelerated(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    debug_assert!(needle.len() >= 2);
+    if needle.len() > haystack.len() {
+        return None;
+    }
+    let anchor_offset = rarest_byte_offset(needle);
+    let anchor_byte = needle[anchor_offset];
+    let last_candidate_start = haystack.len() - needle.len();
+
+    let mut search_from = anchor_offset;
+    while search_from < haystack.len() {
+        let found_at = haystack[search_from..].iter().position(|&b| b == anchor_byte)?;
+        let anchor_pos = search_from + found_at;
+        let candidate_start = anchor_pos - anchor_offset;
+        if candidate_start > last_candidate_start {
+            return None;
+        }
+        if haystack[candidate_start..candidate_start + needle.len()] == *needle {
+            return Some(candidate_start);
+        }
+        search_from = anchor_pos + 1;
+    }
+    None
+}
+
+/// Find the first byte offset of `needle` in `haystack`, or `None` if absent.
+///
+/// Routes needles of two bytes or more through [`find_rare_byte_accelerated`]; needles of zero or
+/// one byte are already as cheap as a direct comparison can be, so they skip straight to it.
+fn find_substring_bytes(haystack: &str, needle: &str) -> Option<usize> {
+    if needle.len() < 2 {
+        return haystack.find(needle);
+    }
+    find_rare_byte_accelerated(haystack.as_bytes(), needle.as_bytes())
+}
+
 /// Check whether a substring is contained in a string (Python-like `in`).
 ///
 /// ## Parameters
@@ -53,7 +197,7 @@ impl StringAccessError {
 /// ## Returns
 /// - `bool`: `true` if `needle` is contained in `haystack`.
 pub fn str_contains(haystack: &str, needle: &str) -> bool {
-    haystack.contains(needle)
+    find_substring_bytes(haystack, needle).is_some()
 }
 
 /// Concatenate two strings.
@@ -223,6 +367,159 @@ pub fn str_slice(
     Ok(out)
 }
 
+/// Convert a byte offset within `s` into its Unicode scalar (char) index.
+///
+/// ## Parameters
+/// - `s`: the string `byte_offset` was found in.
+/// - `byte_offset`: a byte offset into `s` (must land on a char boundary).
+///
+/// ## Returns
+/// - (`usize`): the number of Unicode scalars preceding `byte_offset`.
+fn byte_to_scalar_index(s: &str, byte_offset: usize) -> usize {
+    s[..byte_offset].chars().count()
+}
+
+/// Clamp optional Python-style (possibly negative) scalar `start`/`end` bounds to a valid byte
+/// range within `s`, the same way [`str_slice`] clamps its bounds.
+///
+/// ## Returns
+/// - (`(usize, usize)`): a `start_byte..end_byte` range, always on char boundaries, with
+///   `start_byte <= end_byte`.
+fn scalar_bounds_to_byte_range(s: &str, start: Option<i64>, end: Option<i64>) -> (usize, usize) {
+    let char_byte_offsets: Vec<usize> = s.char_indices().map(|(i, _)| i).collect();
+    let len = char_byte_offsets.len() as i64;
+
+    let mut start_idx = start.unwrap_or(0);
+    let mut end_idx = end.unwrap_or(len);
+    if start_idx < 0 {
+        start_idx += len;
+    }
+    if end_idx < 0 {
+        end_idx += len;
+    }
+    let start_idx = start_idx.clamp(0, len) as usize;
+    let end_idx = (end_idx.clamp(0, len) as usize).max(start_idx);
+
+    let start_byte = char_byte_offsets.get(start_idx).copied().unwrap_or(s.len());
+    let end_byte = char_byte_offsets.get(end_idx).copied().unwrap_or(s.len());
+    (start_byte, end_byte)
+}
+
+/// Find the first occurrence of `needle` in `haystack` (Python-like `str.find`).
+///
+/// ## Parameters
+/// - `haystack`: string to search in.
+/// - `needle`: substring to search for.
+/// - `start`: optional start bound (scalar index; supports negative indices).
+/// - `end`: optional end bound (scalar index, exclusive; supports negative indices).
+///
+/// ## Returns
+/// - (`i64`): the scalar index of the first occurrence, or `-1` if `needle` is absent.
+pub fn str_find(haystack: &str, needle: &str, start: Option<i64>, end: Option<i64>) -> i64 {
+    let (start_byte, end_byte) = scalar_bounds_to_byte_range(haystack, start, end);
+    match find_substring_bytes(&haystack[start_byte..end_byte], needle) {
+        Some(byte_pos) => byte_to_scalar_index(haystack, start_byte + byte_pos) as i64,
+        None => -1,
+    }
+}
+
+/// Find the last occurrence of `needle` in `haystack` (Python-like `str.rfind`).
+///
+/// ## Parameters
+/// - `haystack`: string to search in.
+/// - `needle`: substring to search for.
+/// - `start`: optional start bound (scalar index; supports negative indices).
+/// - `end`: optional end bound (scalar index, exclusive; supports negative indices).
+///
+/// ## Returns
+/// - (`i64`): the scalar index of the last occurrence, or `-1` if `needle` is absent.
+pub fn str_rfind(haystack: &str, needle: &str, start: Option<i64>, end: Option<i64>) -> i64 {
+    let (start_byte, end_byte) = scalar_bounds_to_byte_range(haystack, start, end);
+    match haystack[start_byte..end_byte].rfind(needle) {
+        Some(byte_pos) => byte_to_scalar_index(haystack, start_byte + byte_pos) as i64,
+        None => -1,
+    }
+}
+
+/// Count non-overlapping occurrences of `needle` in `haystack` (Python-like `str.count`).
+///
+/// ## Parameters
+/// - `haystack`: string to search in.
+/// - `needle`: substring to count.
+/// - `start`: optional start bound (scalar index; supports negative indices).
+/// - `end`: optional end bound (scalar index, exclusive; supports negative indices).
+///
+/// ## Returns
+/// - (`usize`): the number of non-overlapping occurrences of `needle`.
+///
+/// ## Notes
+/// - Mirrors Python's treatment of an empty `needle`: one "occurrence" per scalar position,
+///   including the position just past the last scalar (`len(s) + 1` for an empty `needle`).
+pub fn str_count(haystack: &str, needle: &str, start: Option<i64>, end: Option<i64>) -> usize {
+    let (start_byte, end_byte) = scalar_bounds_to_byte_range(haystack, start, end);
+    let window = &haystack[start_byte..end_byte];
+    if needle.is_empty() {
+        return window.chars().count() + 1;
+    }
+    window.matches(needle).count()
+}
+
+/// Encode a string as UTF-8 bytes (Python-like `str.encode()`).
+///
+/// ## Parameters
+/// - `s`: Input string.
+///
+/// ## Returns
+/// - `Vec<u8>`: the UTF-8 bytes of `s`.
+pub fn str_encode(s: &str) -> Vec<u8> {
+    s.as_bytes().to_vec()
+}
+
+/// Decode UTF-8 bytes into a string, failing on invalid input (Python-like `bytes.decode("utf-8")`).
+///
+/// ## Parameters
+/// - `bytes`: Input bytes.
+///
+/// ## Returns
+/// - `Ok(String)`: The decoded string.
+/// - `Err(Utf8DecodeError)`: If `bytes` is not valid UTF-8.
+pub fn str_from_utf8(bytes: &[u8]) -> Result<String, Utf8DecodeError> {
+    std::str::from_utf8(bytes)
+        .map(|s| s.to_string())
+        .map_err(|e| Utf8DecodeError { valid_up_to: e.valid_up_to() })
+}
+
+/// Decode UTF-8 bytes into a string, replacing invalid sequences with U+FFFD
+/// (Python-like `bytes.decode("utf-8", errors="replace")`).
+///
+/// ## Parameters
+/// - `bytes`: Input bytes.
+///
+/// ## Returns
+/// - `String`: The decoded string, with each maximal invalid byte sequence replaced by a single
+///   U+FFFD replacement character.
+pub fn str_from_utf8_lossy(bytes: &[u8]) -> String {
+    let mut out = String::new();
+    let mut rest = bytes;
+    while !rest.is_empty() {
+        match std::str::from_utf8(rest) {
+            Ok(valid) => {
+                out.push_str(valid);
+                break;
+            }
+            Err(e) => {
+                let valid_up_to = e.valid_up_to();
+                let (valid, after_valid) = rest.split_at(valid_up_to);
+                out.push_str(std::str::from_utf8(valid).expect("validated by from_utf8"));
+                out.push('\u{FFFD}');
+                let invalid_len = e.error_len().unwrap_or(after_valid.len());
+                rest = &after_valid[invalid_len..];
+            }
+        }
+    }
+    out
+}
+
 // ---- String methods (shared policy) -------------------------------------------------------------
 
 /// Convert a string to uppercase.
@@ -247,15 +544,141 @@ pub fn str_lower(s: &str) -> String {
     s.to_lowercase()
 }
 
-/// Strip leading and trailing whitespace.
+/// Title-case a string (Python-like `str.title`).
+///
+/// ## Parameters
+/// - `s`: Input string.
+///
+/// ## Returns
+/// - `String`: `s` with the first cased scalar of each word upper-cased and the rest of each word
+///   lower-cased. A "word" boundary is any transition from a non-alphabetic scalar to an
+///   alphabetic one (matching Python's naive word-splitting for `str.title`).
+pub fn str_title(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut prev_is_cased = false;
+    for c in s.chars() {
+        if c.is_alphabetic() {
+            if prev_is_cased {
+                out.extend(c.to_lowercase());
+            } else {
+                out.extend(c.to_uppercase());
+            }
+            prev_is_cased = true;
+        } else {
+            out.push(c);
+            prev_is_cased = false;
+        }
+    }
+    out
+}
+
+/// Capitalize a string (Python-like `str.capitalize`).
+///
+/// ## Parameters
+/// - `s`: Input string.
+///
+/// ## Returns
+/// - `String`: `s` with its first Unicode scalar upper-cased and the remainder lower-cased.
+///   A first scalar whose title case differs from its upper case (e.g. `ǆ`) is upper-cased, not
+///   title-cased.
+pub fn str_capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(first) => {
+            let mut out: String = first.to_uppercase().collect();
+            out.extend(chars.flat_map(|c| c.to_lowercase()));
+            out
+        }
+        None => String::new(),
+    }
+}
+
+/// Casefold a string for aggressive case-insensitive matching (Python-like `str.casefold`).
+///
+/// ## Parameters
+/// - `s`: Input string.
+///
+/// ## Returns
+/// - `String`: a case-folded copy of `s`.
+///
+/// ## Notes
+/// - This crate has no dependencies, so it approximates full Unicode case folding with
+///   [`str::to_lowercase`]; this matches `str.casefold` for the vast majority of text but is not a
+///   byte-for-byte implementation of Unicode's special casefolding table (e.g. German `ß`).
+pub fn str_casefold(s: &str) -> String {
+    s.to_lowercase()
+}
+
+/// Compare two strings for equality, ignoring case (via [`str_casefold`]).
+///
+/// ## Parameters
+/// - `lhs`: left-hand string.
+/// - `rhs`: right-hand string.
+///
+/// ## Returns
+/// - `bool`: whether `lhs` and `rhs` are equal once both are case-folded.
+pub fn str_eq_ignore_case(lhs: &str, rhs: &str) -> bool {
+    str_casefold(lhs) == str_casefold(rhs)
+}
+
+/// Compare two strings lexicographically, ignoring case (via [`str_casefold`]).
+///
+/// ## Parameters
+/// - `lhs`: left-hand string.
+/// - `rhs`: right-hand string.
+///
+/// ## Returns
+/// - (`Ordering`): the lexicographic ordering of the case-folded strings.
+pub fn str_cmp_ignore_case(lhs: &str, rhs: &str) -> Ordering {
+    str_casefold(lhs).cmp(&str_casefold(rhs))
+}
+
+/// Strip leading and trailing characters (Python-like `str.strip(chars)`).
 ///
 /// ## Parameters
 /// - `s`: Input string.
+/// - `chars`: Optional set of characters to strip (treated as a *set*, not a prefix/suffix
+///   substring); if `None`, strips Unicode whitespace.
 ///
 /// ## Returns
 /// - `String`: Stripped string.
-pub fn str_strip(s: &str) -> String {
-    s.trim().to_string()
+pub fn str_strip(s: &str, chars: Option<&str>) -> String {
+    match chars {
+        Some(set) => s.trim_matches(|c: char| set.contains(c)).to_string(),
+        None => s.trim().to_string(),
+    }
+}
+
+/// Strip leading characters (Python-like `str.lstrip(chars)`).
+///
+/// ## Parameters
+/// - `s`: Input string.
+/// - `chars`: Optional set of characters to strip from the left; if `None`, strips Unicode
+///   whitespace.
+///
+/// ## Returns
+/// - `String`: Left-stripped string.
+pub fn str_lstrip(s: &str, chars: Option<&str>) -> String {
+    match chars {
+        Some(set) => s.trim_start_matches(|c: char| set.contains(c)).to_string(),
+        None => s.trim_start().to_string(),
+    }
+}
+
+/// Strip trailing characters (Python-like `str.rstrip(chars)`).
+///
+/// ## Parameters
+/// - `s`: Input string.
+/// - `chars`: Optional set of characters to strip from the right; if `None`, strips Unicode
+///   whitespace.
+///
+/// ## Returns
+/// - `String`: Right-stripped string.
+pub fn str_rstrip(s: &str, chars: Option<&str>) -> String {
+    match chars {
+        Some(set) => s.trim_end_matches(|c: char| set.contains(c)).to_string(),
+        None => s.trim_end().to_string(),
+    }
 }
 
 /// Check whether a string starts with a prefix.
@@ -295,21 +718,126 @@ pub fn str_replace(s: &str, from: &str, to: &str) -> String {
     s.replace(from, to)
 }
 
-/// Split a string by an optional separator.
+/// Split a string by an optional separator, from the left (Python-like `str.split`).
+///
+/// ## Parameters
+/// - `s`: Input string.
+/// - `sep`: Optional separator; if `None`, returns a single-element vector containing `s`.
+/// - `maxsplit`: Optional limit on the number of splits (Python-style: a negative or absent value
+///   means unlimited; the remainder after the last split is left intact).
+///
+/// ## Returns
+/// - `Vec<String>`: Split parts as owned strings.
+pub fn str_split(s: &str, sep: Option<&str>, maxsplit: Option<i64>) -> Vec<String> {
+    match sep {
+        Some(sep) => match maxsplit {
+            Some(n) if n >= 0 => s.splitn(n as usize + 1, sep).map(|p| p.to_string()).collect(),
+            _ => s.split(sep).map(|p| p.to_string()).collect(),
+        },
+        None => vec![s.to_string()],
+    }
+}
+
+/// Split a string by an optional separator, from the right (Python-like `str.rsplit`).
 ///
 /// ## Parameters
 /// - `s`: Input string.
 /// - `sep`: Optional separator; if `None`, returns a single-element vector containing `s`.
+/// - `maxsplit`: Optional limit on the number of splits, counted from the right (Python-style: a
+///   negative or absent value means unlimited; the remainder before the first split is left
+///   intact). The returned parts are still in left-to-right order.
 ///
 /// ## Returns
 /// - `Vec<String>`: Split parts as owned strings.
-pub fn str_split(s: &str, sep: Option<&str>) -> Vec<String> {
+pub fn str_rsplit(s: &str, sep: Option<&str>, maxsplit: Option<i64>) -> Vec<String> {
     match sep {
-        Some(sep) => s.split(sep).map(|p| p.to_string()).collect(),
+        Some(sep) => match maxsplit {
+            Some(n) if n >= 0 => {
+                let mut parts: Vec<String> = s.rsplitn(n as usize + 1, sep).map(|p| p.to_string()).collect();
+                parts.reverse();
+                parts
+            }
+            _ => s.split(sep).map(|p| p.to_string()).collect(),
+        },
         None => vec![s.to_string()],
     }
 }
 
+/// Unicode scalars (besides `\n`/`\r`, handled specially to keep a `\r\n` pair together) that
+/// [`str_splitlines`] treats as line boundaries, matching Python's `str.splitlines`.
+const EXTRA_LINE_BOUNDARIES: [char; 8] = ['\x0b', '\x0c', '\u{1c}', '\u{1d}', '\u{1e}', '\u{85}', '\u{2028}', '\u{2029}'];
+
+/// Split a string on Unicode line boundaries (Python-like `str.splitlines`).
+///
+/// ## Parameters
+/// - `s`: Input string.
+/// - `keepends`: If `true`, each returned line keeps its trailing line-boundary sequence.
+///
+/// ## Returns
+/// - `Vec<String>`: The lines of `s`, in order. A trailing line boundary does not produce an
+///   extra empty line (matching Python).
+///
+/// ## Notes
+/// - Recognizes `\n`, `\r`, `\r\n`, and the vertical-tab/form-feed/Unicode line-separator set in
+///   [`EXTRA_LINE_BOUNDARIES`].
+pub fn str_splitlines(s: &str, keepends: bool) -> Vec<String> {
+    let chars: Vec<char> = s.chars().collect();
+    let mut out = Vec::new();
+    let mut line_start = 0;
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c == '\n' || c == '\r' || EXTRA_LINE_BOUNDARIES.contains(&c) {
+            let mut end = i + 1;
+            if c == '\r' && chars.get(i + 1) == Some(&'\n') {
+                end += 1;
+            }
+            let line_end = if keepends { end } else { i };
+            out.push(chars[line_start..line_end].iter().collect());
+            line_start = end;
+            i = end;
+        } else {
+            i += 1;
+        }
+    }
+    if line_start < chars.len() {
+        out.push(chars[line_start..].iter().collect());
+    }
+    out
+}
+
+/// Split a string on the first occurrence of `sep` (Python-like `str.partition`).
+///
+/// ## Parameters
+/// - `s`: Input string.
+/// - `sep`: Separator to split on.
+///
+/// ## Returns
+/// - `(String, String, String)`: `(before, sep, after)` if `sep` occurs in `s`, otherwise
+///   `(s, "", "")` (mirroring Python's not-found case).
+pub fn str_partition(s: &str, sep: &str) -> (String, String, String) {
+    match find_substring_bytes(s, sep) {
+        Some(pos) => (s[..pos].to_string(), sep.to_string(), s[pos + sep.len()..].to_string()),
+        None => (s.to_string(), String::new(), String::new()),
+    }
+}
+
+/// Split a string on the last occurrence of `sep` (Python-like `str.rpartition`).
+///
+/// ## Parameters
+/// - `s`: Input string.
+/// - `sep`: Separator to split on.
+///
+/// ## Returns
+/// - `(String, String, String)`: `(before, sep, after)` if `sep` occurs in `s`, otherwise
+///   `("", "", s)` (mirroring Python's not-found case, which favors the right side).
+pub fn str_rpartition(s: &str, sep: &str) -> (String, String, String) {
+    match s.rfind(sep) {
+        Some(pos) => (s[..pos].to_string(), sep.to_string(), s[pos + sep.len()..].to_string()),
+        None => (String::new(), String::new(), s.to_string()),
+    }
+}
+
 /// Join items with a separator.
 ///
 /// ## Parameters
@@ -67,6 +67,13 @@ pub fn raise_zero_division() -> ! {
     raise(IncanError::zero_division())
 }
 
+/// Raise a user-declared exception (`class {name}(Exception): ...`) with a `{name}: ...` prefix.
+#[cold]
+#[track_caller]
+pub fn raise_custom_error(name: &'static str, msg: &str) -> ! {
+    raise(IncanError::custom(name, msg))
+}
+
 /// Raise a Python-like JSON serialization error.
 ///
 /// Mirrors Python's `json.dumps(...)` behavior (a `TypeError` with a canonical message).
@@ -89,3 +96,17 @@ pub fn json_decode_error_string(err: impl Display) -> String {
 pub fn raise_json_decode_error(message: &str) -> ! {
     raise(IncanError::json_decode_error(message))
 }
+
+/// Format a Python-like JSON decode error, carrying the failing `(line, col, char)` location, as a `String`.
+///
+/// `byte_pos` is the scanner's byte offset into `doc`; see `IncanError::json_decode_at`.
+pub fn json_decode_error_at_string(msg: &str, doc: &str, byte_pos: usize) -> String {
+    IncanError::json_decode_at(msg, doc, byte_pos).to_string()
+}
+
+/// Raise a Python-like JSON decode error (panic), carrying the failing `(line, col, char)` location.
+#[cold]
+#[track_caller]
+pub fn raise_json_decode_error_at(msg: &str, doc: &str, byte_pos: usize) -> ! {
+    raise(IncanError::json_decode_at(msg, doc, byte_pos))
+}
@@ -6,11 +6,21 @@
 
 use incan_semantics::errors::{STRING_INDEX_OUT_OF_RANGE_MSG, STRING_SLICE_STEP_ZERO_MSG};
 use incan_semantics::strings::{
-    StringAccessError, fstring as semantics_fstring, str_char_at as semantics_str_char_at,
-    str_cmp as semantics_str_cmp, str_concat as semantics_str_concat, str_contains as semantics_str_contains,
-    str_ends_with as semantics_str_ends_with, str_join as semantics_str_join, str_lower as semantics_str_lower,
-    str_replace as semantics_str_replace, str_slice as semantics_str_slice, str_split as semantics_str_split,
-    str_starts_with as semantics_str_starts_with, str_strip as semantics_str_strip, str_upper as semantics_str_upper,
+    STRING_INVALID_UTF8_MSG, StringAccessError, SUBSTRING_NOT_FOUND_MSG, Utf8DecodeError,
+    fstring as semantics_fstring, str_capitalize as semantics_str_capitalize, str_casefold as semantics_str_casefold,
+    str_char_at as semantics_str_char_at, str_cmp as semantics_str_cmp,
+    str_cmp_ignore_case as semantics_str_cmp_ignore_case, str_concat as semantics_str_concat,
+    str_contains as semantics_str_contains, str_count as semantics_str_count,
+    str_encode as semantics_str_encode, str_ends_with as semantics_str_ends_with,
+    str_eq_ignore_case as semantics_str_eq_ignore_case, str_find as semantics_str_find,
+    str_from_utf8 as semantics_str_from_utf8, str_from_utf8_lossy as semantics_str_from_utf8_lossy,
+    str_join as semantics_str_join, str_lower as semantics_str_lower, str_lstrip as semantics_str_lstrip,
+    str_partition as semantics_str_partition, str_replace as semantics_str_replace,
+    str_rfind as semantics_str_rfind, str_rpartition as semantics_str_rpartition,
+    str_rsplit as semantics_str_rsplit, str_rstrip as semantics_str_rstrip, str_slice as semantics_str_slice,
+    str_split as semantics_str_split, str_splitlines as semantics_str_splitlines,
+    str_starts_with as semantics_str_starts_with,
+    str_strip as semantics_str_strip, str_title as semantics_str_title, str_upper as semantics_str_upper,
 };
 
 /// Index a string by Unicode scalar index at runtime.
@@ -188,7 +198,7 @@ pub fn str_lower<S: AsRef<str>>(s: S) -> String {
     semantics_str_lower(s.as_ref())
 }
 
-/// Strip leading and trailing whitespace.
+/// Title-case a string.
 ///
 /// ## Parameters
 ///
@@ -196,9 +206,105 @@ pub fn str_lower<S: AsRef<str>>(s: S) -> String {
 ///
 /// ## Returns
 ///
+/// - (`String`): the title-cased string.
+pub fn str_title<S: AsRef<str>>(s: S) -> String {
+    semantics_str_title(s.as_ref())
+}
+
+/// Capitalize a string.
+///
+/// ## Parameters
+///
+/// - `s`: the input string.
+///
+/// ## Returns
+///
+/// - (`String`): the capitalized string.
+pub fn str_capitalize<S: AsRef<str>>(s: S) -> String {
+    semantics_str_capitalize(s.as_ref())
+}
+
+/// Casefold a string for aggressive case-insensitive matching.
+///
+/// ## Parameters
+///
+/// - `s`: the input string.
+///
+/// ## Returns
+///
+/// - (`String`): a case-folded copy of `s`.
+pub fn str_casefold<S: AsRef<str>>(s: S) -> String {
+    semantics_str_casefold(s.as_ref())
+}
+
+/// Compare two strings for equality, ignoring case.
+///
+/// ## Parameters
+///
+/// - `lhs`: left-hand value.
+/// - `rhs`: right-hand value.
+///
+/// ## Returns
+///
+/// - (`bool`): whether `lhs` and `rhs` are equal once both are case-folded.
+pub fn str_eq_ignore_case<L: AsRef<str>, R: AsRef<str>>(lhs: L, rhs: R) -> bool {
+    semantics_str_eq_ignore_case(lhs.as_ref(), rhs.as_ref())
+}
+
+/// Compare two strings lexicographically, ignoring case.
+///
+/// ## Parameters
+///
+/// - `lhs`: left-hand value.
+/// - `rhs`: right-hand value.
+///
+/// ## Returns
+///
+/// - (`std::cmp::Ordering`): the lexicographic ordering of the case-folded strings.
+pub fn str_cmp_ignore_case<L: AsRef<str>, R: AsRef<str>>(lhs: L, rhs: R) -> std::cmp::Ordering {
+    semantics_str_cmp_ignore_case(lhs.as_ref(), rhs.as_ref())
+}
+
+/// Strip leading and trailing characters.
+///
+/// ## Parameters
+///
+/// - `s`: the input string.
+/// - `chars`: optional set of characters to strip; if `None`, strips Unicode whitespace.
+///
+/// ## Returns
+///
 /// - (`String`): the stripped string.
-pub fn str_strip<S: AsRef<str>>(s: S) -> String {
-    semantics_str_strip(s.as_ref())
+pub fn str_strip<S: AsRef<str>, C: AsRef<str>>(s: S, chars: Option<C>) -> String {
+    semantics_str_strip(s.as_ref(), chars.as_ref().map(|c| c.as_ref()))
+}
+
+/// Strip leading characters.
+///
+/// ## Parameters
+///
+/// - `s`: the input string.
+/// - `chars`: optional set of characters to strip; if `None`, strips Unicode whitespace.
+///
+/// ## Returns
+///
+/// - (`String`): the left-stripped string.
+pub fn str_lstrip<S: AsRef<str>, C: AsRef<str>>(s: S, chars: Option<C>) -> String {
+    semantics_str_lstrip(s.as_ref(), chars.as_ref().map(|c| c.as_ref()))
+}
+
+/// Strip trailing characters.
+///
+/// ## Parameters
+///
+/// - `s`: the input string.
+/// - `chars`: optional set of characters to strip; if `None`, strips Unicode whitespace.
+///
+/// ## Returns
+///
+/// - (`String`): the right-stripped string.
+pub fn str_rstrip<S: AsRef<str>, C: AsRef<str>>(s: S, chars: Option<C>) -> String {
+    semantics_str_rstrip(s.as_ref(), chars.as_ref().map(|c| c.as_ref()))
 }
 
 /// Check whether a string starts with a prefix.
@@ -244,19 +350,79 @@ pub fn str_replace<S: AsRef<str>, F: AsRef<str>, T: AsRef<str>>(s: S, from: F, t
     semantics_str_replace(s.as_ref(), from.as_ref(), to.as_ref())
 }
 
-/// Split a string by an optional separator.
+/// Split a string by an optional separator, from the left.
+///
+/// ## Parameters
+///
+/// - `s`: the input string.
+/// - `sep`: optional separator; if `None`, returns a single-element vector containing `s`.
+/// - `maxsplit`: optional limit on the number of splits (negative or absent means unlimited).
+///
+/// ## Returns
+///
+/// - (`Vec<String>`): split parts as owned strings.
+pub fn str_split<S: AsRef<str>, P: AsRef<str>>(s: S, sep: Option<P>, maxsplit: Option<i64>) -> Vec<String> {
+    let sep_ref = sep.as_ref().map(|p| p.as_ref());
+    semantics_str_split(s.as_ref(), sep_ref, maxsplit)
+}
+
+/// Split a string by an optional separator, from the right.
 ///
 /// ## Parameters
 ///
 /// - `s`: the input string.
 /// - `sep`: optional separator; if `None`, returns a single-element vector containing `s`.
+/// - `maxsplit`: optional limit on the number of splits, counted from the right (negative or
+///   absent means unlimited). The returned parts are still in left-to-right order.
 ///
 /// ## Returns
 ///
 /// - (`Vec<String>`): split parts as owned strings.
-pub fn str_split<S: AsRef<str>, P: AsRef<str>>(s: S, sep: Option<P>) -> Vec<String> {
+pub fn str_rsplit<S: AsRef<str>, P: AsRef<str>>(s: S, sep: Option<P>, maxsplit: Option<i64>) -> Vec<String> {
     let sep_ref = sep.as_ref().map(|p| p.as_ref());
-    semantics_str_split(s.as_ref(), sep_ref)
+    semantics_str_rsplit(s.as_ref(), sep_ref, maxsplit)
+}
+
+/// Split a string on Unicode line boundaries.
+///
+/// ## Parameters
+///
+/// - `s`: the input string.
+/// - `keepends`: if `true`, each returned line keeps its trailing line-boundary sequence.
+///
+/// ## Returns
+///
+/// - (`Vec<String>`): the lines of `s`, in order.
+pub fn str_splitlines<S: AsRef<str>>(s: S, keepends: bool) -> Vec<String> {
+    semantics_str_splitlines(s.as_ref(), keepends)
+}
+
+/// Split a string on the first occurrence of `sep`.
+///
+/// ## Parameters
+///
+/// - `s`: the input string.
+/// - `sep`: separator to split on.
+///
+/// ## Returns
+///
+/// - (`(String, String, String)`): `(before, sep, after)`, or `(s, "", "")` if `sep` is absent.
+pub fn str_partition<S: AsRef<str>, P: AsRef<str>>(s: S, sep: P) -> (String, String, String) {
+    semantics_str_partition(s.as_ref(), sep.as_ref())
+}
+
+/// Split a string on the last occurrence of `sep`.
+///
+/// ## Parameters
+///
+/// - `s`: the input string.
+/// - `sep`: separator to split on.
+///
+/// ## Returns
+///
+/// - (`(String, String, String)`): `(before, sep, after)`, or `("", "", s)` if `sep` is absent.
+pub fn str_rpartition<S: AsRef<str>, P: AsRef<str>>(s: S, sep: P) -> (String, String, String) {
+    semantics_str_rpartition(s.as_ref(), sep.as_ref())
 }
 
 /// Join items with a separator.
@@ -288,9 +454,149 @@ pub fn str_contains<H: AsRef<str>, N: AsRef<str>>(haystack: H, needle: N) -> boo
     semantics_str_contains(haystack.as_ref(), needle.as_ref())
 }
 
+/// Find the first occurrence of `needle` in `haystack`.
+///
+/// ## Parameters
+///
+/// - `haystack`: the string to search in.
+/// - `needle`: the substring to search for.
+/// - `start`: optional start bound (scalar index; supports negative indices).
+/// - `end`: optional end bound (scalar index, exclusive; supports negative indices).
+///
+/// ## Returns
+///
+/// - (`i64`): the scalar index of the first occurrence, or `-1` if `needle` is absent.
+pub fn str_find<H: AsRef<str>, N: AsRef<str>>(haystack: H, needle: N, start: Option<i64>, end: Option<i64>) -> i64 {
+    semantics_str_find(haystack.as_ref(), needle.as_ref(), start, end)
+}
+
+/// Find the last occurrence of `needle` in `haystack`.
+///
+/// ## Parameters
+///
+/// - `haystack`: the string to search in.
+/// - `needle`: the substring to search for.
+/// - `start`: optional start bound (scalar index; supports negative indices).
+/// - `end`: optional end bound (scalar index, exclusive; supports negative indices).
+///
+/// ## Returns
+///
+/// - (`i64`): the scalar index of the last occurrence, or `-1` if `needle` is absent.
+pub fn str_rfind<H: AsRef<str>, N: AsRef<str>>(haystack: H, needle: N, start: Option<i64>, end: Option<i64>) -> i64 {
+    semantics_str_rfind(haystack.as_ref(), needle.as_ref(), start, end)
+}
+
+/// Find the first occurrence of `needle` in `haystack`, panicking if absent.
+///
+/// ## Parameters
+///
+/// - `haystack`: the string to search in.
+/// - `needle`: the substring to search for.
+/// - `start`: optional start bound (scalar index; supports negative indices).
+/// - `end`: optional end bound (scalar index, exclusive; supports negative indices).
+///
+/// ## Returns
+///
+/// - (`i64`): the scalar index of the first occurrence.
+///
+/// ## Panics
+///
+/// - If `needle` is not found: with `ValueError: substring not found`.
+pub fn str_index_of<H: AsRef<str>, N: AsRef<str>>(haystack: H, needle: N, start: Option<i64>, end: Option<i64>) -> i64 {
+    match semantics_str_find(haystack.as_ref(), needle.as_ref(), start, end) {
+        -1 => panic!("{}", SUBSTRING_NOT_FOUND_MSG),
+        pos => pos,
+    }
+}
+
+/// Find the last occurrence of `needle` in `haystack`, panicking if absent.
+///
+/// ## Parameters
+///
+/// - `haystack`: the string to search in.
+/// - `needle`: the substring to search for.
+/// - `start`: optional start bound (scalar index; supports negative indices).
+/// - `end`: optional end bound (scalar index, exclusive; supports negative indices).
+///
+/// ## Returns
+///
+/// - (`i64`): the scalar index of the last occurrence.
+///
+/// ## Panics
+///
+/// - If `needle` is not found: with `ValueError: substring not found`.
+pub fn str_rindex_of<H: AsRef<str>, N: AsRef<str>>(haystack: H, needle: N, start: Option<i64>, end: Option<i64>) -> i64 {
+    match semantics_str_rfind(haystack.as_ref(), needle.as_ref(), start, end) {
+        -1 => panic!("{}", SUBSTRING_NOT_FOUND_MSG),
+        pos => pos,
+    }
+}
+
+/// Count non-overlapping occurrences of `needle` in `haystack`.
+///
+/// ## Parameters
+///
+/// - `haystack`: the string to search in.
+/// - `needle`: the substring to count.
+/// - `start`: optional start bound (scalar index; supports negative indices).
+/// - `end`: optional end bound (scalar index, exclusive; supports negative indices).
+///
+/// ## Returns
+///
+/// - (`i64`): the number of non-overlapping occurrences of `needle`.
+pub fn str_count<H: AsRef<str>, N: AsRef<str>>(haystack: H, needle: N, start: Option<i64>, end: Option<i64>) -> i64 {
+    semantics_str_count(haystack.as_ref(), needle.as_ref(), start, end) as i64
+}
+
 /// Runtime f-string composition using shared semantics.
 ///
 /// `parts` length must be one greater than `args` length.
 pub fn fstring(parts: &[&str], args: &[String]) -> String {
     semantics_fstring(parts, args)
 }
+
+/// Encode a string as UTF-8 bytes.
+///
+/// ## Parameters
+///
+/// - `s`: the input string.
+///
+/// ## Returns
+///
+/// - (`Vec<u8>`): the UTF-8 bytes of `s`.
+pub fn str_encode<S: AsRef<str>>(s: S) -> Vec<u8> {
+    semantics_str_encode(s.as_ref())
+}
+
+/// Decode UTF-8 bytes into a string at runtime.
+///
+/// ## Parameters
+///
+/// - `bytes`: the input bytes.
+///
+/// ## Returns
+///
+/// - (`String`): the decoded string.
+///
+/// ## Panics
+///
+/// - If `bytes` is not valid UTF-8: with `UnicodeDecodeError: invalid utf-8 sequence`.
+pub fn str_from_utf8(bytes: &[u8]) -> String {
+    match semantics_str_from_utf8(bytes) {
+        Ok(s) => s,
+        Err(Utf8DecodeError { .. }) => panic!("{}", STRING_INVALID_UTF8_MSG),
+    }
+}
+
+/// Decode UTF-8 bytes into a string at runtime, replacing invalid sequences with U+FFFD.
+///
+/// ## Parameters
+///
+/// - `bytes`: the input bytes.
+///
+/// ## Returns
+///
+/// - (`String`): the decoded string, with invalid byte sequences replaced by U+FFFD.
+pub fn str_from_utf8_lossy(bytes: &[u8]) -> String {
+    semantics_str_from_utf8_lossy(bytes)
+}
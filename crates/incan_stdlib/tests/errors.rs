@@ -6,7 +6,7 @@
 //! Note: `incan_stdlib`'s `serde_json` dependency is behind the optional `json` feature, so this file uses a small
 //! `Display`-only fake error to test formatting logic without enabling features.
 
-use incan_stdlib::errors::json_decode_error_string;
+use incan_stdlib::errors::{json_decode_error_at_string, json_decode_error_string};
 use std::fmt;
 
 struct FakeJsonError(&'static str);
@@ -30,3 +30,13 @@ fn json_decode_error_string_is_prefixed() {
         "expected line/column info in JSON decode error: {formatted}"
     );
 }
+
+#[test]
+/// `json_decode_error_at_string` computes its own `line`/`column`/`char` location from `(doc, pos)`
+/// rather than relying on the caller to have already formatted them in.
+fn json_decode_error_at_string_computes_location() {
+    let doc = r#"{"a": }"#;
+    let formatted = json_decode_error_at_string("Expecting value", doc, 6);
+
+    assert_eq!(formatted, "JSONDecodeError: Expecting value: line 1 column 7 (char 6)");
+}
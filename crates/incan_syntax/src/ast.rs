@@ -243,6 +243,7 @@ pub struct TraitDecl {
 #[derive(Debug, Clone, PartialEq)]
 pub struct NewtypeDecl {
     pub visibility: Visibility,
+    pub decorators: Vec<Spanned<Decorator>>,
     pub name: Ident,
     pub underlying: Spanned<Type>,
     pub methods: Vec<Spanned<MethodDecl>>,
@@ -255,6 +256,7 @@ pub struct NewtypeDecl {
 #[derive(Debug, Clone, PartialEq)]
 pub struct EnumDecl {
     pub visibility: Visibility,
+    pub decorators: Vec<Spanned<Decorator>>,
     pub name: Ident,
     pub type_params: Vec<Ident>,
     pub variants: Vec<Spanned<VariantDecl>>,
@@ -351,6 +353,10 @@ pub enum Type {
     Tuple(Vec<Spanned<Type>>),
     /// Self type - refers to the implementing type in traits
     SelfType,
+    /// Optional type: `Optional[T]` or `T | None`
+    Optional(Box<Spanned<Type>>),
+    /// Union type: `int | str | float` (PEP 604). Always has at least two members.
+    Union(Vec<Spanned<Type>>),
 }
 
 impl fmt::Display for Type {
@@ -389,6 +395,16 @@ impl fmt::Display for Type {
                 write!(f, ")")
             }
             Type::SelfType => write!(f, "Self"),
+            Type::Optional(inner) => write!(f, "Optional[{}]", inner.node),
+            Type::Union(members) => {
+                for (i, m) in members.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, " | ")?;
+                    }
+                    write!(f, "{}", m.node)?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -574,6 +590,11 @@ pub enum Expr {
     ListComp(Box<ListComp>),
     /// Dict comprehension: `{k: v for x in iter if cond}`
     DictComp(Box<DictComp>),
+    /// Set comprehension: `{expr for x in iter if cond}`
+    SetComp(Box<SetComp>),
+    /// Generator expression: `(expr for x in iter if cond)` — lowers to a lazy iterator
+    /// instead of collecting into a container.
+    GenExp(Box<GenExp>),
     /// Closure: `(x, y) => expr` (a lot like python's lambda)
     Closure(Vec<Spanned<Param>>, Box<Spanned<Expr>>),
     /// Tuple: `(a, b)`
@@ -700,10 +721,38 @@ pub enum Pattern {
     Binding(Ident),
     /// Literal: `42`, `"hello"`, `true`
     Literal(Literal),
-    /// Constructor: `Some(x)`, `Ok(value)`
-    Constructor(Ident, Vec<Spanned<Pattern>>),
+    /// Constructor: `Some(x)`, `Ok(value)`, or a class pattern with keyword sub-patterns
+    /// (`Point(x=0, y=y)`); the two argument kinds are mutually exclusive (PEP 634 class
+    /// patterns don't mix `__match_args__` positional matching with keyword fields here).
+    Constructor(Ident, Vec<Spanned<Pattern>>, Vec<(Ident, Spanned<Pattern>)>),
     /// Tuple: `(a, b)`
     Tuple(Vec<Spanned<Pattern>>),
+    /// Sequence with an optional star-rest: `[a, b]`, `[x, *rest, y]`, `[x, *_, y]`
+    Sequence(SequencePattern),
+    /// Mapping: `{"k": v, **rest}`
+    Mapping(MappingPattern),
+    /// Or-pattern: `a | b | c`
+    Or(Vec<Spanned<Pattern>>),
+    /// Capture-with-subpattern: `pattern as name`
+    As(Box<Spanned<Pattern>>, Ident),
+}
+
+/// A sequence pattern's star-rest split into the patterns before and after it.
+///
+/// `rest` is `None` when the sequence has no `*` at all (a fixed-length pattern), `Some(None)`
+/// for a discarded rest (`*_`), and `Some(Some(name))` for a bound rest (`*rest`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct SequencePattern {
+    pub prefix: Vec<Spanned<Pattern>>,
+    pub rest: Option<Option<Ident>>,
+    pub suffix: Vec<Spanned<Pattern>>,
+}
+
+/// A mapping pattern's key/value sub-patterns plus an optional `**rest` capture.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MappingPattern {
+    pub entries: Vec<(Spanned<Expr>, Spanned<Pattern>)>,
+    pub rest: Option<Ident>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -713,21 +762,46 @@ pub struct IfExpr {
     pub else_body: Option<Vec<Spanned<Statement>>>,
 }
 
+/// One clause of a comprehension's `for`/`if` chain, in source order.
+///
+/// `[f(a, b) for a in xs for b in ys if p(a) if q(b)]` parses to four clauses:
+/// `For(a, xs)`, `For(b, ys)`, `If(p(a))`, `If(q(b))`. A later clause's `iter`/`cond` may
+/// reference variables bound by any earlier `For` clause.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CompClause {
+    /// `for var in iter`
+    For { var: Ident, iter: Spanned<Expr> },
+    /// `if cond`
+    If(Spanned<Expr>),
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct ListComp {
     pub expr: Spanned<Expr>,
-    pub var: Ident,
-    pub iter: Spanned<Expr>,
-    pub filter: Option<Spanned<Expr>>,
+    pub clauses: Vec<CompClause>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct DictComp {
     pub key: Spanned<Expr>,
     pub value: Spanned<Expr>,
-    pub var: Ident,
-    pub iter: Spanned<Expr>,
-    pub filter: Option<Spanned<Expr>>,
+    pub clauses: Vec<CompClause>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SetComp {
+    pub expr: Spanned<Expr>,
+    pub clauses: Vec<CompClause>,
+}
+
+/// A parenthesized generator expression: `(expr for x in iter if cond)`.
+///
+/// Shares its shape with [`ListComp`]/[`SetComp`], but lowers to a lazy iterator rather than a
+/// collected container — see `IrType::Iterator`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GenExp {
+    pub expr: Spanned<Expr>,
+    pub clauses: Vec<CompClause>,
 }
 
 // ============================================================================
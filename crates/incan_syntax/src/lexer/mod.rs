@@ -216,6 +216,7 @@ impl<'a> Lexer<'a> {
             ),
             '/' => self.scan_slash(start),
             '%' => self.operator(start, OperatorId::Percent, &[('=', OperatorId::PercentEq)]),
+            '|' => self.operator(start, OperatorId::Pipe, &[]),
             '?' => self.add_punct(PunctuationId::Question, start),
             '@' => self.add_punct(PunctuationId::At, start),
             ',' => self.add_punct(PunctuationId::Comma, start),
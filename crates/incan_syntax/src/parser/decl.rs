@@ -50,9 +50,9 @@ impl<'a> Parser<'a> {
         } else if self.check_keyword(KeywordId::Trait) {
             Declaration::Trait(self.trait_decl(decorators, visibility)?)
         } else if self.check_keyword(KeywordId::Type) || self.check_keyword(KeywordId::Newtype) {
-            Declaration::Newtype(self.newtype_decl(visibility)?)
+            Declaration::Newtype(self.newtype_decl(decorators, visibility)?)
         } else if self.check_keyword(KeywordId::Enum) {
-            Declaration::Enum(self.enum_decl(visibility)?)
+            Declaration::Enum(self.enum_decl(decorators, visibility)?)
         } else if self.check_keyword(KeywordId::Def) || self.check_keyword(KeywordId::Async) {
             Declaration::Function(self.function_decl(decorators, visibility)?)
         } else {
@@ -439,7 +439,11 @@ impl<'a> Parser<'a> {
     }
 
     /// Parse a newtype declaration.
-    fn newtype_decl(&mut self, visibility: Visibility) -> Result<NewtypeDecl, CompileError> {
+    fn newtype_decl(
+        &mut self,
+        decorators: Vec<Spanned<Decorator>>,
+        visibility: Visibility,
+    ) -> Result<NewtypeDecl, CompileError> {
         // Support both: "type X = newtype T" and "newtype X = T"
         if self.match_keyword(KeywordId::Newtype) {
             // newtype X = T syntax
@@ -472,6 +476,7 @@ impl<'a> Parser<'a> {
 
         Ok(NewtypeDecl {
             visibility,
+            decorators,
             name,
             underlying,
             methods,
@@ -479,7 +484,11 @@ impl<'a> Parser<'a> {
     }
 
     /// Parse an enum declaration.
-    fn enum_decl(&mut self, visibility: Visibility) -> Result<EnumDecl, CompileError> {
+    fn enum_decl(
+        &mut self,
+        decorators: Vec<Spanned<Decorator>>,
+        visibility: Visibility,
+    ) -> Result<EnumDecl, CompileError> {
         self.expect_keyword(KeywordId::Enum, "Expected 'enum'")?;
         let name = self.identifier()?;
         let type_params = self.type_params()?;
@@ -498,6 +507,7 @@ impl<'a> Parser<'a> {
 
         Ok(EnumDecl {
             visibility,
+            decorators,
             name,
             type_params,
             variants,
@@ -581,8 +581,43 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Parse a full pattern: an or-pattern of primary patterns, optionally captured with `as name`.
+    ///
+    /// PEP 634's `|` and `as` both bind looser than any single pattern shape (tuple, sequence,
+    /// constructor, ...), so they're handled here, above [`Self::pattern_primary`].
     fn pattern(&mut self) -> Result<Spanned<Pattern>, CompileError> {
         let start = self.current_span().start;
+        let mut pat = self.pattern_primary()?;
+
+        if self.check(&TokenKind::Operator(OperatorId::Pipe)) {
+            let mut alts = vec![pat];
+            while self.match_token(&TokenKind::Operator(OperatorId::Pipe)) {
+                alts.push(self.pattern_primary()?);
+            }
+            let end = self.tokens[self.pos - 1].span.end;
+            pat = Spanned::new(Pattern::Or(alts), Span::new(start, end));
+        }
+
+        if self.match_token(&TokenKind::Keyword(KeywordId::As)) {
+            let TokenKind::Ident(name) = &self.peek().kind else {
+                return Err(CompileError::syntax(
+                    "Expected a name after 'as'".to_string(),
+                    self.current_span(),
+                ));
+            };
+            let name = name.clone();
+            self.advance();
+            let end = self.tokens[self.pos - 1].span.end;
+            pat = Spanned::new(Pattern::As(Box::new(pat), name), Span::new(start, end));
+        }
+
+        Ok(pat)
+    }
+
+    /// Parse a single pattern shape: wildcard, literal, tuple, sequence, mapping, binding, or
+    /// constructor/class pattern. Does not itself handle `|` or `as` (see [`Self::pattern`]).
+    fn pattern_primary(&mut self) -> Result<Spanned<Pattern>, CompileError> {
+        let start = self.current_span().start;
 
         // Wildcard
         if let TokenKind::Ident(name) = &self.peek().kind {
@@ -618,7 +653,17 @@ impl<'a> Parser<'a> {
             return Ok(Spanned::new(Pattern::Tuple(patterns), Span::new(start, end)));
         }
 
-        // Identifier (binding) or constructor pattern
+        // Sequence pattern: [a, b], [x, *rest, y], [x, *_, y]
+        if self.match_token(&TokenKind::Punctuation(PunctuationId::LBracket)) {
+            return self.sequence_pattern(start);
+        }
+
+        // Mapping pattern: {"k": v, **rest}
+        if self.match_token(&TokenKind::Punctuation(PunctuationId::LBrace)) {
+            return self.mapping_pattern(start);
+        }
+
+        // Identifier (binding) or constructor/class pattern
         if let TokenKind::Ident(name) = &self.peek().kind {
             let mut name = name.clone();
             self.advance();
@@ -648,10 +693,23 @@ impl<'a> Parser<'a> {
             }
 
             if self.match_token(&TokenKind::Punctuation(PunctuationId::LParen)) {
-                // Constructor pattern: Some(x), Ok(value), Shape::Circle(r), etc.
+                // Constructor/class pattern: Some(x), Ok(value), Shape::Circle(r), Point(x=0, y=y)
                 let mut patterns = Vec::new();
+                let mut keyword_patterns = Vec::new();
                 if !self.check(&TokenKind::Punctuation(PunctuationId::RParen)) {
                     loop {
+                        if let TokenKind::Ident(field) = &self.peek().kind {
+                            if self.peek_next().kind == TokenKind::Operator(OperatorId::Eq) {
+                                let field = field.clone();
+                                self.advance(); // field name
+                                self.advance(); // '='
+                                keyword_patterns.push((field, self.pattern()?));
+                                if !self.match_token(&TokenKind::Punctuation(PunctuationId::Comma)) {
+                                    break;
+                                }
+                                continue;
+                            }
+                        }
                         patterns.push(self.pattern()?);
                         if !self.match_token(&TokenKind::Punctuation(PunctuationId::Comma)) {
                             break;
@@ -664,7 +722,7 @@ impl<'a> Parser<'a> {
                 )?;
                 let end = self.tokens[self.pos - 1].span.end;
                 return Ok(Spanned::new(
-                    Pattern::Constructor(name, patterns),
+                    Pattern::Constructor(name, patterns, keyword_patterns),
                     Span::new(start, end),
                 ));
             }
@@ -672,7 +730,10 @@ impl<'a> Parser<'a> {
             // Check if this is a unit variant (qualified without parens): Type.Variant
             if name.contains("::") {
                 let end = self.tokens[self.pos - 1].span.end;
-                return Ok(Spanned::new(Pattern::Constructor(name, vec![]), Span::new(start, end)));
+                return Ok(Spanned::new(
+                    Pattern::Constructor(name, vec![], vec![]),
+                    Span::new(start, end),
+                ));
             }
 
             // Just a binding
@@ -686,6 +747,99 @@ impl<'a> Parser<'a> {
         ))
     }
 
+    /// Parse the inside of a sequence pattern after the opening `[` has been consumed.
+    fn sequence_pattern(&mut self, start: usize) -> Result<Spanned<Pattern>, CompileError> {
+        let mut prefix = Vec::new();
+        let mut rest = None;
+        let mut suffix = Vec::new();
+        let mut seen_star = false;
+
+        if !self.check(&TokenKind::Punctuation(PunctuationId::RBracket)) {
+            loop {
+                if self.match_token(&TokenKind::Operator(OperatorId::Star)) {
+                    if seen_star {
+                        return Err(CompileError::syntax(
+                            "A sequence pattern can only have one '*' rest".to_string(),
+                            self.current_span(),
+                        ));
+                    }
+                    seen_star = true;
+                    if let TokenKind::Ident(name) = &self.peek().kind {
+                        if name == "_" {
+                            self.advance();
+                            rest = Some(None);
+                        } else {
+                            rest = Some(Some(name.clone()));
+                            self.advance();
+                        }
+                    } else {
+                        return Err(CompileError::syntax(
+                            "Expected a name or '_' after '*' in a sequence pattern".to_string(),
+                            self.current_span(),
+                        ));
+                    }
+                } else {
+                    let pat = self.pattern()?;
+                    if seen_star {
+                        suffix.push(pat);
+                    } else {
+                        prefix.push(pat);
+                    }
+                }
+                if !self.match_token(&TokenKind::Punctuation(PunctuationId::Comma)) {
+                    break;
+                }
+            }
+        }
+        self.expect(
+            &TokenKind::Punctuation(PunctuationId::RBracket),
+            "Expected ']' after sequence pattern",
+        )?;
+        let end = self.tokens[self.pos - 1].span.end;
+        Ok(Spanned::new(
+            Pattern::Sequence(SequencePattern { prefix, rest, suffix }),
+            Span::new(start, end),
+        ))
+    }
+
+    /// Parse the inside of a mapping pattern after the opening `{` has been consumed.
+    fn mapping_pattern(&mut self, start: usize) -> Result<Spanned<Pattern>, CompileError> {
+        let mut entries = Vec::new();
+        let mut rest = None;
+
+        if !self.check(&TokenKind::Punctuation(PunctuationId::RBrace)) {
+            loop {
+                if self.match_token(&TokenKind::Operator(OperatorId::StarStar)) {
+                    let TokenKind::Ident(name) = &self.peek().kind else {
+                        return Err(CompileError::syntax(
+                            "Expected a name after '**' in a mapping pattern".to_string(),
+                            self.current_span(),
+                        ));
+                    };
+                    rest = Some(name.clone());
+                    self.advance();
+                    break;
+                }
+                let key = self.expression()?;
+                self.expect(
+                    &TokenKind::Punctuation(PunctuationId::Colon),
+                    "Expected ':' after mapping pattern key",
+                )?;
+                let value = self.pattern()?;
+                entries.push((key, value));
+                if !self.match_token(&TokenKind::Punctuation(PunctuationId::Comma)) {
+                    break;
+                }
+            }
+        }
+        self.expect(
+            &TokenKind::Punctuation(PunctuationId::RBrace),
+            "Expected '}' after mapping pattern",
+        )?;
+        let end = self.tokens[self.pos - 1].span.end;
+        Ok(Spanned::new(Pattern::Mapping(MappingPattern { entries, rest }), Span::new(start, end)))
+    }
+
     fn if_expr(&mut self, start: usize) -> Result<Spanned<Expr>, CompileError> {
         self.expect(&TokenKind::Keyword(KeywordId::If), "Expected 'if'")?;
         let condition = self.expression()?;
@@ -96,6 +96,24 @@ def handle(opt: Option[int]) -> int:
         assert_eq!(program.declarations.len(), 1);
     }
 
+    #[test]
+    fn test_parse_union_and_optional_types() {
+        let source = r#"
+def f(a: int | str, b: Optional[int], c: int | str | None) -> bool:
+  return True
+"#;
+        let program = parse_str(source).unwrap();
+        assert_eq!(program.declarations.len(), 1);
+        match &program.declarations[0].node {
+            Declaration::Function(f) => {
+                assert!(matches!(&f.params[0].node.ty.node, Type::Union(members) if members.len() == 2));
+                assert!(matches!(&f.params[1].node.ty.node, Type::Optional(inner) if inner.node == Type::Simple("int".to_string())));
+                assert!(matches!(&f.params[2].node.ty.node, Type::Optional(inner) if matches!(&inner.node, Type::Union(members) if members.len() == 2)));
+            }
+            _ => panic!("Expected function"),
+        }
+    }
+
     #[test]
     fn test_parse_const_decl() {
         let source = r#"
@@ -5,6 +5,7 @@
 /// - Generic applications (`List[int]`)
 /// - Tuple types (`(int, str)`)
 /// - Function types (`(int, str) -> bool`)
+/// - Union and optional types (`int | str`, `T | None`, `Optional[T]`)
 ///
 /// ## Notes
 /// - `Type` parsing is purely syntactic; semantic meaning is handled by later compiler phases.
@@ -26,8 +27,46 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Parse a type expression, including PEP 604 unions (`int | str`) and `Optional[T]`.
+    ///
+    /// Unions are left-associative and bind looser than generic application (`List[int] | str`
+    /// parses as `Union[List[int], str]`, not `List[int | str]`). A union that includes `None`
+    /// collapses into `Type::Optional` over the remaining member(s), matching `Optional[T]`'s
+    /// meaning as sugar for `Union[T, None]`.
     fn type_expr(&mut self) -> Result<Spanned<Type>, CompileError> {
         let start = self.current_span().start;
+        let first = self.type_atom()?;
+
+        if !self.check(&TokenKind::Operator(OperatorId::Pipe)) {
+            return Ok(first);
+        }
+
+        let mut members = vec![first];
+        while self.match_token(&TokenKind::Operator(OperatorId::Pipe)) {
+            members.push(self.type_atom()?);
+        }
+        let end = self.tokens[self.pos - 1].span.end;
+        let span = Span::new(start, end);
+
+        let none_pos = members
+            .iter()
+            .position(|m| matches!(&m.node, Type::Simple(name) if name == "None"));
+        let Some(none_pos) = none_pos else {
+            return Ok(Spanned::new(Type::Union(members), span));
+        };
+        members.remove(none_pos);
+        if members.len() == 1 {
+            Ok(Spanned::new(Type::Optional(Box::new(members.remove(0))), span))
+        } else {
+            Ok(Spanned::new(
+                Type::Optional(Box::new(Spanned::new(Type::Union(members), span))),
+                span,
+            ))
+        }
+    }
+
+    fn type_atom(&mut self) -> Result<Spanned<Type>, CompileError> {
+        let start = self.current_span().start;
 
         // Unit type
         if self.match_token(&TokenKind::Punctuation(PunctuationId::LParen)) {
@@ -114,7 +153,12 @@ impl<'a> Parser<'a> {
                 "Expected ']' after type arguments",
             )?;
             let end = self.tokens[self.pos - 1].span.end;
-            Ok(Spanned::new(Type::Generic(name, args), Span::new(start, end)))
+            let span = Span::new(start, end);
+            if name == "Optional" && args.len() == 1 {
+                Ok(Spanned::new(Type::Optional(Box::new(args.into_iter().next().unwrap())), span))
+            } else {
+                Ok(Spanned::new(Type::Generic(name, args), span))
+            }
         } else {
             let end = self.tokens[self.pos - 1].span.end;
             Ok(Spanned::new(Type::Simple(name), Span::new(start, end)))
@@ -2,14 +2,24 @@
 //!
 //! Handles emitting models, classes, traits, newtypes, and enums.
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use crate::frontend::ast::*;
 use crate::backend::rust_emitter::{RustEmitter, to_rust_ident};
 
+use super::rename::RenameRule;
 use super::types::DunderMethods;
 use super::RustCodegen;
 
+/// Parsed `@error(...)` decorator attributes for an error-type enum.
+#[derive(Debug, Default)]
+struct ErrorAttrs {
+    /// Variant names whose single wrapped field should also get `#[from]`.
+    from_variants: HashSet<String>,
+    /// Variant name -> `{0}`-style display message template.
+    messages: HashMap<String, String>,
+}
+
 impl RustCodegen<'_> {
     /// Emit a declaration
     pub(crate) fn emit_declaration(&mut self, decl: &Spanned<Declaration>) {
@@ -37,7 +47,8 @@ impl RustCodegen<'_> {
         let mut derives_set: HashSet<&str> = ["Debug", "Clone"].into_iter().collect();
         let mut has_serialize = false;
         let mut has_deserialize = false;
-        
+        let mut has_constructor = false;
+
         for dec in &model.decorators {
             if dec.node.name == "derive" {
                 for arg in &dec.node.args {
@@ -49,6 +60,12 @@ impl RustCodegen<'_> {
                             if name == "Deserialize" {
                                 has_deserialize = true;
                             }
+                            // Unlike the other derives here, `Constructor` can't skip defaulted
+                            // fields on its own, so it's a trigger for our own `new()` rather
+                            // than a `derive_to_rust_vec` entry.
+                            if name == "Constructor" {
+                                has_constructor = true;
+                            }
                             for rust_derive in self.derive_to_rust_vec(name) {
                                 derives_set.insert(rust_derive);
                             }
@@ -70,6 +87,20 @@ impl RustCodegen<'_> {
             derives_set.remove("PartialOrd");
             derives_set.remove("Ord");
         }
+        if dunder_methods.has_repr {
+            derives_set.remove("Debug");
+        }
+        // `derive_more`'s operator derives forward field-wise, which only matches a Python
+        // `__add__`/etc. body for a single-field newtype-style model; on a multi-field model it
+        // would silently replace the user's method with a derive that adds every field
+        // independently (and may not even compile if a field doesn't implement the operator).
+        // Only apply the derive where that field-wise forwarding is actually what the model
+        // looks like; otherwise leave the method as a regular (non-trait) impl method below so
+        // its body isn't discarded.
+        let is_newtype_shaped = model.fields.len() == 1;
+        if is_newtype_shaped {
+            derives_set.extend(dunder_methods.derive_more_derives());
+        }
 
         let derives: Vec<&str> = derives_set.into_iter().collect();
 
@@ -82,29 +113,42 @@ impl RustCodegen<'_> {
             })
             .collect();
 
-        self.emitter.struct_def(&derives, "pub", &model.name, &fields);
+        self.emit_struct_with_rename(&derives, "pub", &model.name, &fields, &model.decorators);
 
         let field_names: Vec<String> = model.fields.iter()
             .map(|f| f.node.name.clone())
             .collect();
 
-        // Generate impl block for non-dunder methods plus reflection and JSON methods
+        let constructor_fields = self.constructor_field_specs(&model.fields);
+
+        // Generate impl block for non-dunder methods plus reflection and JSON methods. Operator
+        // dunders that didn't get a `derive_more` derive above (multi-field models) are kept
+        // here as plain methods rather than dropped, so their body survives lowering even though
+        // it doesn't become a `std::ops` trait impl.
         let regular_methods: Vec<_> = model.methods.iter()
-            .filter(|m| !m.node.name.starts_with("__") || m.node.name == "__init__")
+            .filter(|m| {
+                !m.node.name.starts_with("__")
+                    || m.node.name == "__init__"
+                    || (!is_newtype_shaped && Self::is_operator_dunder_method(&m.node.name))
+            })
             .collect();
 
         self.emitter.blank_line();
         self.emitter.impl_block(None, &model.name, |e| {
+            if has_constructor {
+                Self::emit_constructor(e, &constructor_fields);
+            }
+
             for method in &regular_methods {
                 Self::emit_method_in_impl(e, &method.node);
             }
             Self::emit_reflection_methods(e, &model.name, &field_names);
-            
+
             // Emit to_json() if model has Serialize derive
             if has_serialize {
                 Self::emit_to_json_method(e);
             }
-            
+
             // Emit from_json() if model has Deserialize derive
             if has_deserialize {
                 Self::emit_from_json_method(e);
@@ -112,7 +156,7 @@ impl RustCodegen<'_> {
         });
 
         // Generate trait implementations for dunder methods
-        self.emit_dunder_trait_impls(&model.name, &model.methods, &dunder_methods);
+        self.emit_dunder_trait_impls(&model.name, &model.methods, &dunder_methods, &field_names);
     }
 
     /// Emit a class (struct + impl + trait impls)
@@ -122,7 +166,8 @@ impl RustCodegen<'_> {
         let mut derives_set: HashSet<&str> = ["Debug", "Clone"].into_iter().collect();
         let mut has_serialize = false;
         let mut has_deserialize = false;
-        
+        let mut has_constructor = false;
+
         for dec in &class.decorators {
             if dec.node.name == "derive" {
                 for arg in &dec.node.args {
@@ -134,6 +179,9 @@ impl RustCodegen<'_> {
                             if name == "Deserialize" {
                                 has_deserialize = true;
                             }
+                            if name == "Constructor" {
+                                has_constructor = true;
+                            }
                             for rust_derive in self.derive_to_rust_vec(name) {
                                 derives_set.insert(rust_derive);
                             }
@@ -154,8 +202,9 @@ impl RustCodegen<'_> {
             derives_set.remove("PartialOrd");
             derives_set.remove("Ord");
         }
-
-        let derives: Vec<&str> = derives_set.into_iter().collect();
+        if dunder_methods.has_repr {
+            derives_set.remove("Debug");
+        }
 
         // Collect fields including inherited
         let mut fields: Vec<(String, String)> = Vec::new();
@@ -171,12 +220,25 @@ impl RustCodegen<'_> {
             fields.push((to_rust_ident(&f.node.name), rust_type));
         }
 
-        self.emitter.struct_def(&derives, "pub", &class.name, &fields);
+        // See the matching comment in `emit_model`: only let an operator dunder lower to a
+        // `derive_more` derive when the class is shaped like a single-field newtype, since the
+        // derive forwards field-wise and would otherwise silently replace a hand-written
+        // multi-field `__add__`/etc. with something the user didn't write.
+        let is_newtype_shaped = fields.len() == 1;
+        if is_newtype_shaped {
+            derives_set.extend(dunder_methods.derive_more_derives());
+        }
+
+        let derives: Vec<&str> = derives_set.into_iter().collect();
+
+        self.emit_struct_with_rename(&derives, "pub", &class.name, &fields, &class.decorators);
 
         let field_names: Vec<String> = fields.iter()
             .map(|(name, _)| name.clone())
             .collect();
 
+        let constructor_fields = self.get_all_class_constructor_fields(class);
+
         let all_methods = self.get_all_class_methods(class);
 
         let trait_method_names: HashSet<String> = class.traits.iter()
@@ -185,7 +247,11 @@ impl RustCodegen<'_> {
 
         let struct_methods: Vec<_> = all_methods.iter()
             .filter(|m| !trait_method_names.contains(&m.name))
-            .filter(|m| !Self::is_dunder_method(&m.name) || m.name == "__init__")
+            .filter(|m| {
+                !Self::is_dunder_method(&m.name)
+                    || m.name == "__init__"
+                    || (!is_newtype_shaped && Self::is_operator_dunder_method(&m.name))
+            })
             .cloned()
             .collect::<Vec<_>>();
 
@@ -202,16 +268,20 @@ impl RustCodegen<'_> {
         // Emit struct impl
         self.emitter.blank_line();
         self.emitter.impl_block(None, &class.name, |e| {
+            if has_constructor {
+                Self::emit_constructor(e, &constructor_fields);
+            }
+
             for method in &struct_methods {
                 Self::emit_method_in_impl(e, method);
             }
             Self::emit_reflection_methods(e, &class.name, &field_names);
-            
+
             // Emit to_json() if class has Serialize derive
             if has_serialize {
                 Self::emit_to_json_method(e);
             }
-            
+
             // Emit from_json() if class has Deserialize derive
             if has_deserialize {
                 Self::emit_from_json_method(e);
@@ -240,7 +310,138 @@ impl RustCodegen<'_> {
             }
         }
 
-        self.emit_dunder_trait_impls(&class.name, &class.methods, &dunder_methods);
+        self.emit_dunder_trait_impls(&class.name, &class.methods, &dunder_methods, &field_names);
+    }
+
+    /// Emit a struct definition, honoring an optional `@serde(rename_all = "...")` container
+    /// decorator. Field names are assumed `snake_case` (this DSL's own field-naming convention),
+    /// so they're tokenized by splitting on `_` before being re-cased.
+    fn emit_struct_with_rename(
+        &mut self,
+        derives: &[&str],
+        visibility: &str,
+        name: &str,
+        fields: &[(String, String)],
+        decorators: &[Spanned<Decorator>],
+    ) {
+        let rename_all = Self::extract_rename_all(decorators);
+
+        let Some(rule) = rename_all else {
+            self.emitter.struct_def(derives, visibility, name, fields);
+            return;
+        };
+
+        let container_attrs = vec![format!("#[serde(rename_all = \"{}\")]", rule.as_str())];
+        let fields_with_attrs: Vec<(String, String, Option<String>)> = fields
+            .iter()
+            .map(|(field_name, field_type)| {
+                let field_attr = rule.needs_per_field_rename().then(|| {
+                    let words = RenameRule::words_from_snake_case(field_name);
+                    format!("#[serde(rename = \"{}\")]", rule.apply(&words))
+                });
+                (field_name.clone(), field_type.clone(), field_attr)
+            })
+            .collect();
+
+        self.emitter.struct_def_with_attrs(derives, &container_attrs, visibility, name, &fields_with_attrs);
+    }
+
+    /// Extract and validate a `@serde(rename_all = "...")` container decorator, if present.
+    ///
+    /// Returns `None` for a missing decorator, or one whose value isn't a rule this engine
+    /// recognizes, so callers can fall back to the plain, un-renamed struct definition.
+    fn extract_rename_all(decorators: &[Spanned<Decorator>]) -> Option<RenameRule> {
+        for dec in decorators {
+            if dec.node.name != "serde" {
+                continue;
+            }
+            for arg in &dec.node.args {
+                if let DecoratorArg::Named(name, DecoratorArgValue::Expr(value)) = arg {
+                    if name == "rename_all" {
+                        if let Expr::Literal(Literal::String(s)) = &value.node {
+                            return RenameRule::parse(s);
+                        }
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    /// Emit an error-type enum, as produced by an `@error` decorator.
+    ///
+    /// Mirrors `derive_more::Error` + `derive_more::Display`: a variant's single field is assumed
+    /// to be the wrapped upstream error and is annotated `#[error(source)]`, and additionally
+    /// `#[from]` when the variant is named in `@error(from=[...])`, generating a `From` impl for
+    /// `?`-propagation. A per-variant message from `@error(messages={...})` becomes a
+    /// `#[display("...")]` attribute, with `{0}` referring to the variant's single field.
+    fn emit_error_enum(&mut self, en: &EnumDecl, error_attrs: &ErrorAttrs) {
+        let mut derives: Vec<&str> = vec!["Debug"];
+        derives.extend(self.derive_to_rust_vec("Error"));
+
+        self.emitter.line(&format!("#[derive({})]", derives.join(", ")));
+        self.emitter.line(&format!("pub enum {} {{", en.name));
+        self.emitter.indent();
+        for v in &en.variants {
+            let field_types: Vec<String> = v.node.fields.iter().map(|f| Self::type_to_rust_static(&f.node)).collect();
+
+            if let Some(template) = error_attrs.messages.get(&v.node.name) {
+                self.emitter.line(&format!("#[display(\"{}\")]", template.replace("{0}", "{_0}")));
+            }
+
+            match field_types.as_slice() {
+                [] => self.emitter.line(&format!("{},", v.node.name)),
+                [single] => {
+                    let mut field_attrs = vec!["#[error(source)]"];
+                    if error_attrs.from_variants.contains(&v.node.name) {
+                        field_attrs.push("#[from]");
+                    }
+                    self.emitter.line(&format!("{}({} {}),", v.node.name, field_attrs.join(" "), single));
+                }
+                _ => self.emitter.line(&format!("{}({}),", v.node.name, field_types.join(", "))),
+            }
+        }
+        self.emitter.dedent();
+        self.emitter.line("}");
+    }
+
+    /// Extract a container-level `@error(from=[...], messages={...})` decorator, if present.
+    ///
+    /// `from` names the variants whose single wrapped field should also get `#[from]`; `messages`
+    /// maps a variant name to a `{0}`-style display template for that variant.
+    fn extract_error_attrs(decorators: &[Spanned<Decorator>]) -> Option<ErrorAttrs> {
+        let dec = decorators.iter().find(|d| d.node.name == "error")?;
+        let mut attrs = ErrorAttrs::default();
+
+        for arg in &dec.node.args {
+            if let DecoratorArg::Named(name, DecoratorArgValue::Expr(value)) = arg {
+                match name.as_str() {
+                    "from" => {
+                        if let Expr::List(items) = &value.node {
+                            for item in items {
+                                if let Expr::Literal(Literal::String(s)) = &item.node {
+                                    attrs.from_variants.insert(s.clone());
+                                }
+                            }
+                        }
+                    }
+                    "messages" => {
+                        if let Expr::Dict(entries) = &value.node {
+                            for (key, val) in entries {
+                                if let (Expr::Literal(Literal::String(variant)), Expr::Literal(Literal::String(template))) =
+                                    (&key.node, &val.node)
+                                {
+                                    attrs.messages.insert(variant.clone(), template.clone());
+                                }
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        Some(attrs)
     }
 
     /// Emit a trait
@@ -255,12 +456,15 @@ impl RustCodegen<'_> {
     /// Emit a newtype
     fn emit_newtype(&mut self, nt: &NewtypeDecl) {
         let inner_type = self.type_to_rust(&nt.underlying.node);
-        self.emitter.newtype_def(
-            &["Debug", "Clone", "PartialEq", "Eq"],
-            "pub",
-            &nt.name,
-            &inner_type,
-        );
+
+        // Newtypes are the prime use case for derive_more's operator derives: a wrapper like
+        // `UserId(i64)` wants arithmetic delegated straight to the wrapped field, which is
+        // exactly what `derive_more::Add`/`Sub`/... generate for a single-field tuple struct.
+        let dunder_methods = Self::find_dunder_methods(&nt.methods);
+        let mut derives: Vec<&str> = vec!["Debug", "Clone", "PartialEq", "Eq"];
+        derives.extend(dunder_methods.derive_more_derives());
+
+        self.emitter.newtype_def(&derives, "pub", &nt.name, &inner_type);
 
         if !nt.methods.is_empty() {
             self.emitter.blank_line();
@@ -288,12 +492,83 @@ impl RustCodegen<'_> {
             })
             .collect();
 
-        self.emitter.enum_def(
-            &["Debug", "Clone", "PartialEq", "Eq"],
-            "pub",
-            &en.name,
-            &variants,
-        );
+        if let Some(error_attrs) = Self::extract_error_attrs(&en.decorators) {
+            self.emit_error_enum(en, &error_attrs);
+        } else {
+            let rename_all = Self::extract_rename_all(&en.decorators);
+            match rename_all {
+                None => {
+                    self.emitter.enum_def(
+                        &["Debug", "Clone", "PartialEq", "Eq"],
+                        "pub",
+                        &en.name,
+                        &variants,
+                    );
+                }
+                Some(rule) => {
+                    let container_attrs = vec![format!("#[serde(rename_all = \"{}\")]", rule.as_str())];
+                    let variants_with_attrs: Vec<(String, Vec<String>, Option<String>)> = variants
+                        .iter()
+                        .map(|(variant_name, field_types)| {
+                            let variant_attr = rule.needs_per_field_rename().then(|| {
+                                let words = RenameRule::words_from_pascal_case(variant_name);
+                                format!("#[serde(rename = \"{}\")]", rule.apply(&words))
+                            });
+                            (variant_name.clone(), field_types.clone(), variant_attr)
+                        })
+                        .collect();
+                    self.emitter.enum_def_with_attrs(
+                        &["Debug", "Clone", "PartialEq", "Eq"],
+                        &container_attrs,
+                        "pub",
+                        &en.name,
+                        &variants_with_attrs,
+                    );
+                }
+            }
+        }
+
+        // Borrowing derive_more's `is_variant`: emit one `is_<variant>` boolean accessor per
+        // variant, either opted into via `@is_variant` or automatically for fieldless enums
+        // where there's no constructor-style ambiguity to worry about.
+        let all_fieldless = en.variants.iter().all(|v| v.node.fields.is_empty());
+        let wants_is_variant = en.decorators.iter().any(|d| d.node.name == "is_variant") || all_fieldless;
+
+        if wants_is_variant {
+            self.emitter.blank_line();
+            self.emitter.impl_block(None, &en.name, |e| {
+                for v in &en.variants {
+                    let method_name = format!("is_{}", Self::variant_to_snake_case(&v.node.name));
+                    let pattern = if v.node.fields.is_empty() {
+                        format!("{}::{}", en.name, v.node.name)
+                    } else {
+                        format!("{}::{}(..)", en.name, v.node.name)
+                    };
+                    e.blank_line();
+                    e.line(&format!("pub fn {}(&self) -> bool {{", method_name));
+                    e.indent();
+                    e.line(&format!("matches!(self, {})", pattern));
+                    e.dedent();
+                    e.line("}");
+                }
+            });
+        }
+    }
+
+    /// Convert a `PascalCase` variant name to `snake_case` for an `is_variant` method name.
+    fn variant_to_snake_case(name: &str) -> String {
+        let mut result = String::new();
+        for (i, c) in name.char_indices() {
+            if c.is_uppercase() {
+                if i != 0 {
+                    result.push('_');
+                }
+                result.extend(c.to_lowercase());
+            } else {
+                result.push(c);
+            }
+        }
+        result
     }
 
     /// Emit reflection methods
@@ -346,6 +621,24 @@ impl RustCodegen<'_> {
         name.starts_with("__") && name.ends_with("__") && name.len() > 4
     }
 
+    /// Check if a method name is one of the operator dunders that `derive_more_derives` lowers
+    /// to a derive on single-field newtype-shaped structs. Kept in sync with
+    /// `find_dunder_methods`'s `has_add`/etc. arms below.
+    pub(crate) fn is_operator_dunder_method(name: &str) -> bool {
+        matches!(
+            name,
+            "__add__"
+                | "__sub__"
+                | "__mul__"
+                | "__truediv__"
+                | "__neg__"
+                | "__add_assign__"
+                | "__sub_assign__"
+                | "__mul_assign__"
+                | "__div_assign__"
+        )
+    }
+
     /// Find which dunder methods are defined
     pub(crate) fn find_dunder_methods(methods: &[Spanned<MethodDecl>]) -> DunderMethods {
         let mut result = DunderMethods::new();
@@ -356,6 +649,16 @@ impl RustCodegen<'_> {
                 "__hash__" => result.has_hash = true,
                 "__lt__" | "__le__" | "__gt__" | "__ge__" | "__cmp__" => result.has_ord = true,
                 "__str__" => result.has_str = true,
+                "__repr__" => result.has_repr = true,
+                "__add__" => result.has_add = true,
+                "__sub__" => result.has_sub = true,
+                "__mul__" => result.has_mul = true,
+                "__truediv__" => result.has_div = true,
+                "__neg__" => result.has_neg = true,
+                "__add_assign__" => result.has_add_assign = true,
+                "__sub_assign__" => result.has_sub_assign = true,
+                "__mul_assign__" => result.has_mul_assign = true,
+                "__div_assign__" => result.has_div_assign = true,
                 _ => {}
             }
         }
@@ -363,12 +666,76 @@ impl RustCodegen<'_> {
         result
     }
 
+    /// Extract a `@display("template")` format template from a `__str__` method's decorators.
+    fn display_template(method: &MethodDecl) -> Option<String> {
+        let decorator = method.decorators.iter().find(|d| d.node.name == "display")?;
+        decorator.node.args.iter().find_map(|arg| match arg {
+            DecoratorArg::Positional(expr) => match &expr.node {
+                Expr::Literal(Literal::String(s)) => Some(s.clone()),
+                _ => None,
+            },
+            _ => None,
+        })
+    }
+
+    /// Parse a `@display(...)` template into a Rust format string plus the ordered field names
+    /// referenced by its `{field}` placeholders, mirroring derive_more's `Display` attribute.
+    ///
+    /// Returns `Err` if a placeholder doesn't name a field that exists on the type, so the
+    /// caller can fall back to the default `__str__`-forwarding behavior.
+    fn parse_display_template(template: &str, field_names: &[String]) -> Result<(String, Vec<String>), String> {
+        let mut fmt_str = String::new();
+        let mut fields = Vec::new();
+        let mut chars = template.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.peek() == Some(&'{') => {
+                    chars.next();
+                    fmt_str.push_str("{{");
+                }
+                '}' if chars.peek() == Some(&'}') => {
+                    chars.next();
+                    fmt_str.push_str("}}");
+                }
+                '{' => {
+                    let mut field = String::new();
+                    for fc in chars.by_ref() {
+                        if fc == '}' {
+                            break;
+                        }
+                        field.push(fc);
+                    }
+                    if !field_names.iter().any(|f| f == &field) {
+                        return Err(format!("unknown field \"{field}\" in @display template"));
+                    }
+                    fmt_str.push_str("{}");
+                    fields.push(field);
+                }
+                // Escape quotes/backslashes so the literal segment stays valid once embedded in
+                // the generated `write!(f, "...")` string literal.
+                '"' => fmt_str.push_str("\\\""),
+                '\\' => fmt_str.push_str("\\\\"),
+                _ => fmt_str.push(c),
+            }
+        }
+
+        Ok((fmt_str, fields))
+    }
+
     /// Emit trait implementations for dunder methods
-    fn emit_dunder_trait_impls(&mut self, type_name: &str, methods: &[Spanned<MethodDecl>], dunders: &DunderMethods) {
+    fn emit_dunder_trait_impls(
+        &mut self,
+        type_name: &str,
+        methods: &[Spanned<MethodDecl>],
+        dunders: &DunderMethods,
+        field_names: &[String],
+    ) {
         let eq_method = methods.iter().find(|m| m.node.name == "__eq__");
         let hash_method = methods.iter().find(|m| m.node.name == "__hash__");
         let lt_method = methods.iter().find(|m| m.node.name == "__lt__");
         let str_method = methods.iter().find(|m| m.node.name == "__str__");
+        let repr_method = methods.iter().find(|m| m.node.name == "__repr__");
 
         // PartialEq impl
         if dunders.has_eq {
@@ -452,12 +819,54 @@ impl RustCodegen<'_> {
         // Display impl for __str__
         if dunders.has_str {
             if let Some(str_m) = str_method {
+                // A `@display("{field} (...)")` template on `__str__` takes over the whole
+                // impl, the same way derive_more's `Display` format attribute does; otherwise
+                // fall back to forwarding to the method body, as before.
+                let display_body =
+                    Self::display_template(&str_m.node).and_then(|t| Self::parse_display_template(&t, field_names).ok());
+
                 self.emitter.blank_line();
                 self.emitter.line(&format!("impl std::fmt::Display for {} {{", type_name));
                 self.emitter.indent();
                 self.emitter.line("fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {");
                 self.emitter.indent();
-                self.emitter.line("write!(f, \"{}\", self.__str__())");
+                match &display_body {
+                    Some((fmt_str, fields)) if fields.is_empty() => {
+                        self.emitter.line(&format!("write!(f, \"{}\")", fmt_str));
+                    }
+                    Some((fmt_str, fields)) => {
+                        let args = fields.iter().map(|f| format!("self.{}", f)).collect::<Vec<_>>().join(", ");
+                        self.emitter.line(&format!("write!(f, \"{}\", {})", fmt_str, args));
+                    }
+                    None => {
+                        self.emitter.line("write!(f, \"{}\", self.__str__())");
+                    }
+                }
+                self.emitter.dedent();
+                self.emitter.line("}");
+                self.emitter.dedent();
+                self.emitter.line("}");
+
+                // A template fully replaces the need to call into `__str__`, so only keep the
+                // method itself around when Display still forwards to it.
+                if display_body.is_none() {
+                    self.emitter.blank_line();
+                    self.emitter.impl_block(None, type_name, |e| {
+                        Self::emit_method_in_impl(e, &str_m.node);
+                    });
+                }
+            }
+        }
+
+        // Debug impl for __repr__
+        if dunders.has_repr {
+            if let Some(repr_m) = repr_method {
+                self.emitter.blank_line();
+                self.emitter.line(&format!("impl std::fmt::Debug for {} {{", type_name));
+                self.emitter.indent();
+                self.emitter.line("fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {");
+                self.emitter.indent();
+                self.emitter.line("write!(f, \"{}\", self.__repr__())");
                 self.emitter.dedent();
                 self.emitter.line("}");
                 self.emitter.dedent();
@@ -465,7 +874,7 @@ impl RustCodegen<'_> {
 
                 self.emitter.blank_line();
                 self.emitter.impl_block(None, type_name, |e| {
-                    Self::emit_method_in_impl(e, &str_m.node);
+                    Self::emit_method_in_impl(e, &repr_m.node);
                 });
             }
         }
@@ -485,6 +894,71 @@ impl RustCodegen<'_> {
         None
     }
 
+    /// Build `(param_name, rust_type, default_expr)` triples for a `Constructor`-style `new()`,
+    /// preserving declaration order.
+    fn constructor_field_specs(&self, fields: &[Spanned<FieldDecl>]) -> Vec<(String, String, Option<Spanned<Expr>>)> {
+        fields
+            .iter()
+            .map(|f| {
+                let rust_type = self.type_to_rust(&f.node.ty.node);
+                (to_rust_ident(&f.node.name), rust_type, f.node.default.clone())
+            })
+            .collect()
+    }
+
+    /// Same as [`Self::constructor_field_specs`], but gathering fields (including inherited ones)
+    /// the way [`Self::get_all_class_fields`] does for the plain struct field list.
+    fn get_all_class_constructor_fields(&self, class: &ClassDecl) -> Vec<(String, String, Option<Spanned<Expr>>)> {
+        self.constructor_field_specs(&self.get_all_class_field_decls(class))
+    }
+
+    /// Collect a class's fields including inherited ones, preserving the full [`FieldDecl`]
+    /// (unlike [`Self::get_all_class_fields`], which only keeps the name/type pair).
+    fn get_all_class_field_decls(&self, class: &ClassDecl) -> Vec<Spanned<FieldDecl>> {
+        let mut fields = Vec::new();
+
+        if let Some(parent_name) = &class.extends {
+            if let Some(parent_class) = self.find_class(parent_name) {
+                fields.extend(self.get_all_class_field_decls(parent_class));
+            }
+        }
+
+        fields.extend(class.fields.iter().cloned());
+        fields
+    }
+
+    /// Emit a `derive_more::Constructor`-inspired `new` constructor: one parameter per field in
+    /// declaration order, except fields with a `default`, which are filled in from their default
+    /// expression instead of being taken as a parameter.
+    fn emit_constructor(emitter: &mut RustEmitter, fields: &[(String, String, Option<Spanned<Expr>>)]) {
+        let params: Vec<String> = fields
+            .iter()
+            .filter(|(_, _, default)| default.is_none())
+            .map(|(name, ty, _)| format!("{}: {}", name, ty))
+            .collect();
+
+        emitter.blank_line();
+        emitter.line(&format!("pub fn new({}) -> Self {{", params.join(", ")));
+        emitter.indent();
+        emitter.line("Self {");
+        emitter.indent();
+        for (name, _, default) in fields {
+            match default {
+                Some(expr) => {
+                    emitter.write_indent();
+                    emitter.write(&format!("{}: ", name));
+                    Self::emit_expr(emitter, &expr.node);
+                    emitter.write(",\n");
+                }
+                None => emitter.line(&format!("{},", name)),
+            }
+        }
+        emitter.dedent();
+        emitter.line("}");
+        emitter.dedent();
+        emitter.line("}");
+    }
+
     /// Get all fields for a class including inherited
     pub(crate) fn get_all_class_fields(&self, class: &ClassDecl) -> Vec<(String, String)> {
         let mut fields = Vec::new();
@@ -558,6 +1032,7 @@ mod tests {
         assert!(!dunder.has_hash);
         assert!(!dunder.has_ord);
         assert!(!dunder.has_str);
+        assert!(!dunder.has_repr);
     }
 
     #[test]
@@ -631,8 +1106,7 @@ mod tests {
 
     #[test]
     fn test_find_dunder_methods_repr() {
-        // __repr__ is NOT the same as __str__ in the current implementation
-        // Only __str__ triggers has_str
+        // __repr__ is distinct from __str__: it drives a custom Debug impl, not Display.
         let methods = vec![
             make_spanned(MethodDecl {
                 decorators: vec![],
@@ -645,6 +1119,7 @@ mod tests {
             }),
         ];
         let dunder = RustCodegen::find_dunder_methods(&methods);
+        assert!(dunder.has_repr);
         // __repr__ doesn't set has_str
         assert!(!dunder.has_str);
     }
@@ -920,4 +1395,219 @@ mod tests {
         assert!(derives.contains(&"Eq"));
         assert!(derives.contains(&"PartialEq"));
     }
+
+    // ========================================
+    // @serde(rename_all) tests
+    // ========================================
+
+    fn make_serde_rename_all_decorator(rule: &str) -> Spanned<Decorator> {
+        make_spanned(Decorator {
+            name: "serde".to_string(),
+            args: vec![DecoratorArg::Named(
+                "rename_all".to_string(),
+                DecoratorArgValue::Expr(make_spanned(Expr::Literal(Literal::String(rule.to_string())))),
+            )],
+        })
+    }
+
+    #[test]
+    fn test_extract_rename_all_absent() {
+        assert_eq!(RustCodegen::extract_rename_all(&[]), None);
+    }
+
+    #[test]
+    fn test_extract_rename_all_unknown_rule() {
+        let decorators = vec![make_serde_rename_all_decorator("bogusCase")];
+        assert_eq!(RustCodegen::extract_rename_all(&decorators), None);
+    }
+
+    #[test]
+    fn test_extract_rename_all_known_rule() {
+        let decorators = vec![make_serde_rename_all_decorator("camelCase")];
+        assert_eq!(RustCodegen::extract_rename_all(&decorators), Some(RenameRule::CamelCase));
+    }
+
+    #[test]
+    fn test_emit_model_with_rename_all() {
+        let mut codegen = RustCodegen::new();
+        let model = ModelDecl {
+            decorators: vec![make_serde_rename_all_decorator("camelCase")],
+            name: "User".to_string(),
+            type_params: vec![],
+            fields: vec![make_spanned(FieldDecl {
+                name: "user_id".to_string(),
+                ty: make_spanned(Type::Simple("int".to_string())),
+                default: None,
+            })],
+            methods: vec![],
+        };
+        codegen.emit_model(&model);
+        let output = codegen.emitter.finish();
+        assert!(output.contains("#[serde(rename_all = \"camelCase\")]"));
+        assert!(output.contains("pub user_id: i64"));
+        // camelCase round-trips cleanly, so no per-field override is needed.
+        assert!(!output.contains("#[serde(rename = "));
+    }
+
+    #[test]
+    fn test_emit_model_with_lowercase_rename_all_per_field() {
+        let mut codegen = RustCodegen::new();
+        let model = ModelDecl {
+            decorators: vec![make_serde_rename_all_decorator("lowercase")],
+            name: "User".to_string(),
+            type_params: vec![],
+            fields: vec![make_spanned(FieldDecl {
+                name: "user_id".to_string(),
+                ty: make_spanned(Type::Simple("int".to_string())),
+                default: None,
+            })],
+            methods: vec![],
+        };
+        codegen.emit_model(&model);
+        let output = codegen.emitter.finish();
+        assert!(output.contains("#[serde(rename_all = \"lowercase\")]"));
+        assert!(output.contains("#[serde(rename = \"userid\")]"));
+    }
+
+    #[test]
+    fn test_emit_enum_with_rename_all() {
+        let mut codegen = RustCodegen::new();
+        let enum_decl = EnumDecl {
+            decorators: vec![make_serde_rename_all_decorator("snake_case")],
+            name: "Color".to_string(),
+            type_params: vec![],
+            variants: vec![make_spanned(VariantDecl {
+                name: "LightBlue".to_string(),
+                fields: vec![],
+            })],
+        };
+        codegen.emit_enum(&enum_decl);
+        let output = codegen.emitter.finish();
+        assert!(output.contains("#[serde(rename_all = \"snake_case\")]"));
+        assert!(output.contains("LightBlue,"));
+    }
+
+    // ========================================
+    // @error enum tests
+    // ========================================
+
+    fn make_error_decorator(args: Vec<DecoratorArg>) -> Spanned<Decorator> {
+        make_spanned(Decorator { name: "error".to_string(), args })
+    }
+
+    #[test]
+    fn test_extract_error_attrs_absent() {
+        assert!(RustCodegen::extract_error_attrs(&[]).is_none());
+    }
+
+    #[test]
+    fn test_extract_error_attrs_from_list() {
+        let decorators = vec![make_error_decorator(vec![DecoratorArg::Named(
+            "from".to_string(),
+            DecoratorArgValue::Expr(make_spanned(Expr::List(vec![make_spanned(Expr::Literal(Literal::String(
+                "Io".to_string(),
+            )))]))),
+        )])];
+        let attrs = RustCodegen::extract_error_attrs(&decorators).unwrap();
+        assert!(attrs.from_variants.contains("Io"));
+    }
+
+    #[test]
+    fn test_emit_enum_with_error_decorator() {
+        let mut codegen = RustCodegen::new();
+        let enum_decl = EnumDecl {
+            decorators: vec![make_error_decorator(vec![
+                DecoratorArg::Named(
+                    "from".to_string(),
+                    DecoratorArgValue::Expr(make_spanned(Expr::List(vec![make_spanned(Expr::Literal(
+                        Literal::String("Io".to_string()),
+                    ))]))),
+                ),
+                DecoratorArg::Named(
+                    "messages".to_string(),
+                    DecoratorArgValue::Expr(make_spanned(Expr::Dict(vec![(
+                        make_spanned(Expr::Literal(Literal::String("Io".to_string()))),
+                        make_spanned(Expr::Literal(Literal::String("I/O failure: {0}".to_string()))),
+                    )]))),
+                ),
+            ])],
+            name: "AppError".to_string(),
+            type_params: vec![],
+            variants: vec![
+                make_spanned(VariantDecl {
+                    name: "Io".to_string(),
+                    fields: vec![make_spanned(Type::Simple("str".to_string()))],
+                }),
+                make_spanned(VariantDecl {
+                    name: "NotFound".to_string(),
+                    fields: vec![],
+                }),
+            ],
+        };
+        codegen.emit_enum(&enum_decl);
+        let output = codegen.emitter.finish();
+        assert!(output.contains("derive_more::Error"));
+        assert!(output.contains("derive_more::Display"));
+        assert!(output.contains("#[display(\"I/O failure: {_0}\")]"));
+        assert!(output.contains("#[error(source)] #[from] String"));
+        assert!(output.contains("NotFound,"));
+    }
+
+    // ========================================
+    // @derive(Constructor) tests
+    // ========================================
+
+    fn make_constructor_decorator() -> Spanned<Decorator> {
+        make_spanned(Decorator {
+            name: "derive".to_string(),
+            args: vec![DecoratorArg::Positional(make_spanned(Expr::Ident("Constructor".to_string())))],
+        })
+    }
+
+    #[test]
+    fn test_emit_model_with_constructor() {
+        let mut codegen = RustCodegen::new();
+        let model = ModelDecl {
+            decorators: vec![make_constructor_decorator()],
+            name: "User".to_string(),
+            type_params: vec![],
+            fields: vec![
+                make_spanned(FieldDecl {
+                    name: "name".to_string(),
+                    ty: make_spanned(Type::Simple("str".to_string())),
+                    default: None,
+                }),
+                make_spanned(FieldDecl {
+                    name: "age".to_string(),
+                    ty: make_spanned(Type::Simple("int".to_string())),
+                    default: Some(make_spanned(Expr::Literal(Literal::Int(0)))),
+                }),
+            ],
+            methods: vec![],
+        };
+        codegen.emit_model(&model);
+        let output = codegen.emitter.finish();
+        assert!(output.contains("pub fn new(name: String) -> Self {"));
+        assert!(output.contains("name,"));
+        assert!(output.contains("age: 0,"));
+    }
+
+    #[test]
+    fn test_emit_model_without_constructor_decorator() {
+        let mut codegen = RustCodegen::new();
+        let model = ModelDecl {
+            decorators: vec![],
+            name: "User".to_string(),
+            type_params: vec![],
+            fields: vec![make_spanned(FieldDecl {
+                name: "name".to_string(),
+                ty: make_spanned(Type::Simple("str".to_string())),
+                default: None,
+            })],
+            methods: vec![],
+        };
+        codegen.emit_model(&model);
+        let output = codegen.emitter.finish();
+        assert!(!output.contains("pub fn new("));
+    }
 }
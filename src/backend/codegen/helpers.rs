@@ -2,82 +2,303 @@
 //!
 //! Shared utilities used by multiple codegen modules.
 
+use std::collections::HashSet;
+
 use crate::frontend::ast::*;
 
 /// Check if a variable is used in an expression
-/// 
-/// Recursively traverses the expression tree to determine if the given variable name
-/// appears anywhere in the expression. This is used to suppress unused variable warnings
-/// when a loop/comprehension variable is actually used in the body/filter/map expression.
+///
+/// A variable counts as used if it appears as a free variable anywhere in `expr` - see
+/// [`collect_free_vars`] for what "free" means here. Used to suppress unused variable
+/// warnings when a loop/comprehension variable is actually used in the body/filter/map
+/// expression.
 pub(super) fn is_var_used_in_expr(var_name: &str, expr: &Expr) -> bool {
+    collect_free_vars(expr).contains(var_name)
+}
+
+/// Collect every free variable referenced in `expr`: names that are used but not bound,
+/// within `expr` itself, by an enclosing comprehension target, `for` binding, or closure
+/// parameter.
+///
+/// This is a proper AST walk (in the style of rustc's `visit.rs` walk functions) that
+/// recurses through every `Expr` variant and the statements inside `If`/block bodies and
+/// comprehension clauses, replacing the old conservative version of `is_var_used_in_expr`
+/// that OR'd in `|| true` for `Expr::If` branches and never looked inside comprehensions,
+/// closures, or statement blocks at all.
+///
+/// The critical invariant is scoping: a variable bound by an inner comprehension target,
+/// `for` binding, or closure parameter shadows an identically-named outer variable, so uses
+/// under that binder don't count as uses of the outer name.
+pub(super) fn collect_free_vars(expr: &Expr) -> HashSet<&str> {
+    let mut free = HashSet::new();
+    let mut bound = Vec::new();
+    walk_expr(expr, &mut bound, &mut free);
+    free
+}
+
+/// Record a use of `name`, unless it's shadowed by a binder currently in scope.
+fn use_name<'a>(name: &'a str, bound: &[&str], free: &mut HashSet<&'a str>) {
+    if !bound.contains(&name) {
+        free.insert(name);
+    }
+}
+
+fn walk_expr<'a>(expr: &'a Expr, bound: &mut Vec<&'a str>, free: &mut HashSet<&'a str>) {
     match expr {
-        // Simple identifier: direct string comparison
-        Expr::Ident(name) => name == var_name,
-        
-        // Binary operations (e.g., x + y, a * b): check both operands
+        Expr::Ident(name) => use_name(name, bound, free),
+
+        Expr::Literal(_) | Expr::SelfExpr => {}
+
         Expr::Binary(left, _, right) => {
-            is_var_used_in_expr(var_name, &left.node) ||
-            is_var_used_in_expr(var_name, &right.node)
-        }
-        
-        // Unary operations (e.g., -x, not y): check the inner expression
-        Expr::Unary(_, e) => is_var_used_in_expr(var_name, &e.node),
-        
-        // Function calls (e.g., foo(x, y)): check the callee and all arguments
+            walk_expr(&left.node, bound, free);
+            walk_expr(&right.node, bound, free);
+        }
+
+        Expr::Unary(_, e) => walk_expr(&e.node, bound, free),
+
         Expr::Call(callee, args) => {
-            is_var_used_in_expr(var_name, &callee.node) ||
-            args.iter().any(|arg| match arg {
-                CallArg::Positional(e) => is_var_used_in_expr(var_name, &e.node),
-                CallArg::Named(_, e) => is_var_used_in_expr(var_name, &e.node),
-            })
-        }
-        
-        // Method calls (e.g., obj.method(x)): check the object and all arguments
-        Expr::MethodCall(obj, _, args) => {
-            is_var_used_in_expr(var_name, &obj.node) ||
-            args.iter().any(|arg| match arg {
-                CallArg::Positional(e) => is_var_used_in_expr(var_name, &e.node),
-                CallArg::Named(_, e) => is_var_used_in_expr(var_name, &e.node),
-            })
-        }
-        
-        // Index access (e.g., arr[i]): check both the base and the index expression
+            walk_expr(&callee.node, bound, free);
+            walk_call_args(args, bound, free);
+        }
+
         Expr::Index(base, index) => {
-            is_var_used_in_expr(var_name, &base.node) ||
-            is_var_used_in_expr(var_name, &index.node)
-        }
-        
-        // Field access (e.g., obj.field): only check the base object
-        Expr::Field(base, _) => is_var_used_in_expr(var_name, &base.node),
-        
-        // List literals (e.g., [x, y, z]): check if variable appears in any element
-        Expr::List(items) => items.iter().any(|e| is_var_used_in_expr(var_name, &e.node)),
-        
-        // Dict literals (e.g., {k: v}): check both keys and values
-        Expr::Dict(pairs) => pairs.iter().any(|(k, v)| {
-            is_var_used_in_expr(var_name, &k.node) ||
-            is_var_used_in_expr(var_name, &v.node)
-        }),
-        
-        // Range expressions (e.g., start..end): check both bounds
-        Expr::Range { start, end, inclusive: _ } => {
-            is_var_used_in_expr(var_name, &start.node) ||
-            is_var_used_in_expr(var_name, &end.node)
-        }
-        
-        // F-strings (e.g., f"hello {x}"): check interpolated expressions
-        Expr::FString(parts) => parts.iter().any(|part| match part {
-            FStringPart::Literal(_) => false,
-            FStringPart::Expr(e) => is_var_used_in_expr(var_name, &e.node),
-        }),
-        
-        // If expressions: check condition, conservatively assume variable is used in branches
-        // (branches contain statements, which would require more complex analysis)
+            walk_expr(&base.node, bound, free);
+            walk_expr(&index.node, bound, free);
+        }
+
+        Expr::Slice(base, slice) => {
+            walk_expr(&base.node, bound, free);
+            if let Some(start) = &slice.start {
+                walk_expr(&start.node, bound, free);
+            }
+            if let Some(end) = &slice.end {
+                walk_expr(&end.node, bound, free);
+            }
+            if let Some(step) = &slice.step {
+                walk_expr(&step.node, bound, free);
+            }
+        }
+
+        Expr::Field(base, _) => walk_expr(&base.node, bound, free),
+
+        Expr::MethodCall(obj, _, args) => {
+            walk_expr(&obj.node, bound, free);
+            walk_call_args(args, bound, free);
+        }
+
+        Expr::Await(e) | Expr::Try(e) => walk_expr(&e.node, bound, free),
+
+        Expr::Match(scrutinee, arms) => {
+            walk_expr(&scrutinee.node, bound, free);
+            for arm in arms {
+                let depth = bound.len();
+                bind_pattern(&arm.node.pattern.node, bound);
+                if let Some(guard) = &arm.node.guard {
+                    walk_expr(&guard.node, bound, free);
+                }
+                match &arm.node.body {
+                    MatchBody::Expr(e) => walk_expr(&e.node, bound, free),
+                    MatchBody::Block(stmts) => walk_block(stmts, bound, free),
+                }
+                bound.truncate(depth);
+            }
+        }
+
+        // The branches of an if-expression are statement blocks, not bare expressions - walk
+        // them with `walk_block` instead of conservatively assuming every outer variable is
+        // used, as the old implementation did.
         Expr::If(if_expr) => {
-            is_var_used_in_expr(var_name, &if_expr.condition.node) || true
+            walk_expr(&if_expr.condition.node, bound, free);
+            walk_block(&if_expr.then_body, bound, free);
+            if let Some(else_body) = &if_expr.else_body {
+                walk_block(else_body, bound, free);
+            }
+        }
+
+        Expr::ListComp(comp) => {
+            walk_expr(&comp.iter.node, bound, free);
+            let depth = bound.len();
+            bound.push(&comp.var);
+            if let Some(filter) = &comp.filter {
+                walk_expr(&filter.node, bound, free);
+            }
+            walk_expr(&comp.expr.node, bound, free);
+            bound.truncate(depth);
+        }
+
+        Expr::DictComp(comp) => {
+            walk_expr(&comp.iter.node, bound, free);
+            let depth = bound.len();
+            bound.push(&comp.var);
+            if let Some(filter) = &comp.filter {
+                walk_expr(&filter.node, bound, free);
+            }
+            walk_expr(&comp.key.node, bound, free);
+            walk_expr(&comp.value.node, bound, free);
+            bound.truncate(depth);
+        }
+
+        Expr::Closure(params, body) => {
+            let depth = bound.len();
+            for param in params {
+                bound.push(&param.node.name);
+            }
+            walk_expr(&body.node, bound, free);
+            bound.truncate(depth);
+        }
+
+        Expr::Tuple(items) | Expr::List(items) | Expr::Set(items) => {
+            for item in items {
+                walk_expr(&item.node, bound, free);
+            }
+        }
+
+        Expr::Dict(pairs) => {
+            for (k, v) in pairs {
+                walk_expr(&k.node, bound, free);
+                walk_expr(&v.node, bound, free);
+            }
+        }
+
+        Expr::Paren(e) => walk_expr(&e.node, bound, free),
+
+        Expr::Constructor(_, args) => walk_call_args(args, bound, free),
+
+        Expr::FString(parts) => {
+            for part in parts {
+                if let FStringPart::Expr(e) = part {
+                    walk_expr(&e.node, bound, free);
+                }
+            }
+        }
+
+        Expr::Yield(e) => {
+            if let Some(e) = e {
+                walk_expr(&e.node, bound, free);
+            }
+        }
+
+        Expr::Range { start, end, inclusive: _ } => {
+            walk_expr(&start.node, bound, free);
+            walk_expr(&end.node, bound, free);
+        }
+    }
+}
+
+fn walk_call_args<'a>(args: &'a [CallArg], bound: &mut Vec<&'a str>, free: &mut HashSet<&'a str>) {
+    for arg in args {
+        match arg {
+            CallArg::Positional(e) => walk_expr(&e.node, bound, free),
+            CallArg::Named(_, e) => walk_expr(&e.node, bound, free),
+        }
+    }
+}
+
+/// Bind every name a pattern introduces (e.g. `Some(x)` binds `x`), so the match arm's guard
+/// and body treat it as a local rather than a free reference to an outer variable.
+fn bind_pattern<'a>(pattern: &'a Pattern, bound: &mut Vec<&'a str>) {
+    match pattern {
+        Pattern::Wildcard | Pattern::Literal(_) => {}
+        Pattern::Binding(name) => bound.push(name),
+        Pattern::Constructor(_, patterns) | Pattern::Tuple(patterns) => {
+            for p in patterns {
+                bind_pattern(&p.node, bound);
+            }
+        }
+    }
+}
+
+/// Walk a statement block in its own scope: bindings introduced by statements inside (`for`
+/// loop variables, `let`/assignment targets, tuple unpacking, ...) shadow identically-named
+/// outer variables for the rest of the block, and fall out of scope once the block ends.
+fn walk_block<'a>(stmts: &'a [Spanned<Statement>], bound: &mut Vec<&'a str>, free: &mut HashSet<&'a str>) {
+    let depth = bound.len();
+    for stmt in stmts {
+        walk_stmt(&stmt.node, bound, free);
+    }
+    bound.truncate(depth);
+}
+
+fn walk_stmt<'a>(stmt: &'a Statement, bound: &mut Vec<&'a str>, free: &mut HashSet<&'a str>) {
+    match stmt {
+        Statement::Assignment(a) => {
+            walk_expr(&a.value.node, bound, free);
+            bound.push(&a.name);
+        }
+
+        Statement::FieldAssignment(a) => {
+            walk_expr(&a.object.node, bound, free);
+            walk_expr(&a.value.node, bound, free);
+        }
+
+        Statement::IndexAssignment(a) => {
+            walk_expr(&a.object.node, bound, free);
+            walk_expr(&a.index.node, bound, free);
+            walk_expr(&a.value.node, bound, free);
+        }
+
+        Statement::Return(e) => {
+            if let Some(e) = e {
+                walk_expr(&e.node, bound, free);
+            }
+        }
+
+        Statement::If(if_stmt) => {
+            walk_expr(&if_stmt.condition.node, bound, free);
+            walk_block(&if_stmt.then_body, bound, free);
+            for (cond, body) in &if_stmt.elif_branches {
+                walk_expr(&cond.node, bound, free);
+                walk_block(body, bound, free);
+            }
+            if let Some(else_body) = &if_stmt.else_body {
+                walk_block(else_body, bound, free);
+            }
+        }
+
+        Statement::While(w) => {
+            walk_expr(&w.condition.node, bound, free);
+            walk_block(&w.body, bound, free);
+        }
+
+        // The loop variable is bound fresh by the `for`, so it shadows any outer variable of
+        // the same name for the duration of the body - this is the shadowing case the old
+        // implementation couldn't express at all.
+        Statement::For(f) => {
+            walk_expr(&f.iter.node, bound, free);
+            let depth = bound.len();
+            bound.push(&f.var);
+            walk_block(&f.body, bound, free);
+            bound.truncate(depth);
+        }
+
+        Statement::Expr(e) => walk_expr(&e.node, bound, free),
+
+        Statement::Pass | Statement::Break | Statement::Continue => {}
+
+        Statement::CompoundAssignment(c) => {
+            use_name(&c.name, bound, free);
+            walk_expr(&c.value.node, bound, free);
+        }
+
+        Statement::TupleUnpack(t) => {
+            walk_expr(&t.value.node, bound, free);
+            for name in &t.names {
+                bound.push(name);
+            }
+        }
+
+        Statement::TupleAssign(t) => {
+            for target in &t.targets {
+                walk_expr(&target.node, bound, free);
+            }
+            walk_expr(&t.value.node, bound, free);
+        }
+
+        Statement::ChainedAssignment(c) => {
+            walk_expr(&c.value.node, bound, free);
+            for name in &c.targets {
+                bound.push(name);
+            }
         }
-        
-        // Literals (numbers, strings, booleans, None) don't reference variables
-        _ => false,
     }
 }
@@ -0,0 +1,197 @@
+//! Identifier case-conversion rules for `@serde(rename_all = "...")`.
+//!
+//! Mirrors the casing rules serde itself accepts for `#[serde(rename_all = "...")]`. Unlike a
+//! simple string transform, this engine tokenizes an identifier into words first and rejoins per
+//! target rule, which lets it re-case both `snake_case` field names and `PascalCase` variant
+//! names from the same set of rules.
+
+/// A known `rename_all` casing rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RenameRule {
+    Lowercase,
+    Uppercase,
+    PascalCase,
+    CamelCase,
+    SnakeCase,
+    ScreamingSnakeCase,
+    KebabCase,
+    ScreamingKebabCase,
+}
+
+impl RenameRule {
+    /// Parse the serde-recognized spelling of a `rename_all` rule (e.g. `"camelCase"`).
+    ///
+    /// Returns `None` for any string that isn't one of the rules serde accepts.
+    pub(crate) fn parse(rule: &str) -> Option<Self> {
+        match rule {
+            "lowercase" => Some(Self::Lowercase),
+            "UPPERCASE" => Some(Self::Uppercase),
+            "PascalCase" => Some(Self::PascalCase),
+            "camelCase" => Some(Self::CamelCase),
+            "snake_case" => Some(Self::SnakeCase),
+            "SCREAMING_SNAKE_CASE" => Some(Self::ScreamingSnakeCase),
+            "kebab-case" => Some(Self::KebabCase),
+            "SCREAMING-KEBAB-CASE" => Some(Self::ScreamingKebabCase),
+            _ => None,
+        }
+    }
+
+    /// The serde-recognized spelling for this rule, as would appear in
+    /// `#[serde(rename_all = "...")]`.
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            Self::Lowercase => "lowercase",
+            Self::Uppercase => "UPPERCASE",
+            Self::PascalCase => "PascalCase",
+            Self::CamelCase => "camelCase",
+            Self::SnakeCase => "snake_case",
+            Self::ScreamingSnakeCase => "SCREAMING_SNAKE_CASE",
+            Self::KebabCase => "kebab-case",
+            Self::ScreamingKebabCase => "SCREAMING-KEBAB-CASE",
+        }
+    }
+
+    /// Whether this rule collapses word boundaries (`lowercase`/`UPPERCASE` run every word
+    /// together with no separator), which means a container-level `#[serde(rename_all = "...")]`
+    /// alone can't be trusted to round-trip distinguishable field names. Callers should fall back
+    /// to a precomputed per-field `#[serde(rename = "...")]` in that case.
+    pub(crate) fn needs_per_field_rename(&self) -> bool {
+        matches!(self, Self::Lowercase | Self::Uppercase)
+    }
+
+    /// Tokenize a `snake_case` identifier into words by splitting on `_`.
+    pub(crate) fn words_from_snake_case(ident: &str) -> Vec<&str> {
+        ident.split('_').filter(|w| !w.is_empty()).collect()
+    }
+
+    /// Tokenize a `PascalCase` identifier into words by splitting before each interior uppercase
+    /// letter (so `HttpError` -> `["Http", "Error"]`, `UserId` -> `["User", "Id"]`).
+    pub(crate) fn words_from_pascal_case(ident: &str) -> Vec<String> {
+        let mut words = Vec::new();
+        let mut current = String::new();
+        for c in ident.chars() {
+            if c.is_uppercase() && !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            current.push(c);
+        }
+        if !current.is_empty() {
+            words.push(current);
+        }
+        words
+    }
+
+    /// Apply this rule to a sequence of words, rejoining per the target casing.
+    ///
+    /// An all-digit word (e.g. the `2` in `user_2`) is preserved verbatim rather than having its
+    /// (nonexistent) first letter capitalized; a single-word input is still re-cased like any
+    /// other word, just with no separator to place.
+    pub(crate) fn apply(&self, words: &[impl AsRef<str>]) -> String {
+        let words: Vec<&str> = words.iter().map(|w| w.as_ref()).collect();
+        match self {
+            Self::Lowercase => words.iter().map(|w| w.to_lowercase()).collect::<Vec<_>>().join(""),
+            Self::Uppercase => words.iter().map(|w| w.to_uppercase()).collect::<Vec<_>>().join(""),
+            Self::SnakeCase => words.iter().map(|w| w.to_lowercase()).collect::<Vec<_>>().join("_"),
+            Self::ScreamingSnakeCase => words.iter().map(|w| w.to_uppercase()).collect::<Vec<_>>().join("_"),
+            Self::KebabCase => words.iter().map(|w| w.to_lowercase()).collect::<Vec<_>>().join("-"),
+            Self::ScreamingKebabCase => words.iter().map(|w| w.to_uppercase()).collect::<Vec<_>>().join("-"),
+            Self::PascalCase => words.iter().map(|w| Self::capitalize(w)).collect::<Vec<_>>().join(""),
+            Self::CamelCase => words
+                .iter()
+                .enumerate()
+                .map(|(i, w)| if i == 0 { w.to_lowercase() } else { Self::capitalize(w) })
+                .collect::<Vec<_>>()
+                .join(""),
+        }
+    }
+
+    /// Capitalize a word's first character, leaving an all-digit word unchanged.
+    fn capitalize(word: &str) -> String {
+        if word.chars().all(|c| c.is_ascii_digit()) {
+            return word.to_string();
+        }
+        let mut chars = word.chars();
+        match chars.next() {
+            Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+            None => String::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_known_rules() {
+        assert_eq!(RenameRule::parse("camelCase"), Some(RenameRule::CamelCase));
+        assert_eq!(RenameRule::parse("kebab-case"), Some(RenameRule::KebabCase));
+        assert_eq!(RenameRule::parse("bogus"), None);
+    }
+
+    #[test]
+    fn test_as_str_round_trips_parse() {
+        for rule in [
+            RenameRule::Lowercase,
+            RenameRule::Uppercase,
+            RenameRule::PascalCase,
+            RenameRule::CamelCase,
+            RenameRule::SnakeCase,
+            RenameRule::ScreamingSnakeCase,
+            RenameRule::KebabCase,
+            RenameRule::ScreamingKebabCase,
+        ] {
+            assert_eq!(RenameRule::parse(rule.as_str()), Some(rule));
+        }
+    }
+
+    #[test]
+    fn test_words_from_snake_case() {
+        assert_eq!(RenameRule::words_from_snake_case("user_id"), vec!["user", "id"]);
+        assert_eq!(RenameRule::words_from_snake_case("name"), vec!["name"]);
+    }
+
+    #[test]
+    fn test_words_from_pascal_case() {
+        assert_eq!(RenameRule::words_from_pascal_case("HttpError"), vec!["Http", "Error"]);
+        assert_eq!(RenameRule::words_from_pascal_case("Red"), vec!["Red"]);
+    }
+
+    #[test]
+    fn test_apply_field_name_camel_case() {
+        let words = RenameRule::words_from_snake_case("user_id");
+        assert_eq!(RenameRule::CamelCase.apply(&words), "userId");
+    }
+
+    #[test]
+    fn test_apply_field_name_kebab_case() {
+        let words = RenameRule::words_from_snake_case("user_id");
+        assert_eq!(RenameRule::KebabCase.apply(&words), "user-id");
+    }
+
+    #[test]
+    fn test_apply_variant_name_snake_case() {
+        let words = RenameRule::words_from_pascal_case("HttpError");
+        assert_eq!(RenameRule::SnakeCase.apply(&words), "http_error");
+    }
+
+    #[test]
+    fn test_apply_preserves_all_digit_segment() {
+        let words = RenameRule::words_from_snake_case("user_2");
+        assert_eq!(RenameRule::PascalCase.apply(&words), "User2");
+    }
+
+    #[test]
+    fn test_apply_single_word() {
+        let words = RenameRule::words_from_snake_case("name");
+        assert_eq!(RenameRule::PascalCase.apply(&words), "Name");
+        assert_eq!(RenameRule::CamelCase.apply(&words), "name");
+    }
+
+    #[test]
+    fn test_needs_per_field_rename() {
+        assert!(RenameRule::Lowercase.needs_per_field_rename());
+        assert!(RenameRule::Uppercase.needs_per_field_rename());
+        assert!(!RenameRule::CamelCase.needs_per_field_rename());
+    }
+}
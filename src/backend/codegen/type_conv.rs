@@ -51,6 +51,9 @@ impl RustCodegen<'_> {
                 format!("({})", elem_types.join(", "))
             }
             Type::SelfType => "Self".to_string(),
+            Type::Optional(inner) => format!("Option<{}>", Self::type_to_rust_static(&inner.node)),
+            // No Either type is generated for true unions; a union maps to the unit type `()`.
+            Type::Union(_) => "()".to_string(),
         }
     }
 
@@ -132,6 +135,9 @@ impl RustCodegen<'_> {
             "Serialize" => vec!["serde::Serialize"],
             "Deserialize" => vec!["serde::Deserialize"],
 
+            // Error types - an `@error` enum derives both in one shot
+            "Error" => vec!["derive_more::Error", "derive_more::Display"],
+
             // Legacy/special
             "Validate" => vec!["Debug"],
 
@@ -531,4 +537,12 @@ mod tests {
         let codegen = RustCodegen::new();
         assert!(codegen.derive_to_rust_vec("UnknownDerive").is_empty());
     }
+
+    #[test]
+    fn test_derive_error() {
+        let codegen = RustCodegen::new();
+        let derives = codegen.derive_to_rust_vec("Error");
+        assert!(derives.contains(&"derive_more::Error"));
+        assert!(derives.contains(&"derive_more::Display"));
+    }
 }
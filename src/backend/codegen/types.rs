@@ -8,6 +8,25 @@ pub(crate) struct DunderMethods {
     pub has_hash: bool,
     pub has_ord: bool,
     pub has_str: bool,
+    pub has_repr: bool,
+    /// `__add__` - lowered to a `derive_more::Add` derive rather than a hand-written impl.
+    pub has_add: bool,
+    /// `__sub__` - lowered to a `derive_more::Sub` derive.
+    pub has_sub: bool,
+    /// `__mul__` - lowered to a `derive_more::Mul` derive.
+    pub has_mul: bool,
+    /// `__truediv__` - lowered to a `derive_more::Div` derive.
+    pub has_div: bool,
+    /// `__neg__` - lowered to a `derive_more::Neg` derive.
+    pub has_neg: bool,
+    /// `__add_assign__` - lowered to a `derive_more::AddAssign` derive.
+    pub has_add_assign: bool,
+    /// `__sub_assign__` - lowered to a `derive_more::SubAssign` derive.
+    pub has_sub_assign: bool,
+    /// `__mul_assign__` - lowered to a `derive_more::MulAssign` derive.
+    pub has_mul_assign: bool,
+    /// `__div_assign__` - lowered to a `derive_more::DivAssign` derive.
+    pub has_div_assign: bool,
 }
 
 impl DunderMethods {
@@ -17,8 +36,57 @@ impl DunderMethods {
             has_hash: false,
             has_ord: false,
             has_str: false,
+            has_repr: false,
+            has_add: false,
+            has_sub: false,
+            has_mul: false,
+            has_div: false,
+            has_neg: false,
+            has_add_assign: false,
+            has_sub_assign: false,
+            has_mul_assign: false,
+            has_div_assign: false,
         }
     }
+
+    /// The `derive_more::*` paths implied by the operator dunders found on this type, for
+    /// splicing into the `#[derive(...)]` list alongside the built-in derives.
+    ///
+    /// Unlike `has_eq`/`has_hash`/`has_ord`, these don't have a hand-written trait impl to fall
+    /// back on: `derive_more`'s derives forward field-wise (delegating straight to the wrapped
+    /// field for a newtype), so the dunder method itself only needs to exist as a signature for
+    /// the derive to take over.
+    pub fn derive_more_derives(&self) -> Vec<&'static str> {
+        let mut derives = Vec::new();
+        if self.has_add {
+            derives.push("derive_more::Add");
+        }
+        if self.has_sub {
+            derives.push("derive_more::Sub");
+        }
+        if self.has_mul {
+            derives.push("derive_more::Mul");
+        }
+        if self.has_div {
+            derives.push("derive_more::Div");
+        }
+        if self.has_neg {
+            derives.push("derive_more::Neg");
+        }
+        if self.has_add_assign {
+            derives.push("derive_more::AddAssign");
+        }
+        if self.has_sub_assign {
+            derives.push("derive_more::SubAssign");
+        }
+        if self.has_mul_assign {
+            derives.push("derive_more::MulAssign");
+        }
+        if self.has_div_assign {
+            derives.push("derive_more::DivAssign");
+        }
+        derives
+    }
 }
 
 impl Default for DunderMethods {
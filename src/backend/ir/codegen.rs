@@ -37,7 +37,7 @@ use super::scanners::{
     collect_rust_crates as scan_collect_rust_crates, detect_async_usage, detect_list_helpers_usage, detect_serde_usage,
     detect_web_usage,
 };
-use super::{AstLowering, EmitError, EmitService, IrEmitter, LoweringErrors};
+use super::{infer_constness, postprocess, AstLowering, EmitError, EmitService, IrEmitter, LoweringErrors};
 
 /// Error during Rust code generation.
 ///
@@ -118,6 +118,8 @@ pub struct IrCodegen<'a> {
     test_mode: bool,
     /// Specific test function to mark with #[test] (if any)
     test_function: Option<String>,
+    /// Per-test `ignore`/`should_panic` directives, keyed by test function name
+    test_directives: HashMap<String, super::emit::TestDirectives>,
     /// Fixtures available for test functions (name -> (has_teardown, dependencies))
     fixtures: HashMap<String, (bool, Vec<String>)>,
     /// Rust crates imported via `import rust::` or `from rust::`
@@ -153,6 +155,7 @@ impl<'a> IrCodegen<'a> {
             routes: Vec::new(),
             test_mode: false,
             test_function: None,
+            test_directives: HashMap::new(),
             fixtures: HashMap::new(),
             rust_crates: HashSet::new(),
             emit_zen_in_main: false,
@@ -180,6 +183,11 @@ impl<'a> IrCodegen<'a> {
         self.test_function = Some(name.to_string());
     }
 
+    /// Set `ignore`/`should_panic` directives to apply per test function name
+    pub fn set_test_directives(&mut self, directives: HashMap<String, super::emit::TestDirectives>) {
+        self.test_directives = directives;
+    }
+
     /// Check if serde is needed
     pub fn needs_serde(&self) -> bool {
         self.needs_serde
@@ -393,7 +401,9 @@ impl<'a> IrCodegen<'a> {
             Some(info) => AstLowering::new_with_type_info(info),
             None => AstLowering::new(),
         };
-        let ir_program = lowering.lower_program(program)?;
+        let mut ir_program = lowering.lower_program(program)?;
+        infer_constness(&mut ir_program);
+        postprocess(&mut ir_program);
 
         // Build unified function registry including imported module functions
         let mut unified_registry = ir_program.function_registry.clone();
@@ -401,8 +411,9 @@ impl<'a> IrCodegen<'a> {
             // For dependencies, use best-effort lowering without type info to
             // preserve prior behavior and avoid redundant typechecking.
             let mut dep_lowering = AstLowering::new();
-            let dep_ir = dep_lowering.lower_program(dep_ast)?;
-            unified_registry.merge(&dep_ir.function_registry);
+            let mut dep_ir = dep_lowering.lower_program(dep_ast)?;
+            infer_constness(&mut dep_ir);
+            unified_registry.merge(&dep_ir.function_registry, &dep_ir.interner, &mut ir_program.interner);
         }
 
         // Emit IR to Rust code
@@ -418,9 +429,14 @@ impl<'a> IrCodegen<'a> {
             inner.set_needs_tokio(self.needs_tokio);
             inner.set_needs_axum(self.needs_axum);
             inner.set_external_rust_functions(self.external_rust_functions.clone());
+            inner.set_test_mode(self.test_mode);
+            if let Some(tf) = &self.test_function {
+                inner.set_test_function(tf);
+            }
+            inner.set_test_directives(self.test_directives.clone());
             Ok(svc.emit_program(&ir_program)?)
         } else {
-            let mut emitter = IrEmitter::new(&unified_registry);
+            let mut emitter = IrEmitter::new(&unified_registry, &ir_program.interner);
             if self.emit_zen_in_main {
                 emitter.set_emit_zen(true);
             }
@@ -428,6 +444,11 @@ impl<'a> IrCodegen<'a> {
             emitter.set_needs_tokio(self.needs_tokio);
             emitter.set_needs_axum(self.needs_axum);
             emitter.set_external_rust_functions(self.external_rust_functions.clone());
+            emitter.set_test_mode(self.test_mode);
+            if let Some(tf) = &self.test_function {
+                emitter.set_test_function(tf);
+            }
+            emitter.set_test_directives(self.test_directives.clone());
             Ok(emitter.emit_program(&ir_program)?)
         }
     }
@@ -452,14 +473,16 @@ impl<'a> IrCodegen<'a> {
     pub fn try_generate_module(&mut self, _module_name: &str, program: &Program) -> Result<String, GenerationError> {
         // Use the IR pipeline for module generation too
         let mut lowering = AstLowering::new();
-        let ir_program = lowering.lower_program(program)?;
+        let mut ir_program = lowering.lower_program(program)?;
+        infer_constness(&mut ir_program);
+        postprocess(&mut ir_program);
 
         let use_emit_service = env::var("INCAN_EMIT_SERVICE").ok().as_deref() == Some("1");
         if use_emit_service {
             let mut svc = EmitService::new_from_program(&ir_program);
             Ok(svc.emit_program(&ir_program)?)
         } else {
-            let mut emitter = IrEmitter::new(&ir_program.function_registry);
+            let mut emitter = IrEmitter::new(&ir_program.function_registry, &ir_program.interner);
             if self.emit_zen_in_main {
                 emitter.set_emit_zen(true);
             }
@@ -531,13 +554,15 @@ impl<'a> IrCodegen<'a> {
         for (name, ast) in &self.dependency_modules {
             if module_names.contains(name) {
                 let mut lowering = AstLowering::new();
-                let ir = lowering.lower_program(ast)?;
+                let mut ir = lowering.lower_program(ast)?;
+                infer_constness(&mut ir);
+                postprocess(&mut ir);
                 let use_emit_service = env::var("INCAN_EMIT_SERVICE").ok().as_deref() == Some("1");
                 let module_code = if use_emit_service {
                     let mut svc = EmitService::new_from_program(&ir);
                     svc.emit_program(&ir)?
                 } else {
-                    let mut emitter = IrEmitter::new(&ir.function_registry);
+                    let mut emitter = IrEmitter::new(&ir.function_registry, &ir.interner);
                     emitter.emit_program(&ir)?
                 };
                 modules.insert(name.to_string(), module_code);
@@ -612,13 +637,15 @@ impl<'a> IrCodegen<'a> {
                 let path_name = path.join("_");
                 if path_name == *name {
                     let mut lowering = AstLowering::new();
-                    let ir = lowering.lower_program(ast)?;
+                    let mut ir = lowering.lower_program(ast)?;
+                    infer_constness(&mut ir);
+                    postprocess(&mut ir);
                     let use_emit_service = env::var("INCAN_EMIT_SERVICE").ok().as_deref() == Some("1");
                     let module_code = if use_emit_service {
                         let mut svc = EmitService::new_from_program(&ir);
                         svc.emit_program(&ir)?
                     } else {
-                        let mut emitter = IrEmitter::new(&ir.function_registry);
+                        let mut emitter = IrEmitter::new(&ir.function_registry, &ir.interner);
                         emitter.emit_program(&ir)?
                     };
                     modules.insert(path.clone(), module_code);
@@ -0,0 +1,339 @@
+//! Structural `const fn` eligibility analysis.
+//!
+//! Mirrors rustc's `is_const_fn` query: beyond an explicit `@const` annotation, a function is
+//! const-eligible iff its body contains only const-safe statements/expressions — literals,
+//! arithmetic, field projections, calls to other functions already known to be const-eligible —
+//! and nothing that needs runtime support (heap allocation, I/O, or a non-const builtin).
+//! Eligibility is transitive (a function that only calls const-eligible functions is itself
+//! const-eligible), so [`infer_constness`] iterates the program's declarations to a fixpoint
+//! before recording the result on `IrFunction::constness` and `FunctionRegistry`'s signatures.
+
+use std::collections::HashSet;
+
+use super::decl::{IrDeclKind, IrFunction};
+use super::expr::{IrExpr, IrExprKind, MatchArm};
+use super::stmt::{AssignTarget, IrStmt, IrStmtKind};
+use super::{Constness, IrProgram};
+
+/// Infer `const fn` eligibility across `program`, honoring any function already marked
+/// [`Constness::Const`] by an explicit annotation and adding every function/method that is
+/// structurally const-safe, iterating to a fixpoint so const-eligibility propagates through
+/// call chains.
+pub fn infer_constness(program: &mut IrProgram) {
+    let mut const_fns: HashSet<String> = all_functions(program)
+        .into_iter()
+        .filter(|f| f.constness == Constness::Const)
+        .map(|f| f.name.clone())
+        .collect();
+
+    loop {
+        let mut changed = false;
+        for f in all_functions(program) {
+            if !const_fns.contains(&f.name) && is_const_eligible(&f.body, &const_fns) {
+                const_fns.insert(f.name.clone());
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+
+    for f in all_functions_mut(program) {
+        if const_fns.contains(&f.name) {
+            f.constness = Constness::Const;
+        }
+    }
+    for name in &const_fns {
+        if let Some(sym) = program.interner.get(name) {
+            program.function_registry.mark_const(sym);
+        }
+    }
+}
+
+/// Collect every `IrFunction` in the program: top-level functions and impl/trait methods.
+fn all_functions(program: &IrProgram) -> Vec<&IrFunction> {
+    let mut out = Vec::new();
+    for decl in &program.declarations {
+        match &decl.kind {
+            IrDeclKind::Function(f) => out.push(f),
+            IrDeclKind::Impl(imp) => out.extend(imp.methods.iter()),
+            IrDeclKind::Trait(t) => out.extend(t.methods.iter()),
+            _ => {}
+        }
+    }
+    out
+}
+
+fn all_functions_mut(program: &mut IrProgram) -> Vec<&mut IrFunction> {
+    let mut out = Vec::new();
+    for decl in &mut program.declarations {
+        match &mut decl.kind {
+            IrDeclKind::Function(f) => out.push(f),
+            IrDeclKind::Impl(imp) => out.extend(imp.methods.iter_mut()),
+            IrDeclKind::Trait(t) => out.extend(t.methods.iter_mut()),
+            _ => {}
+        }
+    }
+    out
+}
+
+fn is_const_eligible(body: &[IrStmt], const_fns: &HashSet<String>) -> bool {
+    body.iter().all(|stmt| stmt_const_safe(stmt, const_fns))
+}
+
+fn stmt_const_safe(stmt: &IrStmt, const_fns: &HashSet<String>) -> bool {
+    match &stmt.kind {
+        IrStmtKind::Expr(e) => expr_const_safe(e, const_fns),
+        IrStmtKind::Let { value, .. } => expr_const_safe(value, const_fns),
+        IrStmtKind::Assign { target, value } => target_const_safe(target, const_fns) && expr_const_safe(value, const_fns),
+        IrStmtKind::CompoundAssign { target, value, .. } => {
+            target_const_safe(target, const_fns) && expr_const_safe(value, const_fns)
+        }
+        IrStmtKind::Return(Some(e)) => expr_const_safe(e, const_fns),
+        IrStmtKind::Return(None) | IrStmtKind::Break(_) | IrStmtKind::Continue(_) => true,
+        IrStmtKind::While { condition, body, .. } => {
+            expr_const_safe(condition, const_fns) && is_const_eligible(body, const_fns)
+        }
+        // `for` loops iterate a runtime iterator, which isn't available in const contexts.
+        IrStmtKind::For { .. } => false,
+        IrStmtKind::Loop { body, .. } => is_const_eligible(body, const_fns),
+        IrStmtKind::If {
+            condition,
+            then_branch,
+            else_branch,
+        } => {
+            expr_const_safe(condition, const_fns)
+                && is_const_eligible(then_branch, const_fns)
+                && else_branch.as_deref().is_none_or(|e| is_const_eligible(e, const_fns))
+        }
+        IrStmtKind::Match { scrutinee, arms } => {
+            expr_const_safe(scrutinee, const_fns) && arms.iter().all(|arm| match_arm_const_safe(arm, const_fns))
+        }
+        IrStmtKind::Block(body) => is_const_eligible(body, const_fns),
+    }
+}
+
+fn target_const_safe(target: &AssignTarget, const_fns: &HashSet<String>) -> bool {
+    match target {
+        AssignTarget::Var(_) => true,
+        AssignTarget::Field { object, .. } => expr_const_safe(object, const_fns),
+        AssignTarget::Index { object, index } => expr_const_safe(object, const_fns) && expr_const_safe(index, const_fns),
+    }
+}
+
+fn match_arm_const_safe(arm: &MatchArm, const_fns: &HashSet<String>) -> bool {
+    arm.guard.as_ref().is_none_or(|g| expr_const_safe(g, const_fns)) && expr_const_safe(&arm.body, const_fns)
+}
+
+/// Whether `expr` only uses const-safe IR constructs: literals, arithmetic, field/index
+/// projections, and calls to functions already known to be const-eligible. Heap-allocating
+/// literals (`List`/`Dict`/`Set`), closures, await/try, unknown method calls, and builtins are all
+/// rejected — they either allocate, need runtime support, or aren't known not to.
+fn expr_const_safe(expr: &IrExpr, const_fns: &HashSet<String>) -> bool {
+    match &expr.kind {
+        IrExprKind::Unit
+        | IrExprKind::None
+        | IrExprKind::Bool(_)
+        | IrExprKind::Int(_)
+        | IrExprKind::Float(_)
+        | IrExprKind::String(_)
+        | IrExprKind::Bytes(_)
+        | IrExprKind::Var { .. }
+        | IrExprKind::Literal(_)
+        | IrExprKind::FieldsList(_) => true,
+        IrExprKind::BinOp { left, right, .. } => expr_const_safe(left, const_fns) && expr_const_safe(right, const_fns),
+        IrExprKind::UnaryOp { operand, .. } => expr_const_safe(operand, const_fns),
+        IrExprKind::Call { func, args } => match &func.kind {
+            IrExprKind::Var { name, .. } => const_fns.contains(name) && args.iter().all(|a| expr_const_safe(a, const_fns)),
+            _ => false,
+        },
+        IrExprKind::Field { object, .. } => expr_const_safe(object, const_fns),
+        IrExprKind::Index { object, index } => expr_const_safe(object, const_fns) && expr_const_safe(index, const_fns),
+        IrExprKind::TupleIndex { object, .. } => expr_const_safe(object, const_fns),
+        IrExprKind::Tuple(elems) => elems.iter().all(|e| expr_const_safe(e, const_fns)),
+        IrExprKind::If {
+            condition,
+            then_branch,
+            else_branch,
+        } => {
+            expr_const_safe(condition, const_fns)
+                && expr_const_safe(then_branch, const_fns)
+                && else_branch.as_deref().is_none_or(|e| expr_const_safe(e, const_fns))
+        }
+        IrExprKind::Match { scrutinee, arms } => {
+            expr_const_safe(scrutinee, const_fns) && arms.iter().all(|arm| match_arm_const_safe(arm, const_fns))
+        }
+        IrExprKind::Block { stmts, value } => {
+            is_const_eligible(stmts, const_fns) && value.as_deref().is_none_or(|v| expr_const_safe(v, const_fns))
+        }
+        IrExprKind::Cast { expr, .. } => expr_const_safe(expr, const_fns),
+        // Struct construction (no heap allocation of its own, but only if every field is const-safe)
+        IrExprKind::Struct { fields, .. } => fields.iter().all(|(_, e)| expr_const_safe(e, const_fns)),
+        // Heap allocation, runtime-only, or unanalyzable constructs: never const-safe.
+        IrExprKind::BuiltinCall { .. }
+        | IrExprKind::MethodCall { .. }
+        | IrExprKind::KnownMethodCall { .. }
+        | IrExprKind::Slice { .. }
+        | IrExprKind::ListComp { .. }
+        | IrExprKind::DictComp { .. }
+        | IrExprKind::SetComp { .. }
+        | IrExprKind::GenExp { .. }
+        | IrExprKind::List(_)
+        | IrExprKind::Dict(_)
+        | IrExprKind::Set(_)
+        | IrExprKind::Closure { .. }
+        | IrExprKind::Await(_)
+        | IrExprKind::Try(_)
+        | IrExprKind::Range { .. }
+        | IrExprKind::Format { .. }
+        | IrExprKind::SerdeToJson
+        | IrExprKind::SerdeFromJson(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::ir::decl::{FunctionParam, IrDecl, IrDeclKind};
+    use crate::backend::ir::expr::{BinOp, TypedExpr, VarAccess};
+    use crate::backend::ir::stmt::IrStmtKind;
+    use crate::backend::ir::types::{IrType, Mutability};
+    use crate::backend::ir::{FunctionRegistry, Interner};
+    use crate::backend::ir::decl::Visibility;
+
+    fn var(name: &str) -> IrExpr {
+        TypedExpr::new(
+            IrExprKind::Var {
+                name: name.to_string(),
+                access: VarAccess::Copy,
+            },
+            IrType::Int,
+        )
+    }
+
+    fn function(name: &str, body: Vec<IrStmt>) -> IrFunction {
+        IrFunction {
+            name: name.to_string(),
+            params: vec![FunctionParam {
+                name: "a".to_string(),
+                ty: IrType::Int,
+                mutability: Mutability::Immutable,
+                is_self: false,
+            }],
+            return_type: IrType::Int,
+            body,
+            is_async: false,
+            visibility: Visibility::Public,
+            type_params: vec![],
+            constness: Constness::NotConst,
+        }
+    }
+
+    #[test]
+    fn arithmetic_only_function_is_const_eligible() {
+        let mut program = IrProgram::new();
+        let f = function(
+            "square",
+            vec![IrStmt::new(IrStmtKind::Return(Some(TypedExpr::new(
+                IrExprKind::BinOp {
+                    op: BinOp::Mul,
+                    left: Box::new(var("a")),
+                    right: Box::new(var("a")),
+                },
+                IrType::Int,
+            ))))],
+        );
+        program.declarations.push(IrDecl::new(IrDeclKind::Function(f)));
+
+        infer_constness(&mut program);
+
+        let IrDeclKind::Function(f) = &program.declarations[0].kind else {
+            unreachable!()
+        };
+        assert_eq!(f.constness, Constness::Const);
+    }
+
+    #[test]
+    fn const_eligibility_propagates_through_calls() {
+        let mut program = IrProgram::new();
+        let square = function(
+            "square",
+            vec![IrStmt::new(IrStmtKind::Return(Some(TypedExpr::new(
+                IrExprKind::BinOp {
+                    op: BinOp::Mul,
+                    left: Box::new(var("a")),
+                    right: Box::new(var("a")),
+                },
+                IrType::Int,
+            ))))],
+        );
+        let double_square = function(
+            "double_square",
+            vec![IrStmt::new(IrStmtKind::Return(Some(TypedExpr::new(
+                IrExprKind::BinOp {
+                    op: BinOp::Add,
+                    left: Box::new(TypedExpr::new(
+                        IrExprKind::Call {
+                            func: Box::new(var("square")),
+                            args: vec![var("a")],
+                        },
+                        IrType::Int,
+                    )),
+                    right: Box::new(TypedExpr::new(
+                        IrExprKind::Call {
+                            func: Box::new(var("square")),
+                            args: vec![var("a")],
+                        },
+                        IrType::Int,
+                    )),
+                },
+                IrType::Int,
+            ))))],
+        );
+        program.declarations.push(IrDecl::new(IrDeclKind::Function(square)));
+        program.declarations.push(IrDecl::new(IrDeclKind::Function(double_square)));
+
+        infer_constness(&mut program);
+
+        for decl in &program.declarations {
+            let IrDeclKind::Function(f) = &decl.kind else {
+                unreachable!()
+            };
+            assert_eq!(f.constness, Constness::Const, "{} should be const", f.name);
+        }
+    }
+
+    #[test]
+    fn function_calling_a_builtin_is_not_const_eligible() {
+        let mut program = IrProgram::new();
+        let f = function(
+            "print_a",
+            vec![IrStmt::new(IrStmtKind::Expr(TypedExpr::new(
+                IrExprKind::BuiltinCall {
+                    func: crate::backend::ir::expr::BuiltinFn::Print,
+                    args: vec![var("a")],
+                },
+                IrType::Unit,
+            )))],
+        );
+        program.declarations.push(IrDecl::new(IrDeclKind::Function(f)));
+
+        infer_constness(&mut program);
+
+        let IrDeclKind::Function(f) = &program.declarations[0].kind else {
+            unreachable!()
+        };
+        assert_eq!(f.constness, Constness::NotConst);
+    }
+
+    #[test]
+    fn mark_const_is_reflected_in_the_function_registry() {
+        let mut interner = Interner::new();
+        let mut registry = FunctionRegistry::new();
+        registry.register(&mut interner, "square", vec![], IrType::Int);
+        let sym = interner.get("square").unwrap();
+        registry.mark_const(sym);
+        assert_eq!(registry.get(&interner, "square").unwrap().constness, Constness::Const);
+    }
+}
@@ -83,6 +83,9 @@ pub struct IrImpl {
     pub target_type: String,
     /// The trait being implemented, if any
     pub trait_name: Option<String>,
+    /// Associated type bindings (e.g. `type Item = T;` for `impl Iterator`), emitted before the
+    /// methods when `trait_name` is `Some`. Empty for inherent impls and traits with none.
+    pub assoc_types: Vec<(String, IrType)>,
     /// Methods in this impl block
     pub methods: Vec<IrFunction>,
 }
@@ -98,6 +101,17 @@ pub struct IrFunction {
     pub visibility: Visibility,
     /// Type parameters for generics
     pub type_params: Vec<String>,
+    /// Whether this function is eligible to be emitted as `const fn`; see
+    /// [`super::constness::infer_constness`].
+    pub constness: Constness,
+}
+
+/// Whether a function is eligible to be emitted as `const fn`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Constness {
+    #[default]
+    NotConst,
+    Const,
 }
 
 /// Function parameter
@@ -118,6 +132,11 @@ pub struct IrStruct {
     pub visibility: Visibility,
     /// Type parameters for generics
     pub type_params: Vec<String>,
+    /// `#[serde(rename_all = "...")]`, from an `@serde(rename_all=...)` decorator.
+    pub serde_rename_all: Option<String>,
+    /// Extra serialization formats (e.g. `yaml`, `toml`, `msgpack`) from an `@formats(...)`
+    /// decorator; drives `to_<fmt>`/`from_<fmt>` method generation alongside `to_json`/`from_json`.
+    pub formats: Vec<String>,
 }
 
 /// Struct field
@@ -126,6 +145,12 @@ pub struct StructField {
     pub name: String,
     pub ty: IrType,
     pub visibility: Visibility,
+    /// `#[serde(rename = "...")]`, from the container's `@serde(rename={...})` decorator.
+    pub serde_rename: Option<String>,
+    /// `#[serde(skip)]`, from the container's `@serde(skip=[...])` decorator.
+    pub serde_skip: bool,
+    /// `#[serde(default)]`, from the container's `@serde(default=[...])` decorator.
+    pub serde_default: bool,
 }
 
 /// IR enum definition
@@ -137,6 +162,9 @@ pub struct IrEnum {
     pub visibility: Visibility,
     /// Type parameters for generics
     pub type_params: Vec<String>,
+    /// Extra serialization formats (e.g. `yaml`, `toml`, `msgpack`) from an `@formats(...)`
+    /// decorator; drives `to_<fmt>`/`from_<fmt>` method generation alongside `to_json`/`from_json`.
+    pub formats: Vec<String>,
 }
 
 /// Enum variant
@@ -0,0 +1,349 @@
+//! Span-backed structured diagnostics for the IR pipeline.
+//!
+//! Renders [`LoweringError`]/[`LoweringErrors`] as rustc-style [`Diagnostic`]s: a primary
+//! [`IrSpan`], a message, a [`Severity`], and a list of machine-applyable [`Suggestion`]s.
+//! Two emit modes are supported, mirroring `LoweringErrors`'s own "collect everything, don't
+//! bail on the first" approach:
+//!
+//! - [`render`]/[`render_all`] print the offending source line with a caret-underline under
+//!   the exact span, in the spirit of rustc's terminal snippets.
+//! - [`to_json`]/[`all_to_json`] emit the same information as JSON, for editor/LSP consumption.
+//!
+//! This mirrors [`crate::frontend::diagnostics`] (which renders `Span`-based `CompileError`s
+//! over the pre-lowering AST) one layer down, over `IrSpan`-based IR errors. The two modules
+//! aren't shared: the IR layer doesn't depend on the frontend's diagnostic types, so the small
+//! amount of overlap (`Applicability`, JSON escaping, caret rendering) is reimplemented here at
+//! IR-appropriate scope rather than introducing a cross-layer dependency for it.
+
+use super::lower::{LoweringError, LoweringErrors};
+use super::IrSpan;
+
+/// How confident the compiler is that a [`Suggestion`] is correct, mirroring rustc's
+/// `rustc_errors::Applicability`. Only `MachineApplicable` suggestions should be applied
+/// automatically by tooling.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Applicability {
+    /// The suggestion is definitely what the user meant; safe to apply automatically.
+    MachineApplicable,
+    /// The suggestion is probably right, but could change semantics; ask before applying.
+    MaybeIncorrect,
+    /// The suggestion contains placeholder text that must be filled in by hand.
+    HasPlaceholders,
+    /// The suggestion's correctness hasn't been classified.
+    Unspecified,
+}
+
+/// Severity of a [`Diagnostic`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Error => write!(f, "error"),
+            Severity::Warning => write!(f, "warning"),
+        }
+    }
+}
+
+/// A structured, machine-applyable code fix: replace the source text in `span` with
+/// `replacement`.
+#[derive(Debug, Clone)]
+pub struct Suggestion {
+    pub span: IrSpan,
+    pub replacement: String,
+    pub message: String,
+    pub applicability: Applicability,
+}
+
+impl Suggestion {
+    pub fn new(
+        message: impl Into<String>,
+        span: IrSpan,
+        replacement: impl Into<String>,
+        applicability: Applicability,
+    ) -> Self {
+        Self {
+            span,
+            replacement: replacement.into(),
+            message: message.into(),
+            applicability,
+        }
+    }
+}
+
+/// A rendered-ready diagnostic: a primary span, a message, a severity, and zero or more
+/// structured [`Suggestion`]s.
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    pub span: IrSpan,
+    pub message: String,
+    pub severity: Severity,
+    pub suggestions: Vec<Suggestion>,
+}
+
+impl Diagnostic {
+    pub fn new(message: impl Into<String>, span: IrSpan, severity: Severity) -> Self {
+        Self {
+            span,
+            message: message.into(),
+            severity,
+            suggestions: Vec::new(),
+        }
+    }
+
+    pub fn with_suggestion(mut self, suggestion: Suggestion) -> Self {
+        self.suggestions.push(suggestion);
+        self
+    }
+}
+
+impl From<&LoweringError> for Diagnostic {
+    /// Converts a [`LoweringError`] into a [`Diagnostic`], attaching a `borrow here: &name`
+    /// fix-it when the message matches one of lowering's mutability/ownership-mismatch
+    /// phrasings (see [`suggest_borrow`]).
+    fn from(error: &LoweringError) -> Self {
+        let mut diagnostic = Diagnostic::new(error.message.clone(), error.span, Severity::Error);
+        if let Some(suggestion) = suggest_borrow(&error.message, error.span) {
+            diagnostic = diagnostic.with_suggestion(suggestion);
+        }
+        diagnostic
+    }
+}
+
+/// Detect lowering's mutability/ownership-mismatch messages (e.g. "Cannot reassign immutable
+/// variable 'x'", "Cannot mutate 'x' - variable is immutable") and propose borrowing the named
+/// variable instead, mirroring the "borrow here: `&x`" fix-its rustc attaches to move/borrow
+/// errors. Returns `None` for messages that don't name a single-quoted variable or don't look
+/// like a mutability/ownership complaint.
+fn suggest_borrow(message: &str, span: IrSpan) -> Option<Suggestion> {
+    if !message.contains("immutable") && !message.contains("mutate") {
+        return None;
+    }
+    let name = message.split('\'').nth(1)?;
+    Some(Suggestion::new(
+        format!("borrow here: `&{name}`"),
+        span,
+        format!("&{name}"),
+        Applicability::MaybeIncorrect,
+    ))
+}
+
+/// Convert every error in a [`LoweringErrors`] collection into a [`Diagnostic`], without
+/// stopping at the first one - the "emit all at once" mode described in the module docs.
+pub fn diagnostics_from_lowering_errors(errors: &LoweringErrors) -> Vec<Diagnostic> {
+    errors.iter().map(Diagnostic::from).collect()
+}
+
+/// Line number, 1-based column, and line text for a byte offset into `source`.
+///
+/// Unlike `crate::frontend::diagnostics::get_line_info`, this doesn't account for tabs or
+/// wide characters: IR spans are already post-lowering and the messages they annotate are
+/// plain ASCII identifiers, so a byte-offset column is good enough here.
+fn line_info(source: &str, offset: usize) -> (usize, usize, &str) {
+    let offset = offset.min(source.len());
+    let mut line_num = 1;
+    let mut line_start = 0;
+
+    for (i, c) in source.char_indices() {
+        if i >= offset {
+            break;
+        }
+        if c == '\n' {
+            line_num += 1;
+            line_start = i + 1;
+        }
+    }
+
+    let line_end = source[line_start..]
+        .find('\n')
+        .map(|i| line_start + i)
+        .unwrap_or(source.len());
+    let line_text = &source[line_start..line_end];
+    let col_num = offset.min(line_end) - line_start + 1;
+
+    (line_num, col_num, line_text)
+}
+
+/// Render one [`Diagnostic`] as a line of source with a caret-underline beneath the exact
+/// span, in the spirit of rustc's snippet emitter.
+pub fn render(diagnostic: &Diagnostic, source: &str) -> String {
+    let (line_num, col_num, line_text) = line_info(source, diagnostic.span.start);
+    let underline_len = diagnostic
+        .span
+        .end
+        .saturating_sub(diagnostic.span.start)
+        .max(1)
+        .min(line_text.len().saturating_sub(col_num - 1).max(1));
+    let width = format!("{line_num}").len();
+
+    let mut out = String::new();
+    out.push_str(&format!("{}: {}\n", diagnostic.severity, diagnostic.message));
+    out.push_str(&format!("  --> line {line_num}:{col_num}\n"));
+    out.push_str(&format!("  {:>width$} |\n", "", width = width));
+    out.push_str(&format!("  {line_num:>width$} | {line_text}\n", width = width));
+    out.push_str(&format!(
+        "  {:>width$} | {}{}\n",
+        "",
+        " ".repeat(col_num - 1),
+        "^".repeat(underline_len),
+        width = width
+    ));
+    for suggestion in &diagnostic.suggestions {
+        out.push_str(&format!("  = suggestion: {}\n", suggestion.message));
+    }
+    out
+}
+
+/// Render every diagnostic in `diagnostics` in order, concatenating their [`render`] output -
+/// the "emit all at once" counterpart to [`render`].
+pub fn render_all(diagnostics: &[Diagnostic], source: &str) -> String {
+    diagnostics.iter().map(|d| render(d, source)).collect()
+}
+
+/// Escape a string for embedding in a JSON string literal.
+fn json_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+fn applicability_str(applicability: Applicability) -> &'static str {
+    match applicability {
+        Applicability::MachineApplicable => "MachineApplicable",
+        Applicability::MaybeIncorrect => "MaybeIncorrect",
+        Applicability::HasPlaceholders => "HasPlaceholders",
+        Applicability::Unspecified => "Unspecified",
+    }
+}
+
+/// Render one [`Diagnostic`] as a single line of structured JSON, in the spirit of rustc's
+/// `--error-format=json`, so editors/LSP servers/CI can consume IR diagnostics without
+/// scraping terminal text.
+pub fn to_json(diagnostic: &Diagnostic) -> String {
+    let suggestions: Vec<String> = diagnostic
+        .suggestions
+        .iter()
+        .map(|s| {
+            format!(
+                "{{\"span\":{{\"start\":{},\"end\":{}}},\"replacement\":\"{}\",\"message\":\"{}\",\"applicability\":\"{}\"}}",
+                s.span.start,
+                s.span.end,
+                json_escape(&s.replacement),
+                json_escape(&s.message),
+                applicability_str(s.applicability),
+            )
+        })
+        .collect();
+
+    format!(
+        "{{\"message\":\"{}\",\"severity\":\"{}\",\"span\":{{\"start\":{},\"end\":{}}},\"suggestions\":[{}]}}",
+        json_escape(&diagnostic.message),
+        diagnostic.severity,
+        diagnostic.span.start,
+        diagnostic.span.end,
+        suggestions.join(","),
+    )
+}
+
+/// Render every diagnostic in `diagnostics` as a JSON array - the "emit all at once"
+/// counterpart to [`to_json`].
+pub fn all_to_json(diagnostics: &[Diagnostic]) -> String {
+    format!(
+        "[{}]",
+        diagnostics.iter().map(to_json).collect::<Vec<_>>().join(",")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::ir::lower::LoweringError;
+
+    #[test]
+    fn diagnostic_from_lowering_error_carries_span_and_message() {
+        let error = LoweringError {
+            message: "something went wrong".to_string(),
+            span: IrSpan { start: 4, end: 7 },
+        };
+        let diagnostic = Diagnostic::from(&error);
+
+        assert_eq!(diagnostic.message, "something went wrong");
+        assert_eq!(diagnostic.span.start, 4);
+        assert_eq!(diagnostic.span.end, 7);
+        assert_eq!(diagnostic.severity, Severity::Error);
+        assert!(diagnostic.suggestions.is_empty());
+    }
+
+    #[test]
+    fn suggest_borrow_attaches_fixit_for_immutable_reassignment() {
+        let error = LoweringError {
+            message: "Cannot reassign immutable variable 'total'".to_string(),
+            span: IrSpan { start: 10, end: 15 },
+        };
+        let diagnostic = Diagnostic::from(&error);
+
+        assert_eq!(diagnostic.suggestions.len(), 1);
+        let suggestion = &diagnostic.suggestions[0];
+        assert_eq!(suggestion.replacement, "&total");
+        assert_eq!(suggestion.applicability, Applicability::MaybeIncorrect);
+    }
+
+    #[test]
+    fn diagnostics_from_lowering_errors_covers_every_error() {
+        let errors = LoweringErrors(vec![
+            LoweringError {
+                message: "first".to_string(),
+                span: IrSpan::default(),
+            },
+            LoweringError {
+                message: "second".to_string(),
+                span: IrSpan::default(),
+            },
+        ]);
+        let diagnostics = diagnostics_from_lowering_errors(&errors);
+
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(diagnostics[0].message, "first");
+        assert_eq!(diagnostics[1].message, "second");
+    }
+
+    #[test]
+    fn render_underlines_the_exact_span() {
+        let source = "let total = 1\ntotal = 2\n";
+        let diagnostic = Diagnostic::new("cannot reassign 'total'", IrSpan { start: 14, end: 19 }, Severity::Error);
+
+        let rendered = render(&diagnostic, source);
+        assert!(rendered.contains("error: cannot reassign 'total'"));
+        assert!(rendered.contains("line 2:1"));
+        assert!(rendered.contains("total = 2"));
+        assert!(rendered.contains("^^^^^"));
+    }
+
+    #[test]
+    fn to_json_includes_severity_span_and_suggestions() {
+        let diagnostic = Diagnostic::new("bad thing", IrSpan { start: 1, end: 2 }, Severity::Warning).with_suggestion(
+            Suggestion::new("try this", IrSpan { start: 1, end: 2 }, "fixed", Applicability::MachineApplicable),
+        );
+
+        let json = to_json(&diagnostic);
+        assert!(json.contains("\"message\":\"bad thing\""));
+        assert!(json.contains("\"severity\":\"warning\""));
+        assert!(json.contains("\"span\":{\"start\":1,\"end\":2}"));
+        assert!(json.contains("\"replacement\":\"fixed\""));
+        assert!(json.contains("\"applicability\":\"MachineApplicable\""));
+    }
+}
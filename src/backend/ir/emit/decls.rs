@@ -535,6 +535,59 @@ impl<'a> IrEmitter<'a> {
                     });
                 }
                 "__class_name__" | "__fields__" => regular_methods.push(self.emit_method(method)?),
+                "__add__" => {
+                    regular_methods.push(self.emit_method(method)?);
+                    trait_impls.push(self.emit_binary_op_trait_impl(method, &target_type, quote! { std::ops::Add }, "add")?);
+                }
+                "__sub__" => {
+                    regular_methods.push(self.emit_method(method)?);
+                    trait_impls.push(self.emit_binary_op_trait_impl(method, &target_type, quote! { std::ops::Sub }, "sub")?);
+                }
+                "__mul__" => {
+                    regular_methods.push(self.emit_method(method)?);
+                    trait_impls.push(self.emit_binary_op_trait_impl(method, &target_type, quote! { std::ops::Mul }, "mul")?);
+                }
+                "__truediv__" => {
+                    regular_methods.push(self.emit_method(method)?);
+                    trait_impls.push(self.emit_binary_op_trait_impl(method, &target_type, quote! { std::ops::Div }, "div")?);
+                }
+                "__mod__" => {
+                    regular_methods.push(self.emit_method(method)?);
+                    trait_impls.push(self.emit_binary_op_trait_impl(method, &target_type, quote! { std::ops::Rem }, "rem")?);
+                }
+                "__neg__" => {
+                    regular_methods.push(self.emit_method(method)?);
+                    let dunder_name = format_ident!("{}", &method.name);
+                    let output_ty = self.emit_type(&method.return_type);
+                    trait_impls.push(quote! {
+                        impl std::ops::Neg for #target_type {
+                            type Output = #output_ty;
+                            fn neg(self) -> Self::Output {
+                                self.#dunder_name()
+                            }
+                        }
+                    });
+                }
+                "__getitem__" => {
+                    regular_methods.push(self.emit_method(method)?);
+                    trait_impls.push(self.emit_index_trait_impl(method, &target_type)?);
+                }
+                "__setitem__" => {
+                    // See the matching comment in `super::mod::IrEmitter::emit_impl`: `IndexMut`
+                    // doesn't have a parameter for the assigned value, so there's no faithful
+                    // forwarding impl here — keep it callable as a regular method only.
+                    regular_methods.push(self.emit_method(method)?);
+                }
+                "__len__" => {
+                    regular_methods.push(self.emit_method(method)?);
+                    let dunder_name = format_ident!("{}", &method.name);
+                    let ret_ty = self.emit_type(&method.return_type);
+                    regular_methods.push(quote! {
+                        pub fn len(&self) -> #ret_ty {
+                            self.#dunder_name()
+                        }
+                    });
+                }
                 _ => regular_methods.push(self.emit_method(method)?),
             }
         }
@@ -561,6 +614,10 @@ impl<'a> IrEmitter<'a> {
                         }
                     });
                 }
+
+                if let Some(formats) = self.struct_formats.get(&impl_block.target_type) {
+                    regular_methods.push(self.emit_format_methods(formats, has_serialize, has_deserialize));
+                }
             }
         }
 
@@ -909,6 +966,31 @@ One obvious way.
             })
             .collect();
 
+        // Add to_json/from_json for enums with Serialize/Deserialize, matching struct behavior.
+        let has_serialize = e.derives.iter().any(|d| d == "Serialize");
+        let has_deserialize = e.derives.iter().any(|d| d == "Deserialize");
+        let to_json = has_serialize
+            .then(|| {
+                quote! {
+                    /// Serialize this enum to a JSON string
+                    pub fn to_json(&self) -> String {
+                        serde_json::to_string(self).expect("JSONError: failed to serialize to JSON")
+                    }
+                }
+            })
+            .unwrap_or_default();
+        let from_json = has_deserialize
+            .then(|| {
+                quote! {
+                    /// Deserialize a JSON string into this enum
+                    pub fn from_json(json_str: String) -> Result<Self, String> {
+                        serde_json::from_str(&json_str).map_err(|e| e.to_string())
+                    }
+                }
+            })
+            .unwrap_or_default();
+        let format_methods = self.emit_format_methods(&e.formats, has_serialize, has_deserialize);
+
         Ok(quote! {
             #derive_attr
             #vis enum #name {
@@ -921,6 +1003,10 @@ One obvious way.
                         #(#variant_match_arms),*
                     }
                 }
+
+                #to_json
+                #from_json
+                #format_methods
             }
         })
     }
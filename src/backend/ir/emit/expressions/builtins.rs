@@ -7,10 +7,58 @@
 use proc_macro2::TokenStream;
 use quote::quote;
 
-use super::super::super::expr::{BuiltinFn, IrExprKind, TypedExpr};
+use super::super::super::expr::{BuiltinFn, IrExprKind, TypedExpr, UnaryOp};
 use super::super::super::types::IrType;
 use super::super::{EmitError, IrEmitter};
 
+/// Resolve the element type of a list-like argument (unwrapping a leading `Ref`/`RefMut`).
+///
+/// Shared by the builtins below (`sum`/`min`/`max`/`sorted`) that branch on the element type:
+/// `sum` tells a `Bool` element apart, and `min`/`max`/`sorted` a `Float` element (which requires
+/// `partial_cmp`/`f64::min`/`f64::max`, since `f64` isn't `Ord`), from everything else.
+fn list_elem_type(ty: &IrType) -> &IrType {
+    match ty {
+        IrType::List(elem) | IrType::Iterator(elem) => elem.as_ref(),
+        IrType::Ref(inner) | IrType::RefMut(inner) => match inner.as_ref() {
+            IrType::List(elem) | IrType::Iterator(elem) => elem.as_ref(),
+            _ => &IrType::Unknown,
+        },
+        _ => &IrType::Unknown,
+    }
+}
+
+/// Adapt an emitted argument into an iterator over owned items.
+///
+/// A collected `Vec`/`Ref`/`RefMut` argument needs `.iter().cloned()` to produce one; an
+/// already-lazy `impl Iterator` (the uncollected shape a generator expression lowers to, see
+/// `IrType::Iterator`) already is one, so it's passed straight through with no extra allocation.
+fn owned_iter(ty: &IrType, tokens: &TokenStream) -> TokenStream {
+    match ty {
+        IrType::Iterator(_) => tokens.clone(),
+        IrType::Ref(inner) | IrType::RefMut(inner) if matches!(inner.as_ref(), IrType::Iterator(_)) => tokens.clone(),
+        _ => quote! { #tokens.iter().cloned() },
+    }
+}
+
+/// Extract the value of an integer literal, recognizing `UnaryOp::Neg` applied to an `Int`
+/// literal (negative literals aren't constant-folded during lowering).
+///
+/// Returns `None` if `expr` isn't a (possibly negated) integer literal, e.g. because it's a
+/// variable or some other runtime-computed expression.
+fn extract_int_literal_ir(expr: &TypedExpr) -> Option<i64> {
+    match &expr.kind {
+        IrExprKind::Int(n) => Some(*n),
+        IrExprKind::UnaryOp {
+            op: UnaryOp::Neg,
+            operand,
+        } => match &operand.kind {
+            IrExprKind::Int(n) => Some(-n),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
 impl<'a> IrEmitter<'a> {
     /// Emit a builtin function call using enum-based dispatch.
     ///
@@ -47,19 +95,12 @@ impl<'a> IrEmitter<'a> {
             BuiltinFn::Sum => {
                 if let Some(arg) = args.first() {
                     let a = self.emit_expr(arg)?;
-                    let elem_type = match &arg.ty {
-                        IrType::List(elem) => elem.as_ref(),
-                        IrType::Ref(inner) | IrType::RefMut(inner) => match inner.as_ref() {
-                            IrType::List(elem) => elem.as_ref(),
-                            _ => &IrType::Unknown,
-                        },
-                        _ => &IrType::Unknown,
-                    };
-                    match elem_type {
+                    let iter = owned_iter(&arg.ty, &a);
+                    match list_elem_type(&arg.ty) {
                         IrType::Bool => Ok(quote! {
-                            (#a.iter().map(|x| if *x { 1i64 } else { 0i64 }).sum::<i64>())
+                            (#iter.map(|x| if x { 1i64 } else { 0i64 }).sum::<i64>())
                         }),
-                        _ => Ok(quote! { (#a.iter().sum::<i64>()) }),
+                        _ => Ok(quote! { (#iter.sum::<i64>()) }),
                     }
                 } else {
                     Ok(quote! { 0i64 })
@@ -77,7 +118,9 @@ impl<'a> IrEmitter<'a> {
                 if let Some(arg) = args.first() {
                     let a = self.emit_expr(arg)?;
                     match &arg.ty {
-                        IrType::String => Ok(quote! { #a.parse::<i64>().unwrap() }),
+                        // Python's `int("x")` raises a catchable `ValueError`, not an opaque parse
+                        // panic; route through the stdlib helper so the panic message is canonical.
+                        IrType::String => Ok(quote! { incan_stdlib::conversions::int_from_str(#a) }),
                         IrType::Float => Ok(quote! { #a as i64 }),
                         IrType::Bool => Ok(quote! { if #a { 1 } else { 0 } }),
                         _ => Ok(quote! { #a as i64 }),
@@ -90,7 +133,9 @@ impl<'a> IrEmitter<'a> {
                 if let Some(arg) = args.first() {
                     let a = self.emit_expr(arg)?;
                     match &arg.ty {
-                        IrType::String => Ok(quote! { #a.parse::<f64>().unwrap() }),
+                        // Python's `float("x")` raises a catchable `ValueError`, not an opaque parse
+                        // panic; route through the stdlib helper so the panic message is canonical.
+                        IrType::String => Ok(quote! { incan_stdlib::conversions::float_from_str(#a) }),
                         IrType::Int => Ok(quote! { #a as f64 }),
                         _ => Ok(quote! { #a as f64 }),
                     }
@@ -108,7 +153,7 @@ impl<'a> IrEmitter<'a> {
             }
             BuiltinFn::Range => self
                 .emit_range_call(args)
-                .map(|opt| opt.unwrap_or_else(|| quote! { 0..0 })),
+                .map(|opt| opt.unwrap_or_else(|| quote! { 0i64..0i64 })),
             BuiltinFn::Enumerate => {
                 if let Some(arg) = args.first() {
                     let a = self.emit_expr(arg)?;
@@ -165,9 +210,112 @@ impl<'a> IrEmitter<'a> {
                     Ok(quote! { tokio::time::sleep(tokio::time::Duration::from_secs(0)) })
                 }
             }
+            BuiltinFn::Min | BuiltinFn::Max => self.emit_min_max_call(matches!(func, BuiltinFn::Max), args),
+            BuiltinFn::Sorted => {
+                if let Some(arg) = args.first() {
+                    let a = self.emit_expr(arg)?;
+                    if matches!(list_elem_type(&arg.ty), IrType::Float) {
+                        Ok(quote! { { let mut v = #a.clone(); v.sort_by(|a, b| a.partial_cmp(b).unwrap()); v } })
+                    } else {
+                        Ok(quote! { { let mut v = #a.clone(); v.sort(); v } })
+                    }
+                } else {
+                    Ok(quote! { Vec::<i64>::new() })
+                }
+            }
+            BuiltinFn::Reversed => {
+                if let Some(arg) = args.first() {
+                    let a = self.emit_expr(arg)?;
+                    Ok(quote! { #a.iter().rev().cloned().collect::<Vec<_>>() })
+                } else {
+                    Ok(quote! { Vec::<i64>::new() })
+                }
+            }
+            BuiltinFn::Round => {
+                if let Some(arg) = args.first() {
+                    let a = self.emit_expr(arg)?;
+                    match &arg.ty {
+                        IrType::Int => Ok(quote! { #a }),
+                        _ => Ok(quote! { (#a.round() as i64) }),
+                    }
+                } else {
+                    Ok(quote! { 0i64 })
+                }
+            }
+            BuiltinFn::Map => {
+                if args.len() >= 2 {
+                    let f = self.emit_expr(&args[0])?;
+                    let xs = self.emit_expr(&args[1])?;
+                    Ok(quote! { #xs.iter().cloned().map(#f).collect::<Vec<_>>() })
+                } else {
+                    Ok(quote! { Vec::<i64>::new() })
+                }
+            }
+            BuiltinFn::Filter => {
+                if args.len() >= 2 {
+                    let f = self.emit_expr(&args[0])?;
+                    let xs = self.emit_expr(&args[1])?;
+                    Ok(quote! { #xs.iter().cloned().filter(|v| #f(v.clone())).collect::<Vec<_>>() })
+                } else {
+                    Ok(quote! { Vec::<i64>::new() })
+                }
+            }
+            BuiltinFn::Any => {
+                if let Some(arg) = args.first() {
+                    let a = self.emit_expr(arg)?;
+                    let iter = owned_iter(&arg.ty, &a);
+                    Ok(quote! { #iter.any(|v| v) })
+                } else {
+                    Ok(quote! { false })
+                }
+            }
+            BuiltinFn::All => {
+                if let Some(arg) = args.first() {
+                    let a = self.emit_expr(arg)?;
+                    let iter = owned_iter(&arg.ty, &a);
+                    Ok(quote! { #iter.all(|v| v) })
+                } else {
+                    Ok(quote! { true })
+                }
+            }
         }
     }
 
+    /// Emit a `min`/`max` builtin call.
+    ///
+    /// Python's `min`/`max` accept either a single iterable or two-or-more scalar arguments:
+    /// - One list argument: `.iter().min()/.max()` for `Ord` elements, or an `f64::min`/`f64::max`
+    ///   fold for `Float` elements (since `f64` isn't `Ord`).
+    /// - Two or more scalar arguments: a left fold of `.min(...)`/`.max(...)` calls (both `i64`
+    ///   and `f64` have an inherent `min`/`max` method).
+    fn emit_min_max_call(&self, is_max: bool, args: &[TypedExpr]) -> Result<TokenStream, EmitError> {
+        if args.len() == 1 {
+            let arg = &args[0];
+            let a = self.emit_expr(arg)?;
+            return Ok(match (list_elem_type(&arg.ty), is_max) {
+                (IrType::Float, true) => quote! { #a.iter().cloned().fold(f64::NEG_INFINITY, f64::max) },
+                (IrType::Float, false) => quote! { #a.iter().cloned().fold(f64::INFINITY, f64::min) },
+                (_, true) => quote! { *#a.iter().max().unwrap() },
+                (_, false) => quote! { *#a.iter().min().unwrap() },
+            });
+        }
+
+        let mut args_iter = args.iter();
+        let Some(first) = args_iter.next() else {
+            return Ok(quote! { 0 });
+        };
+        let mut acc = self.emit_expr(first)?;
+        for arg in args_iter {
+            let a = self.emit_expr(arg)?;
+            acc = if is_max {
+                quote! { (#acc).max(#a) }
+            } else {
+                quote! { (#acc).min(#a) }
+            };
+        }
+        Ok(acc)
+    }
+
     /// Try to emit a builtin function call (legacy string-based dispatch).
     ///
     /// This is a fallback for `IrExprKind::Call` expressions where the function name
@@ -228,7 +376,7 @@ impl<'a> IrEmitter<'a> {
                 if let Some(arg) = args.first() {
                     let a = self.emit_expr(arg)?;
                     match &arg.ty {
-                        IrType::String => Ok(Some(quote! { #a.parse::<i64>().unwrap() })),
+                        IrType::String => Ok(Some(quote! { incan_stdlib::conversions::int_from_str(#a) })),
                         IrType::Float => Ok(Some(quote! { #a as i64 })),
                         IrType::Bool => Ok(Some(quote! { if #a { 1 } else { 0 } })),
                         _ => Ok(Some(quote! { #a as i64 })),
@@ -241,7 +389,7 @@ impl<'a> IrEmitter<'a> {
                 if let Some(arg) = args.first() {
                     let a = self.emit_expr(arg)?;
                     match &arg.ty {
-                        IrType::String => Ok(Some(quote! { #a.parse::<f64>().unwrap() })),
+                        IrType::String => Ok(Some(quote! { incan_stdlib::conversions::float_from_str(#a) })),
                         IrType::Int => Ok(Some(quote! { #a as f64 })),
                         _ => Ok(Some(quote! { #a as f64 })),
                     }
@@ -310,11 +458,87 @@ impl<'a> IrEmitter<'a> {
                     Ok(None)
                 }
             }
+            "min" => Ok(Some(self.emit_min_max_call(false, args)?)),
+            "max" => Ok(Some(self.emit_min_max_call(true, args)?)),
+            "sorted" => {
+                if let Some(arg) = args.first() {
+                    let a = self.emit_expr(arg)?;
+                    if matches!(list_elem_type(&arg.ty), IrType::Float) {
+                        Ok(Some(
+                            quote! { { let mut v = #a.clone(); v.sort_by(|a, b| a.partial_cmp(b).unwrap()); v } },
+                        ))
+                    } else {
+                        Ok(Some(quote! { { let mut v = #a.clone(); v.sort(); v } }))
+                    }
+                } else {
+                    Ok(None)
+                }
+            }
+            "reversed" => {
+                if let Some(arg) = args.first() {
+                    let a = self.emit_expr(arg)?;
+                    Ok(Some(quote! { #a.iter().rev().cloned().collect::<Vec<_>>() }))
+                } else {
+                    Ok(None)
+                }
+            }
+            "round" => {
+                if let Some(arg) = args.first() {
+                    let a = self.emit_expr(arg)?;
+                    match &arg.ty {
+                        IrType::Int => Ok(Some(quote! { #a })),
+                        _ => Ok(Some(quote! { (#a.round() as i64) })),
+                    }
+                } else {
+                    Ok(None)
+                }
+            }
+            "map" => {
+                if args.len() >= 2 {
+                    let f = self.emit_expr(&args[0])?;
+                    let xs = self.emit_expr(&args[1])?;
+                    Ok(Some(quote! { #xs.iter().cloned().map(#f).collect::<Vec<_>>() }))
+                } else {
+                    Ok(None)
+                }
+            }
+            "filter" => {
+                if args.len() >= 2 {
+                    let f = self.emit_expr(&args[0])?;
+                    let xs = self.emit_expr(&args[1])?;
+                    Ok(Some(quote! { #xs.iter().cloned().filter(|v| #f(v.clone())).collect::<Vec<_>>() }))
+                } else {
+                    Ok(None)
+                }
+            }
+            "any" => {
+                if let Some(arg) = args.first() {
+                    let a = self.emit_expr(arg)?;
+                    Ok(Some(quote! { #a.iter().any(|v| *v) }))
+                } else {
+                    Ok(None)
+                }
+            }
+            "all" => {
+                if let Some(arg) = args.first() {
+                    let a = self.emit_expr(arg)?;
+                    Ok(Some(quote! { #a.iter().all(|v| *v) }))
+                } else {
+                    Ok(None)
+                }
+            }
             _ => Ok(None),
         }
     }
 
     /// Emit a range() function call.
+    ///
+    /// All forms always yield `i64` items, independent of how the surrounding loop uses them.
+    /// The three-arg form branches on the step: a negative integer literal is emitted as a
+    /// reversed, stepped range; a positive literal step keeps the straightforward ascending form;
+    /// a literal step of 0, or a step that isn't a literal (and so could be positive, negative, or
+    /// zero at runtime), is handed off to `incan_stdlib::iter::range`, which branches on the sign at runtime
+    /// and yields an empty iterator when the direction and bounds disagree.
     pub(in super::super) fn emit_range_call(
         &self,
         args: &[TypedExpr],
@@ -330,35 +554,61 @@ impl<'a> IrEmitter<'a> {
                     (Some(s), Some(e), false) => {
                         let ss = self.emit_expr(s)?;
                         let ee = self.emit_expr(e)?;
-                        return Ok(Some(quote! { #ss..#ee }));
+                        return Ok(Some(quote! { (#ss as i64)..(#ee as i64) }));
                     }
                     (Some(s), Some(e), true) => {
                         let ss = self.emit_expr(s)?;
                         let ee = self.emit_expr(e)?;
-                        return Ok(Some(quote! { #ss..=#ee }));
+                        return Ok(Some(quote! { (#ss as i64)..=(#ee as i64) }));
                     }
                     (None, Some(e), _) => {
                         let ee = self.emit_expr(e)?;
-                        return Ok(Some(quote! { 0..#ee }));
+                        return Ok(Some(quote! { 0i64..(#ee as i64) }));
                     }
                     _ => {}
                 }
             } else {
                 let end = self.emit_expr(&args[0])?;
-                return Ok(Some(quote! { 0..#end }));
+                return Ok(Some(quote! { 0i64..(#end as i64) }));
             }
         }
         match args.len() {
             2 => {
                 let start = self.emit_expr(&args[0])?;
                 let end = self.emit_expr(&args[1])?;
-                Ok(Some(quote! { #start..#end }))
+                Ok(Some(quote! { (#start as i64)..(#end as i64) }))
             }
             3 => {
-                let start = self.emit_expr(&args[0])?;
-                let end = self.emit_expr(&args[1])?;
-                let step = self.emit_expr(&args[2])?;
-                Ok(Some(quote! { (#start..#end).step_by(#step as usize) }))
+                let start_expr = self.emit_expr(&args[0])?;
+                let end_expr = self.emit_expr(&args[1])?;
+                let step_expr = self.emit_expr(&args[2])?;
+                match extract_int_literal_ir(&args[2]) {
+                    Some(step) if step < 0 => Ok(Some(quote! {
+                        (((#end_expr as i64) + 1)..=(#start_expr as i64))
+                            .rev()
+                            .step_by((-(#step_expr) as i64) as usize)
+                    })),
+                    // A literal step of 0 must still raise the canonical `ValueError`, not panic
+                    // with Rust's generic `step_by` assertion message, so route through the same
+                    // runtime helper the non-literal path below uses.
+                    Some(0) => Ok(Some(quote! {
+                        incan_stdlib::iter::range(
+                            #start_expr as i64,
+                            #end_expr as i64,
+                            #step_expr as i64,
+                        )
+                    })),
+                    Some(_) => Ok(Some(quote! {
+                        ((#start_expr as i64)..(#end_expr as i64)).step_by(#step_expr as usize)
+                    })),
+                    None => Ok(Some(quote! {
+                        incan_stdlib::iter::range(
+                            #start_expr as i64,
+                            #end_expr as i64,
+                            #step_expr as i64,
+                        )
+                    })),
+                }
             }
             _ => Ok(None),
         }
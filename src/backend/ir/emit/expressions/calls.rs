@@ -31,7 +31,7 @@ impl<'a> IrEmitter<'a> {
 
         // Look up function signature
         let function_sig = if let IrExprKind::Var { name, .. } = &func.kind {
-            self.function_registry.get(name)
+            self.function_registry.get(self.interner, name)
         } else {
             None
         };
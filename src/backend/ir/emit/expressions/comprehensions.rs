@@ -1,85 +1,207 @@
-//! Emit Rust code for list and dict comprehensions.
+//! Emit Rust code for list, dict, and set comprehensions.
 //!
 //! This module handles:
-//! - List comprehensions: `[expr for var in iter if cond]`
-//! - Dict comprehensions: `{key: value for var in iter if cond}`
+//! - List comprehensions: `[expr for a in xs for b in ys if cond ...]`
+//! - Dict comprehensions: `{key: value for a in xs for b in ys if cond ...}`
+//! - Set comprehensions: `{expr for a in xs for b in ys if cond ...}`
+//!
+//! A comprehension's clauses are a flat `Vec<CompClause>`, but each `for` introduces a new
+//! Rust closure scope, so emission groups the clauses by their leading `for` and nests a
+//! `flat_map` per group (later generators can reference earlier ones' loop variables this way).
+//! The common single-generator case is special-cased to emit the same token shapes as before
+//! (no unnecessary `move`/`flat_map`, and the existing dict key-clone heuristic still applies).
 
-use proc_macro2::TokenStream;
+use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote};
 
-use super::super::super::expr::{IrExprKind, TypedExpr};
+use super::super::super::expr::{CompClause, IrExprKind, TypedExpr};
 use super::super::{EmitError, IrEmitter};
 
+/// One `for`/trailing-`if*` group within a comprehension's clause list.
+struct CompGroup<'a> {
+    variable: &'a str,
+    iterable: &'a TypedExpr,
+    conds: Vec<&'a TypedExpr>,
+}
+
 impl<'a> IrEmitter<'a> {
+    /// Split a comprehension's clauses into one group per `for`, collecting the `if`s that
+    /// trail it. The parser guarantees the first clause is always a `for`.
+    fn comp_groups<'c>(clauses: &'c [CompClause]) -> Vec<CompGroup<'c>> {
+        let mut groups: Vec<CompGroup<'c>> = Vec::new();
+        for clause in clauses {
+            match clause {
+                CompClause::For { variable, iterable } => groups.push(CompGroup {
+                    variable,
+                    iterable,
+                    conds: Vec::new(),
+                }),
+                CompClause::If(cond) => {
+                    groups
+                        .last_mut()
+                        .expect("comprehension must have a leading for clause")
+                        .conds
+                        .push(cond);
+                }
+            }
+        }
+        groups
+    }
+
     /// Emit a list comprehension.
     ///
-    /// Converts `[expr for var in iter if cond]` to Rust iterator chain:
-    /// - Without filter: `iter.iter().cloned().map(|var| expr).collect::<Vec<_>>()`
-    /// - With filter: `iter.iter().cloned().filter(|&var| cond).map(|var| expr).collect::<Vec<_>>()`
+    /// Single generator: `iter.iter().cloned().filter(|&var| cond).map(|var| expr).collect::<Vec<_>>()`
+    /// (range iterables skip `.iter().cloned()` since they're already iterators).
     ///
-    /// For range iterables, we skip `.iter().cloned()` since ranges are already iterators.
+    /// Multiple generators nest via `flat_map`, innermost to outermost, so later generators and
+    /// their filters can see earlier generators' loop variables.
     pub(in super::super) fn emit_list_comp(
         &self,
         element: &TypedExpr,
-        variable: &str,
-        iterable: &TypedExpr,
-        filter: Option<&TypedExpr>,
+        clauses: &[CompClause],
     ) -> Result<TokenStream, EmitError> {
-        let iter = self.emit_expr(iterable)?;
-        let var_ident = format_ident!("{}", variable);
+        let groups = Self::comp_groups(clauses);
         let elem = self.emit_expr(element)?;
-
-        let is_range = self.is_range_iterable(iterable);
-        let iter_wrapped = quote! { (#iter) };
-
-        if let Some(filter_expr) = filter {
-            let filter_tokens = self.emit_expr(filter_expr)?;
-            if is_range {
-                Ok(quote! {
-                    #iter_wrapped.filter(|&#var_ident| #filter_tokens).map(|#var_ident| #elem).collect::<Vec<_>>()
-                })
-            } else {
-                Ok(quote! {
-                    #iter_wrapped.iter().cloned().filter(|&#var_ident| #filter_tokens).map(|#var_ident| #elem).collect::<Vec<_>>()
-                })
-            }
-        } else if is_range {
-            Ok(quote! {
-                #iter_wrapped.map(|#var_ident| #elem).collect::<Vec<_>>()
-            })
-        } else {
-            Ok(quote! {
-                #iter_wrapped.iter().cloned().map(|#var_ident| #elem).collect::<Vec<_>>()
-            })
+        if let [group] = groups.as_slice() {
+            return self.emit_single_gen_list(group, &elem, |chain| quote! { #chain.collect::<Vec<_>>() });
         }
+
+        let chain = self.emit_nested_chain(&groups, &quote! { std::iter::once(#elem) })?;
+        Ok(quote! { #chain.collect::<Vec<_>>() })
     }
 
     /// Emit a dict comprehension.
     ///
-    /// Converts `{key: value for var in iter if cond}` to Rust iterator chain:
-    /// `iter.iter().cloned().filter(...).map(|var| (key, value)).collect::<HashMap<_, _>>()`
+    /// Single generator: `iter.iter().cloned().filter(...).map(|var| (key, value)).collect::<HashMap<_, _>>()`.
+    /// Multiple generators nest via `flat_map` like [`Self::emit_list_comp`].
     pub(in super::super) fn emit_dict_comp(
         &self,
         key: &TypedExpr,
         value: &TypedExpr,
-        variable: &str,
-        iterable: &TypedExpr,
-        filter: Option<&TypedExpr>,
+        clauses: &[CompClause],
+    ) -> Result<TokenStream, EmitError> {
+        let groups = Self::comp_groups(clauses);
+        if let [group] = groups.as_slice() {
+            return self.emit_single_gen_dict(group, key, value);
+        }
+
+        let key_tokens = self.emit_expr(key)?;
+        let value_tokens = self.emit_expr(value)?;
+        let chain = self.emit_nested_chain(&groups, &quote! { std::iter::once((#key_tokens, #value_tokens)) })?;
+        Ok(quote! { #chain.collect::<std::collections::HashMap<_, _>>() })
+    }
+
+    /// Emit a set comprehension.
+    ///
+    /// Shares the same generator-chain emission as [`Self::emit_list_comp`], collecting into a
+    /// `HashSet` instead of a `Vec`.
+    pub(in super::super) fn emit_set_comp(
+        &self,
+        element: &TypedExpr,
+        clauses: &[CompClause],
+    ) -> Result<TokenStream, EmitError> {
+        let groups = Self::comp_groups(clauses);
+        let elem = self.emit_expr(element)?;
+        if let [group] = groups.as_slice() {
+            return self.emit_single_gen_set(group, &elem);
+        }
+
+        let chain = self.emit_nested_chain(&groups, &quote! { std::iter::once(#elem) })?;
+        Ok(quote! { #chain.collect::<std::collections::HashSet<_>>() })
+    }
+
+    /// Emit a generator expression.
+    ///
+    /// When `lazy` (decided during lowering by `lower::purity::is_lazy_safe`), this reuses the
+    /// same adapter chain as [`Self::emit_list_comp`] but skips the trailing `.collect()`,
+    /// leaving an `impl Iterator` the caller can consume without allocating. When the body isn't
+    /// provably side-effect-free, it falls back to `emit_list_comp`'s collecting shape.
+    pub(in super::super) fn emit_gen_exp(
+        &self,
+        element: &TypedExpr,
+        clauses: &[CompClause],
+        lazy: bool,
+    ) -> Result<TokenStream, EmitError> {
+        if !lazy {
+            return self.emit_list_comp(element, clauses);
+        }
+
+        let groups = Self::comp_groups(clauses);
+        let elem = self.emit_expr(element)?;
+        if let [group] = groups.as_slice() {
+            return self.emit_single_gen_list(group, &elem, |chain| chain);
+        }
+
+        self.emit_nested_chain(&groups, &quote! { std::iter::once(#elem) })
+    }
+
+    /// Emit the single-generator list comprehension shape, reusing `finish` to adapt the
+    /// trailing `.collect()` (list comprehensions collect into `Vec<_>`).
+    fn emit_single_gen_list(
+        &self,
+        group: &CompGroup<'_>,
+        elem: &TokenStream,
+        finish: impl Fn(TokenStream) -> TokenStream,
     ) -> Result<TokenStream, EmitError> {
-        let iter = self.emit_expr(iterable)?;
-        let var_ident = format_ident!("{}", variable);
+        let iter = self.emit_expr(group.iterable)?;
+        let var_ident = format_ident!("{}", group.variable);
+        let is_range = self.is_range_iterable(group.iterable);
+        let iter_wrapped = quote! { (#iter) };
+        let cond = self.combine_conds(&group.conds)?;
+
+        let chain = match (is_range, cond) {
+            (true, Some(cond)) => quote! {
+                #iter_wrapped.filter(|&#var_ident| #cond).map(|#var_ident| #elem)
+            },
+            (true, None) => quote! {
+                #iter_wrapped.map(|#var_ident| #elem)
+            },
+            (false, Some(cond)) => quote! {
+                #iter_wrapped.iter().cloned().filter(|&#var_ident| #cond).map(|#var_ident| #elem)
+            },
+            (false, None) => quote! {
+                #iter_wrapped.iter().cloned().map(|#var_ident| #elem)
+            },
+        };
+        Ok(finish(chain))
+    }
+
+    /// Single-generator set comprehension: identical shape to the list case but collecting into
+    /// a `HashSet`.
+    fn emit_single_gen_set(&self, group: &CompGroup<'_>, elem: &TokenStream) -> Result<TokenStream, EmitError> {
+        self.emit_single_gen_list(group, elem, |chain| quote! { #chain.collect::<std::collections::HashSet<_>>() })
+    }
+
+    /// Single-generator dict comprehension, preserving the existing key-clone heuristic.
+    fn emit_single_gen_dict(&self, group: &CompGroup<'_>, key: &TypedExpr, value: &TypedExpr) -> Result<TokenStream, EmitError> {
+        let iter = self.emit_expr(group.iterable)?;
+        let var_ident = format_ident!("{}", group.variable);
         let key_tokens = self.emit_expr(key)?;
         let value_tokens = self.emit_expr(value)?;
+        let cloned_key = self.clone_key_if_needed(key, group.variable, group.iterable, &key_tokens);
+        let cond = self.combine_conds(&group.conds)?;
 
-        // Determine if the key needs cloning.
-        // For dict comprehensions, keys need cloning when:
-        // 1. The key type is non-Copy AND
-        // 2. The key is NOT a simple variable reference to the loop variable (in which case it's already "consumed" by
-        //    the key tuple position)
-        //
-        // Special case: when iterating over a list of string literals (`Vec<&str>`), the IR element type is
-        // `IrType::String`, but the Rust runtime type is `&str` which IS Copy. Check if the key is just the loop
-        // variable, and if the iterable's element type is String (which emits as `&str` for literals).
+        if let Some(cond_tokens) = cond {
+            Ok(quote! {
+                (#iter).iter().cloned().filter(|#var_ident| #cond_tokens).map(|#var_ident| (#cloned_key, #value_tokens)).collect::<std::collections::HashMap<_, _>>()
+            })
+        } else {
+            Ok(quote! {
+                (#iter).iter().cloned().map(|#var_ident| (#cloned_key, #value_tokens)).collect::<std::collections::HashMap<_, _>>()
+            })
+        }
+    }
+
+    /// Determine if the dict comprehension key needs `.clone()`.
+    ///
+    /// Keys need cloning when the key type is non-Copy AND the key is not a simple reference to
+    /// the loop variable (in which case it's already "consumed" by the key tuple position).
+    ///
+    /// Special case: when iterating over a list of string literals (`Vec<&str>`), the IR element
+    /// type is `IrType::String`, but the Rust runtime type is `&str`, which IS Copy. Check if the
+    /// key is just the loop variable, and if the iterable's element type is String (which emits
+    /// as `&str` for literals).
+    fn clone_key_if_needed(&self, key: &TypedExpr, variable: &str, iterable: &TypedExpr, key_tokens: &TokenStream) -> TokenStream {
         let is_key_copy = key.ty.is_copy();
         let is_key_just_loop_var = matches!(
             &key.kind,
@@ -91,24 +213,57 @@ impl<'a> IrEmitter<'a> {
             }
             _ => false,
         };
-        // Skip clone if the key is Copy, OR if the key is just the loop var and we're iterating over a list of
-        // strings (which are &str at runtime)
         let needs_clone = !(is_key_copy || (is_key_just_loop_var && iterable_elem_is_string));
-        let cloned_key = if needs_clone {
+        if needs_clone {
             quote! { #key_tokens.clone() }
         } else {
             quote! { #key_tokens }
+        }
+    }
+
+    /// Combine a group's `if` clauses into a single `&&`-joined predicate, if any are present.
+    fn combine_conds(&self, conds: &[&TypedExpr]) -> Result<Option<TokenStream>, EmitError> {
+        let mut conds_tokens = conds.iter().map(|c| self.emit_expr(c));
+        let Some(first) = conds_tokens.next() else {
+            return Ok(None);
         };
+        let mut combined = first?;
+        for cond in conds_tokens {
+            let cond = cond?;
+            combined = quote! { (#combined) && (#cond) };
+        }
+        Ok(Some(combined))
+    }
 
-        if let Some(filter_expr) = filter {
-            let filter_tokens = self.emit_expr(filter_expr)?;
-            Ok(quote! {
-                #iter.iter().cloned().filter(|#var_ident| #filter_tokens).map(|#var_ident| (#cloned_key, #value_tokens)).collect::<std::collections::HashMap<_, _>>()
-            })
+    /// Emit nested `flat_map`s, one per generator group, innermost group last.
+    ///
+    /// Every group's iterable and filter closures are `move` closures, since inner groups
+    /// reference the loop variables bound by outer ones. `body` is the innermost producer
+    /// (e.g. `std::iter::once(expr)`), reused unchanged at the bottom of the chain.
+    fn emit_nested_chain(&self, groups: &[CompGroup<'_>], body: &TokenStream) -> Result<TokenStream, EmitError> {
+        let Some((group, rest)) = groups.split_first() else {
+            return Ok(body.clone());
+        };
+
+        let iter = self.emit_expr(group.iterable)?;
+        let var_ident: Ident = format_ident!("{}", group.variable);
+        let is_range = self.is_range_iterable(group.iterable);
+        let base = if is_range {
+            quote! { (#iter) }
         } else {
-            Ok(quote! {
-                #iter.iter().cloned().map(|#var_ident| (#cloned_key, #value_tokens)).collect::<std::collections::HashMap<_, _>>()
-            })
+            quote! { (#iter).iter().cloned() }
+        };
+        let cond = self.combine_conds(&group.conds)?;
+        let filtered = match cond {
+            Some(cond) => quote! { #base.filter(move |&#var_ident| #cond) },
+            None => base,
+        };
+
+        if rest.is_empty() {
+            Ok(quote! { #filtered.flat_map(move |#var_ident| #body) })
+        } else {
+            let inner = self.emit_nested_chain(rest, body)?;
+            Ok(quote! { #filtered.flat_map(move |#var_ident| #inner) })
         }
     }
 
@@ -123,6 +123,18 @@ impl<'a> IrEmitter<'a> {
         }
     }
 
+    /// Emit a tuple element access (`t[0]`, `t[-1]`), already resolved to a statically known
+    /// field index during lowering (see [`IrExprKind::TupleIndex`](super::super::super::expr::IrExprKind::TupleIndex)).
+    pub(in super::super) fn emit_tuple_index_expr(
+        &self,
+        object: &TypedExpr,
+        index: usize,
+    ) -> Result<TokenStream, EmitError> {
+        let o = self.emit_expr(object)?;
+        let idx = syn::Index::from(index);
+        Ok(quote! { #o.#idx })
+    }
+
     /// Helper: emit an index expression with negative-index handling.
     ///
     /// Converts Python-style negative indices to `len() - offset`.
@@ -29,12 +29,47 @@ pub(super) fn emit_string_method(
         } else {
             Ok(quote! { #r.to_lowercase() })
         }),
-        MethodKind::Strip => Some(if is_stringish {
-            Ok(quote! { incan_stdlib::strings::str_strip(#r_borrow) })
+        MethodKind::Title => Some(if is_stringish {
+            Ok(quote! { incan_stdlib::strings::str_title(#r_borrow) })
         } else {
-            Ok(quote! { #r.trim().to_string() })
+            Ok(quote! { #r.to_string() })
         }),
-        MethodKind::Split => {
+        MethodKind::Capitalize => Some(if is_stringish {
+            Ok(quote! { incan_stdlib::strings::str_capitalize(#r_borrow) })
+        } else {
+            Ok(quote! { #r.to_string() })
+        }),
+        MethodKind::Casefold => Some(if is_stringish {
+            Ok(quote! { incan_stdlib::strings::str_casefold(#r_borrow) })
+        } else {
+            Ok(quote! { #r.to_lowercase() })
+        }),
+        MethodKind::Strip | MethodKind::Lstrip | MethodKind::Rstrip => {
+            let chars = match args.first() {
+                Some(arg) => match emitter.emit_expr(arg) {
+                    Ok(a) => quote! { Some(&#a) },
+                    Err(e) => return Some(Err(e)),
+                },
+                None => quote! { None },
+            };
+            let func = match kind {
+                MethodKind::Strip => quote! { incan_stdlib::strings::str_strip },
+                MethodKind::Lstrip => quote! { incan_stdlib::strings::str_lstrip },
+                MethodKind::Rstrip => quote! { incan_stdlib::strings::str_rstrip },
+                _ => unreachable!("only called for Strip/Lstrip/Rstrip"),
+            };
+            Some(if is_stringish {
+                Ok(quote! { #func(#r_borrow, #chars) })
+            } else {
+                match kind {
+                    MethodKind::Strip => Ok(quote! { #r.trim().to_string() }),
+                    MethodKind::Lstrip => Ok(quote! { #r.trim_start().to_string() }),
+                    MethodKind::Rstrip => Ok(quote! { #r.trim_end().to_string() }),
+                    _ => unreachable!("only called for Strip/Lstrip/Rstrip"),
+                }
+            })
+        }
+        MethodKind::Split | MethodKind::Rsplit => {
             let sep = if let Some(arg) = args.first() {
                 match emitter.emit_expr(arg) {
                     Ok(a) => quote! { Some(&#a) },
@@ -43,8 +78,44 @@ pub(super) fn emit_string_method(
             } else {
                 quote! { None }
             };
-            Some(Ok(quote! { incan_stdlib::strings::str_split(#r_borrow, #sep) }))
+            let maxsplit = match args.get(1) {
+                Some(arg) => match emitter.emit_expr(arg) {
+                    Ok(a) => quote! { Some(#a) },
+                    Err(e) => return Some(Err(e)),
+                },
+                None => quote! { None },
+            };
+            let func = match kind {
+                MethodKind::Split => quote! { incan_stdlib::strings::str_split },
+                MethodKind::Rsplit => quote! { incan_stdlib::strings::str_rsplit },
+                _ => unreachable!("only called for Split/Rsplit"),
+            };
+            Some(Ok(quote! { #func(#r_borrow, #sep, #maxsplit) }))
         }
+        MethodKind::Splitlines => {
+            let keepends = match args.first() {
+                Some(arg) => match emitter.emit_expr(arg) {
+                    Ok(a) => a,
+                    Err(e) => return Some(Err(e)),
+                },
+                None => quote! { false },
+            };
+            Some(Ok(quote! { incan_stdlib::strings::str_splitlines(#r_borrow, #keepends) }))
+        }
+        MethodKind::Partition | MethodKind::Rpartition => Some(if let Some(arg) = args.first() {
+            let sep = match emitter.emit_expr(arg) {
+                Ok(v) => v,
+                Err(e) => return Some(Err(e)),
+            };
+            let func = match kind {
+                MethodKind::Partition => quote! { incan_stdlib::strings::str_partition },
+                MethodKind::Rpartition => quote! { incan_stdlib::strings::str_rpartition },
+                _ => unreachable!("only called for Partition/Rpartition"),
+            };
+            Ok(quote! { #func(#r_borrow, &#sep) })
+        } else {
+            Ok(quote! { (#r.to_string(), String::new(), String::new()) })
+        }),
         MethodKind::Replace => Some(if args.len() >= 2 {
             let pattern = match emitter.emit_expr(&args[0]) {
                 Ok(p) => p,
@@ -108,6 +179,47 @@ pub(super) fn emit_string_method(
         } else {
             Ok(quote! { false })
         }),
+        MethodKind::Find | MethodKind::Rfind | MethodKind::IndexOf | MethodKind::RindexOf | MethodKind::Count => {
+            Some(emit_string_search_method(emitter, r_borrow, kind, args))
+        }
         _ => None,
     }
 }
+
+/// Emit `find`/`rfind`/`index`/`rindex`/`count`, all of which share the same
+/// `(needle, start: Option<i64>, end: Option<i64>)` shape in `incan_stdlib::strings`.
+fn emit_string_search_method(
+    emitter: &IrEmitter,
+    r_borrow: &TokenStream,
+    kind: &MethodKind,
+    args: &[TypedExpr],
+) -> Result<TokenStream, EmitError> {
+    let Some(needle) = args.first() else {
+        return Ok(quote! { 0i64 });
+    };
+    let needle = emitter.emit_expr(needle)?;
+    let start = match args.get(1) {
+        Some(arg) => {
+            let a = emitter.emit_expr(arg)?;
+            quote! { Some(#a) }
+        }
+        None => quote! { None },
+    };
+    let end = match args.get(2) {
+        Some(arg) => {
+            let a = emitter.emit_expr(arg)?;
+            quote! { Some(#a) }
+        }
+        None => quote! { None },
+    };
+
+    let func = match kind {
+        MethodKind::Find => quote! { incan_stdlib::strings::str_find },
+        MethodKind::Rfind => quote! { incan_stdlib::strings::str_rfind },
+        MethodKind::IndexOf => quote! { incan_stdlib::strings::str_index_of },
+        MethodKind::RindexOf => quote! { incan_stdlib::strings::str_rindex_of },
+        MethodKind::Count => quote! { incan_stdlib::strings::str_count },
+        _ => unreachable!("only called for the string search MethodKind variants"),
+    };
+    Ok(quote! { #func(#r_borrow, &#needle, #start, #end) })
+}
@@ -125,21 +125,13 @@ impl<'a> IrEmitter<'a> {
 
             IrExprKind::Field { object, field } => self.emit_field_expr(object, field),
             IrExprKind::Index { object, index } => self.emit_index_expr(object, index),
+            IrExprKind::TupleIndex { object, index } => self.emit_tuple_index_expr(object, *index),
             IrExprKind::Slice { target, start, end } => self.emit_slice_expr(target, start, end),
 
-            IrExprKind::ListComp {
-                element,
-                variable,
-                iterable,
-                filter,
-            } => self.emit_list_comp(element, variable, iterable, filter.as_deref()),
-            IrExprKind::DictComp {
-                key,
-                value,
-                variable,
-                iterable,
-                filter,
-            } => self.emit_dict_comp(key, value, variable, iterable, filter.as_deref()),
+            IrExprKind::ListComp { element, clauses } => self.emit_list_comp(element, clauses),
+            IrExprKind::DictComp { key, value, clauses } => self.emit_dict_comp(key, value, clauses),
+            IrExprKind::SetComp { element, clauses } => self.emit_set_comp(element, clauses),
+            IrExprKind::GenExp { element, clauses, lazy } => self.emit_gen_exp(element, clauses, *lazy),
 
             IrExprKind::List(items) => {
                 let item_tokens: Vec<TokenStream> = items
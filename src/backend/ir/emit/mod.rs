@@ -66,8 +66,12 @@ pub struct IrEmitter<'a> {
     needs_axum: bool,
     /// Function registry for call-site type checking
     function_registry: &'a FunctionRegistry,
+    /// Interner backing `function_registry`'s keys
+    interner: &'a Interner,
     /// Track struct derives for generating serde methods in impl blocks
     struct_derives: std::collections::HashMap<String, Vec<String>>,
+    /// Track struct `@formats(...)` entries for generating to_<fmt>/from_<fmt> methods
+    struct_formats: std::collections::HashMap<String, Vec<String>>,
     /// Current function's return type (for applying conversions in return statements)
     current_function_return_type: RefCell<Option<IrType>>,
     /// Functions imported from external Rust crates
@@ -78,6 +82,24 @@ pub struct IrEmitter<'a> {
     struct_field_types: std::collections::HashMap<(String, String), IrType>,
     /// Whether we're currently emitting a return expression (allows moves instead of clones)
     in_return_context: RefCell<bool>,
+    /// Whether generating in test mode (emit #[test] attributes)
+    test_mode: bool,
+    /// Specific test function to mark with #[test] (if any; otherwise every
+    /// `test_`-prefixed function is marked)
+    test_function: Option<String>,
+    /// Per-test directives (`ignore`, `should_panic`) parsed during discovery (see
+    /// `crate::cli::test_runner::TestMarker`), keyed by test function name.
+    test_directives: std::collections::HashMap<String, TestDirectives>,
+}
+
+/// Directives attached to a single test function that affect its emitted `#[test]` attributes,
+/// mirroring rustdoc's `Ignore`/`should_panic` doctest attributes and compiletest headers.
+#[derive(Debug, Clone, Default)]
+pub struct TestDirectives {
+    /// `#[ignore]` (or `#[ignore = "reason"]`) - still compiled, not executed.
+    pub ignore: Option<String>,
+    /// `#[should_panic]`, optionally with `expected = "..."`.
+    pub should_panic: Option<Option<String>>,
 }
 
 /// Import tracking for warning-free codegen
@@ -218,6 +240,7 @@ impl ImportTracker {
                 self.scan_expr(index);
             }
             IrExprKind::Field { object, .. } => self.scan_expr(object),
+            IrExprKind::TupleIndex { object, .. } => self.scan_expr(object),
             IrExprKind::If {
                 condition,
                 then_branch,
@@ -248,7 +271,7 @@ impl ImportTracker {
 }
 
 impl<'a> IrEmitter<'a> {
-    pub fn new(function_registry: &'a FunctionRegistry) -> Self {
+    pub fn new(function_registry: &'a FunctionRegistry, interner: &'a Interner) -> Self {
         Self {
             // Enable minimal allows for patterns that can't easily be made warning-free:
             // - dead_code: library modules export functions that may not be used by main
@@ -260,12 +283,17 @@ impl<'a> IrEmitter<'a> {
             needs_tokio: false,
             needs_axum: false,
             function_registry,
+            interner,
             struct_derives: std::collections::HashMap::new(),
+            struct_formats: std::collections::HashMap::new(),
             current_function_return_type: RefCell::new(None),
             external_rust_functions: std::collections::HashSet::new(),
             enum_variant_fields: std::collections::HashMap::new(),
             struct_field_types: std::collections::HashMap::new(),
             in_return_context: RefCell::new(false),
+            test_mode: false,
+            test_function: None,
+            test_directives: std::collections::HashMap::new(),
         }
     }
 
@@ -289,6 +317,21 @@ impl<'a> IrEmitter<'a> {
         self.needs_axum = needs;
     }
 
+    /// Enable test mode (emit #[test] attributes)
+    pub fn set_test_mode(&mut self, enabled: bool) {
+        self.test_mode = enabled;
+    }
+
+    /// Set specific test function to mark with #[test]
+    pub fn set_test_function(&mut self, name: &str) {
+        self.test_function = Some(name.to_string());
+    }
+
+    /// Set `ignore`/`should_panic` directives to apply per test function name.
+    pub fn set_test_directives(&mut self, directives: std::collections::HashMap<String, TestDirectives>) {
+        self.test_directives = directives;
+    }
+
     /// Escape Rust keywords by adding r# prefix
     /// Note: self and Self cannot be raw identifiers
     fn escape_keyword(name: &str) -> String {
@@ -332,6 +375,10 @@ impl<'a> IrEmitter<'a> {
                     self.struct_derives
                         .insert(s.name.clone(), s.derives.clone());
                 }
+                if !s.formats.is_empty() {
+                    self.struct_formats
+                        .insert(s.name.clone(), s.formats.clone());
+                }
                 // Collect field types for conversion targeting
                 for field in &s.fields {
                     self.struct_field_types
@@ -627,6 +674,64 @@ impl<'a> IrEmitter<'a> {
                     // Keep these as regular methods for now (reflection)
                     regular_methods.push(self.emit_method(method)?);
                 }
+                "__add__" => {
+                    regular_methods.push(self.emit_method(method)?);
+                    trait_impls.push(self.emit_binary_op_trait_impl(method, &target_type, quote! { std::ops::Add }, "add")?);
+                }
+                "__sub__" => {
+                    regular_methods.push(self.emit_method(method)?);
+                    trait_impls.push(self.emit_binary_op_trait_impl(method, &target_type, quote! { std::ops::Sub }, "sub")?);
+                }
+                "__mul__" => {
+                    regular_methods.push(self.emit_method(method)?);
+                    trait_impls.push(self.emit_binary_op_trait_impl(method, &target_type, quote! { std::ops::Mul }, "mul")?);
+                }
+                "__truediv__" => {
+                    regular_methods.push(self.emit_method(method)?);
+                    trait_impls.push(self.emit_binary_op_trait_impl(method, &target_type, quote! { std::ops::Div }, "div")?);
+                }
+                "__mod__" => {
+                    regular_methods.push(self.emit_method(method)?);
+                    trait_impls.push(self.emit_binary_op_trait_impl(method, &target_type, quote! { std::ops::Rem }, "rem")?);
+                }
+                "__neg__" => {
+                    // Generate impl Neg (unary, no rhs parameter)
+                    regular_methods.push(self.emit_method(method)?);
+                    let dunder_name = format_ident!("{}", &method.name);
+                    let output_ty = self.emit_type(&method.return_type);
+                    trait_impls.push(quote! {
+                        impl std::ops::Neg for #target_type {
+                            type Output = #output_ty;
+                            fn neg(self) -> Self::Output {
+                                self.#dunder_name()
+                            }
+                        }
+                    });
+                }
+                "__getitem__" => {
+                    regular_methods.push(self.emit_method(method)?);
+                    trait_impls.push(self.emit_index_trait_impl(method, &target_type)?);
+                }
+                "__setitem__" => {
+                    // `IndexMut::index_mut(&mut self, index) -> &mut Self::Output` hands the
+                    // caller a mutable slot for `obj[i] = v` to assign through; it has no
+                    // second parameter for `v`. That doesn't fit `__setitem__`'s Python
+                    // `(self, index, value)` shape, so there's no faithful forwarding impl here
+                    // — keep it callable as a regular method only.
+                    regular_methods.push(self.emit_method(method)?);
+                }
+                "__len__" => {
+                    // Keep __len__ itself callable, and add an inherent `len()` matching Rust
+                    // convention (e.g. for `is_empty`/collection-like usage) that forwards to it.
+                    regular_methods.push(self.emit_method(method)?);
+                    let dunder_name = format_ident!("{}", &method.name);
+                    let ret_ty = self.emit_type(&method.return_type);
+                    regular_methods.push(quote! {
+                        pub fn len(&self) -> #ret_ty {
+                            self.#dunder_name()
+                        }
+                    });
+                }
                 _ => {
                     regular_methods.push(self.emit_method(method)?);
                 }
@@ -657,6 +762,10 @@ impl<'a> IrEmitter<'a> {
                         }
                     });
                 }
+
+                if let Some(formats) = self.struct_formats.get(&impl_block.target_type) {
+                    regular_methods.push(self.emit_format_methods(formats, has_serialize, has_deserialize));
+                }
             }
         }
 
@@ -675,9 +784,19 @@ impl<'a> IrEmitter<'a> {
                     })
                     .map(|m| self.emit_trait_method(m))
                     .collect::<Result<_, _>>()?;
+                let assoc_types: Vec<TokenStream> = impl_block
+                    .assoc_types
+                    .iter()
+                    .map(|(name, ty)| {
+                        let n = format_ident!("{}", name);
+                        let t = self.emit_type(ty);
+                        quote! { type #n = #t; }
+                    })
+                    .collect();
                 let trait_ident = format_ident!("{}", trait_name);
                 quote! {
                     impl #trait_ident for #target_type {
+                        #(#assoc_types)*
                         #(#trait_methods)*
                     }
                 }
@@ -707,6 +826,141 @@ impl<'a> IrEmitter<'a> {
         })
     }
 
+    /// Emit `impl <trait_path> for Type`, where the trait's single binary-op method (e.g.
+    /// `Add::add`) takes `self`/`rhs` by value (as the `std::ops` traits require) and forwards to
+    /// the dunder inherent method — which takes `&self`, so the call auto-refs `self`.
+    fn emit_binary_op_trait_impl(
+        &self,
+        method: &super::decl::IrFunction,
+        target_type: &proc_macro2::Ident,
+        trait_path: TokenStream,
+        op_name: &str,
+    ) -> Result<TokenStream, EmitError> {
+        let dunder_name = format_ident!("{}", &method.name);
+        let op_ident = format_ident!("{}", op_name);
+        let rhs_param = method.params.iter().find(|p| !p.is_self);
+        let rhs_ty = rhs_param
+            .map(|p| self.emit_type(&p.ty))
+            .unwrap_or_else(|| quote! { Self });
+        let rhs_name = rhs_param
+            .map(|p| format_ident!("{}", &p.name))
+            .unwrap_or_else(|| format_ident!("rhs"));
+        let output_ty = self.emit_type(&method.return_type);
+
+        Ok(quote! {
+            impl #trait_path for #target_type {
+                type Output = #output_ty;
+                fn #op_ident(self, #rhs_name: #rhs_ty) -> Self::Output {
+                    self.#dunder_name(#rhs_name)
+                }
+            }
+        })
+    }
+
+    /// Emit `impl std::ops::Index<Idx> for Type`, forwarding to `__getitem__`.
+    ///
+    /// `Index::index` must return `&Self::Output`. If `__getitem__`'s declared return type is
+    /// itself a reference, `Output` is the pointee and the call forwards directly; otherwise
+    /// `Output` is the declared (owned) type and the call is borrowed, which only type-checks if
+    /// `__getitem__`'s body actually returns a reference into `self` (e.g. indexing a stored
+    /// `Vec` field) despite the declared type.
+    fn emit_index_trait_impl(
+        &self,
+        method: &super::decl::IrFunction,
+        target_type: &proc_macro2::Ident,
+    ) -> Result<TokenStream, EmitError> {
+        let dunder_name = format_ident!("{}", &method.name);
+        let idx_param = method.params.iter().find(|p| !p.is_self);
+        let idx_ty = idx_param
+            .map(|p| self.emit_type(&p.ty))
+            .unwrap_or_else(|| quote! { usize });
+        let idx_name = idx_param
+            .map(|p| format_ident!("{}", &p.name))
+            .unwrap_or_else(|| format_ident!("index"));
+
+        let (output_ty, call) = match &method.return_type {
+            IrType::Ref(inner) | IrType::RefMut(inner) => {
+                let ty = self.emit_type(inner);
+                (ty, quote! { self.#dunder_name(#idx_name) })
+            }
+            other => {
+                let ty = self.emit_type(other);
+                (ty, quote! { &self.#dunder_name(#idx_name) })
+            }
+        };
+
+        Ok(quote! {
+            impl std::ops::Index<#idx_ty> for #target_type {
+                type Output = #output_ty;
+                fn index(&self, #idx_name: #idx_ty) -> &Self::Output {
+                    #call
+                }
+            }
+        })
+    }
+
+    /// Emit `to_<fmt>`/`from_<fmt>` methods for each format named in an `@formats(...)`
+    /// decorator, gated independently on `has_serialize`/`has_deserialize` the same way
+    /// `to_json`/`from_json` are.
+    fn emit_format_methods(&self, formats: &[String], has_serialize: bool, has_deserialize: bool) -> TokenStream {
+        let mut methods = Vec::new();
+        for format in formats {
+            let (to_method, from_method) = match format.as_str() {
+                "yaml" => (
+                    quote! {
+                        /// Serialize this model to a YAML string
+                        pub fn to_yaml(&self) -> String {
+                            serde_yaml::to_string(self).expect("YamlError: failed to serialize to YAML")
+                        }
+                    },
+                    quote! {
+                        /// Deserialize a YAML string into this model
+                        pub fn from_yaml(yaml_str: String) -> Result<Self, String> {
+                            serde_yaml::from_str(&yaml_str).map_err(|e| e.to_string())
+                        }
+                    },
+                ),
+                "toml" => (
+                    quote! {
+                        /// Serialize this model to a TOML string
+                        pub fn to_toml(&self) -> String {
+                            toml::to_string(self).expect("TomlError: failed to serialize to TOML")
+                        }
+                    },
+                    quote! {
+                        /// Deserialize a TOML string into this model
+                        pub fn from_toml(toml_str: String) -> Result<Self, String> {
+                            toml::from_str(&toml_str).map_err(|e| e.to_string())
+                        }
+                    },
+                ),
+                "msgpack" => (
+                    quote! {
+                        /// Serialize this model to MessagePack bytes
+                        pub fn to_msgpack(&self) -> Vec<u8> {
+                            rmp_serde::to_vec(self).expect("MsgpackError: failed to serialize to MessagePack")
+                        }
+                    },
+                    quote! {
+                        /// Deserialize MessagePack bytes into this model
+                        pub fn from_msgpack(bytes: Vec<u8>) -> Result<Self, String> {
+                            rmp_serde::from_slice(&bytes).map_err(|e| e.to_string())
+                        }
+                    },
+                ),
+                _ => continue,
+            };
+
+            if has_serialize {
+                methods.push(to_method);
+            }
+            if has_deserialize {
+                methods.push(from_method);
+            }
+        }
+        quote! { #(#methods)* }
+    }
+
     /// Emit a method (like a function but inside an impl block)
     fn emit_method(&self, func: &super::decl::IrFunction) -> Result<TokenStream, EmitError> {
         let name = format_ident!("{}", &func.name);
@@ -753,8 +1007,14 @@ impl<'a> IrEmitter<'a> {
         // Clear return type after method emission
         *self.current_function_return_type.borrow_mut() = None;
 
+        let const_kw = if func.constness == Constness::Const {
+            quote! { const }
+        } else {
+            quote! {}
+        };
+
         Ok(quote! {
-            #vis fn #name(#(#params),*) #ret {
+            #vis #const_kw fn #name(#(#params),*) #ret {
                 #(#body_stmts)*
             }
         })
@@ -823,6 +1083,13 @@ impl<'a> IrEmitter<'a> {
             quote! {}
         };
 
+        // `const fn` and `async fn` are mutually exclusive, and `main` is never const.
+        let const_kw = if !is_main && !func.is_async && func.constness == Constness::Const {
+            quote! { const }
+        } else {
+            quote! {}
+        };
+
         // For main, optionally emit the Zen of Incan at the start
         let zen_stmt = if is_main && self.emit_zen_in_main {
             let zen_text = r#"
@@ -851,12 +1118,42 @@ impl<'a> IrEmitter<'a> {
             quote! {}
         };
 
+        // In test mode, mark `test_`-prefixed functions with #[test] so `cargo test`
+        // picks them up (see `src/cli/test_runner.rs`). When a specific test function
+        // was requested, only that one is marked.
+        let is_test = self.test_mode
+            && func.name.starts_with("test_")
+            && self.test_function.as_ref().map_or(true, |tf| tf == &func.name);
+        let test_attr = if is_test {
+            let directives = self.test_directives.get(&func.name);
+
+            let ignore_attr = match directives.and_then(|d| d.ignore.as_ref()) {
+                Some(reason) if !reason.is_empty() => quote! { #[ignore = #reason] },
+                Some(_) => quote! { #[ignore] },
+                None => quote! {},
+            };
+            let should_panic_attr = match directives.and_then(|d| d.should_panic.as_ref()) {
+                Some(Some(expected)) => quote! { #[should_panic(expected = #expected)] },
+                Some(None) => quote! { #[should_panic] },
+                None => quote! {},
+            };
+
+            quote! {
+                #[test]
+                #ignore_attr
+                #should_panic_attr
+            }
+        } else {
+            quote! {}
+        };
+
         // Omit return type for main and functions returning unit
         let ret_ty_is_unit = matches!(func.return_type, IrType::Unit);
         if is_main || ret_ty_is_unit {
             Ok(quote! {
                 #tokio_main_attr
-                #vis #async_kw fn #name(#(#params),*) {
+                #test_attr
+                #vis #const_kw #async_kw fn #name(#(#params),*) {
                     #zen_stmt
                     #(#body_stmts)*
                 }
@@ -865,7 +1162,8 @@ impl<'a> IrEmitter<'a> {
             let ret_ty = self.emit_type(&func.return_type);
             Ok(quote! {
                 #tokio_main_attr
-                #vis #async_kw fn #name(#(#params),*) -> #ret_ty {
+                #test_attr
+                #vis #const_kw #async_kw fn #name(#(#params),*) -> #ret_ty {
                     #(#body_stmts)*
                 }
             })
@@ -899,6 +1197,15 @@ impl<'a> IrEmitter<'a> {
             quote! { #[derive(#(#derives),*)] }
         };
 
+        // `#[serde(...)]` attributes only make sense (and only avoid "unused attribute"
+        // warnings) on structs that actually derive Serialize/Deserialize.
+        let has_serde_derive = s.derives.iter().any(|d| d == "Serialize" || d == "Deserialize");
+
+        let rename_all_attr = match (&s.serde_rename_all, has_serde_derive) {
+            (Some(case), true) => quote! { #[serde(rename_all = #case)] },
+            _ => quote! {},
+        };
+
         // Check if this is a tuple struct (newtype) - field names are numeric
         let is_tuple_struct = !s.fields.is_empty()
             && s.fields
@@ -930,7 +1237,24 @@ impl<'a> IrEmitter<'a> {
                     let fname = format_ident!("{}", &f.name);
                     let fty = self.emit_type(&f.ty);
                     let fvis = self.emit_visibility(&f.visibility);
-                    quote! { #fvis #fname: #fty }
+
+                    let field_serde_attr = if has_serde_derive {
+                        let rename = f
+                            .serde_rename
+                            .as_ref()
+                            .map(|r| quote! { #[serde(rename = #r)] })
+                            .unwrap_or_default();
+                        let skip = f.serde_skip.then(|| quote! { #[serde(skip)] }).unwrap_or_default();
+                        let default = f
+                            .serde_default
+                            .then(|| quote! { #[serde(default)] })
+                            .unwrap_or_default();
+                        quote! { #rename #skip #default }
+                    } else {
+                        quote! {}
+                    };
+
+                    quote! { #field_serde_attr #fvis #fname: #fty }
                 })
                 .collect();
 
@@ -970,6 +1294,7 @@ impl<'a> IrEmitter<'a> {
 
             Ok(quote! {
                 #derive_attr
+                #rename_all_attr
                 #vis struct #name {
                     #(#fields),*
                 }
@@ -1054,6 +1379,31 @@ impl<'a> IrEmitter<'a> {
             })
             .collect();
 
+        // Add to_json/from_json for enums with Serialize/Deserialize, matching struct behavior.
+        let has_serialize = e.derives.iter().any(|d| d == "Serialize");
+        let has_deserialize = e.derives.iter().any(|d| d == "Deserialize");
+        let to_json = has_serialize
+            .then(|| {
+                quote! {
+                    /// Serialize this enum to a JSON string
+                    pub fn to_json(&self) -> String {
+                        serde_json::to_string(self).expect("JSONError: failed to serialize to JSON")
+                    }
+                }
+            })
+            .unwrap_or_default();
+        let from_json = has_deserialize
+            .then(|| {
+                quote! {
+                    /// Deserialize a JSON string into this enum
+                    pub fn from_json(json_str: String) -> Result<Self, String> {
+                        serde_json::from_str(&json_str).map_err(|e| e.to_string())
+                    }
+                }
+            })
+            .unwrap_or_default();
+        let format_methods = self.emit_format_methods(&e.formats, has_serialize, has_deserialize);
+
         Ok(quote! {
             #derive_attr
             #vis enum #name {
@@ -1066,6 +1416,10 @@ impl<'a> IrEmitter<'a> {
                         #(#variant_match_arms),*
                     }
                 }
+
+                #to_json
+                #from_json
+                #format_methods
             }
         })
     }
@@ -1142,15 +1496,17 @@ mod tests {
             is_async: false,
             visibility: Visibility::Public,
             type_params: vec![],
+            constness: Constness::NotConst,
         };
 
         let program = IrProgram {
             declarations: vec![IrDecl::new(IrDeclKind::Function(func))],
             entry_point: None,
             function_registry: FunctionRegistry::new(),
+            interner: Interner::new(),
         };
 
-        let mut emitter = IrEmitter::new(&program.function_registry);
+        let mut emitter = IrEmitter::new(&program.function_registry, &program.interner);
         let result = emitter.emit_program(&program);
         assert!(result.is_ok());
         let code = match result {
@@ -1170,25 +1526,34 @@ mod tests {
                     name: "name".to_string(),
                     ty: IrType::String,
                     visibility: Visibility::Public,
+                    serde_rename: None,
+                    serde_skip: false,
+                    serde_default: false,
                 },
                 super::super::decl::StructField {
                     name: "age".to_string(),
                     ty: IrType::Int,
                     visibility: Visibility::Public,
+                    serde_rename: None,
+                    serde_skip: false,
+                    serde_default: false,
                 },
             ],
             derives: vec!["Debug".to_string(), "Clone".to_string()],
             visibility: Visibility::Public,
             type_params: vec![],
+            serde_rename_all: None,
+            formats: vec![],
         };
 
         let program = IrProgram {
             declarations: vec![IrDecl::new(IrDeclKind::Struct(s))],
             entry_point: None,
             function_registry: FunctionRegistry::new(),
+            interner: Interner::new(),
         };
 
-        let mut emitter = IrEmitter::new(&program.function_registry);
+        let mut emitter = IrEmitter::new(&program.function_registry, &program.interner);
         let result = emitter.emit_program(&program);
         assert!(result.is_ok());
         let code = match result {
@@ -1206,7 +1571,8 @@ mod tests {
     #[test]
     fn test_emit_type_int() {
         let registry = FunctionRegistry::new();
-        let emitter = IrEmitter::new(&registry);
+        let interner = Interner::new();
+        let emitter = IrEmitter::new(&registry, &interner);
         let ty = IrType::Int;
         let result = emitter.emit_type(&ty);
         assert_eq!(result.to_string(), "i64");
@@ -1215,7 +1581,8 @@ mod tests {
     #[test]
     fn test_emit_type_list_int() {
         let registry = FunctionRegistry::new();
-        let emitter = IrEmitter::new(&registry);
+        let interner = Interner::new();
+        let emitter = IrEmitter::new(&registry, &interner);
         let ty = IrType::List(Box::new(IrType::Int));
         let result = emitter.emit_type(&ty);
         assert_eq!(result.to_string(), "Vec < i64 >");
@@ -1224,7 +1591,8 @@ mod tests {
     #[test]
     fn test_emit_type_option_string() {
         let registry = FunctionRegistry::new();
-        let emitter = IrEmitter::new(&registry);
+        let interner = Interner::new();
+        let emitter = IrEmitter::new(&registry, &interner);
         let ty = IrType::Option(Box::new(IrType::String));
         let result = emitter.emit_type(&ty);
         assert_eq!(result.to_string(), "Option < String >");
@@ -1233,7 +1601,8 @@ mod tests {
     #[test]
     fn test_emit_type_dict_string_int() {
         let registry = FunctionRegistry::new();
-        let emitter = IrEmitter::new(&registry);
+        let interner = Interner::new();
+        let emitter = IrEmitter::new(&registry, &interner);
         let ty = IrType::Dict(Box::new(IrType::String), Box::new(IrType::Int));
         let result = emitter.emit_type(&ty);
         assert_eq!(
@@ -1249,7 +1618,8 @@ mod tests {
     #[test]
     fn test_emit_binop_add() {
         let registry = FunctionRegistry::new();
-        let emitter = IrEmitter::new(&registry);
+        let interner = Interner::new();
+        let emitter = IrEmitter::new(&registry, &interner);
         let result = emitter.emit_binop(&BinOp::Add);
         assert_eq!(result.to_string(), "+");
     }
@@ -1257,7 +1627,8 @@ mod tests {
     #[test]
     fn test_emit_compound_op_mul() {
         let registry = FunctionRegistry::new();
-        let emitter = IrEmitter::new(&registry);
+        let interner = Interner::new();
+        let emitter = IrEmitter::new(&registry, &interner);
         let result = emitter.emit_compound_op(&BinOp::Mul);
         assert_eq!(result.to_string(), "*=");
     }
@@ -1265,7 +1636,8 @@ mod tests {
     #[test]
     fn test_all_binary_operators_map() {
         let registry = FunctionRegistry::new();
-        let emitter = IrEmitter::new(&registry);
+        let interner = Interner::new();
+        let emitter = IrEmitter::new(&registry, &interner);
 
         // Test a representative set of binary operators
         let tests = vec![
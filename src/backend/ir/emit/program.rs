@@ -310,7 +310,7 @@ impl<'a> IrEmitter<'a> {
             let wrapper_name = format_ident!("__incan_web_{}", r.handler_name);
             let handler_ident = format_ident!("{}", Self::escape_keyword(&r.handler_name));
 
-            let sig_opt = self.function_registry.get(&r.handler_name);
+            let sig_opt = self.function_registry.get(self.interner, &r.handler_name);
             let params = sig_opt.map(|s| &s.params[..]).unwrap_or(&[]);
 
             // For now: support 0 or 1 path params (enough for hello_web).
@@ -32,6 +32,7 @@ fn for_body_needs_mut_iteration(pattern: &Pattern, body: &[IrStmt]) -> bool {
             IrExprKind::Var { name, .. } => Some(name.as_str()),
             IrExprKind::Field { object, .. } => root_var_name(object),
             IrExprKind::Index { object, .. } => root_var_name(object),
+            IrExprKind::TupleIndex { object, .. } => root_var_name(object),
             _ => None,
         }
     }
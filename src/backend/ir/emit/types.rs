@@ -37,6 +37,10 @@ impl<'a> IrEmitter<'a> {
                 let e = self.emit_type(elem);
                 quote! { HashSet<#e> }
             }
+            IrType::Iterator(elem) => {
+                let e = self.emit_type(elem);
+                quote! { impl Iterator<Item = #e> }
+            }
             IrType::Tuple(types) => {
                 let ts: Vec<_> = types.iter().map(|t| self.emit_type(t)).collect();
                 quote! { (#(#ts),*) }
@@ -171,6 +175,23 @@ impl<'a> IrEmitter<'a> {
                 let ps: Vec<_> = pats.iter().map(|p| self.emit_pattern(p)).collect();
                 quote! { #(#ps)|* }
             }
+            Pattern::Slice { prefix, rest, suffix } => {
+                let prefix_pats: Vec<_> = prefix.iter().map(|p| self.emit_pattern(p)).collect();
+                let suffix_pats: Vec<_> = suffix.iter().map(|p| self.emit_pattern(p)).collect();
+                match rest {
+                    None => quote! { [#(#prefix_pats),*] },
+                    Some(None) => quote! { [#(#prefix_pats,)* .., #(#suffix_pats),*] },
+                    Some(Some(name)) => {
+                        let n = format_ident!("{}", Self::escape_keyword(name));
+                        quote! { [#(#prefix_pats,)* #n @ .., #(#suffix_pats),*] }
+                    }
+                }
+            }
+            Pattern::As(inner, name) => {
+                let n = format_ident!("{}", Self::escape_keyword(name));
+                let p = self.emit_pattern(inner);
+                quote! { #n @ #p }
+            }
         }
     }
 }
@@ -14,7 +14,7 @@ pub struct EmitService<'a> {
 impl<'a> EmitService<'a> {
     pub fn new_from_program(ir: &'a IrProgram) -> Self {
         Self {
-            inner: IrEmitter::new(&ir.function_registry),
+            inner: IrEmitter::new(&ir.function_registry, &ir.interner),
             builtins: BuiltinHandlers::new(),
         }
     }
@@ -130,6 +130,13 @@ pub enum IrExprKind {
         index: Box<IrExpr>,
     },
 
+    /// Tuple element access (`t[0]`, `t[-1]`) resolved to a statically known field index during
+    /// lowering, emitted as Rust tuple field access (`t.0`, `t.1`, …).
+    TupleIndex {
+        object: Box<IrExpr>,
+        index: usize,
+    },
+
     // Slice access (list[start:end[:step]])
     Slice {
         target: Box<IrExpr>,
@@ -141,16 +148,29 @@ pub enum IrExprKind {
     // List comprehension
     ListComp {
         element: Box<IrExpr>,
-        variable: String,
-        iterable: Box<IrExpr>,
-        filter: Option<Box<IrExpr>>,
+        clauses: Vec<CompClause>,
     },
     DictComp {
         key: Box<IrExpr>,
         value: Box<IrExpr>,
-        variable: String,
-        iterable: Box<IrExpr>,
-        filter: Option<Box<IrExpr>>,
+        clauses: Vec<CompClause>,
+    },
+    // Set comprehension
+    SetComp {
+        element: Box<IrExpr>,
+        clauses: Vec<CompClause>,
+    },
+
+    /// Generator expression: `(expr for x in iter if cond)`.
+    ///
+    /// `lazy` is decided once, during lowering, by the eager-vs-lazy purity analysis (see
+    /// `lower::purity`): when `true` the emitter leaves the adapter chain uncollected and the
+    /// expression's type is `IrType::Iterator(elem)`; when `false` it falls back to the same
+    /// collecting shape as [`IrExprKind::ListComp`] and the type is `IrType::List(elem)`.
+    GenExp {
+        element: Box<IrExpr>,
+        clauses: Vec<CompClause>,
+        lazy: bool,
     },
 
     // List literal
@@ -250,6 +270,22 @@ pub enum FormatPart {
     Expr(IrExpr),
 }
 
+/// One clause of a comprehension's generator chain, in source order.
+///
+/// Mirrors `ast::CompClause`. A comprehension lowers to a `Vec<CompClause>` rather than a single
+/// `variable`/`iterable`/`filter` triple so that later clauses (and their filters) can refer to
+/// loop variables bound by earlier ones, matching Python's nested `for`/`if` semantics.
+#[derive(Debug, Clone)]
+pub enum CompClause {
+    /// `for variable in iterable`
+    For {
+        variable: String,
+        iterable: Box<IrExpr>,
+    },
+    /// `if condition`
+    If(Box<IrExpr>),
+}
+
 /// How a variable is accessed
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum VarAccess {
@@ -310,6 +346,9 @@ pub enum UnaryOp {
 #[derive(Debug, Clone)]
 pub struct MatchArm {
     pub pattern: Pattern,
+    /// Source span of `pattern`, recorded in the lowering pass's `SourceMap` (patterns have no
+    /// span field of their own to carry it inline). `None` for arms built outside `lower_match_arms`.
+    pub pattern_id: Option<super::lower::IrId>,
     pub guard: Option<IrExpr>,
     pub body: IrExpr,
 }
@@ -331,6 +370,16 @@ pub enum Pattern {
         fields: Vec<Pattern>,
     },
     Or(Vec<Pattern>),
+    /// Slice pattern with an optional star-rest, e.g. `[a, b, ..]` or `[a, rest @ .., b]`.
+    /// `rest` is `None` for a fixed-length sequence, `Some(None)` for a discarded rest (`..`),
+    /// and `Some(Some(name))` for a bound rest (`name @ ..`).
+    Slice {
+        prefix: Vec<Pattern>,
+        rest: Option<Option<String>>,
+        suffix: Vec<Pattern>,
+    },
+    /// Capture-with-subpattern, e.g. `name @ 1..=5` (Python's `pattern as name`).
+    As(Box<Pattern>, String),
 }
 
 // ============================================================================
@@ -377,6 +426,24 @@ pub enum BuiltinFn {
     JsonStringify,
     /// `sleep(secs)` → `tokio::time::sleep(...)`
     Sleep,
+    /// `min(x)` / `min(a, b, ...)` → `.iter().min()` (lists) or `.min(...)` (scalars)
+    Min,
+    /// `max(x)` / `max(a, b, ...)` → `.iter().max()` (lists) or `.max(...)` (scalars)
+    Max,
+    /// `sorted(x)` → `{ let mut v = x.clone(); v.sort(); v }`
+    Sorted,
+    /// `reversed(x)` → `x.iter().rev().cloned().collect()`
+    Reversed,
+    /// `round(x)` → dispatches on int vs float
+    Round,
+    /// `map(f, x)` → `x.iter().cloned().map(f).collect()`
+    Map,
+    /// `filter(f, x)` → `x.iter().cloned().filter(...).collect()`
+    Filter,
+    /// `any(x)` → `x.iter().any(|v| *v)`
+    Any,
+    /// `all(x)` → `x.iter().all(|v| *v)`
+    All,
 }
 
 impl BuiltinFn {
@@ -400,6 +467,15 @@ impl BuiltinFn {
             BuiltinFnId::WriteFile => Self::WriteFile,
             BuiltinFnId::JsonStringify => Self::JsonStringify,
             BuiltinFnId::Sleep => Self::Sleep,
+            BuiltinFnId::Min => Self::Min,
+            BuiltinFnId::Max => Self::Max,
+            BuiltinFnId::Sorted => Self::Sorted,
+            BuiltinFnId::Reversed => Self::Reversed,
+            BuiltinFnId::Round => Self::Round,
+            BuiltinFnId::Map => Self::Map,
+            BuiltinFnId::Filter => Self::Filter,
+            BuiltinFnId::Any => Self::Any,
+            BuiltinFnId::All => Self::All,
         })
     }
 }
@@ -421,10 +497,28 @@ pub enum MethodKind {
     Upper,
     /// `s.lower()` → `s.to_lowercase()`
     Lower,
-    /// `s.strip()` → `s.trim().to_string()`
+    /// `s.title()` → upper-cases the first cased scalar of each word, lower-cases the rest
+    Title,
+    /// `s.capitalize()` → upper-cases the first scalar, lower-cases the remainder
+    Capitalize,
+    /// `s.casefold()` → casefolds for aggressive case-insensitive matching
+    Casefold,
+    /// `s.strip(chars)` → `s.trim().to_string()` (or a char-set trim when `chars` is given)
     Strip,
-    /// `s.split(sep)` → `s.split(sep).map(...).collect()`
+    /// `s.lstrip(chars)` → the left-only counterpart of [`Self::Strip`]
+    Lstrip,
+    /// `s.rstrip(chars)` → the right-only counterpart of [`Self::Strip`]
+    Rstrip,
+    /// `s.split(sep, maxsplit)` → `s.split(sep).map(...).collect()` (or `splitn` when bounded)
     Split,
+    /// `s.rsplit(sep, maxsplit)` → the right-anchored counterpart of [`Self::Split`]
+    Rsplit,
+    /// `s.splitlines(keepends)` → lines split on Unicode line boundaries
+    Splitlines,
+    /// `s.partition(sep)` → `(before, sep, after)` split on the first occurrence of `sep`
+    Partition,
+    /// `s.rpartition(sep)` → `(before, sep, after)` split on the last occurrence of `sep`
+    Rpartition,
     /// `s.replace(old, new)` → `s.replace(old, new)`
     Replace,
     /// `sep.join(items)` → `items.join(sep)`
@@ -433,6 +527,16 @@ pub enum MethodKind {
     StartsWith,
     /// `s.endswith(suffix)` → `s.ends_with(suffix)`
     EndsWith,
+    /// `s.find(needle)` → scalar index of the first occurrence, or `-1` if absent
+    Find,
+    /// `s.rfind(needle)` → scalar index of the last occurrence, or `-1` if absent
+    Rfind,
+    /// `s.index(needle)` → scalar index of the first occurrence, panics if absent
+    IndexOf,
+    /// `s.rindex(needle)` → scalar index of the last occurrence, panics if absent
+    RindexOf,
+    /// `s.count(needle)` → number of non-overlapping occurrences
+    Count,
 
     // ---- Collection methods ----
     /// `x.contains(item)` → varies by type
@@ -509,13 +613,27 @@ impl MethodKind {
             return match id {
                 S::Upper => Some(Self::Upper),
                 S::Lower => Some(Self::Lower),
+                S::Title => Some(Self::Title),
+                S::Capitalize => Some(Self::Capitalize),
+                S::Casefold => Some(Self::Casefold),
                 S::Strip => Some(Self::Strip),
+                S::Lstrip => Some(Self::Lstrip),
+                S::Rstrip => Some(Self::Rstrip),
                 S::Split => Some(Self::Split),
+                S::Rsplit => Some(Self::Rsplit),
+                S::Splitlines => Some(Self::Splitlines),
+                S::Partition => Some(Self::Partition),
+                S::Rpartition => Some(Self::Rpartition),
                 S::Replace => Some(Self::Replace),
                 S::Join => Some(Self::Join),
                 S::StartsWith => Some(Self::StartsWith),
                 S::EndsWith => Some(Self::EndsWith),
                 S::Contains => Some(Self::Contains),
+                S::Find => Some(Self::Find),
+                S::Rfind => Some(Self::Rfind),
+                S::Index => Some(Self::IndexOf),
+                S::Rindex => Some(Self::RindexOf),
+                S::Count => Some(Self::Count),
                 // The rest are either typechecker-only (return types) or normal method calls:
                 _ => None,
             };
@@ -0,0 +1,80 @@
+//! String interning for IR identifiers.
+//!
+//! Function names, type names, and field names flow through the IR as owned `String`s that get
+//! cloned repeatedly during lowering, registry merging, and codegen. An [`Interner`] replaces
+//! comparisons and lookups on those strings with a small integer [`Symbol`]: `intern` maps a
+//! string to its `Symbol` (creating one the first time a string is seen), and `resolve` maps a
+//! `Symbol` back to its string. Interning is idempotent — the same string always yields the same
+//! `Symbol` — and symbols stay valid for the lifetime of the `Interner` that produced them.
+
+use std::collections::HashMap;
+
+/// A small integer standing in for an interned string.
+///
+/// `Symbol`s are only comparable against other symbols produced by the same [`Interner`]; see
+/// [`FunctionRegistry::merge`](super::FunctionRegistry::merge) for how registries built against
+/// different interners are reconciled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Symbol(u32);
+
+/// Associates each distinct string with a stable [`Symbol`], backed by an arena so resolved
+/// `&str`s can be handed out cheaply.
+#[derive(Debug, Clone, Default)]
+pub struct Interner {
+    map: HashMap<String, Symbol>,
+    // Arena of interned strings, indexed by `Symbol`. Entries are never removed, so a `Symbol`
+    // stays valid (and its string never moves) for the lifetime of this `Interner`.
+    arena: Vec<String>,
+}
+
+impl Interner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Intern `s`, returning its existing `Symbol` if already seen or assigning a new one.
+    pub fn intern(&mut self, s: &str) -> Symbol {
+        if let Some(&sym) = self.map.get(s) {
+            return sym;
+        }
+        let sym = Symbol(self.arena.len() as u32);
+        self.arena.push(s.to_string());
+        self.map.insert(s.to_string(), sym);
+        sym
+    }
+
+    /// Look up the `Symbol` for `s` without interning it.
+    pub fn get(&self, s: &str) -> Option<Symbol> {
+        self.map.get(s).copied()
+    }
+
+    /// Resolve a `Symbol` back to its string.
+    pub fn resolve(&self, sym: Symbol) -> &str {
+        &self.arena[sym.0 as usize]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interning_is_idempotent() {
+        let mut interner = Interner::new();
+        let a = interner.intern("foo");
+        let b = interner.intern("foo");
+        assert_eq!(a, b);
+        assert_eq!(interner.resolve(a), "foo");
+    }
+
+    #[test]
+    fn distinct_strings_get_distinct_symbols() {
+        let mut interner = Interner::new();
+        let a = interner.intern("foo");
+        let b = interner.intern("bar");
+        assert_ne!(a, b);
+        assert_eq!(interner.get("foo"), Some(a));
+        assert_eq!(interner.get("bar"), Some(b));
+        assert_eq!(interner.get("baz"), None);
+    }
+}
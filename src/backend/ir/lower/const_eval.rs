@@ -0,0 +1,206 @@
+//! Compile-time constant folding for literal expressions.
+//!
+//! Runs inside [`super::expr`]'s `Binary`/`Unary` lowering arms, right after a `BinOp`/`UnaryOp`
+//! IR node is built: if every leaf operand is itself a literal, the whole node collapses into a
+//! single literal `IrExprKind`, carrying the same result type `binary_result_type`/
+//! `pow_exponent_kind` already assigned it. This shrinks the emitted Rust and lets downstream
+//! passes (e.g. `constness`) see concrete values instead of an expression tree.
+//!
+//! Integer division/modulo by zero and operations that would overflow are left unfolded, so the
+//! generated code still performs — and panics from — the real runtime operation.
+
+use super::super::expr::{BinOp, IrExprKind, TypedExpr, UnaryOp};
+use super::super::types::IrType;
+use super::AstLowering;
+
+/// A folded compile-time constant.
+///
+/// Distinct from [`IrExprKind`]'s literal variants: `Int` is widened to `i128` so arithmetic can
+/// detect an `i64` overflow before committing to a fold, rather than wrapping silently.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum ConstValue {
+    Int(i128),
+    Float(f64),
+    Bool(bool),
+    Str(String),
+}
+
+impl ConstValue {
+    /// Rebuild the `(IrExprKind, IrType)` pair this value folds back into.
+    fn into_literal(self) -> (IrExprKind, IrType) {
+        match self {
+            ConstValue::Int(n) => (IrExprKind::Int(n as i64), IrType::Int),
+            ConstValue::Float(f) => (IrExprKind::Float(f), IrType::Float),
+            ConstValue::Bool(b) => (IrExprKind::Bool(b), IrType::Bool),
+            ConstValue::Str(s) => (IrExprKind::String(s), IrType::String),
+        }
+    }
+}
+
+impl AstLowering {
+    /// Recursively evaluate `e` to a compile-time constant, if possible.
+    ///
+    /// Returns `Some` only for literal nodes and `BinOp`/`UnaryOp` nodes whose operands all
+    /// const-eval; any other expression (variable reads, calls, indexing, ...) yields `None`.
+    pub(super) fn const_eval(&self, e: &TypedExpr) -> Option<ConstValue> {
+        match &e.kind {
+            IrExprKind::Int(n) => Some(ConstValue::Int(*n as i128)),
+            IrExprKind::Float(f) => Some(ConstValue::Float(*f)),
+            IrExprKind::Bool(b) => Some(ConstValue::Bool(*b)),
+            IrExprKind::String(s) => Some(ConstValue::Str(s.clone())),
+            IrExprKind::UnaryOp { op, operand } => const_eval_unary(*op, self.const_eval(operand)?),
+            IrExprKind::BinOp { op, left, right } => {
+                let lhs = self.const_eval(left)?;
+                let rhs = self.const_eval(right)?;
+                const_eval_binop(*op, lhs, rhs)
+            }
+            _ => None,
+        }
+    }
+
+    /// Fold `expr` into a literal `(IrExprKind, IrType)` pair if its value is a compile-time
+    /// constant, otherwise return `expr` unchanged.
+    pub(super) fn try_fold(&self, expr: TypedExpr) -> (IrExprKind, IrType) {
+        match self.const_eval(&expr) {
+            Some(value) => value.into_literal(),
+            None => (expr.kind, expr.ty),
+        }
+    }
+}
+
+fn const_eval_unary(op: UnaryOp, value: ConstValue) -> Option<ConstValue> {
+    match (op, value) {
+        // `i64::MIN` negates to a value one past `i64::MAX` in magnitude; leave it unfolded so
+        // the runtime's own overflow behavior applies rather than silently folding past it.
+        (UnaryOp::Neg, ConstValue::Int(n)) => n.checked_neg().filter(|r| i64::try_from(*r).is_ok()).map(ConstValue::Int),
+        (UnaryOp::Neg, ConstValue::Float(f)) => Some(ConstValue::Float(-f)),
+        (UnaryOp::Not, ConstValue::Bool(b)) => Some(ConstValue::Bool(!b)),
+        _ => None,
+    }
+}
+
+fn const_eval_binop(op: BinOp, lhs: ConstValue, rhs: ConstValue) -> Option<ConstValue> {
+    use ConstValue::{Bool, Float, Int, Str};
+
+    // `i64` is the runtime representation of `IrType::Int`; a fold that doesn't fit would change
+    // behavior (wrapping vs. the real operation's panic/promotion), so it's left as a runtime op.
+    fn fits_i64(n: i128) -> Option<ConstValue> {
+        i64::try_from(n).ok().map(|_| Int(n))
+    }
+
+    match (op, lhs, rhs) {
+        // Arithmetic: Int op Int.
+        (BinOp::Add, Int(a), Int(b)) => a.checked_add(b).and_then(fits_i64),
+        (BinOp::Sub, Int(a), Int(b)) => a.checked_sub(b).and_then(fits_i64),
+        (BinOp::Mul, Int(a), Int(b)) => a.checked_mul(b).and_then(fits_i64),
+        (BinOp::Mod, Int(a), Int(b)) if b != 0 => Some(Int(py_mod_i128(a, b))),
+        // A negative integer exponent isn't representable as `Int ** Int`; the numeric policy
+        // (`PowExponentKind::NegativeIntLiteral`) already assigns this case a Float result, so
+        // fold it as one rather than leaving it unfolded.
+        (BinOp::Pow, Int(a), Int(b)) => match u32::try_from(b) {
+            Ok(exp) => a.checked_pow(exp).and_then(fits_i64),
+            Err(_) if b < 0 => Some(Float((a as f64).powf(b as f64))),
+            // An exponent too large for `u32` overflows like any other; leave it unfolded.
+            Err(_) => None,
+        },
+        // `/` always yields a float per the numeric policy, even for two ints.
+        (BinOp::Div, Int(a), Int(b)) if b != 0 => Some(Float(a as f64 / b as f64)),
+        (BinOp::FloorDiv, Int(a), Int(b)) if b != 0 => py_floor_div_i128(a, b).and_then(fits_i64),
+
+        // Comparisons and concatenation over strings. These must come before the generic
+        // Int/Float promotion arms below, since those match on any `(a, b)` pair and would
+        // otherwise shadow the string cases (silently discarding them via `numeric_pair`'s `None`).
+        (BinOp::Eq, Str(a), Str(b)) => Some(Bool(a == b)),
+        (BinOp::Ne, Str(a), Str(b)) => Some(Bool(a != b)),
+        (BinOp::Lt, Str(a), Str(b)) => Some(Bool(a < b)),
+        (BinOp::Le, Str(a), Str(b)) => Some(Bool(a <= b)),
+        (BinOp::Gt, Str(a), Str(b)) => Some(Bool(a > b)),
+        (BinOp::Ge, Str(a), Str(b)) => Some(Bool(a >= b)),
+        (BinOp::Add, Str(a), Str(b)) => Some(Str(a + &b)),
+
+        // Comparisons over bools (same ordering reason as above).
+        (BinOp::Eq, Bool(a), Bool(b)) => Some(Bool(a == b)),
+        (BinOp::Ne, Bool(a), Bool(b)) => Some(Bool(a != b)),
+
+        // Logical.
+        (BinOp::And, Bool(a), Bool(b)) => Some(Bool(a && b)),
+        (BinOp::Or, Bool(a), Bool(b)) => Some(Bool(a || b)),
+
+        // Arithmetic: promotion to Float whenever either side is already Float.
+        (BinOp::Add, a, b) => numeric_pair(a, b).map(|(x, y)| Float(x + y)),
+        (BinOp::Sub, a, b) => numeric_pair(a, b).map(|(x, y)| Float(x - y)),
+        (BinOp::Mul, a, b) => numeric_pair(a, b).map(|(x, y)| Float(x * y)),
+        (BinOp::Div, a, b) if !matches!(b, Int(n) if n == 0) && !matches!(b, Float(f) if f == 0.0) => {
+            numeric_pair(a, b).map(|(x, y)| Float(x / y))
+        }
+        (BinOp::Mod, a, b) if !matches!(b, Int(n) if n == 0) && !matches!(b, Float(f) if f == 0.0) => {
+            numeric_pair(a, b).map(|(x, y)| Float(py_mod_f64(x, y)))
+        }
+        (BinOp::FloorDiv, a, b) if !matches!(b, Int(n) if n == 0) && !matches!(b, Float(f) if f == 0.0) => {
+            numeric_pair(a, b).map(|(x, y)| Float(py_floor_div_f64(x, y)))
+        }
+        (BinOp::Pow, a, b) => numeric_pair(a, b).map(|(x, y)| Float(x.powf(y))),
+
+        // Comparisons: numeric, across Int/Float, compared as f64 so `1 == 1.0` folds correctly.
+        (BinOp::Eq, a @ (Int(_) | Float(_)), b @ (Int(_) | Float(_))) => {
+            numeric_pair(a, b).map(|(x, y)| Bool(x == y))
+        }
+        (BinOp::Ne, a @ (Int(_) | Float(_)), b @ (Int(_) | Float(_))) => {
+            numeric_pair(a, b).map(|(x, y)| Bool(x != y))
+        }
+        (BinOp::Lt, a @ (Int(_) | Float(_)), b @ (Int(_) | Float(_))) => {
+            numeric_pair(a, b).map(|(x, y)| Bool(x < y))
+        }
+        (BinOp::Le, a @ (Int(_) | Float(_)), b @ (Int(_) | Float(_))) => {
+            numeric_pair(a, b).map(|(x, y)| Bool(x <= y))
+        }
+        (BinOp::Gt, a @ (Int(_) | Float(_)), b @ (Int(_) | Float(_))) => {
+            numeric_pair(a, b).map(|(x, y)| Bool(x > y))
+        }
+        (BinOp::Ge, a @ (Int(_) | Float(_)), b @ (Int(_) | Float(_))) => {
+            numeric_pair(a, b).map(|(x, y)| Bool(x >= y))
+        }
+
+        _ => None,
+    }
+}
+
+/// Coerce a pair of `Int`/`Float` constants to `f64`, for ops that promote on mixed operands.
+fn numeric_pair(a: ConstValue, b: ConstValue) -> Option<(f64, f64)> {
+    let as_f64 = |v: ConstValue| match v {
+        ConstValue::Int(n) => Some(n as f64),
+        ConstValue::Float(f) => Some(f),
+        _ => None,
+    };
+    Some((as_f64(a)?, as_f64(b)?))
+}
+
+/// Python-style modulo for `i128`: the remainder's sign follows the divisor, matching
+/// `incan_stdlib::num::py_mod_i64` (not Rust's `%`, which follows the dividend).
+fn py_mod_i128(a: i128, b: i128) -> i128 {
+    let r = a % b;
+    if (r > 0 && b < 0) || (r < 0 && b > 0) { r + b } else { r }
+}
+
+/// Python-style floor division for `i128`: rounds toward negative infinity, matching
+/// `incan_stdlib::num::py_floor_div_i64` (not Rust's `/`, which truncates toward zero). Returns
+/// `None` if the adjusted quotient would overflow (only possible for `i128::MIN / -1`).
+fn py_floor_div_i128(a: i128, b: i128) -> Option<i128> {
+    let q = a.checked_div(b)?;
+    let r = a % b;
+    if r == 0 {
+        return Some(q);
+    }
+    if (r < 0) == (b < 0) { Some(q) } else { q.checked_sub(1) }
+}
+
+/// Python-style modulo for `f64`, matching `incan_stdlib::num::py_mod_f64`.
+fn py_mod_f64(a: f64, b: f64) -> f64 {
+    let r = a % b;
+    if (r > 0.0 && b < 0.0) || (r < 0.0 && b > 0.0) { r + b } else { r }
+}
+
+/// Python-style floor division for `f64`, matching `incan_stdlib::num::py_floor_div_f64`.
+fn py_floor_div_f64(a: f64, b: f64) -> f64 {
+    (a / b).floor()
+}
@@ -6,18 +6,28 @@
 use std::collections::HashMap;
 
 use super::super::decl::{
-    EnumVariant, FunctionParam, IrDecl, IrDeclKind, IrEnum, IrFunction, IrImpl, IrStruct, IrTrait, StructField,
-    VariantFields, Visibility,
+    Constness, EnumVariant, FunctionParam, IrDecl, IrDeclKind, IrEnum, IrFunction, IrImpl, IrStruct, IrTrait,
+    StructField, VariantFields, Visibility,
 };
+use super::super::rename::RenameRule;
 use super::super::types::IrType;
 use super::super::{IrSpan, Mutability};
 use super::AstLowering;
 use super::errors::LoweringError;
 use crate::frontend::ast::{self, Spanned};
 
+/// Parsed form of a container-level `@serde(...)` decorator on a model or class.
+#[derive(Debug, Default)]
+pub(super) struct SerdeAttrs {
+    rename_all: Option<String>,
+    rename: HashMap<String, String>,
+    skip: Vec<String>,
+    default: Vec<String>,
+}
+
 impl AstLowering {
     /// Map frontend visibility (`pub` / private) to IR visibility for Rust emission.
-    fn map_visibility(vis: crate::frontend::ast::Visibility) -> Visibility {
+    pub(super) fn map_visibility(vis: crate::frontend::ast::Visibility) -> Visibility {
         match vis {
             crate::frontend::ast::Visibility::Private => Visibility::Private,
             crate::frontend::ast::Visibility::Public => Visibility::Public,
@@ -160,9 +170,22 @@ impl AstLowering {
             is_async: f.is_async,
             visibility: Self::map_visibility(f.visibility),
             type_params: f.type_params.clone(),
+            constness: Self::explicit_constness(&f.decorators),
         })
     }
 
+    /// Whether `decorators` carries an explicit `@const` annotation.
+    ///
+    /// This only seeds [`IrFunction::constness`]; [`super::super::constness::infer_constness`]
+    /// runs afterward and may additionally mark functions const based on their body.
+    fn explicit_constness(decorators: &[Spanned<ast::Decorator>]) -> Constness {
+        if decorators.iter().any(|d| d.node.name == "const") {
+            Constness::Const
+        } else {
+            Constness::NotConst
+        }
+    }
+
     /// Extract derives from decorators.
     ///
     /// Parses `@derive(Serialize, Deserialize)` decorators and returns the list
@@ -205,41 +228,162 @@ impl AstLowering {
         derives
     }
 
-    /// Lower a model declaration to struct.
-    pub(super) fn lower_model(&mut self, m: &ast::ModelDecl) -> Result<IrStruct, LoweringError> {
-        let mut fields: Vec<StructField> = Vec::new();
-        for f in &m.fields {
-            let default = f
-                .node
-                .default
-                .as_ref()
-                .map(|d| self.lower_expr_spanned(d))
-                .transpose()?;
-            fields.push(StructField {
-                name: f.node.name.clone(),
-                ty: self.lower_type(&f.node.ty.node),
-                visibility: Self::map_visibility(f.node.visibility),
-                default,
-            });
+    /// Extract `@derive(...)` derives from `decorators`, then append any `defaults` the type
+    /// always carries (e.g. models always derive `Debug`/`Clone`) that the user didn't already
+    /// request explicitly.
+    fn derives_with_defaults(&self, decorators: &[Spanned<ast::Decorator>], defaults: &[&str]) -> Vec<String> {
+        let mut derives = self.extract_derives(decorators);
+        for default in defaults {
+            if !derives.iter().any(|d| d == default) {
+                derives.push((*default).to_string());
+            }
         }
+        derives
+    }
+
+    /// Extra serialization formats a model/class/enum can request via `@formats(...)`, beyond
+    /// the always-on `to_json`/`from_json` pair.
+    const SUPPORTED_FORMATS: &'static [&'static str] = &["yaml", "toml", "msgpack"];
 
-        let mut derives = self.extract_derives(&m.decorators);
+    /// Extract format names from an `@formats(yaml, toml, msgpack)` decorator.
+    ///
+    /// Each entry drives a matching `to_<fmt>`/`from_<fmt>` method pair at emission time,
+    /// gated on the same `Serialize`/`Deserialize` derives that gate `to_json`/`from_json`.
+    pub(super) fn extract_formats(&self, decorators: &[Spanned<ast::Decorator>]) -> Result<Vec<String>, LoweringError> {
+        let mut formats = Vec::new();
 
-        // Models always get Debug and Clone by default
-        if !derives.contains(&"Debug".to_string()) {
-            derives.push("Debug".to_string());
+        for decorator in decorators {
+            if decorator.node.name != "formats" {
+                continue;
+            }
+            for arg in &decorator.node.args {
+                if let ast::DecoratorArg::Positional(expr) = arg {
+                    if let ast::Expr::Ident(name) = &expr.node {
+                        if !Self::SUPPORTED_FORMATS.contains(&name.as_str()) {
+                            return Err(LoweringError {
+                                message: format!(
+                                    "unknown @formats(...) entry \"{name}\"; expected one of {}",
+                                    Self::SUPPORTED_FORMATS.join(", ")
+                                ),
+                                span: IrSpan::default(),
+                            });
+                        }
+                        formats.push(name.clone());
+                    }
+                }
+            }
         }
-        if !derives.contains(&"Clone".to_string()) {
-            derives.push("Clone".to_string());
+
+        Ok(formats)
+    }
+
+    /// Extract a string literal from a decorator argument expression, if it is one.
+    fn decorator_arg_string(expr: &ast::Expr) -> Option<String> {
+        match expr {
+            ast::Expr::Literal(ast::Literal::String(s)) => Some(s.clone()),
+            _ => None,
         }
-        // Models always get FieldInfo for reflection
-        if !derives.contains(&"FieldInfo".to_string()) {
-            derives.push("FieldInfo".to_string());
+    }
+
+    /// Extract a `@serde(...)` container decorator into field/struct attribute data.
+    ///
+    /// Individual fields in this language can't carry their own decorators (see
+    /// [`ast::FieldDecl`]), so per-field serde customization is expressed as named,
+    /// field-name-keyed arguments on the single container decorator:
+    /// `@serde(rename_all="camelCase", rename={"user_id": "userId"}, skip=["cache"])`.
+    pub(super) fn extract_serde_attrs(&self, decorators: &[Spanned<ast::Decorator>]) -> SerdeAttrs {
+        let mut attrs = SerdeAttrs::default();
+
+        for decorator in decorators {
+            if decorator.node.name != "serde" {
+                continue;
+            }
+            for arg in &decorator.node.args {
+                let ast::DecoratorArg::Named(name, ast::DecoratorArgValue::Expr(value)) = arg else {
+                    continue;
+                };
+                match name.as_str() {
+                    "rename_all" => attrs.rename_all = Self::decorator_arg_string(&value.node),
+                    "rename" => {
+                        if let ast::Expr::Dict(entries) = &value.node {
+                            for (k, v) in entries {
+                                if let (Some(field), Some(renamed)) = (
+                                    Self::decorator_arg_string(&k.node),
+                                    Self::decorator_arg_string(&v.node),
+                                ) {
+                                    attrs.rename.insert(field, renamed);
+                                }
+                            }
+                        }
+                    }
+                    "skip" => {
+                        if let ast::Expr::List(items) = &value.node {
+                            attrs
+                                .skip
+                                .extend(items.iter().filter_map(|i| Self::decorator_arg_string(&i.node)));
+                        }
+                    }
+                    "default" => {
+                        if let ast::Expr::List(items) = &value.node {
+                            attrs
+                                .default
+                                .extend(items.iter().filter_map(|i| Self::decorator_arg_string(&i.node)));
+                        }
+                    }
+                    _ => {}
+                }
+            }
         }
-        // Models always get IncanClass for __class__() and __fields__() methods
-        if !derives.contains(&"IncanClass".to_string()) {
-            derives.push("IncanClass".to_string());
+
+        attrs
+    }
+
+    /// Build a [`StructField`] from a lowered field, applying any `@serde(...)` attributes that
+    /// named this field.
+    fn lower_struct_field(
+        &mut self,
+        f: &Spanned<ast::FieldDecl>,
+        serde_attrs: &SerdeAttrs,
+    ) -> Result<StructField, LoweringError> {
+        Ok(StructField {
+            name: f.node.name.clone(),
+            ty: self.lower_type(&f.node.ty.node),
+            visibility: Self::map_visibility(f.node.visibility),
+            serde_rename: serde_attrs.rename.get(&f.node.name).cloned(),
+            serde_skip: serde_attrs.skip.iter().any(|s| s == &f.node.name),
+            serde_default: serde_attrs.default.iter().any(|s| s == &f.node.name),
+        })
+    }
+
+    /// Validate that `rename_all`, if present, names one of the [`RenameRule`]s.
+    fn validate_rename_all(serde_attrs: &SerdeAttrs) -> Result<(), LoweringError> {
+        if let Some(rule) = &serde_attrs.rename_all {
+            if RenameRule::parse(rule).is_none() {
+                return Err(LoweringError {
+                    message: format!(
+                        "unknown @serde(rename_all=\"{rule}\") rule; expected one of \
+                         PascalCase, camelCase, SCREAMING_SNAKE_CASE, kebab-case, SCREAMING-KEBAB-CASE"
+                    ),
+                    span: IrSpan::default(),
+                });
+            }
         }
+        Ok(())
+    }
+
+    /// Lower a model declaration to struct.
+    pub(super) fn lower_model(&mut self, m: &ast::ModelDecl) -> Result<IrStruct, LoweringError> {
+        let serde_attrs = self.extract_serde_attrs(&m.decorators);
+        Self::validate_rename_all(&serde_attrs)?;
+        let mut fields: Vec<StructField> = Vec::new();
+        for f in &m.fields {
+            fields.push(self.lower_struct_field(f, &serde_attrs)?);
+        }
+
+        // Models always get Debug, Clone, FieldInfo (reflection), and IncanClass (for
+        // __class__()/__fields__()) by default.
+        let derives = self.derives_with_defaults(&m.decorators, &["Debug", "Clone", "FieldInfo", "IncanClass"]);
+        let formats = self.extract_formats(&m.decorators)?;
 
         Ok(IrStruct {
             name: m.name.clone(),
@@ -247,6 +391,8 @@ impl AstLowering {
             derives,
             visibility: Self::map_visibility(m.visibility),
             type_params: m.type_params.clone(),
+            serde_rename_all: serde_attrs.rename_all,
+            formats,
         })
     }
 
@@ -259,39 +405,18 @@ impl AstLowering {
             self.collect_inherited_fields(parent_name, &mut fields)?;
         }
 
+        let serde_attrs = self.extract_serde_attrs(&c.decorators);
+        Self::validate_rename_all(&serde_attrs)?;
+
         // Add this class's own fields
         for f in &c.fields {
-            let default = f
-                .node
-                .default
-                .as_ref()
-                .map(|d| self.lower_expr_spanned(d))
-                .transpose()?;
-            fields.push(StructField {
-                name: f.node.name.clone(),
-                ty: self.lower_type(&f.node.ty.node),
-                visibility: Self::map_visibility(f.node.visibility),
-                default,
-            });
+            fields.push(self.lower_struct_field(f, &serde_attrs)?);
         }
 
-        let mut derives = self.extract_derives(&c.decorators);
-
-        // Classes always get Debug and Clone by default
-        if !derives.contains(&"Debug".to_string()) {
-            derives.push("Debug".to_string());
-        }
-        if !derives.contains(&"Clone".to_string()) {
-            derives.push("Clone".to_string());
-        }
-        // Classes always get FieldInfo for reflection
-        if !derives.contains(&"FieldInfo".to_string()) {
-            derives.push("FieldInfo".to_string());
-        }
-        // Classes always get IncanClass for __class__() and __fields__() methods
-        if !derives.contains(&"IncanClass".to_string()) {
-            derives.push("IncanClass".to_string());
-        }
+        // Classes always get Debug, Clone, FieldInfo (reflection), and IncanClass (for
+        // __class__()/__fields__()) by default.
+        let derives = self.derives_with_defaults(&c.decorators, &["Debug", "Clone", "FieldInfo", "IncanClass"]);
+        let formats = self.extract_formats(&c.decorators)?;
 
         Ok(IrStruct {
             name: c.name.clone(),
@@ -299,6 +424,8 @@ impl AstLowering {
             derives,
             visibility: Self::map_visibility(c.visibility),
             type_params: c.type_params.clone(),
+            serde_rename_all: serde_attrs.rename_all,
+            formats,
         })
     }
 
@@ -318,17 +445,13 @@ impl AstLowering {
 
             // Then add parent's own fields
             for f in &parent_class.fields {
-                let default = f
-                    .node
-                    .default
-                    .as_ref()
-                    .map(|d| self.lower_expr_spanned(d))
-                    .transpose()?;
                 fields.push(StructField {
                     name: f.node.name.clone(),
                     ty: self.lower_type(&f.node.ty.node),
                     visibility: Self::map_visibility(f.node.visibility),
-                    default,
+                    serde_rename: None,
+                    serde_skip: false,
+                    serde_default: false,
                 });
             }
         }
@@ -368,20 +491,25 @@ impl AstLowering {
             name: "0".to_string(),
             ty: underlying_ty.clone(),
             visibility: Visibility::Private,
-            default: None,
+            serde_rename: None,
+            serde_skip: false,
+            serde_default: false,
         }];
-        // Newtypes auto-derive Debug, Clone
-        // Only add Copy if underlying type is Copy (int, float, bool)
-        let mut derives = vec!["Debug".to_string(), "Clone".to_string()];
-        if underlying_ty.is_copy() {
+        // Newtypes auto-derive Debug, Clone, plus whatever the user requested via @derive(...).
+        // Only add Copy if the underlying type is Copy (int, float, bool).
+        let mut derives = self.derives_with_defaults(&n.decorators, &["Debug", "Clone"]);
+        if underlying_ty.is_copy() && !derives.contains(&"Copy".to_string()) {
             derives.push("Copy".to_string());
         }
+        let formats = self.extract_formats(&n.decorators)?;
         Ok(IrStruct {
             name: n.name.clone(),
             fields,
             derives,
             visibility: Self::map_visibility(n.visibility),
             type_params: vec![],
+            serde_rename_all: None,
+            formats,
         })
     }
 
@@ -404,6 +532,7 @@ impl AstLowering {
         Ok(IrImpl {
             target_type: type_name.to_string(),
             trait_name: None,
+            assoc_types: vec![],
             methods: lowered_methods,
         })
     }
@@ -459,6 +588,7 @@ impl AstLowering {
         Ok(IrImpl {
             target_type: type_name.to_string(),
             trait_name: Some(trait_name.to_string()),
+            assoc_types: vec![],
             methods,
         })
     }
@@ -517,6 +647,7 @@ impl AstLowering {
             is_async: m.is_async,
             visibility: Visibility::Private,
             type_params: vec![],
+            constness: Self::explicit_constness(&m.decorators),
         })
     }
 
@@ -539,6 +670,7 @@ impl AstLowering {
         Ok(IrImpl {
             target_type: type_name.to_string(),
             trait_name: None,
+            assoc_types: vec![],
             methods: lowered_methods,
         })
     }
@@ -618,6 +750,7 @@ impl AstLowering {
             is_async: m.is_async,
             visibility: Visibility::Private,
             type_params: vec![],
+            constness: Self::explicit_constness(&m.decorators),
         })
     }
 
@@ -681,6 +814,7 @@ impl AstLowering {
                     is_async: m.node.is_async,
                     visibility: Visibility::Private,
                     type_params: vec![],
+                    constness: Constness::NotConst,
                 })
             })
             .collect::<Result<Vec<_>, LoweringError>>()?;
@@ -710,8 +844,10 @@ impl AstLowering {
             })
             .collect();
 
-        // Enums always get Debug, Clone, PartialEq by default
-        let derives = vec!["Debug".to_string(), "Clone".to_string(), "PartialEq".to_string()];
+        // Enums always get Debug, Clone, PartialEq by default, plus whatever the user requested
+        // via @derive(...) (e.g. Hash, Copy, Serialize, Deserialize).
+        let derives = self.derives_with_defaults(&e.decorators, &["Debug", "Clone", "PartialEq"]);
+        let formats = self.extract_formats(&e.decorators)?;
 
         Ok(IrEnum {
             name: e.name.clone(),
@@ -719,6 +855,7 @@ impl AstLowering {
             derives,
             visibility: Self::map_visibility(e.visibility),
             type_params: e.type_params.clone(),
+            formats,
         })
     }
 
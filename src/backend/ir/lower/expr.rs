@@ -3,9 +3,11 @@
 //! This module handles lowering of all expression types: literals, identifiers,
 //! binary/unary operations, function calls, method calls, comprehensions, etc.
 
-use super::super::TypedExpr;
-use super::super::expr::{BuiltinFn, IrExpr, IrExprKind, MatchArm, MethodKind, Pattern, UnaryOp, VarAccess};
+use super::super::{IrSpan, Mutability, TypedExpr};
+use super::super::expr::{BinOp, BuiltinFn, CompClause, IrExpr, IrExprKind, MatchArm, MethodKind, Pattern, UnaryOp, VarAccess};
+use super::super::stmt::{IrStmt, IrStmtKind};
 use super::super::types::IrType;
+use super::source_map::IrId;
 use super::AstLowering;
 use super::errors::LoweringError;
 use crate::frontend::ast::{self, Spanned};
@@ -16,8 +18,21 @@ impl AstLowering {
     ///
     /// This wraps [`lower_expr`] and then overrides the inferred IR type using the typechecker
     /// span->type map. This is a stepping stone toward fully typed lowering.
+    ///
+    /// It also records `expr.span` as the node's [`TypedExpr::span`] and as `self.current_span`
+    /// for the duration of the call, so panics synthesized deeper in lowering (e.g. the newtype
+    /// checked-construction `expect`) can blame the original source location instead of a
+    /// generated-code line. `current_span` tracks the *nearest enclosing* spanned expression, not
+    /// every sub-expression individually — lowering call sites that still use plain [`lower_expr`]
+    /// (rather than this method) inherit their parent's span.
     pub fn lower_expr_spanned(&mut self, expr: &Spanned<ast::Expr>) -> Result<TypedExpr, LoweringError> {
-        let mut lowered = self.lower_expr(&expr.node)?;
+        let span = IrSpan::from(expr.span);
+        let prev_span = self.current_span;
+        self.current_span = span;
+        let result = self.lower_expr(&expr.node);
+        self.current_span = prev_span;
+        let mut lowered = result?;
+        lowered.span = span;
         if let Some(info) = &self.type_info {
             if let Some(res_ty) = info.expr_type(expr.span) {
                 // Preserve reference wrappers introduced by lowering (e.g. mutable parameters are tracked as
@@ -130,14 +145,14 @@ impl AstLowering {
                             None
                         };
                         let result_ty = self.binary_result_type(&left.ty, &right.ty, op, pow_exp_kind);
-                        (
+                        self.try_fold(TypedExpr::new(
                             IrExprKind::BinOp {
                                 op: self.lower_binop(op),
                                 left: Box::new(left),
                                 right: Box::new(right),
                             },
                             result_ty,
-                        )
+                        ))
                     }
                 }
             }
@@ -145,7 +160,7 @@ impl AstLowering {
             ast::Expr::Unary(op, e) => {
                 let operand = self.lower_expr(&e.node)?;
                 let ty = operand.ty.clone();
-                (
+                self.try_fold(TypedExpr::new(
                     IrExprKind::UnaryOp {
                         op: match op {
                             ast::UnaryOp::Neg => UnaryOp::Neg,
@@ -154,7 +169,7 @@ impl AstLowering {
                         operand: Box::new(operand),
                     },
                     ty,
-                )
+                ))
             }
 
             ast::Expr::Call(f, args) => {
@@ -190,7 +205,7 @@ impl AstLowering {
                             let ast::CallArg::Positional(value) = &args[0] else {
                                 unreachable!("checked by matches! above")
                             };
-                            let lowered_value = self.lower_expr(&value.node)?;
+                            let lowered_value = self.lower_expr_spanned(value)?;
                             let ctor = self
                                 .newtype_checked_ctor
                                 .get(name)
@@ -215,9 +230,15 @@ impl AstLowering {
                             );
                             // Prefer `expect` over `unwrap` so panics carry context. Note: for `Result`,
                             // Rust's `expect` includes the `Err(...)` payload via Debug formatting.
+                            //
+                            // The source span is baked into the message (rather than relying on the
+                            // panic location of the generated `expect` call) since the generated Rust
+                            // line/column don't correspond to anything in the user's program.
+                            let span = self.current_span;
                             let msg = TypedExpr::new(
                                 IrExprKind::Literal(super::super::expr::Literal::StaticStr(format!(
-                                    "validated newtype construction failed: {name}::{ctor}"
+                                    "validated newtype construction failed: {name}::{ctor} (at source offset {}..{})",
+                                    span.start, span.end
                                 ))),
                                 IrType::StaticStr,
                             );
@@ -228,7 +249,8 @@ impl AstLowering {
                                     args: vec![msg],
                                 },
                                 struct_ty,
-                            ));
+                            )
+                            .with_span(span));
                         }
 
                         // This is a constructor call - lower as struct instantiation
@@ -255,7 +277,8 @@ impl AstLowering {
                                 fields,
                             },
                             struct_ty,
-                        ));
+                        )
+                        .with_span(self.current_span));
                     }
 
                     // Check for known builtins (enum-based dispatch)
@@ -267,12 +290,13 @@ impl AstLowering {
                                 args: args_ir,
                             },
                             IrType::Unknown, // Return type depends on the builtin
-                        ));
+                        )
+                        .with_span(self.current_span));
                     }
                 }
 
                 // Regular function call (user-defined or unknown)
-                let func = self.lower_expr(&f.node)?;
+                let func = self.lower_expr_spanned(f)?;
                 let args_ir = self.lower_call_args(args)?;
                 let ret_ty = if let IrType::Function { ret, .. } = &func.ty {
                     (**ret).clone()
@@ -289,7 +313,7 @@ impl AstLowering {
             }
 
             ast::Expr::MethodCall(o, m, args) => {
-                let receiver = self.lower_expr(&o.node)?;
+                let receiver = self.lower_expr_spanned(o)?;
                 let args_ir = self.lower_call_args(args)?;
 
                 // Check for known methods (enum-based dispatch)
@@ -316,8 +340,38 @@ impl AstLowering {
             }
 
             ast::Expr::Index(o, i) => {
-                let obj = self.lower_expr(&o.node)?;
-                let idx = self.lower_expr(&i.node)?;
+                let obj = self.lower_expr_spanned(o)?;
+
+                if let IrType::Tuple(elem_tys) = &obj.ty {
+                    let Some(literal_index) = Self::extract_int_literal(i) else {
+                        return Err(LoweringError {
+                            message: "tuple indices must be an integer literal (e.g. t[0], t[-1])".to_string(),
+                            span: IrSpan::from(i.span),
+                        });
+                    };
+                    let len = elem_tys.len() as i64;
+                    let resolved = if literal_index < 0 { literal_index + len } else { literal_index };
+                    if resolved < 0 || resolved >= len {
+                        return Err(LoweringError {
+                            message: format!(
+                                "tuple index {literal_index} out of bounds for tuple of length {len}"
+                            ),
+                            span: IrSpan::from(i.span),
+                        });
+                    }
+                    let resolved = resolved as usize;
+                    let result_ty = elem_tys[resolved].clone();
+                    return Ok(TypedExpr::new(
+                        IrExprKind::TupleIndex {
+                            object: Box::new(obj),
+                            index: resolved,
+                        },
+                        result_ty,
+                    )
+                    .with_span(self.current_span));
+                }
+
+                let idx = self.lower_expr_spanned(i)?;
                 let elem_ty = match &obj.ty {
                     IrType::List(e) => (**e).clone(),
                     IrType::Dict(_, v) => (**v).clone(),
@@ -351,7 +405,7 @@ impl AstLowering {
             }
 
             ast::Expr::Try(e) => {
-                let inner = self.lower_expr(&e.node)?;
+                let inner = self.lower_expr_spanned(e)?;
                 let ty = match &inner.ty {
                     IrType::Result(ok, _) => (**ok).clone(),
                     _ => inner.ty.clone(),
@@ -361,8 +415,8 @@ impl AstLowering {
 
             ast::Expr::Match(s, arms) => {
                 let scrutinee = self.lower_expr(&s.node)?;
-                let arms_ir = self.lower_match_arms(arms)?;
-                let ty = arms_ir.first().map(|a| a.body.ty.clone()).unwrap_or(IrType::Unknown);
+                let arms_ir = self.lower_match_arms(arms, &scrutinee.ty)?;
+                let ty = self.unify_branch_types(&arms_ir.iter().map(|a| a.body.ty.clone()).collect::<Vec<_>>());
                 (
                     IrExprKind::Match {
                         scrutinee: Box::new(scrutinee),
@@ -374,29 +428,21 @@ impl AstLowering {
 
             ast::Expr::If(i) => {
                 let cond = self.lower_expr(&i.condition.node)?;
-                let then_stmts = self.lower_statements(&i.then_body)?;
-                let then_expr = TypedExpr::new(
-                    IrExprKind::Block {
-                        stmts: then_stmts,
-                        value: None,
-                    },
-                    IrType::Unit,
-                );
-                let else_expr = i
-                    .else_body
-                    .as_ref()
-                    .map(|b| {
-                        self.lower_statements(b)
-                            .map(|stmts| TypedExpr::new(IrExprKind::Block { stmts, value: None }, IrType::Unit))
-                    })
-                    .transpose()?;
+                let then_expr = self.lower_value_block(&i.then_body)?;
+                let else_expr = i.else_body.as_ref().map(|b| self.lower_value_block(b)).transpose()?;
+                let ty = match &else_expr {
+                    Some(else_expr) => self.unify_branch_types(&[then_expr.ty.clone(), else_expr.ty.clone()]),
+                    // No `else` means the `if` can't be used as a value (the condition-false path
+                    // produces nothing), matching the pre-existing `Unit` behavior.
+                    None => IrType::Unit,
+                };
                 (
                     IrExprKind::If {
                         condition: Box::new(cond),
                         then_branch: Box::new(then_expr),
                         else_branch: else_expr.map(Box::new),
                     },
-                    IrType::Unit,
+                    ty,
                 )
             }
 
@@ -543,46 +589,23 @@ impl AstLowering {
             }
 
             ast::Expr::ListComp(comp) => {
-                // [expr for var in iter if cond]
-                // → iter.iter().filter(|var| cond).map(|var| expr).collect()
-                let iter_expr = self.lower_expr(&comp.iter.node)?;
-                let var_name = comp.var.clone();
-
-                // Build the filter predicate if present
-                let filter_tokens = if let Some(filter) = &comp.filter {
-                    Some(Box::new(self.lower_expr(&filter.node)?))
-                } else {
-                    None
-                };
-
-                // Build the map expression
+                // [expr for a in xs for b in ys if cond ...]
+                let clauses = self.lower_comp_clauses(&comp.clauses)?;
                 let map_expr = self.lower_expr(&comp.expr.node)?;
-
-                // Determine element type from map expression
                 let elem_ty = map_expr.ty.clone();
 
                 (
                     IrExprKind::ListComp {
                         element: Box::new(map_expr),
-                        variable: var_name,
-                        iterable: Box::new(iter_expr),
-                        filter: filter_tokens,
+                        clauses,
                     },
                     IrType::List(Box::new(elem_ty)),
                 )
             }
 
             ast::Expr::DictComp(comp) => {
-                // {key: value for var in iter if cond}
-                let iter_expr = self.lower_expr(&comp.iter.node)?;
-                let var_name = comp.var.clone();
-
-                let filter_tokens = if let Some(filter) = &comp.filter {
-                    Some(Box::new(self.lower_expr(&filter.node)?))
-                } else {
-                    None
-                };
-
+                // {key: value for a in xs for b in ys if cond ...}
+                let clauses = self.lower_comp_clauses(&comp.clauses)?;
                 let key_expr = self.lower_expr(&comp.key.node)?;
                 let value_expr = self.lower_expr(&comp.value.node)?;
 
@@ -593,18 +616,60 @@ impl AstLowering {
                     IrExprKind::DictComp {
                         key: Box::new(key_expr),
                         value: Box::new(value_expr),
-                        variable: var_name,
-                        iterable: Box::new(iter_expr),
-                        filter: filter_tokens,
+                        clauses,
                     },
                     IrType::Dict(Box::new(key_ty), Box::new(value_ty)),
                 )
             }
 
-            // Expressions that need desugaring (emit placeholder for now)
+            ast::Expr::SetComp(comp) => {
+                // {expr for a in xs for b in ys if cond ...}
+                let clauses = self.lower_comp_clauses(&comp.clauses)?;
+                let elem_expr = self.lower_expr(&comp.expr.node)?;
+                let elem_ty = elem_expr.ty.clone();
+
+                (
+                    IrExprKind::SetComp {
+                        element: Box::new(elem_expr),
+                        clauses,
+                    },
+                    IrType::Set(Box::new(elem_ty)),
+                )
+            }
+
+            ast::Expr::GenExp(comp) => {
+                // (expr for a in xs for b in ys if cond ...)
+                let clauses = self.lower_comp_clauses(&comp.clauses)?;
+                let elem_expr = self.lower_expr(&comp.expr.node)?;
+                let elem_ty = elem_expr.ty.clone();
+                let lazy = super::purity::is_lazy_safe(comp);
+
+                (
+                    IrExprKind::GenExp {
+                        element: Box::new(elem_expr),
+                        clauses,
+                        lazy,
+                    },
+                    if lazy {
+                        IrType::Iterator(Box::new(elem_ty))
+                    } else {
+                        IrType::List(Box::new(elem_ty))
+                    },
+                )
+            }
+
+            // Top-level `yield` statements in a generator-shaped function body are desugared by
+            // `generators::lower_generator_function` before a function body ever reaches regular
+            // statement/expression lowering. A `yield` that still reaches here is one our flat
+            // top-level-only desugaring doesn't support (e.g. nested inside an expression, or
+            // otherwise not caught by `generators::is_generator_body`); emit a harmless
+            // placeholder rather than failing the whole lowering pass.
             ast::Expr::Yield(_) => (IrExprKind::Unit, IrType::Unknown),
         };
-        Ok(TypedExpr::new(kind, ty))
+        // `current_span` is the nearest enclosing `lower_expr_spanned` call's span (see its doc
+        // comment); it's overwritten with the precise span if this node is itself the direct
+        // result of a `lower_expr_spanned` call.
+        Ok(TypedExpr::new(kind, ty).with_span(self.current_span))
     }
 
     /// Lower call arguments to IR expressions.
@@ -621,7 +686,7 @@ impl AstLowering {
     pub(super) fn lower_call_args(&mut self, args: &[ast::CallArg]) -> Result<Vec<TypedExpr>, LoweringError> {
         args.iter()
             .map(|a| match a {
-                ast::CallArg::Positional(e) | ast::CallArg::Named(_, e) => self.lower_expr(&e.node),
+                ast::CallArg::Positional(e) | ast::CallArg::Named(_, e) => self.lower_expr_spanned(e),
             })
             .collect()
     }
@@ -635,57 +700,343 @@ impl AstLowering {
     /// # Returns
     ///
     /// A vector of IR match arms.
-    pub(super) fn lower_match_arms(&mut self, arms: &[Spanned<ast::MatchArm>]) -> Result<Vec<MatchArm>, LoweringError> {
-        arms.iter()
-            .map(|a| {
-                let pattern = self.lower_pattern(&a.node.pattern.node);
-                let guard = a.node.guard.as_ref().map(|g| self.lower_expr(&g.node)).transpose()?;
-                let body = match &a.node.body {
-                    ast::MatchBody::Expr(e) => self.lower_expr(&e.node)?,
-                    ast::MatchBody::Block(stmts) => {
-                        let ir_stmts = self.lower_statements(stmts)?;
-                        TypedExpr::new(
-                            IrExprKind::Block {
-                                stmts: ir_stmts,
-                                value: None,
-                            },
-                            IrType::Unit,
-                        )
-                    }
-                };
-                Ok(MatchArm { pattern, guard, body })
+    ///
+    /// `scrutinee_ty` is needed to type a mapping pattern's synthesized `get`/`contains_key`
+    /// guard (see [`Self::lower_mapping_match_arm`]), since Rust has no literal map pattern.
+    pub(super) fn lower_match_arms(
+        &mut self,
+        arms: &[Spanned<ast::MatchArm>],
+        scrutinee_ty: &IrType,
+    ) -> Result<Vec<MatchArm>, LoweringError> {
+        arms.iter().map(|a| self.lower_match_arm(a, scrutinee_ty)).collect()
+    }
+
+    /// Lower a single match arm.
+    ///
+    /// A top-level mapping pattern (`{"k": v, **rest}`) is special-cased: Rust has no pattern
+    /// that destructures a map, so it binds the whole scrutinee to a synthetic variable and
+    /// rewrites the key checks into a guard (`tmp.contains_key(&k) && ...`) plus `let` bindings
+    /// prepended to the arm body (`let v = tmp.get(&k).unwrap().clone();`).
+    fn lower_match_arm(&mut self, a: &Spanned<ast::MatchArm>, scrutinee_ty: &IrType) -> Result<MatchArm, LoweringError> {
+        if let ast::Pattern::Mapping(mapping) = &a.node.pattern.node {
+            return self.lower_mapping_match_arm(mapping, a, scrutinee_ty);
+        }
+
+        let (pattern, pattern_id) = self.lower_pattern_spanned(&a.node.pattern, scrutinee_ty)?;
+        let guard = a.node.guard.as_ref().map(|g| self.lower_expr(&g.node)).transpose()?;
+        let body = match &a.node.body {
+            ast::MatchBody::Expr(e) => self.lower_expr(&e.node)?,
+            ast::MatchBody::Block(stmts) => self.lower_value_block(stmts)?,
+        };
+        Ok(MatchArm { pattern, pattern_id: Some(pattern_id), guard, body })
+    }
+
+    /// Lower a match arm whose top-level pattern is a mapping pattern. See
+    /// [`Self::lower_match_arm`] for the overall strategy.
+    fn lower_mapping_match_arm(
+        &mut self,
+        mapping: &ast::MappingPattern,
+        a: &Spanned<ast::MatchArm>,
+        scrutinee_ty: &IrType,
+    ) -> Result<MatchArm, LoweringError> {
+        let IrType::Dict(key_ty, val_ty) = scrutinee_ty else {
+            return Err(LoweringError {
+                message: "mapping patterns can only match a dict-typed value".to_string(),
+                span: IrSpan::from(a.node.pattern.span),
+            });
+        };
+        let dict_ty = IrType::Dict(key_ty.clone(), val_ty.clone());
+
+        let scrutinee_name = self.fresh_binding("map_pat");
+        let pattern = Pattern::Var(scrutinee_name.clone());
+        let pattern_id = self.source_map.record(IrSpan::from(a.node.pattern.span));
+
+        let mut guard: Option<TypedExpr> = None;
+        let mut extract_stmts = Vec::new();
+        for (key_expr, value_pat) in &mapping.entries {
+            let key = self.lower_expr(&key_expr.node)?;
+            let contains = TypedExpr::new(
+                IrExprKind::KnownMethodCall {
+                    receiver: Box::new(self.map_pat_scrutinee(&scrutinee_name, &dict_ty)),
+                    kind: MethodKind::Contains,
+                    args: vec![key.clone()],
+                },
+                IrType::Bool,
+            );
+            guard = Some(match guard {
+                Some(acc) => TypedExpr::new(
+                    IrExprKind::BinOp { op: BinOp::And, left: Box::new(acc), right: Box::new(contains) },
+                    IrType::Bool,
+                ),
+                None => contains,
+            });
+
+            match &value_pat.node {
+                ast::Pattern::Wildcard => {}
+                ast::Pattern::Binding(name) => {
+                    let get = TypedExpr::new(
+                        IrExprKind::KnownMethodCall {
+                            receiver: Box::new(self.map_pat_scrutinee(&scrutinee_name, &dict_ty)),
+                            kind: MethodKind::Get,
+                            args: vec![key],
+                        },
+                        IrType::Unknown,
+                    );
+                    let unwrapped =
+                        TypedExpr::new(IrExprKind::MethodCall { receiver: Box::new(get), method: "unwrap".to_string(), args: vec![] }, (**val_ty).clone());
+                    let cloned = TypedExpr::new(
+                        IrExprKind::MethodCall { receiver: Box::new(unwrapped), method: "clone".to_string(), args: vec![] },
+                        (**val_ty).clone(),
+                    );
+                    extract_stmts.push(IrStmt::new(IrStmtKind::Let {
+                        name: name.clone(),
+                        ty: (**val_ty).clone(),
+                        mutability: Mutability::Immutable,
+                        value: cloned,
+                    }));
+                }
+                _ => {
+                    return Err(LoweringError {
+                        message: "mapping pattern entries must bind to a name or '_'".to_string(),
+                        span: IrSpan::from(value_pat.span),
+                    });
+                }
+            }
+        }
+
+        if mapping.rest.is_some() {
+            return Err(LoweringError {
+                message: "mapping pattern '**rest' captures are not yet supported by codegen".to_string(),
+                span: IrSpan::from(a.node.pattern.span),
+            });
+        }
+
+        let mut body = match &a.node.body {
+            ast::MatchBody::Expr(e) => self.lower_expr(&e.node)?,
+            ast::MatchBody::Block(stmts) => self.lower_value_block(stmts)?,
+        };
+        if !extract_stmts.is_empty() {
+            // The extracted `let`s must run before the body is evaluated, so wrap both in a
+            // block with the body as the trailing (value-producing) expression.
+            let ty = body.ty.clone();
+            body = TypedExpr::new(IrExprKind::Block { stmts: extract_stmts, value: Some(Box::new(body)) }, ty);
+        }
+
+        let guard = match (guard, a.node.guard.as_ref()) {
+            (Some(synth), Some(user)) => {
+                let user = self.lower_expr(&user.node)?;
+                Some(TypedExpr::new(IrExprKind::BinOp { op: BinOp::And, left: Box::new(synth), right: Box::new(user) }, IrType::Bool))
+            }
+            (Some(synth), None) => Some(synth),
+            (None, Some(user)) => Some(self.lower_expr(&user.node)?),
+            (None, None) => None,
+        };
+
+        Ok(MatchArm { pattern, pattern_id: Some(pattern_id), guard, body })
+    }
+
+    /// Build a borrowing reference to a mapping pattern's synthetic whole-scrutinee binding.
+    fn map_pat_scrutinee(&self, name: &str, ty: &IrType) -> TypedExpr {
+        TypedExpr::new(IrExprKind::Var { name: name.to_string(), access: VarAccess::Borrow }, ty.clone())
+    }
+
+    /// Lower a comprehension's `for`/`if` clauses, in source order.
+    ///
+    /// Clauses are lowered left to right so that an `if` or later `for` referencing an earlier
+    /// clause's loop variable resolves correctly (the variable isn't actually bound in this
+    /// lowering pass, but keeping the order matches the emitter's nested-generator strategy).
+    pub(super) fn lower_comp_clauses(&mut self, clauses: &[ast::CompClause]) -> Result<Vec<CompClause>, LoweringError> {
+        clauses
+            .iter()
+            .map(|c| {
+                Ok(match c {
+                    ast::CompClause::For { var, iter } => CompClause::For {
+                        variable: var.clone(),
+                        iterable: Box::new(self.lower_expr(&iter.node)?),
+                    },
+                    ast::CompClause::If(cond) => CompClause::If(Box::new(self.lower_expr(&cond.node)?)),
+                })
             })
             .collect()
     }
 
+    /// Lower a statement block to IR, promoting a trailing bare expression statement into the
+    /// block's `value` so the block can itself be used in value position (e.g. as an `if`/`match`
+    /// branch).
+    ///
+    /// # Parameters
+    ///
+    /// * `stmts` - The AST statements making up the block
+    ///
+    /// # Returns
+    ///
+    /// An `IrExprKind::Block` wrapped in a `TypedExpr` whose type is the promoted trailing
+    /// expression's type, `IrType::Unknown` if the block diverges via a trailing `return`, or
+    /// `IrType::Unit` otherwise.
+    pub(super) fn lower_value_block(&mut self, stmts: &[Spanned<ast::Statement>]) -> Result<TypedExpr, LoweringError> {
+        let mut ir_stmts = self.lower_statements(stmts)?;
+        let (value, ty) = match ir_stmts.last().map(|s| &s.kind) {
+            Some(IrStmtKind::Expr(_)) => {
+                let Some(last) = ir_stmts.pop() else { unreachable!() };
+                let IrStmtKind::Expr(expr) = last.kind else { unreachable!() };
+                let ty = expr.ty.clone();
+                (Some(Box::new(expr)), ty)
+            }
+            // A block ending in `return` diverges, so it contributes no value of its own;
+            // `Unknown` is absorbed by `unify_branch_types` rather than forcing `Unit`.
+            Some(IrStmtKind::Return(_)) => (None, IrType::Unknown),
+            _ => (None, IrType::Unit),
+        };
+        Ok(TypedExpr::new(IrExprKind::Block { stmts: ir_stmts, value }, ty))
+    }
+
+    /// Compute a common type across a set of value-producing branches (`if`/`match` arms), with
+    /// light coercion.
+    ///
+    /// Equal types collapse to that type; `Int` against `Float` widens to `Float`; a concrete
+    /// type against `IrType::Unknown` (a diverging branch) yields the concrete type; an empty or
+    /// all-`Unknown` input, or a genuine mismatch between two concrete types, falls back to
+    /// `IrType::Unknown`.
+    ///
+    /// # Parameters
+    ///
+    /// * `tys` - The branch types to unify
+    ///
+    /// # Returns
+    ///
+    /// The unified type.
+    pub(super) fn unify_branch_types(&self, tys: &[IrType]) -> IrType {
+        let mut acc = IrType::Unknown;
+        for ty in tys.iter().cloned() {
+            acc = match (acc, ty) {
+                (IrType::Unknown, ty) => ty,
+                (acc, IrType::Unknown) => acc,
+                (acc, ty) if acc == ty => acc,
+                (IrType::Int, IrType::Float) | (IrType::Float, IrType::Int) => IrType::Float,
+                // A mismatch is final: a later branch must not restore a concrete type.
+                _ => return IrType::Unknown,
+            };
+        }
+        acc
+    }
+
+    /// Lower a match arm's top-level pattern to IR, recording its source span.
+    ///
+    /// Unlike `TypedExpr`, `Pattern` has no `span` field of its own to carry this inline, so the
+    /// span is recorded in `self.source_map` instead and handed back as an `IrId` the caller can
+    /// attach wherever it has room (`MatchArm::pattern_id`).
+    ///
+    /// # Parameters
+    ///
+    /// * `p` - The spanned AST pattern
+    ///
+    /// # Returns
+    ///
+    /// The lowered pattern and the `IrId` its span was recorded under.
+    pub(super) fn lower_pattern_spanned(
+        &mut self,
+        p: &Spanned<ast::Pattern>,
+        scrutinee_ty: &IrType,
+    ) -> Result<(Pattern, IrId), LoweringError> {
+        let pattern = self.lower_pattern(p, scrutinee_ty)?;
+        let id = self.source_map.record(p.span.into());
+        Ok((pattern, id))
+    }
+
     /// Lower a pattern to IR.
     ///
-    /// Handles wildcard, binding, literal, constructor, and tuple patterns.
+    /// Handles wildcard, binding, literal, constructor/class, tuple, or-, sequence-with-rest, and
+    /// capture-with-subpattern (`as`) patterns. Mapping patterns (`{"k": v}`) have no direct Rust
+    /// equivalent and are only supported as a match arm's top-level pattern, where
+    /// [`Self::lower_mapping_match_arm`] rewrites them into a guard and `let` bindings instead of
+    /// calling into here.
+    ///
+    /// `scrutinee_ty` is the type of the value this pattern matches against; it resolves a
+    /// constructor pattern's variant to its owning enum (see [`Self::resolve_enum_name`]). Nested
+    /// sub-patterns are lowered with `IrType::Unknown`, since we don't track per-field types here
+    /// — their own constructor patterns (if any) fall back to the whole-program variant search.
     ///
     /// # Parameters
     ///
-    /// * `p` - The AST pattern
+    /// * `p` - The spanned AST pattern
+    /// * `scrutinee_ty` - The IR type of the value being matched
     ///
     /// # Returns
     ///
-    /// The corresponding IR pattern.
-    pub(super) fn lower_pattern(&mut self, p: &ast::Pattern) -> Pattern {
-        match p {
-            ast::Pattern::Wildcard => Pattern::Wildcard,
-            ast::Pattern::Binding(name) => Pattern::Var(name.clone()),
-            ast::Pattern::Literal(lit) => {
-                // Lower the literal to an IR expression
-                // If lowering fails (unlikely for literals), fall back to wildcard
-                self.lower_expr(&ast::Expr::Literal(lit.clone()))
-                    .map(Pattern::Literal)
-                    .unwrap_or(Pattern::Wildcard)
-            }
-            ast::Pattern::Constructor(name, args) => Pattern::Enum {
-                name: String::new(),
-                variant: name.clone(),
-                fields: args.iter().map(|a| self.lower_pattern(&a.node)).collect(),
-            },
-            ast::Pattern::Tuple(items) => Pattern::Tuple(items.iter().map(|i| self.lower_pattern(&i.node)).collect()),
+    /// The corresponding IR pattern, or a `LoweringError` if the pattern can't be represented
+    /// (e.g. a nested mapping pattern, an unresolvable enum variant, or a literal that fails to
+    /// lower).
+    pub(super) fn lower_pattern(&mut self, p: &Spanned<ast::Pattern>, scrutinee_ty: &IrType) -> Result<Pattern, LoweringError> {
+        match &p.node {
+            ast::Pattern::Wildcard => Ok(Pattern::Wildcard),
+            ast::Pattern::Binding(name) => Ok(Pattern::Var(name.clone())),
+            ast::Pattern::Literal(lit) => self.lower_expr(&ast::Expr::Literal(lit.clone())).map(Pattern::Literal),
+            ast::Pattern::Constructor(name, args, keyword_args) if !keyword_args.is_empty() => {
+                if !args.is_empty() {
+                    return Err(LoweringError {
+                        message: "a class pattern cannot mix positional and keyword sub-patterns".to_string(),
+                        span: IrSpan::from(p.span),
+                    });
+                }
+                let fields = keyword_args
+                    .iter()
+                    .map(|(field, pat)| Ok((field.clone(), self.lower_pattern(pat, &IrType::Unknown)?)))
+                    .collect::<Result<Vec<_>, LoweringError>>()?;
+                Ok(Pattern::Struct { name: name.clone(), fields })
+            }
+            ast::Pattern::Constructor(name, args, _) => {
+                let enum_name = self.resolve_enum_name(scrutinee_ty, name, p.span)?;
+                Ok(Pattern::Enum {
+                    name: enum_name,
+                    variant: name.clone(),
+                    fields: args.iter().map(|a| self.lower_pattern(a, &IrType::Unknown)).collect::<Result<_, _>>()?,
+                })
+            }
+            ast::Pattern::Tuple(items) => Ok(Pattern::Tuple(
+                items.iter().map(|i| self.lower_pattern(i, &IrType::Unknown)).collect::<Result<_, _>>()?,
+            )),
+            ast::Pattern::Sequence(seq) => Ok(Pattern::Slice {
+                prefix: seq.prefix.iter().map(|i| self.lower_pattern(i, &IrType::Unknown)).collect::<Result<_, _>>()?,
+                rest: seq.rest.clone(),
+                suffix: seq.suffix.iter().map(|i| self.lower_pattern(i, &IrType::Unknown)).collect::<Result<_, _>>()?,
+            }),
+            ast::Pattern::Or(alts) => Ok(Pattern::Or(
+                alts.iter().map(|a| self.lower_pattern(a, scrutinee_ty)).collect::<Result<_, _>>()?,
+            )),
+            ast::Pattern::As(inner, name) => {
+                Ok(Pattern::As(Box::new(self.lower_pattern(inner, scrutinee_ty)?), name.clone()))
+            }
+            ast::Pattern::Mapping(_) => Err(LoweringError {
+                message: "mapping patterns are only supported as a match arm's top-level pattern".to_string(),
+                span: IrSpan::from(p.span),
+            }),
+        }
+    }
+
+    /// Resolve a constructor pattern's variant name to its owning enum's name.
+    ///
+    /// When `scrutinee_ty` is a known `IrType::Enum`, that enum is authoritative. Otherwise (most
+    /// commonly `IrType::Unknown`, e.g. for a nested sub-pattern whose field type we don't track),
+    /// falls back to searching every enum declared in the program for a variant with this name,
+    /// in the spirit of rust-analyzer's path resolution during body lowering. Errors if the
+    /// variant belongs to more than one enum (ambiguous) or to none (unresolvable).
+    fn resolve_enum_name(&self, scrutinee_ty: &IrType, variant: &str, span: ast::Span) -> Result<String, LoweringError> {
+        if let IrType::Enum(name) = scrutinee_ty {
+            return Ok(name.clone());
+        }
+        match self.variant_to_enum.get(variant) {
+            Some(owners) if owners.len() == 1 => Ok(owners[0].clone()),
+            Some(owners) => Err(LoweringError {
+                message: format!(
+                    "ambiguous enum variant '{variant}': matches {}; annotate the scrutinee's type to disambiguate",
+                    owners.join(", ")
+                ),
+                span: IrSpan::from(span),
+            }),
+            None => Err(LoweringError {
+                message: format!("cannot resolve enum variant '{variant}' to a declared enum"),
+                span: IrSpan::from(span),
+            }),
         }
     }
 
@@ -0,0 +1,819 @@
+//! Desugaring for `yield`/generator functions into iterator state machines.
+//!
+//! Python-style generators have no direct encoding in the IR: `ast::Expr::Yield` has no notion of
+//! suspending and resuming a call stack, and the typed IR's statements always run to completion.
+//! This module detects functions whose body contains `yield` and, instead of lowering the body
+//! directly, rewrites the whole declaration into a hand-rolled `Iterator` impl:
+//!
+//! - A struct holds an integer `state` discriminant plus every parameter and local variable,
+//!   promoted from stack locals to struct fields (any of them could be read again after a
+//!   `yield` has returned control to the caller).
+//! - `next(&mut self)` is a `match self.state { ... }` that runs the statements between the
+//!   previous and next yield point, advances `state`, and returns `Some(value)` — or `None`
+//!   once the body has fully run.
+//! - The original function becomes a thin constructor returning `impl Iterator<Item = T>`.
+//!
+//! ## Scope
+//!
+//! `yield` is supported as a direct statement in the function's top-level body, plus one
+//! additional recognized shape: a `for <var> in range(...): yield <value>` loop as the function's
+//! final statement (the single most common generator shape, e.g. `for i in range(n): yield i`).
+//! That loop resumes across `next()` calls via a numeric cursor field rather than a promoted
+//! iterator object, since `IrType::Iterator` is an `impl Trait` that can't live in a struct field
+//! (see its doc comment) — tracking just the cursor sidesteps needing a boxed trait object.
+//!
+//! `yield` nested inside any other `if`/`while`/`for`, or a `for`-loop over something other than
+//! `range(...)` with 1-2 args, is not supported and is rejected with a [`LoweringError`] rather
+//! than silently producing a function that drops it on the floor (the pre-existing behavior
+//! `ast::Expr::Yield` lowering falls back to when it isn't routed through here). Locals are
+//! promoted unconditionally rather than via a precise live-across-yield set — always safe, if not
+//! maximally tight. A full control-flow walk with real liveness analysis would subsume both
+//! restrictions, but is out of scope for this pass.
+
+use std::collections::{HashMap, HashSet};
+
+use super::super::decl::{FunctionParam, IrFunction, IrImpl, IrStruct, StructField, Visibility};
+use super::super::expr::{BinOp, IrExprKind, MatchArm, Pattern, VarAccess};
+use super::super::stmt::{AssignTarget, IrStmt, IrStmtKind};
+use super::super::types::IrType;
+use super::super::visit::{self, IrFolder};
+use super::super::{IrSpan, Mutability, TypedExpr};
+use super::AstLowering;
+use super::errors::LoweringError;
+use crate::frontend::ast::{self, Spanned};
+
+/// Whether `body` contains a `yield` statement: a `yield` that is a whole expression statement,
+/// either at the top level or, at any depth, inside `if`/`while`/`for` bodies.
+///
+/// A `yield` inside a larger expression (e.g. `x = yield 1`) or inside a `match` arm is not
+/// detected. Used to decide whether a function should be routed through generator desugaring at all; the
+/// desugaring itself only actually supports the subset described in the module doc comment, and
+/// reports a [`LoweringError`] for the rest once it starts splitting the body.
+pub(super) fn is_generator_body(body: &[Spanned<ast::Statement>]) -> bool {
+    body.iter().any(|s| stmt_has_yield(&s.node))
+}
+
+fn stmt_has_yield(stmt: &ast::Statement) -> bool {
+    match stmt {
+        ast::Statement::Expr(e) => matches!(e.node, ast::Expr::Yield(_)),
+        ast::Statement::If(s) => {
+            s.then_body.iter().any(|st| stmt_has_yield(&st.node))
+                || s.elif_branches
+                    .iter()
+                    .any(|(_, body)| body.iter().any(|st| stmt_has_yield(&st.node)))
+                || s.else_body
+                    .as_ref()
+                    .is_some_and(|body| body.iter().any(|st| stmt_has_yield(&st.node)))
+        }
+        ast::Statement::While(s) => s.body.iter().any(|st| stmt_has_yield(&st.node)),
+        ast::Statement::For(s) => s.body.iter().any(|st| stmt_has_yield(&st.node)),
+        _ => false,
+    }
+}
+
+/// What follows a segment's leading statements: either a plain top-level `yield` (`Some`, with
+/// the yielded value expression — `None` for a bare `yield`, or no `yield` at all for the
+/// trailing segment that exhausts the generator), or the one recognized loop shape.
+enum SegmentEnd {
+    Plain(Option<Option<Spanned<ast::Expr>>>),
+    RangeForYield(RangeForYield),
+}
+
+/// One run of a generator body: statements to run, followed by a [`SegmentEnd`].
+struct Segment {
+    stmts: Vec<Spanned<ast::Statement>>,
+    end: SegmentEnd,
+}
+
+/// A recognized `for <var> in range(<start>, <end>): yield <value>` loop — the one loop shape
+/// this desugaring resumes across `next()` calls (see the module doc comment for why).
+struct RangeForYield {
+    var: String,
+    start: Spanned<ast::Expr>,
+    end: Spanned<ast::Expr>,
+    value: Option<Spanned<ast::Expr>>,
+}
+
+/// Recognize a `for <var> in range(...): yield <value>` loop whose body is *exactly* the bare
+/// `yield`, and whose `range(...)` call has 1 (`range(n)`) or 2 (`range(start, n)`) positional
+/// args. A 3-arg `range(start, n, step)` or any other iterable is left unrecognized so the caller
+/// falls back to the usual nested-yield error rather than guessing at step direction.
+fn recognize_range_for_yield(stmt: &ast::Statement) -> Option<RangeForYield> {
+    let ast::Statement::For(f) = stmt else {
+        return None;
+    };
+    let [body_stmt] = f.body.as_slice() else {
+        return None;
+    };
+    let ast::Statement::Expr(e) = &body_stmt.node else {
+        return None;
+    };
+    let ast::Expr::Yield(value) = &e.node else {
+        return None;
+    };
+    let ast::Expr::Call(callee, args) = &f.iter.node else {
+        return None;
+    };
+    let ast::Expr::Ident(name) = &callee.node else {
+        return None;
+    };
+    if name != "range" {
+        return None;
+    }
+    let positional: Vec<&Spanned<ast::Expr>> = args
+        .iter()
+        .map(|a| match a {
+            ast::CallArg::Positional(e) => Some(e),
+            ast::CallArg::Named(..) => None,
+        })
+        .collect::<Option<Vec<_>>>()?;
+    let (start, end) = match positional.as_slice() {
+        [end] => (
+            Spanned::new(ast::Expr::Literal(ast::Literal::Int(0)), f.iter.span),
+            (*end).clone(),
+        ),
+        [start, end] => ((*start).clone(), (*end).clone()),
+        _ => return None,
+    };
+    Some(RangeForYield {
+        var: f.var.clone(),
+        start,
+        end,
+        value: value.as_ref().map(|b| (**b).clone()),
+    })
+}
+
+/// Split a generator function's body into segments at each top-level `yield` statement (or the
+/// one recognized `for ... range(...): yield ...` loop).
+///
+/// # Errors
+///
+/// Returns a [`LoweringError`] if a `yield` appears anywhere other than as a direct top-level
+/// statement or inside the recognized range-loop shape (e.g. nested inside a plain `if`/`while`,
+/// or a `for` loop over something other than `range(...)`) — those shapes aren't supported by
+/// this desugaring. A `yield` embedded in a larger expression is not detected (see
+/// [`is_generator_body`]) and raises no error here. Also errors if the range-loop shape appears
+/// anywhere but as the function's last statement, since nothing in this desugaring can resume
+/// "after the loop" independently of the loop's own exhaustion check.
+fn split_body(body: &[Spanned<ast::Statement>]) -> Result<Vec<Segment>, LoweringError> {
+    let mut segments = Vec::new();
+    let mut current = Vec::new();
+    let mut stmts = body.iter().peekable();
+    while let Some(stmt) = stmts.next() {
+        if let ast::Statement::Expr(e) = &stmt.node {
+            if let ast::Expr::Yield(value) = &e.node {
+                let taken = std::mem::take(&mut current);
+                segments.push(Segment {
+                    stmts: taken,
+                    end: SegmentEnd::Plain(Some(value.as_ref().map(|b| (**b).clone()))),
+                });
+                continue;
+            }
+        }
+        if let Some(loop_) = recognize_range_for_yield(&stmt.node) {
+            if stmts.peek().is_some() {
+                return Err(LoweringError {
+                    message: "a `for <var> in range(...): yield <value>` loop must be the last \
+                              statement in a generator function; this desugaring can't resume \
+                              statements that follow it"
+                        .to_string(),
+                    span: IrSpan::from(stmt.span),
+                });
+            }
+            let taken = std::mem::take(&mut current);
+            segments.push(Segment {
+                stmts: taken,
+                end: SegmentEnd::RangeForYield(loop_),
+            });
+            return Ok(segments);
+        }
+        if stmt_has_yield(&stmt.node) {
+            return Err(LoweringError {
+                message: "generator functions only support `yield` as a direct top-level \
+                          statement, or inside a `for <var> in range(...): yield <value>` loop \
+                          as the function's last statement; `yield` inside any other nested \
+                          if/while/for is not yet supported"
+                    .to_string(),
+                span: IrSpan::from(stmt.span),
+            });
+        }
+        current.push(stmt.clone());
+    }
+    segments.push(Segment {
+        stmts: current,
+        end: SegmentEnd::Plain(None),
+    });
+    Ok(segments)
+}
+
+/// Recursively collect every name bound by a `let` anywhere in `stmts`, including inside nested
+/// `if`/`while`/`for`/`match`/block bodies, together with the type it was first seen with.
+///
+/// Over-approximates on purpose: a local is promoted to a struct field even if it happens not to
+/// be read after any `yield`, since computing the precise live-across-yield set would require a
+/// real control-flow/liveness analysis that this pass doesn't do (see the module doc comment).
+fn collect_lets(stmts: &[IrStmt], out: &mut Vec<(String, IrType)>, seen: &mut HashSet<String>) {
+    for stmt in stmts {
+        match &stmt.kind {
+            IrStmtKind::Let { name, ty, .. } => {
+                if seen.insert(name.clone()) {
+                    out.push((name.clone(), ty.clone()));
+                }
+            }
+            IrStmtKind::While { body, .. } | IrStmtKind::For { body, .. } | IrStmtKind::Loop { body, .. } => {
+                collect_lets(body, out, seen);
+            }
+            IrStmtKind::If {
+                then_branch,
+                else_branch,
+                ..
+            } => {
+                collect_lets(then_branch, out, seen);
+                if let Some(else_branch) = else_branch {
+                    collect_lets(else_branch, out, seen);
+                }
+            }
+            IrStmtKind::Match { arms, .. } => {
+                for arm in arms {
+                    if let IrExprKind::Block { stmts, .. } = &arm.body.kind {
+                        collect_lets(stmts, out, seen);
+                    }
+                }
+            }
+            IrStmtKind::Block(stmts) => collect_lets(stmts, out, seen),
+            _ => {}
+        }
+    }
+}
+
+/// Build a placeholder initial value for a promoted local's struct field.
+///
+/// Locals are promoted unconditionally (see [`collect_lets`]), including ones whose `let` only
+/// runs in a later segment, so the struct needs *some* value to construct with up front; the
+/// real value is written in as soon as that segment's `let` runs, via [`FieldPromoter`].
+///
+/// # Errors
+///
+/// Returns a [`LoweringError`] for types with no obvious placeholder (user-defined structs,
+/// enums, traits, references, ...) rather than guessing. A generator with such a local isn't
+/// supported yet.
+fn default_value_for_field(ty: &IrType) -> Result<TypedExpr, LoweringError> {
+    let kind = match ty {
+        IrType::Unit => IrExprKind::Unit,
+        IrType::Bool => IrExprKind::Bool(false),
+        IrType::Int => IrExprKind::Int(0),
+        IrType::Float => IrExprKind::Float(0.0),
+        IrType::String => IrExprKind::String(String::new()),
+        IrType::List(_) => IrExprKind::List(vec![]),
+        IrType::Dict(_, _) => IrExprKind::Dict(vec![]),
+        IrType::Set(_) => IrExprKind::Set(vec![]),
+        IrType::Tuple(types) => IrExprKind::Tuple(
+            types
+                .iter()
+                .map(default_value_for_field)
+                .collect::<Result<Vec<_>, _>>()?,
+        ),
+        IrType::Option(_) => IrExprKind::None,
+        _ => {
+            return Err(LoweringError {
+                message: format!(
+                    "generator functions can't promote a local of type {ty:?} to a struct field yet \
+                     (no placeholder initial value); only primitives, collections, tuples, and \
+                     Option locals are supported"
+                ),
+                span: IrSpan::default(),
+            });
+        }
+    };
+    Ok(TypedExpr::new(kind, ty.clone()))
+}
+
+/// `self` as a borrowed `Var` expression, as used throughout the lowered method bodies this
+/// struct's methods consist of (see e.g. `ast::Expr::SelfExpr` lowering).
+fn self_expr() -> TypedExpr {
+    TypedExpr::new(
+        IrExprKind::Var {
+            name: "self".to_string(),
+            access: VarAccess::Borrow,
+        },
+        IrType::Unknown,
+    )
+}
+
+fn self_field(field: &str, ty: IrType) -> TypedExpr {
+    TypedExpr::new(
+        IrExprKind::Field {
+            object: Box::new(self_expr()),
+            field: field.to_string(),
+        },
+        ty,
+    )
+}
+
+/// Rewrites promoted locals/parameters from stack bindings into `self` field accesses: a `Var`
+/// read of a renamed name becomes a `Field` read of its mapped field name, and a
+/// `Let`/`Assign`/`CompoundAssign` that targets a renamed name is retargeted at the matching
+/// field (dropping the now-redundant field type annotation on `Let`, since the struct field is
+/// already typed). Most callers map a name to itself (the common "promote this local verbatim"
+/// case); the range-for-yield loop var is the one case that maps to a differently-named field
+/// (its numeric cursor field).
+///
+/// Implemented as an [`IrFolder`] rather than a bespoke walk so nested `if`/`while`/`for`/`match`
+/// bodies are covered for free via the trait's structural `super_fold_*` rebuild.
+struct FieldPromoter<'a> {
+    renames: &'a HashMap<String, String>,
+}
+
+impl<'a> FieldPromoter<'a> {
+    fn field_target(&self, field: String) -> AssignTarget {
+        AssignTarget::Field {
+            object: Box::new(self_expr()),
+            field,
+        }
+    }
+}
+
+impl<'a> IrFolder for FieldPromoter<'a> {
+    fn fold_expr(&mut self, expr: TypedExpr) -> TypedExpr {
+        if let IrExprKind::Var { ref name, .. } = expr.kind {
+            if let Some(field) = self.renames.get(name) {
+                return self_field(field, expr.ty).with_span(expr.span);
+            }
+        }
+        visit::super_fold_expr(self, expr)
+    }
+
+    fn fold_stmt(&mut self, stmt: IrStmt) -> IrStmt {
+        let IrStmt { kind, span } = stmt;
+        match kind {
+            IrStmtKind::Let { name, value, .. } if self.renames.contains_key(&name) => {
+                let value = self.fold_expr(value);
+                IrStmt {
+                    kind: IrStmtKind::Assign {
+                        target: self.field_target(self.renames[&name].clone()),
+                        value,
+                    },
+                    span,
+                }
+            }
+            IrStmtKind::Assign {
+                target: AssignTarget::Var(name),
+                value,
+            } if self.renames.contains_key(&name) => {
+                let value = self.fold_expr(value);
+                IrStmt {
+                    kind: IrStmtKind::Assign {
+                        target: self.field_target(self.renames[&name].clone()),
+                        value,
+                    },
+                    span,
+                }
+            }
+            IrStmtKind::CompoundAssign {
+                target: AssignTarget::Var(name),
+                op,
+                value,
+            } if self.renames.contains_key(&name) => {
+                let value = self.fold_expr(value);
+                IrStmt {
+                    kind: IrStmtKind::CompoundAssign {
+                        target: self.field_target(self.renames[&name].clone()),
+                        op,
+                        value,
+                    },
+                    span,
+                }
+            }
+            other => visit::super_fold_stmt(self, IrStmt { kind: other, span }),
+        }
+    }
+}
+
+/// A lowered [`SegmentEnd`]: the same shape, but with every expression already lowered to IR.
+enum LoweredEnd {
+    Plain(Option<TypedExpr>),
+    RangeForYield {
+        var: String,
+        start: TypedExpr,
+        bound: TypedExpr,
+        value: TypedExpr,
+    },
+}
+
+impl AstLowering {
+    /// Lower a generator function (one `generators::is_generator_body` accepts) into a struct
+    /// implementing `Iterator`, its impl block, and a thin constructor function replacing the
+    /// original declaration.
+    pub(super) fn lower_generator_function(
+        &mut self,
+        f: &ast::FunctionDecl,
+    ) -> Result<(IrStruct, IrImpl, IrFunction), LoweringError> {
+        let segments = split_body(&f.body)?;
+
+        self.scopes.push(std::collections::HashMap::new());
+
+        let params: Vec<FunctionParam> = f
+            .params
+            .iter()
+            .map(|p| {
+                let ty = self.lower_type(&p.node.ty.node);
+                if let Some(scope) = self.scopes.last_mut() {
+                    scope.insert(p.node.name.clone(), ty.clone());
+                }
+                FunctionParam {
+                    name: p.node.name.clone(),
+                    ty,
+                    mutability: Mutability::Immutable,
+                    is_self: false,
+                }
+            })
+            .collect();
+
+        // Lower every segment's statements and its terminator (a yielded value, or the recognized
+        // range-loop's bounds plus yielded value), up front, before promoting any locals to
+        // fields: lowering needs them as ordinary scoped variables to resolve types correctly.
+        let mut lowered_segments: Vec<(Vec<IrStmt>, LoweredEnd)> = Vec::new();
+        for segment in &segments {
+            let stmts = self.lower_statements(&segment.stmts)?;
+            let end = match &segment.end {
+                SegmentEnd::Plain(Some(Some(e))) => LoweredEnd::Plain(Some(self.lower_expr_spanned(e)?)),
+                SegmentEnd::Plain(Some(None)) => {
+                    LoweredEnd::Plain(Some(TypedExpr::new(IrExprKind::Unit, IrType::Unit)))
+                }
+                SegmentEnd::Plain(None) => LoweredEnd::Plain(None),
+                SegmentEnd::RangeForYield(loop_) => {
+                    let start = self.lower_expr_spanned(&loop_.start)?;
+                    let bound = self.lower_expr_spanned(&loop_.end)?;
+                    // The loop var is only ever bound as a plain local (see the arm body built
+                    // below), never promoted to a field, so it just needs a scope entry here to
+                    // resolve types while lowering the yielded value.
+                    self.scopes.push(std::collections::HashMap::new());
+                    if let Some(scope) = self.scopes.last_mut() {
+                        scope.insert(loop_.var.clone(), IrType::Int);
+                    }
+                    let value = match &loop_.value {
+                        Some(e) => self.lower_expr_spanned(e)?,
+                        None => TypedExpr::new(IrExprKind::Unit, IrType::Unit),
+                    };
+                    self.scopes.pop();
+                    LoweredEnd::RangeForYield {
+                        var: loop_.var.clone(),
+                        start,
+                        bound,
+                        value,
+                    }
+                }
+            };
+            lowered_segments.push((stmts, end));
+        }
+        self.scopes.pop();
+
+        let item_ty = self.unify_branch_types(
+            &lowered_segments
+                .iter()
+                .filter_map(|(_, end)| match end {
+                    LoweredEnd::Plain(Some(e)) => Some(e.ty.clone()),
+                    LoweredEnd::RangeForYield { value, .. } => Some(value.ty.clone()),
+                    LoweredEnd::Plain(None) => None,
+                })
+                .collect::<Vec<_>>(),
+        );
+
+        // Promoted fields: every parameter, plus every local bound anywhere in the body.
+        let mut promoted: Vec<(String, IrType)> =
+            params.iter().map(|p| (p.name.clone(), p.ty.clone())).collect();
+        let mut seen: HashSet<String> = promoted.iter().map(|(n, _)| n.clone()).collect();
+        for (stmts, _) in &lowered_segments {
+            collect_lets(stmts, &mut promoted, &mut seen);
+        }
+
+        // The recognized range-loop (always the last segment, if present) resumes across
+        // `next()` calls via two numeric cursor fields rather than a promoted iterator object,
+        // since `IrType::Iterator` can't live in a struct field (see the module doc comment).
+        let mut range_fields: Option<(String, String)> = None;
+        let mut range_inits: Vec<(String, TypedExpr)> = Vec::new();
+        let mut range_loop_var: Option<String> = None;
+        if let Some((
+            _,
+            LoweredEnd::RangeForYield {
+                var, start, bound, ..
+            },
+        )) = lowered_segments.last()
+        {
+            range_loop_var = Some(var.clone());
+            let cur_hint = format!("{var}_cur");
+            let cur_name = if seen.insert(cur_hint.clone()) {
+                cur_hint
+            } else {
+                self.fresh_binding(&cur_hint)
+            };
+            let end_hint = format!("{var}_end");
+            let end_name = if seen.insert(end_hint.clone()) {
+                end_hint
+            } else {
+                self.fresh_binding(&end_hint)
+            };
+            promoted.push((cur_name.clone(), IrType::Int));
+            promoted.push((end_name.clone(), IrType::Int));
+            range_inits.push((cur_name.clone(), start.clone()));
+            range_inits.push((end_name.clone(), bound.clone()));
+            range_fields = Some((cur_name, end_name));
+        }
+
+        let state_field = if seen.contains("state") {
+            self.fresh_binding("gen_state")
+        } else {
+            "state".to_string()
+        };
+
+        let struct_name = format!("{}Iter", Self::pascal_case(&f.name));
+
+        let mut fields: Vec<StructField> = promoted
+            .iter()
+            .map(|(name, ty)| StructField {
+                name: name.clone(),
+                ty: ty.clone(),
+                visibility: Visibility::Private,
+                serde_rename: None,
+                serde_skip: false,
+                serde_default: false,
+            })
+            .collect();
+        fields.push(StructField {
+            name: state_field.clone(),
+            ty: IrType::Int,
+            visibility: Visibility::Private,
+            serde_rename: None,
+            serde_skip: false,
+            serde_default: false,
+        });
+
+        let struct_ir = IrStruct {
+            name: struct_name.clone(),
+            fields,
+            derives: vec![],
+            visibility: Self::map_visibility(f.visibility),
+            type_params: f.type_params.clone(),
+            serde_rename_all: None,
+            formats: vec![],
+        };
+
+        // Rewrite every segment's statements (and yielded value) to read/write promoted names as
+        // `self` fields instead of locals. The range-loop's own loop var is excluded from
+        // `renames` by construction: it's bound as a plain local in the arm built for it below
+        // (see `LoweredEnd::RangeForYield` handling), not promoted to a field. This matters even
+        // when the loop var's name collides with a promoted param or local (e.g. `for n in
+        // range(n)` reusing a parameter named `n`) — without the exclusion, `FieldPromoter` would
+        // rewrite the yielded value's reference to the loop var into the frozen `self` field
+        // instead of leaving it to resolve against the per-iteration local bound below.
+        let renames: HashMap<String, String> = promoted
+            .iter()
+            .map(|(n, _)| n.clone())
+            .filter(|n| Some(n) != range_loop_var.as_ref())
+            .map(|n| (n.clone(), n))
+            .collect();
+        let mut promoter = FieldPromoter { renames: &renames };
+        let rewritten_segments: Vec<(Vec<IrStmt>, LoweredEnd)> = lowered_segments
+            .into_iter()
+            .map(|(stmts, end)| {
+                let stmts = stmts.into_iter().map(|s| promoter.fold_stmt(s)).collect();
+                let end = match end {
+                    LoweredEnd::Plain(v) => LoweredEnd::Plain(v.map(|v| promoter.fold_expr(v))),
+                    LoweredEnd::RangeForYield {
+                        var,
+                        start,
+                        bound,
+                        value,
+                    } => LoweredEnd::RangeForYield {
+                        var,
+                        start,
+                        bound,
+                        value: promoter.fold_expr(value),
+                    },
+                };
+                (stmts, end)
+            })
+            .collect();
+
+        // Build `next(&mut self) -> Option<Item>` as `match self.state { ... }`.
+        let mut arms: Vec<MatchArm> = Vec::new();
+        for (i, (stmts, end)) in rewritten_segments.into_iter().enumerate() {
+            let mut body_stmts = stmts;
+            match end {
+                LoweredEnd::Plain(value) => {
+                    body_stmts.push(IrStmt::new(IrStmtKind::Assign {
+                        target: AssignTarget::Field {
+                            object: Box::new(self_expr()),
+                            field: state_field.clone(),
+                        },
+                        value: TypedExpr::new(IrExprKind::Int(i as i64 + 1), IrType::Int),
+                    }));
+                    let return_value = match value {
+                        Some(v) => TypedExpr::new(
+                            IrExprKind::Struct {
+                                name: "Some".to_string(),
+                                fields: vec![(String::new(), v)],
+                            },
+                            IrType::Option(Box::new(item_ty.clone())),
+                        ),
+                        None => {
+                            TypedExpr::new(IrExprKind::None, IrType::Option(Box::new(item_ty.clone())))
+                        }
+                    };
+                    body_stmts.push(IrStmt::new(IrStmtKind::Return(Some(return_value))));
+                }
+                // `self.<cur>` tracks loop position directly, so this state never advances while
+                // the loop has more to give: the arm re-enters itself on every `next()` call
+                // until the cursor reaches the bound, then advances past the loop like any other
+                // segment boundary.
+                LoweredEnd::RangeForYield { var, value, .. } => {
+                    let (cur_field, end_field) = range_fields
+                        .clone()
+                        .expect("range_fields is populated alongside every RangeForYield segment");
+                    let condition = TypedExpr::new(
+                        IrExprKind::BinOp {
+                            op: BinOp::Lt,
+                            left: Box::new(self_field(&cur_field, IrType::Int)),
+                            right: Box::new(self_field(&end_field, IrType::Int)),
+                        },
+                        IrType::Bool,
+                    );
+                    let then_branch = vec![
+                        IrStmt::new(IrStmtKind::Let {
+                            name: var.clone(),
+                            ty: IrType::Int,
+                            mutability: Mutability::Immutable,
+                            value: self_field(&cur_field, IrType::Int),
+                        }),
+                        IrStmt::new(IrStmtKind::Assign {
+                            target: AssignTarget::Field {
+                                object: Box::new(self_expr()),
+                                field: cur_field.clone(),
+                            },
+                            value: TypedExpr::new(
+                                IrExprKind::BinOp {
+                                    op: BinOp::Add,
+                                    left: Box::new(TypedExpr::new(
+                                        IrExprKind::Var {
+                                            name: var.clone(),
+                                            access: VarAccess::Copy,
+                                        },
+                                        IrType::Int,
+                                    )),
+                                    right: Box::new(TypedExpr::new(IrExprKind::Int(1), IrType::Int)),
+                                },
+                                IrType::Int,
+                            ),
+                        }),
+                        IrStmt::new(IrStmtKind::Return(Some(TypedExpr::new(
+                            IrExprKind::Struct {
+                                name: "Some".to_string(),
+                                fields: vec![(String::new(), value)],
+                            },
+                            IrType::Option(Box::new(item_ty.clone())),
+                        )))),
+                    ];
+                    let else_branch = vec![
+                        IrStmt::new(IrStmtKind::Assign {
+                            target: AssignTarget::Field {
+                                object: Box::new(self_expr()),
+                                field: state_field.clone(),
+                            },
+                            value: TypedExpr::new(IrExprKind::Int(i as i64 + 1), IrType::Int),
+                        }),
+                        IrStmt::new(IrStmtKind::Return(Some(TypedExpr::new(
+                            IrExprKind::None,
+                            IrType::Option(Box::new(item_ty.clone())),
+                        )))),
+                    ];
+                    body_stmts.push(IrStmt::new(IrStmtKind::If {
+                        condition,
+                        then_branch,
+                        else_branch: Some(else_branch),
+                    }));
+                }
+            }
+
+            arms.push(MatchArm {
+                pattern: Pattern::Literal(TypedExpr::new(IrExprKind::Int(i as i64), IrType::Int)),
+                pattern_id: None,
+                guard: None,
+                body: TypedExpr::new(
+                    IrExprKind::Block {
+                        stmts: body_stmts,
+                        value: None,
+                    },
+                    IrType::Unit,
+                ),
+            });
+        }
+        // Already-exhausted: every call after the trailing segment returns `None`.
+        arms.push(MatchArm {
+            pattern: Pattern::Wildcard,
+            pattern_id: None,
+            guard: None,
+            body: TypedExpr::new(
+                IrExprKind::Block {
+                    stmts: vec![IrStmt::new(IrStmtKind::Return(Some(TypedExpr::new(
+                        IrExprKind::None,
+                        IrType::Option(Box::new(item_ty.clone())),
+                    ))))],
+                    value: None,
+                },
+                IrType::Unit,
+            ),
+        });
+
+        let next_fn = IrFunction {
+            name: "next".to_string(),
+            params: vec![FunctionParam {
+                name: "self".to_string(),
+                ty: IrType::Unknown,
+                mutability: Mutability::Mutable,
+                is_self: true,
+            }],
+            return_type: IrType::Option(Box::new(item_ty.clone())),
+            body: vec![IrStmt::new(IrStmtKind::Match {
+                scrutinee: self_field(&state_field, IrType::Int),
+                arms,
+            })],
+            is_async: false,
+            visibility: Visibility::Private,
+            type_params: vec![],
+            constness: super::super::decl::Constness::NotConst,
+        };
+
+        let impl_ir = IrImpl {
+            target_type: struct_name.clone(),
+            trait_name: Some("Iterator".to_string()),
+            assoc_types: vec![("Item".to_string(), item_ty.clone())],
+            methods: vec![next_fn],
+        };
+
+        // The original function becomes a constructor: build the struct from its parameters plus
+        // a placeholder for every promoted local, with `state` starting at 0.
+        let mut ctor_fields: Vec<(String, TypedExpr)> = Vec::new();
+        for param in &params {
+            ctor_fields.push((
+                param.name.clone(),
+                TypedExpr::new(
+                    IrExprKind::Var {
+                        name: param.name.clone(),
+                        access: VarAccess::Move,
+                    },
+                    param.ty.clone(),
+                ),
+            ));
+        }
+        let param_names: HashSet<&str> = params.iter().map(|p| p.name.as_str()).collect();
+        // The range-loop's cursor/bound fields (if any) get their real starting value from the
+        // loop's `range(...)` args instead of a placeholder, since e.g. the bound field needs to
+        // actually hold the end of the range for the `next()` arm's exhaustion check to work.
+        let mut range_inits: HashMap<String, TypedExpr> = range_inits.into_iter().collect();
+        for (name, ty) in &promoted {
+            if param_names.contains(name.as_str()) {
+                continue;
+            }
+            let value = match range_inits.remove(name) {
+                Some(v) => v,
+                None => default_value_for_field(ty)?,
+            };
+            ctor_fields.push((name.clone(), value));
+        }
+        ctor_fields.push((state_field, TypedExpr::new(IrExprKind::Int(0), IrType::Int)));
+
+        let ctor_fn = IrFunction {
+            name: f.name.clone(),
+            params,
+            return_type: IrType::Iterator(Box::new(item_ty)),
+            body: vec![IrStmt::new(IrStmtKind::Return(Some(TypedExpr::new(
+                IrExprKind::Struct {
+                    name: struct_name,
+                    fields: ctor_fields,
+                },
+                IrType::Unknown,
+            ))))],
+            is_async: false,
+            visibility: Self::map_visibility(f.visibility),
+            type_params: f.type_params.clone(),
+            constness: super::super::decl::Constness::NotConst,
+        };
+
+        Ok((struct_ir, impl_ir, ctor_fn))
+    }
+
+    /// `snake_case`/arbitrary identifier -> `PascalCase`, for the name of the generated iterator
+    /// struct backing a generator function.
+    fn pascal_case(name: &str) -> String {
+        name.split('_')
+            .filter(|part| !part.is_empty())
+            .map(|part| {
+                let mut chars = part.chars();
+                match chars.next() {
+                    Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+                    None => String::new(),
+                }
+            })
+            .collect()
+    }
+}
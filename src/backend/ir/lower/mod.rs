@@ -16,6 +16,10 @@
 //! - `decl` - Declaration lowering (functions, models, classes, enums, etc.)
 //! - `stmt` - Statement lowering
 //! - `expr` - Expression lowering
+//! - `const_eval` - Compile-time constant folding of literal `BinOp`/`UnaryOp` expressions
+//! - `source_map` - `IrId` -> source span side table for nodes with no span field of their own
+//! - `purity` - Eager-vs-lazy safety analysis for generator expressions
+//! - `generators` - Desugars `yield`/generator functions into hand-rolled `Iterator` state machines
 //!
 //! # Usage
 //!
@@ -26,9 +30,13 @@
 //! let ir_program = lowering.lower_program(&ast_program)?;
 //! ```
 
+mod const_eval;
 mod decl;
 mod errors;
 mod expr;
+mod generators;
+mod purity;
+mod source_map;
 mod stmt;
 mod types;
 
@@ -42,6 +50,8 @@ use crate::frontend::typechecker::TypeCheckInfo;
 
 // Re-export error types
 pub use errors::{LoweringError, LoweringErrors};
+// Re-export source-map types
+pub use source_map::{IrId, SourceMap};
 
 /// AST to IR lowering context.
 ///
@@ -67,6 +77,9 @@ pub struct AstLowering {
     pub(super) struct_names: HashMap<String, IrType>,
     /// Track declared enums for type resolution
     pub(super) enum_names: HashMap<String, IrType>,
+    /// Variant name -> owning enum name(s), for resolving constructor patterns whose scrutinee
+    /// type is unknown. More than one owner means the variant name is ambiguous.
+    pub(super) variant_to_enum: HashMap<String, Vec<String>>,
     /// Track mutable variables for auto-borrow at call sites
     pub(super) mutable_vars: HashMap<String, bool>,
     /// Track class declarations for inheritance resolution
@@ -75,6 +88,16 @@ pub struct AstLowering {
     pub(super) trait_methods: HashMap<String, Vec<String>>,
     /// Optional typechecker output used to drive lowering (avoid heuristics).
     pub(super) type_info: Option<TypeCheckInfo>,
+    /// Span of the nearest enclosing expression lowered via `lower_expr_spanned`, used to blame
+    /// synthesized panics (e.g. the newtype checked-construction `expect`) on the original
+    /// source location rather than on generated code.
+    pub(super) current_span: super::IrSpan,
+    /// `IrId -> span` side table for nodes (currently: patterns) that have no span field of
+    /// their own to carry it inline.
+    pub(super) source_map: SourceMap,
+    /// Counter for synthesized binding names (e.g. the whole-scrutinee temp a mapping pattern
+    /// binds to, since Rust has no literal map pattern to destructure it directly).
+    pub(super) next_synthetic_id: u32,
 }
 
 impl AstLowering {
@@ -86,13 +109,32 @@ impl AstLowering {
             scopes: vec![HashMap::new()],
             struct_names: HashMap::new(),
             enum_names: HashMap::new(),
+            variant_to_enum: HashMap::new(),
             mutable_vars: HashMap::new(),
             class_decls: HashMap::new(),
             trait_methods: HashMap::new(),
             type_info: None,
+            current_span: super::IrSpan::default(),
+            source_map: SourceMap::new(),
+            next_synthetic_id: 0,
         }
     }
 
+    /// Generate a fresh, source-unreachable binding name with the given hint.
+    pub(super) fn fresh_binding(&mut self, hint: &str) -> String {
+        let id = self.next_synthetic_id;
+        self.next_synthetic_id += 1;
+        format!("__{hint}_{id}")
+    }
+
+    /// Access the `IrId -> span` side table accumulated so far.
+    ///
+    /// Useful after `lower_program` returns, for a diagnostic that only holds onto an `IrId`
+    /// (e.g. recovered from a `Pattern`) and needs to resolve it back to a source span.
+    pub fn source_map(&self) -> &SourceMap {
+        &self.source_map
+    }
+
     /// Create a lowering context that uses typechecker output for more accurate lowering.
     pub fn new_with_type_info(type_info: TypeCheckInfo) -> Self {
         let mut s = Self::new();
@@ -125,7 +167,9 @@ impl AstLowering {
         let mut ir_program = IrProgram::new();
         let mut errors: Vec<LoweringError> = Vec::new();
 
-        // First pass: collect class declarations and trait method names
+        // First pass: collect class declarations, trait method names, and enum variant ownership
+        // (the latter up front, not just when each enum is lowered, so a match pattern can
+        // resolve a variant to its enum regardless of declaration order).
         for decl in &program.declarations {
             if let ast::Declaration::Class(ref c) = decl.node {
                 self.class_decls.insert(c.name.clone(), c.clone());
@@ -135,6 +179,12 @@ impl AstLowering {
                     t.methods.iter().map(|m| m.node.name.clone()).collect();
                 self.trait_methods.insert(t.name.clone(), method_names);
             }
+            if let ast::Declaration::Enum(ref e) = decl.node {
+                self.enum_names.insert(e.name.clone(), IrType::Enum(e.name.clone()));
+                for v in &e.variants {
+                    self.variant_to_enum.entry(v.node.name.clone()).or_default().push(e.name.clone());
+                }
+            }
         }
 
         // Pass 1.5: register module-level const names into the root scope for lookups.
@@ -175,7 +225,7 @@ impl AstLowering {
                 let return_type = self.lower_type(&f.return_type.node);
                 ir_program
                     .function_registry
-                    .register(f.name.clone(), params, return_type);
+                    .register(&mut ir_program.interner, &f.name, params, return_type);
             }
         }
 
@@ -261,6 +311,50 @@ impl AstLowering {
                         Err(e) => errors.push(e),
                     }
                 }
+                ast::Declaration::Newtype(n) => {
+                    // Generate struct + impl block (may be empty if no methods, serde methods
+                    // added during emission), same as models.
+                    match self.lower_newtype(n) {
+                        Ok(struct_ir) => {
+                            self.struct_names
+                                .insert(struct_ir.name.clone(), IrType::Struct(struct_ir.name.clone()));
+                            ir_program
+                                .declarations
+                                .push(IrDecl::new(IrDeclKind::Struct(struct_ir.clone())));
+
+                            match self.lower_model_methods(&struct_ir.name, &n.methods) {
+                                Ok(impl_ir) => {
+                                    ir_program
+                                        .declarations
+                                        .push(IrDecl::new(IrDeclKind::Impl(impl_ir)));
+                                }
+                                Err(e) => errors.push(e),
+                            }
+                        }
+                        Err(e) => errors.push(e),
+                    }
+                }
+                ast::Declaration::Function(f) if generators::is_generator_body(&f.body) => {
+                    // Generator function: desugar into a struct + `impl Iterator` + a thin
+                    // constructor function, instead of lowering the body directly (see
+                    // `generators` module doc comment).
+                    match self.lower_generator_function(f) {
+                        Ok((struct_ir, impl_ir, ctor_fn)) => {
+                            self.struct_names
+                                .insert(struct_ir.name.clone(), IrType::Struct(struct_ir.name.clone()));
+                            ir_program
+                                .declarations
+                                .push(IrDecl::new(IrDeclKind::Struct(struct_ir)));
+                            ir_program
+                                .declarations
+                                .push(IrDecl::new(IrDeclKind::Impl(impl_ir)));
+                            ir_program
+                                .declarations
+                                .push(IrDecl::new(IrDeclKind::Function(ctor_fn)));
+                        }
+                        Err(e) => errors.push(e),
+                    }
+                }
                 _ => {
                     // Regular declaration lowering
                     match self.lower_declaration(&decl.node) {
@@ -296,6 +390,7 @@ impl Default for AstLowering {
 #[allow(clippy::unwrap_used)]
 mod tests {
     use super::*;
+    use crate::backend::ir::{IrExpr, IrExprKind, IrFunction, IrStmtKind};
     use crate::frontend::{lexer, parser};
 
     fn lower_source(source: &str) -> Result<IrProgram, LoweringErrors> {
@@ -477,4 +572,147 @@ def test() -> int:
             "Error should mention immutable"
         );
     }
+
+    #[test]
+    fn test_lower_generator_function() {
+        let ir = lower_source(
+            r#"
+def counter(start: int) -> int:
+    mut x = start
+    yield x
+    x = x + 1
+    yield x
+"#,
+        )
+        .unwrap();
+        // A struct, its `impl Iterator`, and the constructor function.
+        assert_eq!(ir.declarations.len(), 3);
+        let Some(struct_decl) = ir.declarations.iter().find_map(|d| match &d.kind {
+            IrDeclKind::Struct(s) => Some(s),
+            _ => None,
+        }) else {
+            panic!("Expected a generated struct declaration");
+        };
+        assert_eq!(struct_decl.name, "CounterIter");
+        // `start`, `x`, and the state discriminant.
+        assert_eq!(struct_decl.fields.len(), 3);
+
+        let Some(impl_decl) = ir.declarations.iter().find_map(|d| match &d.kind {
+            IrDeclKind::Impl(i) => Some(i),
+            _ => None,
+        }) else {
+            panic!("Expected a generated Iterator impl");
+        };
+        assert_eq!(impl_decl.trait_name.as_deref(), Some("Iterator"));
+        assert_eq!(impl_decl.methods.len(), 1);
+        assert_eq!(impl_decl.methods[0].name, "next");
+
+        let Some(ctor) = ir.declarations.iter().find_map(|d| match &d.kind {
+            IrDeclKind::Function(f) => Some(f),
+            _ => None,
+        }) else {
+            panic!("Expected the constructor function");
+        };
+        assert_eq!(ctor.name, "counter");
+        assert!(matches!(ctor.return_type, IrType::Iterator(_)));
+    }
+
+    #[test]
+    fn test_lower_generator_rejects_nested_yield() {
+        let result = lower_source(
+            r#"
+def broken() -> int:
+    if true:
+        yield 1
+"#,
+        );
+        assert!(result.is_err(), "yield nested in if should be rejected");
+        let errors = result.unwrap_err();
+        assert!(
+            errors.0[0].message.contains("nested"),
+            "Error should mention the nested-yield restriction"
+        );
+    }
+
+    /// Find the `if cond { ... } else { ... }` statement generated for a `RangeForYield`
+    /// segment's `next()` arm, and return the `value` wrapped by its `Some(value)` return.
+    fn range_for_yield_return_value(next_method: &IrFunction) -> &IrExpr {
+        for stmt in &next_method.body {
+            if let IrStmtKind::Match { arms, .. } = &stmt.kind {
+                for arm in arms {
+                    if let IrExprKind::Block { stmts, .. } = &arm.body.kind {
+                        for arm_stmt in stmts {
+                            if let IrStmtKind::If { then_branch, .. } = &arm_stmt.kind {
+                                for then_stmt in then_branch {
+                                    if let IrStmtKind::Return(Some(IrExpr {
+                                        kind: IrExprKind::Struct { name, fields },
+                                        ..
+                                    })) = &then_stmt.kind
+                                    {
+                                        if name == "Some" {
+                                            return &fields[0].1;
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        panic!("Expected a RangeForYield `if` arm with a `Some(value)` return");
+    }
+
+    #[test]
+    fn test_lower_generator_range_for_yield() {
+        let ir = lower_source(
+            r#"
+def upto(bound: int) -> int:
+    for i in range(bound):
+        yield i
+"#,
+        )
+        .unwrap();
+        let Some(impl_decl) = ir.declarations.iter().find_map(|d| match &d.kind {
+            IrDeclKind::Impl(i) => Some(i),
+            _ => None,
+        }) else {
+            panic!("Expected a generated Iterator impl");
+        };
+        let next_method = &impl_decl.methods[0];
+        let value = range_for_yield_return_value(next_method);
+        assert!(
+            matches!(&value.kind, IrExprKind::Var { name, .. } if name == "i"),
+            "Expected the yielded value to read the per-iteration local `i`, got {value:?}"
+        );
+    }
+
+    #[test]
+    fn test_lower_generator_range_for_yield_loop_var_shadows_param() {
+        // `n` is both the function's parameter and the loop variable, a legal Python pattern
+        // since Python has no block scoping. The parameter gets promoted to a `self` field;
+        // the loop var must not be rewritten to read that frozen field too, or every yielded
+        // value would be `bound` repeated instead of `0, 1, ..., bound - 1`.
+        let ir = lower_source(
+            r#"
+def gen(n: int) -> int:
+    for n in range(n):
+        yield n
+"#,
+        )
+        .unwrap();
+        let Some(impl_decl) = ir.declarations.iter().find_map(|d| match &d.kind {
+            IrDeclKind::Impl(i) => Some(i),
+            _ => None,
+        }) else {
+            panic!("Expected a generated Iterator impl");
+        };
+        let next_method = &impl_decl.methods[0];
+        let value = range_for_yield_return_value(next_method);
+        assert!(
+            matches!(&value.kind, IrExprKind::Var { name, .. } if name == "n"),
+            "Expected the yielded value to read the per-iteration local `n`, not a `self` field \
+             frozen from the parameter of the same name; got {value:?}"
+        );
+    }
 }
@@ -0,0 +1,127 @@
+//! Eager-vs-lazy purity analysis for generator expressions.
+//!
+//! Modeled on clippy's `eager_or_lazy` pass: a generator expression (`(expr for x in iter if
+//! cond)`) only stays a lazy iterator chain if its element and filter expressions are free of
+//! observable side effects. A side effect that fires lazily — once per pull, rather than once up
+//! front — would be visible to a consumer that doesn't drain the whole iterator (e.g. an early
+//! `break`), so anything we can't prove pure forces a fall back to the eager, collecting shape
+//! that list comprehensions already use.
+//!
+//! This is deliberately conservative: an unknown function call or an unrecognized method is
+//! treated as impure, never the reverse.
+
+use crate::frontend::ast::{CallArg, CompClause, Expr, GenExp};
+
+/// Builtins known to be pure (no I/O, no mutation of anything they're handed by reference).
+///
+/// Anything not on this list — including every user-defined function — is assumed impure, since
+/// lowering has no interprocedural purity analysis to fall back on.
+const PURE_BUILTINS: &[&str] = &[
+    "abs", "min", "max", "round", "len", "str", "int", "float", "bool", "sum",
+];
+
+/// Methods known to be pure (read-only, no mutation of their receiver).
+///
+/// Anything not on this list — including every user-defined method — is assumed impure, for the
+/// same reason `PURE_BUILTINS` is an allowlist rather than a denylist of known-mutating methods:
+/// an unrecognized method must never be assumed side-effect-free.
+const PURE_METHODS: &[&str] = &[
+    // str
+    "upper",
+    "lower",
+    "title",
+    "capitalize",
+    "casefold",
+    "strip",
+    "lstrip",
+    "rstrip",
+    "replace",
+    "join",
+    "to_string",
+    "split_whitespace",
+    "split",
+    "rsplit",
+    "splitlines",
+    "partition",
+    "rpartition",
+    "contains",
+    "startswith",
+    "endswith",
+    "len",
+    "is_empty",
+    "find",
+    "rfind",
+    "index",
+    "rindex",
+    "count",
+    // dict
+    "keys",
+    "values",
+    "get",
+    "contains_key",
+    // float
+    "sqrt",
+    "abs",
+    "floor",
+    "ceil",
+    "round",
+    "sin",
+    "cos",
+    "tan",
+    "exp",
+    "ln",
+    "log2",
+    "log10",
+    "is_nan",
+    "is_infinite",
+    "is_finite",
+    "powi",
+    "powf",
+];
+
+/// Decide whether a generator expression's clauses and body can lower to a lazy iterator chain.
+pub fn is_lazy_safe(comp: &GenExp) -> bool {
+    comp.clauses.iter().all(clause_is_lazy_safe) && expr_is_lazy_safe(&comp.expr.node)
+}
+
+fn clause_is_lazy_safe(clause: &CompClause) -> bool {
+    match clause {
+        CompClause::For { iter, .. } => expr_is_lazy_safe(&iter.node),
+        CompClause::If(cond) => expr_is_lazy_safe(&cond.node),
+    }
+}
+
+fn expr_is_lazy_safe(expr: &Expr) -> bool {
+    match expr {
+        Expr::Ident(_) | Expr::Literal(_) | Expr::SelfExpr => true,
+        Expr::Binary(left, _, right) => expr_is_lazy_safe(&left.node) && expr_is_lazy_safe(&right.node),
+        Expr::Unary(_, inner) | Expr::Try(inner) | Expr::Paren(inner) => expr_is_lazy_safe(&inner.node),
+        Expr::Field(base, _) => expr_is_lazy_safe(&base.node),
+        Expr::Index(base, index) => expr_is_lazy_safe(&base.node) && expr_is_lazy_safe(&index.node),
+        Expr::Slice(base, _) => expr_is_lazy_safe(&base.node),
+        Expr::Call(function, args) => {
+            matches!(&function.node, Expr::Ident(name) if PURE_BUILTINS.contains(&name.as_str()))
+                && args.iter().all(call_arg_is_lazy_safe)
+        }
+        Expr::MethodCall(receiver, method, args) => {
+            PURE_METHODS.contains(&method.as_str())
+                && expr_is_lazy_safe(&receiver.node)
+                && args.iter().all(call_arg_is_lazy_safe)
+        }
+        Expr::Tuple(items) | Expr::List(items) | Expr::Set(items) => items.iter().all(|i| expr_is_lazy_safe(&i.node)),
+        Expr::Dict(pairs) => pairs
+            .iter()
+            .all(|(k, v)| expr_is_lazy_safe(&k.node) && expr_is_lazy_safe(&v.node)),
+        Expr::Range { start, end, .. } => expr_is_lazy_safe(&start.node) && expr_is_lazy_safe(&end.node),
+        // Calls we can't see through, mutation, async suspension, control flow with hidden
+        // statements, and nested comprehensions are all conservatively treated as impure.
+        _ => false,
+    }
+}
+
+fn call_arg_is_lazy_safe(arg: &CallArg) -> bool {
+    match arg {
+        CallArg::Positional(expr) => expr_is_lazy_safe(&expr.node),
+        CallArg::Named(_, expr) => expr_is_lazy_safe(&expr.node),
+    }
+}
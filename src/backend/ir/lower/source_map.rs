@@ -0,0 +1,43 @@
+//! Side table mapping lowered IR nodes back to their originating source spans.
+//!
+//! `TypedExpr` already carries its own `span: IrSpan` field (populated by
+//! `super::expr::lower_expr_spanned`), which is the fast path for "what span produced this
+//! specific expression". `SourceMap` complements that: it lets a span be recovered later from
+//! just an [`IrId`], which is useful for IR nodes that have no span field of their own — `Pattern`
+//! chief among them, since it's a bare enum lowered in several places (`lower_pattern`,
+//! `lower_match_arms`) with no room to carry one inline without restructuring every variant and
+//! every site that matches on it.
+
+use super::super::IrSpan;
+use std::collections::HashMap;
+
+/// Opaque identifier for a lowered IR node, used as a key into a [`SourceMap`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IrId(u32);
+
+/// Side table recording the source span each [`IrId`] was lowered from.
+#[derive(Debug, Default)]
+pub struct SourceMap {
+    spans: HashMap<IrId, IrSpan>,
+    next_id: u32,
+}
+
+impl SourceMap {
+    /// Create an empty source map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allocate a fresh `IrId` and record its span.
+    pub(super) fn record(&mut self, span: IrSpan) -> IrId {
+        let id = IrId(self.next_id);
+        self.next_id += 1;
+        self.spans.insert(id, span);
+        id
+    }
+
+    /// Look up the span an `IrId` was lowered from.
+    pub fn span_of(&self, id: IrId) -> Option<IrSpan> {
+        self.spans.get(&id).copied()
+    }
+}
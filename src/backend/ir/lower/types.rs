@@ -21,6 +21,7 @@ enum GenericBaseKind {
     List,
     Dict,
     Set,
+    Iterator,
     Option,
     Result,
     Tuple,
@@ -35,6 +36,7 @@ fn classify_generic_base(name: &str) -> GenericBaseKind {
         "List" | "list" => GenericBaseKind::List,
         "Dict" | "dict" | "HashMap" => GenericBaseKind::Dict,
         "Set" | "set" => GenericBaseKind::Set,
+        "Iterator" | "iterator" | "generator" => GenericBaseKind::Iterator,
         "Option" | "option" => GenericBaseKind::Option,
         "Result" | "result" => GenericBaseKind::Result,
         "Tuple" | "tuple" => GenericBaseKind::Tuple,
@@ -141,6 +143,11 @@ impl AstLowering {
                         .map(|t| self.lower_resolved_type(t))
                         .unwrap_or(IrType::Unknown),
                 )),
+                GenericBaseKind::Iterator => IrType::Iterator(Box::new(
+                    args.first()
+                        .map(|t| self.lower_resolved_type(t))
+                        .unwrap_or(IrType::Unknown),
+                )),
                 GenericBaseKind::Option => IrType::Option(Box::new(
                     args.first()
                         .map(|t| self.lower_resolved_type(t))
@@ -171,6 +178,10 @@ impl AstLowering {
             ResolvedType::Tuple(items) => IrType::Tuple(items.iter().map(|t| self.lower_resolved_type(t)).collect()),
             ResolvedType::TypeVar(name) => IrType::Generic(name.clone()),
             ResolvedType::SelfType => IrType::Unknown,
+            ResolvedType::Optional(inner) => IrType::Option(Box::new(self.lower_resolved_type(inner))),
+            // True unions need a generated enum/Either that doesn't exist yet; fall back to
+            // Unknown until codegen can emit one.
+            ResolvedType::Union(_) => IrType::Unknown,
             ResolvedType::Unknown => IrType::Unknown,
         }
     }
@@ -233,6 +244,12 @@ impl AstLowering {
                             .map(|p| self.lower_type(&p.node))
                             .unwrap_or(IrType::Unknown),
                     )),
+                    GenericBaseKind::Iterator => IrType::Iterator(Box::new(
+                        params
+                            .first()
+                            .map(|p| self.lower_type(&p.node))
+                            .unwrap_or(IrType::Unknown),
+                    )),
                     GenericBaseKind::Option => IrType::Option(Box::new(
                         params
                             .first()
@@ -24,29 +24,41 @@ pub mod conversions;
 pub mod prelude;
 
 pub mod codegen;
+pub mod constness;
 pub mod decl;
+pub mod diagnostics;
 pub mod emit;
 pub mod emit_service;
 pub mod expr;
 pub mod facade;
+pub mod interner;
 pub mod lower;
+pub mod rename;
+pub mod reorder;
 pub mod scanners;
 pub mod stmt;
 pub mod types;
+pub mod visit;
 
 pub use codegen::{GenerationError, IrCodegen};
-pub use decl::{FunctionParam, IrDecl, IrDeclKind, IrFunction, IrStruct};
+pub use constness::infer_constness;
+pub use decl::{Constness, FunctionParam, IrDecl, IrDeclKind, IrFunction, IrStruct};
+pub use diagnostics::{Applicability, Diagnostic, Severity, Suggestion};
 pub use emit::{EmitError, IrEmitter};
 pub use emit_service::EmitService;
 pub use expr::{BuiltinFn, IrExpr, IrExprKind, MethodKind, TypedExpr};
 pub use facade::CodegenFacade;
+pub use interner::{Interner, Symbol};
 pub use lower::{AstLowering, LoweringError, LoweringErrors};
+pub use rename::RenameRule;
+pub use reorder::postprocess;
 pub use scanners::{
     check_for_this_import, collect_routes, collect_rust_crates, detect_async_usage, detect_list_helpers_usage,
     detect_serde_usage, detect_web_usage,
 };
 pub use stmt::{IrStmt, IrStmtKind};
 pub use types::{IrType, Mutability, Ownership};
+pub use visit::{fold_program, walk_program, IrFolder, IrVisitor};
 
 use crate::frontend::ast::Span;
 use std::collections::HashMap;
@@ -56,13 +68,20 @@ use std::collections::HashMap;
 pub struct FunctionSignature {
     pub params: Vec<FunctionParam>,
     pub return_type: IrType,
+    /// Whether the function this signature describes is eligible for `const fn`; see
+    /// [`constness::infer_constness`].
+    pub constness: Constness,
 }
 
 /// Registry of all function signatures in the program
+///
+/// Keyed on [`Symbol`] rather than `String`: names are interned once (via the program's shared
+/// [`Interner`]) so registration, lookup, and merging are integer compares instead of owned-string
+/// clones and hashes.
 #[derive(Debug, Clone, Default)]
 pub struct FunctionRegistry {
-    /// Map from function name to its signature
-    signatures: HashMap<String, FunctionSignature>,
+    /// Map from interned function name to its signature
+    signatures: HashMap<Symbol, FunctionSignature>,
 }
 
 impl FunctionRegistry {
@@ -70,20 +89,48 @@ impl FunctionRegistry {
         Self::default()
     }
 
-    /// Register a function signature
-    pub fn register(&mut self, name: String, params: Vec<FunctionParam>, return_type: IrType) {
-        self.signatures.insert(name, FunctionSignature { params, return_type });
+    /// Register a function signature, interning `name` against `interner`
+    pub fn register(&mut self, interner: &mut Interner, name: &str, params: Vec<FunctionParam>, return_type: IrType) {
+        let sym = interner.intern(name);
+        self.signatures.insert(
+            sym,
+            FunctionSignature {
+                params,
+                return_type,
+                constness: Constness::NotConst,
+            },
+        );
     }
 
-    /// Look up a function signature by name
-    pub fn get(&self, name: &str) -> Option<&FunctionSignature> {
-        self.signatures.get(name)
+    /// Look up a function signature by name, resolving `name` against `interner`
+    pub fn get(&self, interner: &Interner, name: &str) -> Option<&FunctionSignature> {
+        let sym = interner.get(name)?;
+        self.signatures.get(&sym)
     }
 
-    /// Merge another registry into this one
-    pub fn merge(&mut self, other: &FunctionRegistry) {
-        for (name, sig) in &other.signatures {
-            self.signatures.insert(name.clone(), sig.clone());
+    /// Mark the signature already registered under `sym` as `const fn`-eligible.
+    ///
+    /// Called by [`constness::infer_constness`] once const-eligibility has been computed; a no-op
+    /// if `sym` isn't registered (e.g. it resolved to a name intern()'d for another purpose).
+    pub fn mark_const(&mut self, sym: Symbol) {
+        if let Some(sig) = self.signatures.get_mut(&sym) {
+            sig.constness = Constness::Const;
+        }
+    }
+
+    /// Merge another registry into this one.
+    ///
+    /// `other` was built against `other_interner`, which may not be the same `Interner` instance
+    /// as `interner` (e.g. a dependency module lowered with its own `AstLowering`). Each entry is
+    /// resolved back to its string via `other_interner` and re-interned against `interner`, so
+    /// merging is safe even across interners; when both registries share the same interner,
+    /// `intern`'s idempotence means every entry resolves to its existing `Symbol` and nothing new
+    /// is allocated.
+    pub fn merge(&mut self, other: &FunctionRegistry, other_interner: &Interner, interner: &mut Interner) {
+        for (&sym, sig) in &other.signatures {
+            let name = other_interner.resolve(sym);
+            let sym = interner.intern(name);
+            self.signatures.insert(sym, sig.clone());
         }
     }
 }
@@ -97,6 +144,8 @@ pub struct IrProgram {
     pub entry_point: Option<String>,
     /// Function signature registry for call-site type checking
     pub function_registry: FunctionRegistry,
+    /// Interner backing `function_registry`'s keys (and, over time, other IR identifiers)
+    pub interner: Interner,
 }
 
 impl IrProgram {
@@ -105,6 +154,7 @@ impl IrProgram {
             declarations: Vec::new(),
             entry_point: None,
             function_registry: FunctionRegistry::new(),
+            interner: Interner::new(),
         }
     }
 }
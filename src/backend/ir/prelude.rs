@@ -17,6 +17,9 @@ pub use super::lower::{AstLowering, LoweringError};
 // Program representation (defined in mod.rs)
 pub use super::{FunctionRegistry, FunctionSignature, IrProgram};
 
+// Interning
+pub use super::interner::{Interner, Symbol};
+
 // Scanners
 pub use super::scanners::{
     check_for_this_import, collect_routes, collect_rust_crates, detect_async_usage,
@@ -0,0 +1,91 @@
+//! Identifier case-conversion rules for `@serde(rename_all = "...")`.
+//!
+//! Mirrors the casing rules serde itself accepts for `#[serde(rename_all = "...")]`, so that an
+//! unknown rule string can be rejected at lowering time instead of silently reaching `rustc` as an
+//! opaque string literal.
+
+/// A known `rename_all` casing rule, computed from a canonical `snake_case` identifier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenameRule {
+    PascalCase,
+    CamelCase,
+    ScreamingSnakeCase,
+    KebabCase,
+    ScreamingKebabCase,
+}
+
+impl RenameRule {
+    /// Parse the serde-recognized spelling of a `rename_all` rule (e.g. `"camelCase"`).
+    ///
+    /// Returns `None` for any string that isn't one of the rules implemented here.
+    pub fn parse(rule: &str) -> Option<Self> {
+        match rule {
+            "PascalCase" => Some(Self::PascalCase),
+            "camelCase" => Some(Self::CamelCase),
+            "SCREAMING_SNAKE_CASE" => Some(Self::ScreamingSnakeCase),
+            "kebab-case" => Some(Self::KebabCase),
+            "SCREAMING-KEBAB-CASE" => Some(Self::ScreamingKebabCase),
+            _ => None,
+        }
+    }
+
+    /// The serde-recognized spelling for this rule, as would appear in
+    /// `#[serde(rename_all = "...")]`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::PascalCase => "PascalCase",
+            Self::CamelCase => "camelCase",
+            Self::ScreamingSnakeCase => "SCREAMING_SNAKE_CASE",
+            Self::KebabCase => "kebab-case",
+            Self::ScreamingKebabCase => "SCREAMING-KEBAB-CASE",
+        }
+    }
+
+    /// Apply this rule to a canonical `snake_case` identifier.
+    ///
+    /// Doubled underscores (and thus empty segments) and leading/trailing underscores are
+    /// preserved verbatim, so applying a rule and then re-deriving a Rust identifier via
+    /// `to_rust_ident` round-trips stably.
+    pub fn apply(&self, ident: &str) -> String {
+        match self {
+            Self::ScreamingSnakeCase => ident.to_ascii_uppercase(),
+            Self::KebabCase => ident.replace('_', "-"),
+            Self::ScreamingKebabCase => ident.to_ascii_uppercase().replace('_', "-"),
+            Self::PascalCase => Self::camel_words(ident, true),
+            Self::CamelCase => Self::camel_words(ident, false),
+        }
+    }
+
+    /// Split `ident` on `_`, uppercasing each word's first character (lowercasing the very first
+    /// word's first character unless `capitalize_first`), and concatenate.
+    ///
+    /// Empty segments (from doubled underscores) and segments from leading/trailing underscores
+    /// are pushed through unchanged, so the underscores they represent are preserved in the
+    /// output.
+    fn camel_words(ident: &str, capitalize_first: bool) -> String {
+        let mut out = String::with_capacity(ident.len());
+        let mut seen_word = false;
+        let mut prev_empty = false;
+        for (i, word) in ident.split('_').enumerate() {
+            // The `_` before a segment is dropped only between two words; next to an empty
+            // segment it is kept, so a run of underscores comes through at its full length.
+            if i > 0 && (word.is_empty() || prev_empty) {
+                out.push('_');
+            }
+            prev_empty = word.is_empty();
+            if word.is_empty() {
+                continue;
+            }
+            let mut chars = word.chars();
+            let Some(first) = chars.next() else { continue };
+            if !seen_word && !capitalize_first {
+                out.extend(first.to_lowercase());
+            } else {
+                out.extend(first.to_uppercase());
+            }
+            out.push_str(chars.as_str());
+            seen_word = true;
+        }
+        out
+    }
+}
@@ -0,0 +1,201 @@
+//! Deterministic declaration post-processing.
+//!
+//! An optional stage run on [`IrProgram::declarations`] before [`super::IrCodegen`] emits Rust,
+//! modeled on bindgen's `merge_extern_blocks`/`sort_semantically` passes:
+//!
+//! 1. **merge** ([`merge_declarations`]) — coalesces `Import` declarations that target the same
+//!    path/alias (as can happen when several `from rust::crate import ...` statements surface the
+//!    same crate via `collect_rust_crates`/`check_for_this_import`) into one, deduplicating items.
+//! 2. **sort** ([`sort_declarations`]) — reorders the (merged) declarations into a stable,
+//!    human-friendly layout: imports, then type aliases and constants, then structs, enums,
+//!    traits, and impls, then functions — with the program's `entry_point` function placed last.
+//!
+//! Both passes are deterministic functions of their input, and the sort is a stable sort keyed on
+//! `(category, name)`, so running [`postprocess`] twice in a row is a no-op the second time:
+//! regenerating unchanged Incan source yields byte-identical Rust output.
+
+use std::collections::HashMap;
+
+use super::decl::{IrDecl, IrDeclKind, IrImportItem};
+use super::IrProgram;
+
+/// Run both the merge and sort passes on `program.declarations`.
+pub fn postprocess(program: &mut IrProgram) {
+    let declarations = std::mem::take(&mut program.declarations);
+    let declarations = merge_declarations(declarations);
+    program.declarations = sort_declarations(declarations, program.entry_point.as_deref());
+}
+
+/// Coalesce `Import` declarations that share a `(path, alias)` target into one, merging their
+/// `items` lists (deduplicated by name) and keeping the position of the first occurrence.
+/// Non-`Import` declarations pass through unchanged and keep their original relative order.
+pub fn merge_declarations(declarations: Vec<IrDecl>) -> Vec<IrDecl> {
+    let mut merged: Vec<IrDecl> = Vec::with_capacity(declarations.len());
+    let mut index_of: HashMap<(Vec<String>, Option<String>), usize> = HashMap::new();
+
+    for decl in declarations {
+        let IrDeclKind::Import { path, alias, items } = &decl.kind else {
+            merged.push(decl);
+            continue;
+        };
+        let key = (path.clone(), alias.clone());
+        if let Some(&i) = index_of.get(&key) {
+            let IrDeclKind::Import { items: existing, .. } = &mut merged[i].kind else {
+                unreachable!("index_of only maps to Import declarations");
+            };
+            for item in items {
+                if !existing.iter().any(|e: &IrImportItem| e.name == item.name) {
+                    existing.push(item.clone());
+                }
+            }
+        } else {
+            index_of.insert(key, merged.len());
+            merged.push(decl);
+        }
+    }
+
+    merged
+}
+
+/// Reorder `declarations` into a stable, human-friendly layout (see module docs for the order),
+/// placing the function named `entry_point` (if any) last. Ties within a category keep their
+/// original relative order ([`slice::sort_by_key`] is a stable sort), which is what makes repeated
+/// application of this pass idempotent.
+pub fn sort_declarations(mut declarations: Vec<IrDecl>, entry_point: Option<&str>) -> Vec<IrDecl> {
+    declarations.sort_by_key(|decl| decl_sort_key(decl, entry_point));
+    declarations
+}
+
+/// `(category, name)` total ordering key for one declaration.
+///
+/// Categories: imports, type aliases, constants, structs, enums, traits, impls, functions — with
+/// the entry point function bumped into its own trailing category.
+fn decl_sort_key(decl: &IrDecl, entry_point: Option<&str>) -> (u8, String) {
+    match &decl.kind {
+        IrDeclKind::Import { path, alias, .. } => (0, alias.clone().unwrap_or_else(|| path.join("::"))),
+        IrDeclKind::TypeAlias { name, .. } => (1, name.clone()),
+        IrDeclKind::Const { name, .. } => (2, name.clone()),
+        IrDeclKind::Struct(s) => (3, s.name.clone()),
+        IrDeclKind::Enum(e) => (4, e.name.clone()),
+        IrDeclKind::Trait(t) => (5, t.name.clone()),
+        IrDeclKind::Impl(imp) => (6, imp.target_type.clone()),
+        IrDeclKind::Function(f) if Some(f.name.as_str()) == entry_point => (8, f.name.clone()),
+        IrDeclKind::Function(f) => (7, f.name.clone()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::ir::decl::{Constness, IrFunction, IrStruct, Visibility};
+    use crate::backend::ir::types::IrType;
+
+    fn function(name: &str) -> IrDecl {
+        IrDecl::new(IrDeclKind::Function(IrFunction {
+            name: name.to_string(),
+            params: vec![],
+            return_type: IrType::Unit,
+            body: vec![],
+            is_async: false,
+            visibility: Visibility::Public,
+            type_params: vec![],
+            constness: Constness::NotConst,
+        }))
+    }
+
+    fn struct_decl(name: &str) -> IrDecl {
+        IrDecl::new(IrDeclKind::Struct(IrStruct {
+            name: name.to_string(),
+            fields: vec![],
+            derives: vec![],
+            visibility: Visibility::Public,
+            type_params: vec![],
+            serde_rename_all: None,
+            formats: vec![],
+        }))
+    }
+
+    fn import(path: &[&str], items: &[&str]) -> IrDecl {
+        IrDecl::new(IrDeclKind::Import {
+            path: path.iter().map(|s| s.to_string()).collect(),
+            alias: None,
+            items: items
+                .iter()
+                .map(|name| IrImportItem {
+                    name: name.to_string(),
+                    alias: None,
+                })
+                .collect(),
+        })
+    }
+
+    #[test]
+    fn sort_puts_structs_before_functions_and_entry_point_last() {
+        let declarations = vec![function("main"), function("helper"), struct_decl("Point")];
+        let sorted = sort_declarations(declarations, Some("main"));
+
+        let names: Vec<&str> = sorted
+            .iter()
+            .map(|d| match &d.kind {
+                IrDeclKind::Struct(s) => s.name.as_str(),
+                IrDeclKind::Function(f) => f.name.as_str(),
+                _ => unreachable!(),
+            })
+            .collect();
+        assert_eq!(names, vec!["Point", "helper", "main"]);
+    }
+
+    #[test]
+    fn sort_is_idempotent() {
+        let declarations = vec![function("main"), struct_decl("Zeta"), struct_decl("Alpha")];
+        let once = sort_declarations(declarations, Some("main"));
+        let twice = sort_declarations(once.clone(), Some("main"));
+
+        let names_of = |decls: &[IrDecl]| -> Vec<String> {
+            decls
+                .iter()
+                .map(|d| match &d.kind {
+                    IrDeclKind::Struct(s) => s.name.clone(),
+                    IrDeclKind::Function(f) => f.name.clone(),
+                    _ => unreachable!(),
+                })
+                .collect()
+        };
+        assert_eq!(names_of(&once), names_of(&twice));
+    }
+
+    #[test]
+    fn merge_combines_duplicate_imports_and_dedupes_items() {
+        let declarations = vec![
+            import(&["rust", "std"], &["fs"]),
+            struct_decl("Point"),
+            import(&["rust", "std"], &["fs", "io"]),
+        ];
+        let merged = merge_declarations(declarations);
+
+        assert_eq!(merged.len(), 2, "duplicate import should be folded into the first");
+        let IrDeclKind::Import { items, .. } = &merged[0].kind else {
+            unreachable!()
+        };
+        let names: Vec<&str> = items.iter().map(|i| i.name.as_str()).collect();
+        assert_eq!(names, vec!["fs", "io"]);
+    }
+
+    #[test]
+    fn postprocess_merges_then_sorts() {
+        let mut program = IrProgram::new();
+        program.entry_point = Some("main".to_string());
+        program.declarations = vec![
+            function("main"),
+            import(&["rust", "std"], &["fs"]),
+            struct_decl("Point"),
+            import(&["rust", "std"], &["io"]),
+        ];
+
+        postprocess(&mut program);
+
+        assert_eq!(program.declarations.len(), 3, "the two imports should have merged");
+        let last = program.declarations.last().unwrap();
+        assert!(matches!(&last.kind, IrDeclKind::Function(f) if f.name == "main"));
+    }
+}
@@ -6,7 +6,7 @@
 use std::collections::HashSet;
 
 use crate::frontend::ast::{self, Declaration, Expr, Literal, Program, Spanned, Statement};
-use crate::frontend::ast::{CallArg, DecoratorArg, FStringPart, ImportKind};
+use crate::frontend::ast::{CallArg, CompClause, DecoratorArg, FStringPart, ImportKind};
 
 /// Detect whether serde derives are used anywhere in the program
 pub fn detect_serde_usage(program: &Program) -> bool {
@@ -143,17 +143,12 @@ fn expr_uses_async(expr: &Expr) -> bool {
         Expr::FString(parts) => parts
             .iter()
             .any(|part| matches!(part, FStringPart::Expr(e) if expr_uses_async(&e.node))),
-        Expr::ListComp(comp) => {
-            expr_uses_async(&comp.expr.node)
-                || expr_uses_async(&comp.iter.node)
-                || comp.filter.as_ref().is_some_and(|c| expr_uses_async(&c.node))
-        }
+        Expr::ListComp(comp) => expr_uses_async(&comp.expr.node) || comp_clauses_use_async(&comp.clauses),
         Expr::DictComp(comp) => {
-            expr_uses_async(&comp.key.node)
-                || expr_uses_async(&comp.value.node)
-                || expr_uses_async(&comp.iter.node)
-                || comp.filter.as_ref().is_some_and(|c| expr_uses_async(&c.node))
+            expr_uses_async(&comp.key.node) || expr_uses_async(&comp.value.node) || comp_clauses_use_async(&comp.clauses)
         }
+        Expr::SetComp(comp) => expr_uses_async(&comp.expr.node) || comp_clauses_use_async(&comp.clauses),
+        Expr::GenExp(comp) => expr_uses_async(&comp.expr.node) || comp_clauses_use_async(&comp.clauses),
         Expr::Constructor(_, args) => args.iter().any(call_arg_uses_async),
         Expr::Try(inner) => expr_uses_async(&inner.node),
         Expr::Paren(inner) => expr_uses_async(&inner.node),
@@ -168,6 +163,13 @@ fn call_arg_uses_async(arg: &CallArg) -> bool {
     }
 }
 
+fn comp_clauses_use_async(clauses: &[CompClause]) -> bool {
+    clauses.iter().any(|clause| match clause {
+        CompClause::For { iter, .. } => expr_uses_async(&iter.node),
+        CompClause::If(cond) => expr_uses_async(&cond.node),
+    })
+}
+
 fn match_body_uses_async(body: &ast::MatchBody) -> bool {
     match body {
         ast::MatchBody::Expr(expr) => expr_uses_async(&expr.node),
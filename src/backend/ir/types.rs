@@ -48,6 +48,12 @@ pub enum IrType {
     Set(Box<IrType>),
     Tuple(Vec<IrType>),
 
+    /// Lazy iterator produced by a generator expression, emitted as `impl Iterator<Item = T>`.
+    ///
+    /// Only valid where Rust allows `impl Trait` (function/closure return position, or an
+    /// un-annotated `let` binding) — never as a struct field or explicit variable type.
+    Iterator(Box<IrType>),
+
     // Option and Result
     Option(Box<IrType>),
     Result(Box<IrType>, Box<IrType>),
@@ -124,6 +130,7 @@ impl IrType {
             IrType::List(elem) => format!("Vec<{}>", elem.rust_name()),
             IrType::Dict(k, v) => format!("std::collections::HashMap<{}, {}>", k.rust_name(), v.rust_name()),
             IrType::Set(elem) => format!("std::collections::HashSet<{}>", elem.rust_name()),
+            IrType::Iterator(elem) => format!("impl Iterator<Item = {}>", elem.rust_name()),
             IrType::Tuple(elems) => {
                 let inner: Vec<_> = elems.iter().map(|e| e.rust_name()).collect();
                 format!("({})", inner.join(", "))
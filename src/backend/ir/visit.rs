@@ -0,0 +1,1011 @@
+//! Visitor and folder framework for the IR tree.
+//!
+//! Mirrors stable_mir's `Visitor`/`MirFolder` split: [`IrVisitor`] walks an IR tree by shared
+//! reference for read-only analyses (validation, usage collection), while [`IrFolder`] consumes
+//! and rebuilds the tree for transforms (const-folding, dead-code elimination). Both traits expose
+//! one method per node family — `{visit,fold}_decl`, `{visit,fold}_stmt`, `{visit,fold}_expr`,
+//! `{visit,fold}_type` — whose default bodies call a `super_*` free function that drives the
+//! structural walk and calls back into the trait method for every child node. Overriding a single
+//! method therefore only needs to describe what happens *at* that node kind; recursing into
+//! children not of interest happens for free by falling through to the default.
+//!
+//! [`walk_program`] and [`fold_program`] are the entry points: they run a visitor/folder across
+//! every declaration in an [`IrProgram`], in order.
+
+use super::decl::{
+    EnumVariant, IrDecl, IrDeclKind, IrEnum, IrFunction, IrImpl, IrStruct, IrTrait, StructField, VariantFields,
+};
+use super::expr::{CompClause, FormatPart, IrExpr, IrExprKind, MatchArm, Pattern};
+use super::stmt::{AssignTarget, IrStmt, IrStmtKind};
+use super::{IrProgram, IrType};
+
+// ============================================================================
+// IrVisitor: read-only traversal
+// ============================================================================
+
+/// Read-only traversal over an IR tree.
+///
+/// Each method's default body recurses into the node's children via the matching `super_visit_*`
+/// function; override a method to act on that node kind while keeping the structural walk.
+pub trait IrVisitor {
+    fn visit_decl(&mut self, decl: &IrDecl) {
+        super_visit_decl(self, decl);
+    }
+
+    fn visit_stmt(&mut self, stmt: &IrStmt) {
+        super_visit_stmt(self, stmt);
+    }
+
+    fn visit_expr(&mut self, expr: &IrExpr) {
+        super_visit_expr(self, expr);
+    }
+
+    fn visit_type(&mut self, ty: &IrType) {
+        super_visit_type(self, ty);
+    }
+}
+
+/// Run `visitor` over every declaration in `program`, in order.
+pub fn walk_program<V: IrVisitor + ?Sized>(visitor: &mut V, program: &IrProgram) {
+    for decl in &program.declarations {
+        visitor.visit_decl(decl);
+    }
+}
+
+/// Structural walk for [`IrVisitor::visit_decl`]'s default body.
+pub fn super_visit_decl<V: IrVisitor + ?Sized>(visitor: &mut V, decl: &IrDecl) {
+    match &decl.kind {
+        IrDeclKind::Function(f) => visit_function(visitor, f),
+        IrDeclKind::Struct(s) => visit_struct(visitor, s),
+        IrDeclKind::Enum(e) => visit_enum(visitor, e),
+        IrDeclKind::Trait(t) => visit_trait(visitor, t),
+        IrDeclKind::TypeAlias { ty, .. } => visitor.visit_type(ty),
+        IrDeclKind::Const { ty, value, .. } => {
+            visitor.visit_type(ty);
+            visitor.visit_expr(value);
+        }
+        IrDeclKind::Import { .. } => {}
+        IrDeclKind::Impl(imp) => visit_impl(visitor, imp),
+    }
+}
+
+fn visit_function<V: IrVisitor + ?Sized>(visitor: &mut V, f: &IrFunction) {
+    for param in &f.params {
+        visitor.visit_type(&param.ty);
+    }
+    visitor.visit_type(&f.return_type);
+    for stmt in &f.body {
+        visitor.visit_stmt(stmt);
+    }
+}
+
+fn visit_struct<V: IrVisitor + ?Sized>(visitor: &mut V, s: &IrStruct) {
+    for field in &s.fields {
+        visit_struct_field(visitor, field);
+    }
+}
+
+fn visit_struct_field<V: IrVisitor + ?Sized>(visitor: &mut V, field: &StructField) {
+    visitor.visit_type(&field.ty);
+}
+
+fn visit_enum<V: IrVisitor + ?Sized>(visitor: &mut V, e: &IrEnum) {
+    for variant in &e.variants {
+        visit_variant(visitor, variant);
+    }
+}
+
+fn visit_variant<V: IrVisitor + ?Sized>(visitor: &mut V, variant: &EnumVariant) {
+    match &variant.fields {
+        VariantFields::Unit => {}
+        VariantFields::Tuple(tys) => {
+            for ty in tys {
+                visitor.visit_type(ty);
+            }
+        }
+        VariantFields::Struct(fields) => {
+            for field in fields {
+                visit_struct_field(visitor, field);
+            }
+        }
+    }
+}
+
+fn visit_trait<V: IrVisitor + ?Sized>(visitor: &mut V, t: &IrTrait) {
+    for method in &t.methods {
+        visit_function(visitor, method);
+    }
+}
+
+fn visit_impl<V: IrVisitor + ?Sized>(visitor: &mut V, imp: &IrImpl) {
+    for method in &imp.methods {
+        visit_function(visitor, method);
+    }
+}
+
+fn visit_pattern<V: IrVisitor + ?Sized>(visitor: &mut V, pattern: &Pattern) {
+    match pattern {
+        Pattern::Wildcard | Pattern::Var(_) => {}
+        Pattern::Literal(e) => visitor.visit_expr(e),
+        Pattern::Tuple(patterns) | Pattern::Or(patterns) => {
+            for p in patterns {
+                visit_pattern(visitor, p);
+            }
+        }
+        Pattern::Struct { fields, .. } => {
+            for (_, p) in fields {
+                visit_pattern(visitor, p);
+            }
+        }
+        Pattern::Enum { fields, .. } => {
+            for p in fields {
+                visit_pattern(visitor, p);
+            }
+        }
+        Pattern::Slice { prefix, suffix, .. } => {
+            for p in prefix.iter().chain(suffix.iter()) {
+                visit_pattern(visitor, p);
+            }
+        }
+        Pattern::As(inner, _) => visit_pattern(visitor, inner),
+    }
+}
+
+fn visit_match_arm<V: IrVisitor + ?Sized>(visitor: &mut V, arm: &MatchArm) {
+    visit_pattern(visitor, &arm.pattern);
+    if let Some(guard) = &arm.guard {
+        visitor.visit_expr(guard);
+    }
+    visitor.visit_expr(&arm.body);
+}
+
+fn visit_comp_clauses<V: IrVisitor + ?Sized>(visitor: &mut V, clauses: &[CompClause]) {
+    for clause in clauses {
+        match clause {
+            CompClause::For { iterable, .. } => visitor.visit_expr(iterable),
+            CompClause::If(cond) => visitor.visit_expr(cond),
+        }
+    }
+}
+
+fn visit_assign_target<V: IrVisitor + ?Sized>(visitor: &mut V, target: &AssignTarget) {
+    match target {
+        AssignTarget::Var(_) => {}
+        AssignTarget::Field { object, .. } => visitor.visit_expr(object),
+        AssignTarget::Index { object, index } => {
+            visitor.visit_expr(object);
+            visitor.visit_expr(index);
+        }
+    }
+}
+
+/// Structural walk for [`IrVisitor::visit_stmt`]'s default body.
+pub fn super_visit_stmt<V: IrVisitor + ?Sized>(visitor: &mut V, stmt: &IrStmt) {
+    match &stmt.kind {
+        IrStmtKind::Expr(e) => visitor.visit_expr(e),
+        IrStmtKind::Let { ty, value, .. } => {
+            visitor.visit_type(ty);
+            visitor.visit_expr(value);
+        }
+        IrStmtKind::Assign { target, value } => {
+            visit_assign_target(visitor, target);
+            visitor.visit_expr(value);
+        }
+        IrStmtKind::CompoundAssign { target, value, .. } => {
+            visit_assign_target(visitor, target);
+            visitor.visit_expr(value);
+        }
+        IrStmtKind::Return(value) => {
+            if let Some(e) = value {
+                visitor.visit_expr(e);
+            }
+        }
+        IrStmtKind::Break(_) | IrStmtKind::Continue(_) => {}
+        IrStmtKind::While { condition, body, .. } => {
+            visitor.visit_expr(condition);
+            for s in body {
+                visitor.visit_stmt(s);
+            }
+        }
+        IrStmtKind::For {
+            pattern,
+            iterable,
+            body,
+            ..
+        } => {
+            visit_pattern(visitor, pattern);
+            visitor.visit_expr(iterable);
+            for s in body {
+                visitor.visit_stmt(s);
+            }
+        }
+        IrStmtKind::Loop { body, .. } => {
+            for s in body {
+                visitor.visit_stmt(s);
+            }
+        }
+        IrStmtKind::If {
+            condition,
+            then_branch,
+            else_branch,
+        } => {
+            visitor.visit_expr(condition);
+            for s in then_branch {
+                visitor.visit_stmt(s);
+            }
+            if let Some(else_branch) = else_branch {
+                for s in else_branch {
+                    visitor.visit_stmt(s);
+                }
+            }
+        }
+        IrStmtKind::Match { scrutinee, arms } => {
+            visitor.visit_expr(scrutinee);
+            for arm in arms {
+                visit_match_arm(visitor, arm);
+            }
+        }
+        IrStmtKind::Block(stmts) => {
+            for s in stmts {
+                visitor.visit_stmt(s);
+            }
+        }
+    }
+}
+
+/// Structural walk for [`IrVisitor::visit_expr`]'s default body.
+pub fn super_visit_expr<V: IrVisitor + ?Sized>(visitor: &mut V, expr: &IrExpr) {
+    visitor.visit_type(&expr.ty);
+    match &expr.kind {
+        IrExprKind::Unit
+        | IrExprKind::None
+        | IrExprKind::Bool(_)
+        | IrExprKind::Int(_)
+        | IrExprKind::Float(_)
+        | IrExprKind::String(_)
+        | IrExprKind::Bytes(_)
+        | IrExprKind::Var { .. }
+        | IrExprKind::Literal(_)
+        | IrExprKind::FieldsList(_)
+        | IrExprKind::SerdeToJson
+        | IrExprKind::SerdeFromJson(_) => {}
+        IrExprKind::BinOp { left, right, .. } => {
+            visitor.visit_expr(left);
+            visitor.visit_expr(right);
+        }
+        IrExprKind::UnaryOp { operand, .. } => visitor.visit_expr(operand),
+        IrExprKind::Call { func, args } => {
+            visitor.visit_expr(func);
+            for a in args {
+                visitor.visit_expr(a);
+            }
+        }
+        IrExprKind::BuiltinCall { args, .. } => {
+            for a in args {
+                visitor.visit_expr(a);
+            }
+        }
+        IrExprKind::MethodCall { receiver, args, .. } => {
+            visitor.visit_expr(receiver);
+            for a in args {
+                visitor.visit_expr(a);
+            }
+        }
+        IrExprKind::KnownMethodCall { receiver, args, .. } => {
+            visitor.visit_expr(receiver);
+            for a in args {
+                visitor.visit_expr(a);
+            }
+        }
+        IrExprKind::Field { object, .. } => visitor.visit_expr(object),
+        IrExprKind::Index { object, index } => {
+            visitor.visit_expr(object);
+            visitor.visit_expr(index);
+        }
+        IrExprKind::TupleIndex { object, .. } => visitor.visit_expr(object),
+        IrExprKind::Slice { target, start, end, step } => {
+            visitor.visit_expr(target);
+            for e in [start, end, step].into_iter().flatten() {
+                visitor.visit_expr(e);
+            }
+        }
+        IrExprKind::ListComp { element, clauses } => {
+            visit_comp_clauses(visitor, clauses);
+            visitor.visit_expr(element);
+        }
+        IrExprKind::DictComp { key, value, clauses } => {
+            visit_comp_clauses(visitor, clauses);
+            visitor.visit_expr(key);
+            visitor.visit_expr(value);
+        }
+        IrExprKind::SetComp { element, clauses } => {
+            visit_comp_clauses(visitor, clauses);
+            visitor.visit_expr(element);
+        }
+        IrExprKind::GenExp { element, clauses, .. } => {
+            visit_comp_clauses(visitor, clauses);
+            visitor.visit_expr(element);
+        }
+        IrExprKind::List(elems) | IrExprKind::Set(elems) | IrExprKind::Tuple(elems) => {
+            for e in elems {
+                visitor.visit_expr(e);
+            }
+        }
+        IrExprKind::Dict(entries) => {
+            for (k, v) in entries {
+                visitor.visit_expr(k);
+                visitor.visit_expr(v);
+            }
+        }
+        IrExprKind::Struct { fields, .. } => {
+            for (_, e) in fields {
+                visitor.visit_expr(e);
+            }
+        }
+        IrExprKind::If {
+            condition,
+            then_branch,
+            else_branch,
+        } => {
+            visitor.visit_expr(condition);
+            visitor.visit_expr(then_branch);
+            if let Some(else_branch) = else_branch {
+                visitor.visit_expr(else_branch);
+            }
+        }
+        IrExprKind::Match { scrutinee, arms } => {
+            visitor.visit_expr(scrutinee);
+            for arm in arms {
+                visit_match_arm(visitor, arm);
+            }
+        }
+        IrExprKind::Closure { params, body, .. } => {
+            for (_, ty) in params {
+                visitor.visit_type(ty);
+            }
+            visitor.visit_expr(body);
+        }
+        IrExprKind::Block { stmts, value } => {
+            for s in stmts {
+                visitor.visit_stmt(s);
+            }
+            if let Some(value) = value {
+                visitor.visit_expr(value);
+            }
+        }
+        IrExprKind::Await(e) | IrExprKind::Try(e) => visitor.visit_expr(e),
+        IrExprKind::Range { start, end, .. } => {
+            for e in [start, end].into_iter().flatten() {
+                visitor.visit_expr(e);
+            }
+        }
+        IrExprKind::Cast { expr, to_type } => {
+            visitor.visit_expr(expr);
+            visitor.visit_type(to_type);
+        }
+        IrExprKind::Format { parts } => {
+            for part in parts {
+                if let FormatPart::Expr(e) = part {
+                    visitor.visit_expr(e);
+                }
+            }
+        }
+    }
+}
+
+/// Structural walk for [`IrVisitor::visit_type`]'s default body.
+pub fn super_visit_type<V: IrVisitor + ?Sized>(visitor: &mut V, ty: &IrType) {
+    match ty {
+        IrType::Unit
+        | IrType::Bool
+        | IrType::Int
+        | IrType::Float
+        | IrType::String
+        | IrType::StaticStr
+        | IrType::StaticBytes
+        | IrType::StrRef
+        | IrType::Struct(_)
+        | IrType::Enum(_)
+        | IrType::Trait(_)
+        | IrType::Generic(_)
+        | IrType::SelfType
+        | IrType::Unknown => {}
+        IrType::List(elem) | IrType::Set(elem) | IrType::Ref(elem) | IrType::RefMut(elem) | IrType::Iterator(elem) => {
+            visitor.visit_type(elem)
+        }
+        IrType::Dict(k, v) | IrType::Result(k, v) => {
+            visitor.visit_type(k);
+            visitor.visit_type(v);
+        }
+        IrType::Tuple(elems) => {
+            for e in elems {
+                visitor.visit_type(e);
+            }
+        }
+        IrType::Option(inner) => visitor.visit_type(inner),
+        IrType::NamedGeneric(_, args) => {
+            for a in args {
+                visitor.visit_type(a);
+            }
+        }
+        IrType::Function { params, ret } => {
+            for p in params {
+                visitor.visit_type(p);
+            }
+            visitor.visit_type(ret);
+        }
+    }
+}
+
+// ============================================================================
+// IrFolder: consuming transform that rebuilds the tree
+// ============================================================================
+
+/// Consuming transform over an IR tree.
+///
+/// Each method's default body rebuilds the node from its folded children via the matching
+/// `super_fold_*` function; override a method to rewrite that node kind while keeping the
+/// structural rebuild for everything else. `IrSpan` and resolved `IrType`s are preserved as-is
+/// unless a fold explicitly replaces them (e.g. via `fold_type`).
+pub trait IrFolder {
+    fn fold_decl(&mut self, decl: IrDecl) -> IrDecl {
+        super_fold_decl(self, decl)
+    }
+
+    fn fold_stmt(&mut self, stmt: IrStmt) -> IrStmt {
+        super_fold_stmt(self, stmt)
+    }
+
+    fn fold_expr(&mut self, expr: IrExpr) -> IrExpr {
+        super_fold_expr(self, expr)
+    }
+
+    fn fold_type(&mut self, ty: IrType) -> IrType {
+        super_fold_type(self, ty)
+    }
+}
+
+/// Run `folder` over every declaration in `program`, in order, and return the rebuilt program.
+pub fn fold_program<F: IrFolder + ?Sized>(folder: &mut F, program: IrProgram) -> IrProgram {
+    IrProgram {
+        declarations: program.declarations.into_iter().map(|d| folder.fold_decl(d)).collect(),
+        ..program
+    }
+}
+
+/// Structural rebuild for [`IrFolder::fold_decl`]'s default body.
+pub fn super_fold_decl<F: IrFolder + ?Sized>(folder: &mut F, decl: IrDecl) -> IrDecl {
+    let kind = match decl.kind {
+        IrDeclKind::Function(f) => IrDeclKind::Function(fold_function(folder, f)),
+        IrDeclKind::Struct(s) => IrDeclKind::Struct(fold_struct(folder, s)),
+        IrDeclKind::Enum(e) => IrDeclKind::Enum(fold_enum(folder, e)),
+        IrDeclKind::Trait(t) => IrDeclKind::Trait(fold_trait(folder, t)),
+        IrDeclKind::TypeAlias { name, ty } => IrDeclKind::TypeAlias {
+            name,
+            ty: folder.fold_type(ty),
+        },
+        IrDeclKind::Const { name, ty, value } => IrDeclKind::Const {
+            name,
+            ty: folder.fold_type(ty),
+            value: folder.fold_expr(value),
+        },
+        kind @ IrDeclKind::Import { .. } => kind,
+        IrDeclKind::Impl(imp) => IrDeclKind::Impl(fold_impl(folder, imp)),
+    };
+    IrDecl { kind, span: decl.span }
+}
+
+fn fold_function<F: IrFolder + ?Sized>(folder: &mut F, f: IrFunction) -> IrFunction {
+    IrFunction {
+        name: f.name,
+        params: f
+            .params
+            .into_iter()
+            .map(|p| super::decl::FunctionParam {
+                ty: folder.fold_type(p.ty),
+                ..p
+            })
+            .collect(),
+        return_type: folder.fold_type(f.return_type),
+        body: f.body.into_iter().map(|s| folder.fold_stmt(s)).collect(),
+        is_async: f.is_async,
+        visibility: f.visibility,
+        type_params: f.type_params,
+        constness: f.constness,
+    }
+}
+
+fn fold_struct<F: IrFolder + ?Sized>(folder: &mut F, s: IrStruct) -> IrStruct {
+    IrStruct {
+        name: s.name,
+        fields: s.fields.into_iter().map(|field| fold_struct_field(folder, field)).collect(),
+        derives: s.derives,
+        visibility: s.visibility,
+        type_params: s.type_params,
+        serde_rename_all: s.serde_rename_all,
+        formats: s.formats,
+    }
+}
+
+fn fold_struct_field<F: IrFolder + ?Sized>(folder: &mut F, field: StructField) -> StructField {
+    StructField {
+        ty: folder.fold_type(field.ty),
+        ..field
+    }
+}
+
+fn fold_enum<F: IrFolder + ?Sized>(folder: &mut F, e: IrEnum) -> IrEnum {
+    IrEnum {
+        name: e.name,
+        variants: e.variants.into_iter().map(|v| fold_variant(folder, v)).collect(),
+        derives: e.derives,
+        visibility: e.visibility,
+        type_params: e.type_params,
+        formats: e.formats,
+    }
+}
+
+fn fold_variant<F: IrFolder + ?Sized>(folder: &mut F, variant: EnumVariant) -> EnumVariant {
+    let fields = match variant.fields {
+        VariantFields::Unit => VariantFields::Unit,
+        VariantFields::Tuple(tys) => VariantFields::Tuple(tys.into_iter().map(|ty| folder.fold_type(ty)).collect()),
+        VariantFields::Struct(fields) => {
+            VariantFields::Struct(fields.into_iter().map(|f| fold_struct_field(folder, f)).collect())
+        }
+    };
+    EnumVariant { name: variant.name, fields }
+}
+
+fn fold_trait<F: IrFolder + ?Sized>(folder: &mut F, t: IrTrait) -> IrTrait {
+    IrTrait {
+        name: t.name,
+        methods: t.methods.into_iter().map(|m| fold_function(folder, m)).collect(),
+        visibility: t.visibility,
+    }
+}
+
+fn fold_impl<F: IrFolder + ?Sized>(folder: &mut F, imp: IrImpl) -> IrImpl {
+    IrImpl {
+        target_type: imp.target_type,
+        trait_name: imp.trait_name,
+        assoc_types: imp
+            .assoc_types
+            .into_iter()
+            .map(|(name, ty)| (name, folder.fold_type(ty)))
+            .collect(),
+        methods: imp.methods.into_iter().map(|m| fold_function(folder, m)).collect(),
+    }
+}
+
+fn fold_pattern<F: IrFolder + ?Sized>(folder: &mut F, pattern: Pattern) -> Pattern {
+    match pattern {
+        Pattern::Wildcard => Pattern::Wildcard,
+        Pattern::Var(name) => Pattern::Var(name),
+        Pattern::Literal(e) => Pattern::Literal(folder.fold_expr(e)),
+        Pattern::Tuple(patterns) => Pattern::Tuple(patterns.into_iter().map(|p| fold_pattern(folder, p)).collect()),
+        Pattern::Struct { name, fields } => Pattern::Struct {
+            name,
+            fields: fields
+                .into_iter()
+                .map(|(name, p)| (name, fold_pattern(folder, p)))
+                .collect(),
+        },
+        Pattern::Enum { name, variant, fields } => Pattern::Enum {
+            name,
+            variant,
+            fields: fields.into_iter().map(|p| fold_pattern(folder, p)).collect(),
+        },
+        Pattern::Or(patterns) => Pattern::Or(patterns.into_iter().map(|p| fold_pattern(folder, p)).collect()),
+        Pattern::Slice { prefix, rest, suffix } => Pattern::Slice {
+            prefix: prefix.into_iter().map(|p| fold_pattern(folder, p)).collect(),
+            rest,
+            suffix: suffix.into_iter().map(|p| fold_pattern(folder, p)).collect(),
+        },
+        Pattern::As(inner, name) => Pattern::As(Box::new(fold_pattern(folder, *inner)), name),
+    }
+}
+
+fn fold_match_arm<F: IrFolder + ?Sized>(folder: &mut F, arm: MatchArm) -> MatchArm {
+    MatchArm {
+        pattern: fold_pattern(folder, arm.pattern),
+        pattern_id: arm.pattern_id,
+        guard: arm.guard.map(|g| folder.fold_expr(g)),
+        body: folder.fold_expr(arm.body),
+    }
+}
+
+fn fold_comp_clauses<F: IrFolder + ?Sized>(folder: &mut F, clauses: Vec<CompClause>) -> Vec<CompClause> {
+    clauses
+        .into_iter()
+        .map(|clause| match clause {
+            CompClause::For { variable, iterable } => CompClause::For {
+                variable,
+                iterable: fold_box_expr(folder, iterable),
+            },
+            CompClause::If(cond) => CompClause::If(fold_box_expr(folder, cond)),
+        })
+        .collect()
+}
+
+fn fold_assign_target<F: IrFolder + ?Sized>(folder: &mut F, target: AssignTarget) -> AssignTarget {
+    match target {
+        AssignTarget::Var(name) => AssignTarget::Var(name),
+        AssignTarget::Field { object, field } => AssignTarget::Field {
+            object: fold_box_expr(folder, object),
+            field,
+        },
+        AssignTarget::Index { object, index } => AssignTarget::Index {
+            object: fold_box_expr(folder, object),
+            index: fold_box_expr(folder, index),
+        },
+    }
+}
+
+fn fold_box_expr<F: IrFolder + ?Sized>(folder: &mut F, expr: Box<IrExpr>) -> Box<IrExpr> {
+    Box::new(folder.fold_expr(*expr))
+}
+
+fn fold_opt_box_expr<F: IrFolder + ?Sized>(folder: &mut F, expr: Option<Box<IrExpr>>) -> Option<Box<IrExpr>> {
+    expr.map(|e| fold_box_expr(folder, e))
+}
+
+/// Structural rebuild for [`IrFolder::fold_stmt`]'s default body.
+pub fn super_fold_stmt<F: IrFolder + ?Sized>(folder: &mut F, stmt: IrStmt) -> IrStmt {
+    let kind = match stmt.kind {
+        IrStmtKind::Expr(e) => IrStmtKind::Expr(folder.fold_expr(e)),
+        IrStmtKind::Let {
+            name,
+            ty,
+            mutability,
+            value,
+        } => IrStmtKind::Let {
+            name,
+            ty: folder.fold_type(ty),
+            mutability,
+            value: folder.fold_expr(value),
+        },
+        IrStmtKind::Assign { target, value } => IrStmtKind::Assign {
+            target: fold_assign_target(folder, target),
+            value: folder.fold_expr(value),
+        },
+        IrStmtKind::CompoundAssign { target, op, value } => IrStmtKind::CompoundAssign {
+            target: fold_assign_target(folder, target),
+            op,
+            value: folder.fold_expr(value),
+        },
+        IrStmtKind::Return(value) => IrStmtKind::Return(value.map(|e| folder.fold_expr(e))),
+        kind @ (IrStmtKind::Break(_) | IrStmtKind::Continue(_)) => kind,
+        IrStmtKind::While { label, condition, body } => IrStmtKind::While {
+            label,
+            condition: folder.fold_expr(condition),
+            body: body.into_iter().map(|s| folder.fold_stmt(s)).collect(),
+        },
+        IrStmtKind::For {
+            label,
+            pattern,
+            iterable,
+            body,
+        } => IrStmtKind::For {
+            label,
+            pattern: fold_pattern(folder, pattern),
+            iterable: folder.fold_expr(iterable),
+            body: body.into_iter().map(|s| folder.fold_stmt(s)).collect(),
+        },
+        IrStmtKind::Loop { label, body } => IrStmtKind::Loop {
+            label,
+            body: body.into_iter().map(|s| folder.fold_stmt(s)).collect(),
+        },
+        IrStmtKind::If {
+            condition,
+            then_branch,
+            else_branch,
+        } => IrStmtKind::If {
+            condition: folder.fold_expr(condition),
+            then_branch: then_branch.into_iter().map(|s| folder.fold_stmt(s)).collect(),
+            else_branch: else_branch.map(|stmts| stmts.into_iter().map(|s| folder.fold_stmt(s)).collect()),
+        },
+        IrStmtKind::Match { scrutinee, arms } => IrStmtKind::Match {
+            scrutinee: folder.fold_expr(scrutinee),
+            arms: arms.into_iter().map(|arm| fold_match_arm(folder, arm)).collect(),
+        },
+        IrStmtKind::Block(stmts) => IrStmtKind::Block(stmts.into_iter().map(|s| folder.fold_stmt(s)).collect()),
+    };
+    IrStmt { kind, span: stmt.span }
+}
+
+/// Structural rebuild for [`IrFolder::fold_expr`]'s default body.
+pub fn super_fold_expr<F: IrFolder + ?Sized>(folder: &mut F, expr: IrExpr) -> IrExpr {
+    let ty = folder.fold_type(expr.ty);
+    let kind = match expr.kind {
+        kind @ (IrExprKind::Unit
+        | IrExprKind::None
+        | IrExprKind::Bool(_)
+        | IrExprKind::Int(_)
+        | IrExprKind::Float(_)
+        | IrExprKind::String(_)
+        | IrExprKind::Bytes(_)
+        | IrExprKind::Var { .. }
+        | IrExprKind::Literal(_)
+        | IrExprKind::FieldsList(_)
+        | IrExprKind::SerdeToJson
+        | IrExprKind::SerdeFromJson(_)) => kind,
+        IrExprKind::BinOp { op, left, right } => IrExprKind::BinOp {
+            op,
+            left: fold_box_expr(folder, left),
+            right: fold_box_expr(folder, right),
+        },
+        IrExprKind::UnaryOp { op, operand } => IrExprKind::UnaryOp {
+            op,
+            operand: fold_box_expr(folder, operand),
+        },
+        IrExprKind::Call { func, args } => IrExprKind::Call {
+            func: fold_box_expr(folder, func),
+            args: args.into_iter().map(|a| folder.fold_expr(a)).collect(),
+        },
+        IrExprKind::BuiltinCall { func, args } => IrExprKind::BuiltinCall {
+            func,
+            args: args.into_iter().map(|a| folder.fold_expr(a)).collect(),
+        },
+        IrExprKind::MethodCall { receiver, method, args } => IrExprKind::MethodCall {
+            receiver: fold_box_expr(folder, receiver),
+            method,
+            args: args.into_iter().map(|a| folder.fold_expr(a)).collect(),
+        },
+        IrExprKind::KnownMethodCall { receiver, kind, args } => IrExprKind::KnownMethodCall {
+            receiver: fold_box_expr(folder, receiver),
+            kind,
+            args: args.into_iter().map(|a| folder.fold_expr(a)).collect(),
+        },
+        IrExprKind::Field { object, field } => IrExprKind::Field {
+            object: fold_box_expr(folder, object),
+            field,
+        },
+        IrExprKind::Index { object, index } => IrExprKind::Index {
+            object: fold_box_expr(folder, object),
+            index: fold_box_expr(folder, index),
+        },
+        IrExprKind::TupleIndex { object, index } => IrExprKind::TupleIndex {
+            object: fold_box_expr(folder, object),
+            index,
+        },
+        IrExprKind::Slice { target, start, end, step } => IrExprKind::Slice {
+            target: fold_box_expr(folder, target),
+            start: fold_opt_box_expr(folder, start),
+            end: fold_opt_box_expr(folder, end),
+            step: fold_opt_box_expr(folder, step),
+        },
+        IrExprKind::ListComp { element, clauses } => IrExprKind::ListComp {
+            element: fold_box_expr(folder, element),
+            clauses: fold_comp_clauses(folder, clauses),
+        },
+        IrExprKind::DictComp { key, value, clauses } => IrExprKind::DictComp {
+            key: fold_box_expr(folder, key),
+            value: fold_box_expr(folder, value),
+            clauses: fold_comp_clauses(folder, clauses),
+        },
+        IrExprKind::SetComp { element, clauses } => IrExprKind::SetComp {
+            element: fold_box_expr(folder, element),
+            clauses: fold_comp_clauses(folder, clauses),
+        },
+        IrExprKind::GenExp { element, clauses, lazy } => IrExprKind::GenExp {
+            element: fold_box_expr(folder, element),
+            clauses: fold_comp_clauses(folder, clauses),
+            lazy,
+        },
+        IrExprKind::List(elems) => IrExprKind::List(elems.into_iter().map(|e| folder.fold_expr(e)).collect()),
+        IrExprKind::Dict(entries) => IrExprKind::Dict(
+            entries
+                .into_iter()
+                .map(|(k, v)| (folder.fold_expr(k), folder.fold_expr(v)))
+                .collect(),
+        ),
+        IrExprKind::Set(elems) => IrExprKind::Set(elems.into_iter().map(|e| folder.fold_expr(e)).collect()),
+        IrExprKind::Tuple(elems) => IrExprKind::Tuple(elems.into_iter().map(|e| folder.fold_expr(e)).collect()),
+        IrExprKind::Struct { name, fields } => IrExprKind::Struct {
+            name,
+            fields: fields
+                .into_iter()
+                .map(|(name, e)| (name, folder.fold_expr(e)))
+                .collect(),
+        },
+        IrExprKind::If {
+            condition,
+            then_branch,
+            else_branch,
+        } => IrExprKind::If {
+            condition: fold_box_expr(folder, condition),
+            then_branch: fold_box_expr(folder, then_branch),
+            else_branch: fold_opt_box_expr(folder, else_branch),
+        },
+        IrExprKind::Match { scrutinee, arms } => IrExprKind::Match {
+            scrutinee: fold_box_expr(folder, scrutinee),
+            arms: arms.into_iter().map(|arm| fold_match_arm(folder, arm)).collect(),
+        },
+        IrExprKind::Closure { params, body, captures } => IrExprKind::Closure {
+            params: params.into_iter().map(|(name, ty)| (name, folder.fold_type(ty))).collect(),
+            body: fold_box_expr(folder, body),
+            captures,
+        },
+        IrExprKind::Block { stmts, value } => IrExprKind::Block {
+            stmts: stmts.into_iter().map(|s| folder.fold_stmt(s)).collect(),
+            value: fold_opt_box_expr(folder, value),
+        },
+        IrExprKind::Await(e) => IrExprKind::Await(fold_box_expr(folder, e)),
+        IrExprKind::Try(e) => IrExprKind::Try(fold_box_expr(folder, e)),
+        IrExprKind::Range { start, end, inclusive } => IrExprKind::Range {
+            start: fold_opt_box_expr(folder, start),
+            end: fold_opt_box_expr(folder, end),
+            inclusive,
+        },
+        IrExprKind::Cast { expr, to_type } => IrExprKind::Cast {
+            expr: fold_box_expr(folder, expr),
+            to_type: folder.fold_type(to_type),
+        },
+        IrExprKind::Format { parts } => IrExprKind::Format {
+            parts: parts
+                .into_iter()
+                .map(|part| match part {
+                    FormatPart::Literal(s) => FormatPart::Literal(s),
+                    FormatPart::Expr(e) => FormatPart::Expr(folder.fold_expr(e)),
+                })
+                .collect(),
+        },
+    };
+    IrExpr {
+        kind,
+        ty,
+        ownership: expr.ownership,
+        span: expr.span,
+    }
+}
+
+/// Structural rebuild for [`IrFolder::fold_type`]'s default body.
+pub fn super_fold_type<F: IrFolder + ?Sized>(folder: &mut F, ty: IrType) -> IrType {
+    match ty {
+        ty @ (IrType::Unit
+        | IrType::Bool
+        | IrType::Int
+        | IrType::Float
+        | IrType::String
+        | IrType::StaticStr
+        | IrType::StaticBytes
+        | IrType::StrRef
+        | IrType::Struct(_)
+        | IrType::Enum(_)
+        | IrType::Trait(_)
+        | IrType::Generic(_)
+        | IrType::SelfType
+        | IrType::Unknown) => ty,
+        IrType::List(elem) => IrType::List(Box::new(folder.fold_type(*elem))),
+        IrType::Set(elem) => IrType::Set(Box::new(folder.fold_type(*elem))),
+        IrType::Iterator(elem) => IrType::Iterator(Box::new(folder.fold_type(*elem))),
+        IrType::Ref(elem) => IrType::Ref(Box::new(folder.fold_type(*elem))),
+        IrType::RefMut(elem) => IrType::RefMut(Box::new(folder.fold_type(*elem))),
+        IrType::Dict(k, v) => IrType::Dict(Box::new(folder.fold_type(*k)), Box::new(folder.fold_type(*v))),
+        IrType::Result(ok, err) => IrType::Result(Box::new(folder.fold_type(*ok)), Box::new(folder.fold_type(*err))),
+        IrType::Tuple(elems) => IrType::Tuple(elems.into_iter().map(|e| folder.fold_type(e)).collect()),
+        IrType::Option(inner) => IrType::Option(Box::new(folder.fold_type(*inner))),
+        IrType::NamedGeneric(name, args) => {
+            IrType::NamedGeneric(name, args.into_iter().map(|a| folder.fold_type(a)).collect())
+        }
+        IrType::Function { params, ret } => IrType::Function {
+            params: params.into_iter().map(|p| folder.fold_type(p)).collect(),
+            ret: Box::new(folder.fold_type(*ret)),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::ir::decl::Visibility;
+    use crate::backend::ir::expr::{BinOp, TypedExpr, VarAccess};
+    use crate::backend::ir::stmt::IrStmtKind;
+
+    fn int(n: i64) -> IrExpr {
+        TypedExpr::new(IrExprKind::Int(n), IrType::Int)
+    }
+
+    #[test]
+    fn visitor_counts_every_int_literal() {
+        struct CountInts(usize);
+        impl IrVisitor for CountInts {
+            fn visit_expr(&mut self, expr: &IrExpr) {
+                if matches!(expr.kind, IrExprKind::Int(_)) {
+                    self.0 += 1;
+                }
+                super_visit_expr(self, expr);
+            }
+        }
+
+        let mut program = IrProgram::new();
+        program.declarations.push(IrDecl::new(IrDeclKind::Function(IrFunction {
+            name: "f".to_string(),
+            params: vec![],
+            return_type: IrType::Int,
+            body: vec![IrStmt::new(IrStmtKind::Return(Some(TypedExpr::new(
+                IrExprKind::BinOp {
+                    op: BinOp::Add,
+                    left: Box::new(int(1)),
+                    right: Box::new(int(2)),
+                },
+                IrType::Int,
+            ))))],
+            is_async: false,
+            visibility: Visibility::Public,
+            type_params: vec![],
+            constness: super::super::decl::Constness::NotConst,
+        })));
+
+        let mut counter = CountInts(0);
+        walk_program(&mut counter, &program);
+        assert_eq!(counter.0, 2);
+    }
+
+    #[test]
+    fn folder_rewrites_every_int_literal() {
+        struct DoubleInts;
+        impl IrFolder for DoubleInts {
+            fn fold_expr(&mut self, expr: IrExpr) -> IrExpr {
+                let expr = super_fold_expr(self, expr);
+                match expr.kind {
+                    IrExprKind::Int(n) => TypedExpr {
+                        kind: IrExprKind::Int(n * 2),
+                        ..expr
+                    },
+                    _ => expr,
+                }
+            }
+        }
+
+        let mut program = IrProgram::new();
+        program.declarations.push(IrDecl::new(IrDeclKind::Function(IrFunction {
+            name: "f".to_string(),
+            params: vec![],
+            return_type: IrType::Int,
+            body: vec![IrStmt::new(IrStmtKind::Return(Some(TypedExpr::new(
+                IrExprKind::BinOp {
+                    op: BinOp::Add,
+                    left: Box::new(int(1)),
+                    right: Box::new(int(2)),
+                },
+                IrType::Int,
+            ))))],
+            is_async: false,
+            visibility: Visibility::Public,
+            type_params: vec![],
+            constness: super::super::decl::Constness::NotConst,
+        })));
+
+        let program = fold_program(&mut DoubleInts, program);
+
+        let IrDeclKind::Function(f) = &program.declarations[0].kind else {
+            unreachable!()
+        };
+        let IrStmtKind::Return(Some(ret)) = &f.body[0].kind else {
+            unreachable!()
+        };
+        let IrExprKind::BinOp { left, right, .. } = &ret.kind else {
+            unreachable!()
+        };
+        assert!(matches!(left.kind, IrExprKind::Int(2)));
+        assert!(matches!(right.kind, IrExprKind::Int(4)));
+    }
+
+    #[test]
+    fn fold_var_access_is_unused() {
+        // Smoke-test that Var nodes (no sub-expressions) round-trip unchanged through fold_expr.
+        struct Identity;
+        impl IrFolder for Identity {}
+
+        let v = TypedExpr::new(
+            IrExprKind::Var {
+                name: "x".to_string(),
+                access: VarAccess::Copy,
+            },
+            IrType::Int,
+        );
+        let folded = Identity.fold_expr(v);
+        assert!(matches!(folded.kind, IrExprKind::Var { name, .. } if name == "x"));
+    }
+}
@@ -270,6 +270,8 @@ pub struct ProjectGenerator {
     /// Additional Rust crate dependencies from `rust::` imports
     /// Key: crate name, Value: optional version spec (if None, uses latest)
     rust_crate_deps: std::collections::HashMap<String, Option<String>>,
+    /// Rust edition to target in the generated Cargo.toml
+    edition: String,
 }
 
 impl ProjectGenerator {
@@ -282,9 +284,21 @@ impl ProjectGenerator {
             needs_tokio: false,
             needs_axum: false,
             rust_crate_deps: std::collections::HashMap::new(),
+            edition: "2021".to_string(),
         }
     }
 
+    /// Target a specific Rust edition in the generated Cargo.toml (default `"2021"`)
+    pub fn with_edition(mut self, edition: &str) -> Self {
+        self.edition = edition.to_string();
+        self
+    }
+
+    /// Set the Rust edition to target in the generated Cargo.toml
+    pub fn set_edition(&mut self, edition: &str) {
+        self.edition = edition.to_string();
+    }
+
     /// Enable serde support (for JSON serialization)
     pub fn with_serde(mut self) -> Self {
         self.needs_serde = true;
@@ -647,7 +661,7 @@ path = "src/lib.rs""#
             r#"[package]
 name = "{name}"
 version = "{incan_version}"
-edition = "2021"
+edition = "{edition}"
 
 # Generated by the Incan compiler
 
@@ -661,6 +675,7 @@ edition = "2021"
 "#,
             name = self.name,
             incan_version = INCAN_VERSION,
+            edition = self.edition,
             dependencies = dependencies,
             crate_type = crate_type.replace("{name}", &self.name)
         )
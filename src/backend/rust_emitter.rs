@@ -285,6 +285,46 @@ impl RustEmitter {
         }
     }
 
+    /// Write a struct definition with extra container- and field-level attributes, e.g. for
+    /// `#[serde(rename_all = "...")]` / `#[serde(rename = "...")]`. `struct_def` stays the plain
+    /// path for callers that don't need attributes beyond the derive list.
+    pub fn struct_def_with_attrs(
+        &mut self,
+        derives: &[&str],
+        container_attrs: &[String],
+        visibility: &str,
+        name: &str,
+        fields: &[(String, String, Option<String>)],
+    ) {
+        if !derives.is_empty() {
+            self.line(&format!("#[derive({})]", derives.join(", ")));
+        }
+        for attr in container_attrs {
+            self.line(attr);
+        }
+
+        let vis_str = if visibility.is_empty() {
+            String::new()
+        } else {
+            format!("{} ", visibility)
+        };
+
+        if fields.is_empty() {
+            self.line(&format!("{}struct {};", vis_str, name));
+        } else {
+            self.line(&format!("{}struct {} {{", vis_str, name));
+            self.indent();
+            for (field_name, field_type, field_attr) in fields {
+                if let Some(attr) = field_attr {
+                    self.line(attr);
+                }
+                self.line(&format!("pub {}: {},", field_name, field_type));
+            }
+            self.dedent();
+            self.line("}");
+        }
+    }
+
     /// Write an enum definition
     pub fn enum_def(&mut self, derives: &[&str], visibility: &str, name: &str, variants: &[(String, Vec<String>)]) {
         if !derives.is_empty() {
@@ -310,6 +350,45 @@ impl RustEmitter {
         self.line("}");
     }
 
+    /// Write an enum definition with extra container- and per-variant attributes, e.g. for
+    /// `#[serde(rename_all = "...")]` / `#[serde(rename = "...")]`.
+    pub fn enum_def_with_attrs(
+        &mut self,
+        derives: &[&str],
+        container_attrs: &[String],
+        visibility: &str,
+        name: &str,
+        variants: &[(String, Vec<String>, Option<String>)],
+    ) {
+        if !derives.is_empty() {
+            self.line(&format!("#[derive({})]", derives.join(", ")));
+        }
+        for attr in container_attrs {
+            self.line(attr);
+        }
+
+        let vis_str = if visibility.is_empty() {
+            String::new()
+        } else {
+            format!("{} ", visibility)
+        };
+
+        self.line(&format!("{}enum {} {{", vis_str, name));
+        self.indent();
+        for (variant_name, fields, variant_attr) in variants {
+            if let Some(attr) = variant_attr {
+                self.line(attr);
+            }
+            if fields.is_empty() {
+                self.line(&format!("{},", variant_name));
+            } else {
+                self.line(&format!("{}({}),", variant_name, fields.join(", ")));
+            }
+        }
+        self.dedent();
+        self.line("}");
+    }
+
     /// Write a trait definition
     pub fn trait_def<F>(&mut self, visibility: &str, name: &str, f: F)
     where
@@ -494,4 +573,44 @@ mod tests {
         assert!(code.contains("pub struct User {"));
         assert!(code.contains("pub name: String,"));
     }
+
+    #[test]
+    fn test_emitter_struct_with_attrs() {
+        let mut e = RustEmitter::new();
+        e.struct_def_with_attrs(
+            &["Debug", "Serialize"],
+            &["#[serde(rename_all = \"camelCase\")]".to_string()],
+            "pub",
+            "User",
+            &[
+                ("user_id".to_string(), "i64".to_string(), Some("#[serde(rename = \"userid\")]".to_string())),
+                ("name".to_string(), "String".to_string(), None),
+            ],
+        );
+        let code = e.finish();
+        assert!(code.contains("#[serde(rename_all = \"camelCase\")]"));
+        assert!(code.contains("#[serde(rename = \"userid\")]"));
+        assert!(code.contains("pub user_id: i64,"));
+        assert!(code.contains("pub name: String,"));
+    }
+
+    #[test]
+    fn test_emitter_enum_with_attrs() {
+        let mut e = RustEmitter::new();
+        e.enum_def_with_attrs(
+            &["Debug", "Serialize"],
+            &["#[serde(rename_all = \"lowercase\")]".to_string()],
+            "pub",
+            "Color",
+            &[
+                ("Red".to_string(), vec![], Some("#[serde(rename = \"red\")]".to_string())),
+                ("Blue".to_string(), vec![], None),
+            ],
+        );
+        let code = e.finish();
+        assert!(code.contains("#[serde(rename_all = \"lowercase\")]"));
+        assert!(code.contains("#[serde(rename = \"red\")]"));
+        assert!(code.contains("Red,"));
+        assert!(code.contains("Blue,"));
+    }
 }
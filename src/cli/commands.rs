@@ -206,6 +206,17 @@ pub fn parse_file(file_path: &str) {
     }
 }
 
+/// Print the long `--explain` writeup for a stable diagnostic error code
+pub fn explain_code(code: &str) {
+    match diagnostics::explain(code) {
+        Some(text) => println!("{}", text),
+        None => {
+            eprintln!("error: no explanation available for error code `{}`", code);
+            process::exit(1);
+        }
+    }
+}
+
 /// Type check a file
 pub fn check_file(file_path: &str) {
     let modules = collect_modules(file_path);
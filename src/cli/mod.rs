@@ -8,12 +8,15 @@
 //! - `run <file>` - Compile and run the program
 //! - `fmt <file|dir>` - Format Incan source files
 //! - `test [path]` - Run tests (pytest-style)
+//! - `repl` - Interactive read-eval-print loop
+//! - `--explain <CODE>` - Print the long writeup for a diagnostic error code
 //!
 //! ## Modules
 //!
 //! - `commands` - Command implementations
 //! - `prelude` - Stdlib/prelude loading
 //! - `test_runner` - Test discovery and execution
+//! - `repl` - Interactive REPL
 //!
 //! ## Design
 //!
@@ -27,6 +30,7 @@
 
 pub mod commands;
 pub mod prelude;
+pub mod repl;
 pub mod test_interfaces;
 pub mod test_runner;
 
@@ -136,6 +140,10 @@ pub struct Cli {
     /// Enable strict mode for --emit-rust (warning-clean output)
     #[arg(long = "strict", requires = "emit_rust_file")]
     pub strict: bool,
+
+    /// Print the long explanation for a diagnostic error code (e.g. `E0308`)
+    #[arg(long = "explain", value_name = "CODE", conflicts_with = "file")]
+    pub explain_code: Option<String>,
 }
 
 #[derive(Subcommand, Debug)]
@@ -190,7 +198,14 @@ pub enum Command {
         /// Filter tests by keyword expression
         #[arg(short = 'k', value_name = "EXPR")]
         filter: Option<String>,
+        /// Write missing/mismatched compile_fail expected-stderr fixtures instead of failing on
+        /// them (like `cargo insta`'s or `trybuild`'s bless workflow)
+        #[arg(long)]
+        bless: bool,
     },
+
+    /// Start an interactive REPL
+    Repl,
 }
 
 /// Generate the logo string for clap
@@ -234,6 +249,10 @@ pub fn run() {
 
 /// Execute the CLI command and return result.
 fn execute(cli: Cli) -> CliResult<ExitCode> {
+    if let Some(code) = cli.explain_code {
+        return commands::explain_code(&code);
+    }
+
     // Handle debug flags first
     if let Some(file) = cli.lex_file {
         return commands::lex_file(&file.to_string_lossy());
@@ -264,13 +283,16 @@ fn execute(cli: Cli) -> CliResult<ExitCode> {
             stop_on_fail,
             slow,
             filter,
+            bless,
         }) => test_runner::run_tests(
             &path.to_string_lossy(),
             verbose,
             stop_on_fail,
             slow,
             filter.as_deref(),
+            bless,
         ),
+        Some(Command::Repl) => repl::run_repl(),
         None => {
             // Default: type check the file if provided
             if let Some(file) = cli.file {
@@ -412,6 +434,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cli_parse_test_bless() {
+        let cli = Cli::try_parse_from(["incan", "test", "--bless"]).unwrap();
+        if let Some(Command::Test { bless, .. }) = cli.command {
+            assert!(bless);
+        } else {
+            panic!("Expected Test command");
+        }
+    }
+
+    #[test]
+    fn test_cli_parse_test_bless_defaults_false() {
+        let cli = Cli::try_parse_from(["incan", "test"]).unwrap();
+        if let Some(Command::Test { bless, .. }) = cli.command {
+            assert!(!bless);
+        } else {
+            panic!("Expected Test command");
+        }
+    }
+
     #[test]
     fn test_cli_parse_debug_flags() {
         let cli = Cli::try_parse_from(["incan", "--lex", "test.incn"]).unwrap();
@@ -0,0 +1,215 @@
+//! Interactive REPL.
+//!
+//! Each entry is transpiled and run independently: `let` bindings
+//! ([`IrStmtKind::Let`]) are re-emitted into a persistent generated `main` so
+//! later entries can still see them, while expression statements
+//! ([`IrStmtKind::Expr`]) are executed once and their value auto-printed,
+//! the way Python's interactive prompt echoes expression results.
+//!
+//! Input is read line by line. After each line we try to parse everything
+//! typed so far; if the parser only failed because it ran off the end of
+//! the input (an unterminated block, an open `(`/`[`, or a trailing `:`
+//! still waiting for its suite), we buffer the line and prompt for more
+//! instead of reporting an error. A real syntax error is only surfaced once
+//! a complete statement has been assembled.
+
+use std::io::{self, Write};
+
+use crate::backend::ir::{
+    infer_constness, postprocess, AstLowering, BuiltinFn, IrDeclKind, IrEmitter, IrExprKind, IrStmt, IrStmtKind,
+    IrType, TypedExpr,
+};
+use crate::backend::ProjectGenerator;
+use crate::frontend::typechecker::TypeChecker;
+use crate::frontend::{ast, diagnostics::CompileError, lexer, parser};
+
+use super::{CliError, CliResult, ExitCode};
+
+const PROMPT: &str = "incan> ";
+const CONTINUATION_PROMPT: &str = "...    ";
+
+/// Name of the synthetic function each REPL entry is parsed and lowered as the body of.
+const ENTRY_POINT: &str = "main";
+
+/// Outcome of attempting to parse the buffered REPL input.
+enum ParseOutcome {
+    /// A complete program was parsed.
+    Ready(ast::Program),
+    /// Parsing only failed because the input ended mid-construct; keep buffering.
+    NeedsMore,
+    /// A real syntax error; report it and reset the buffer.
+    Errors(Vec<CompileError>),
+}
+
+/// Run the interactive REPL until the user sends EOF (Ctrl-D).
+pub fn run_repl() -> CliResult<ExitCode> {
+    println!("Incan REPL ({})", super::VERSION);
+    println!("Type Ctrl-D to exit.");
+
+    let stdin = io::stdin();
+    // Source of already-accepted `let` bindings, already indented as a function body.
+    let mut bindings_source = String::new();
+    // Raw, not-yet-indented lines for the statement currently being assembled.
+    let mut pending = String::new();
+
+    loop {
+        print!("{}", if pending.is_empty() { PROMPT } else { CONTINUATION_PROMPT });
+        io::stdout()
+            .flush()
+            .map_err(|e| CliError::failure(format!("error writing to stdout: {e}")))?;
+
+        let mut line = String::new();
+        let bytes_read = stdin
+            .read_line(&mut line)
+            .map_err(|e| CliError::failure(format!("error reading stdin: {e}")))?;
+        if bytes_read == 0 {
+            println!();
+            break;
+        }
+        pending.push_str(&line);
+
+        let candidate_body = format!("{bindings_source}{}", indent_block(&pending));
+        let full_source = format!("def {ENTRY_POINT}() -> Unit:\n{candidate_body}");
+
+        match try_parse(&full_source) {
+            ParseOutcome::NeedsMore => continue,
+            ParseOutcome::Errors(errors) => {
+                for error in &errors {
+                    eprintln!("error: {}", error.message);
+                }
+                pending.clear();
+            }
+            ParseOutcome::Ready(program) => {
+                pending.clear();
+                match run_entry(&program) {
+                    Ok(keeps_binding) => {
+                        if keeps_binding {
+                            bindings_source = candidate_body;
+                        }
+                    }
+                    Err(message) => eprintln!("error: {message}"),
+                }
+            }
+        }
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Indent every non-empty line by one function-body level.
+fn indent_block(source: &str) -> String {
+    let mut out = String::new();
+    for line in source.lines() {
+        if line.is_empty() {
+            out.push('\n');
+        } else {
+            out.push_str("  ");
+            out.push_str(line);
+            out.push('\n');
+        }
+    }
+    out
+}
+
+/// Attempt to lex and parse `source`, distinguishing "needs more input" from a real error.
+fn try_parse(source: &str) -> ParseOutcome {
+    let tokens = match lexer::lex(source) {
+        Ok(tokens) => tokens,
+        Err(errors) => {
+            return if errors.iter().all(is_eof_continuation) {
+                ParseOutcome::NeedsMore
+            } else {
+                ParseOutcome::Errors(errors)
+            };
+        }
+    };
+
+    match parser::parse(&tokens) {
+        Ok(program) => ParseOutcome::Ready(program),
+        Err(errors) => {
+            if errors.iter().all(is_eof_continuation) {
+                ParseOutcome::NeedsMore
+            } else {
+                ParseOutcome::Errors(errors)
+            }
+        }
+    }
+}
+
+/// Whether `error` looks like it was raised because the parser ran out of tokens mid-construct
+/// (an unterminated block, an open bracket, or a suite-opening `:` with no body yet) rather than
+/// a genuine syntax mistake. The token kind is embedded in the message via `{:?}` formatting, so
+/// an EOF-triggered error always mentions `Eof`.
+fn is_eof_continuation(error: &CompileError) -> bool {
+    error.message.contains("Eof")
+}
+
+/// Typecheck, lower, and run one accepted REPL entry.
+///
+/// Returns `Ok(true)` if the entry should be persisted into `bindings_source` so future entries
+/// can still see it (only plain `let` bindings are replayed this way); `Ok(false)` if it ran but
+/// leaves nothing to replay (e.g. a printed expression).
+fn run_entry(program: &ast::Program) -> Result<bool, String> {
+    let mut checker = TypeChecker::new();
+    let type_info = checker.check_program(program).ok().map(|()| checker.type_info().clone());
+
+    let mut lowering = match type_info {
+        Some(info) => AstLowering::new_with_type_info(info),
+        None => AstLowering::new(),
+    };
+    let mut ir_program = lowering.lower_program(program).map_err(|e| e.to_string())?;
+    infer_constness(&mut ir_program);
+    postprocess(&mut ir_program);
+
+    let Some(entry) = ir_program.declarations.iter_mut().find_map(|decl| match &mut decl.kind {
+        IrDeclKind::Function(func) if func.name == ENTRY_POINT => Some(func),
+        _ => None,
+    }) else {
+        return Err(format!("no `{ENTRY_POINT}` function in REPL entry"));
+    };
+
+    // Only the trailing statement is new: everything before it is the replayed `bindings_source`.
+    let keeps_binding = matches!(
+        entry.body.last().map(|stmt| &stmt.kind),
+        Some(IrStmtKind::Let { .. })
+    );
+    if let Some(last) = entry.body.last_mut() {
+        auto_print_if_expr(last);
+    }
+
+    let emitter_registry = ir_program.function_registry.clone();
+    let mut emitter = IrEmitter::new(&emitter_registry, &ir_program.interner);
+    let rust_code = emitter.emit_program(&ir_program).map_err(|e| e.to_string())?;
+
+    let out_dir = std::env::temp_dir().join(format!("incan_repl_{}", std::process::id()));
+    let generator = ProjectGenerator::new(&out_dir, "incan_repl", true);
+    generator.generate(&rust_code).map_err(|e| format!("error generating project: {e}"))?;
+
+    let result = generator.run().map_err(|e| format!("error running entry: {e}"))?;
+    if !result.stdout.is_empty() {
+        print!("{}", result.stdout);
+    }
+    if !result.stderr.is_empty() {
+        eprint!("{}", result.stderr);
+    }
+
+    Ok(keeps_binding)
+}
+
+/// Rewrite a top-level expression statement into a `print(...)` call so its value is echoed,
+/// mirroring Python's interactive prompt. Expressions of type `Unit` are left alone (nothing
+/// useful to print, same as a REPL not echoing `None`-returning calls).
+fn auto_print_if_expr(stmt: &mut IrStmt) {
+    if let IrStmtKind::Expr(expr) = &mut stmt.kind {
+        if !matches!(expr.ty, IrType::Unit) {
+            let original = expr.clone();
+            *stmt = IrStmt::new(IrStmtKind::Expr(TypedExpr::new(
+                IrExprKind::BuiltinCall {
+                    func: BuiltinFn::Print,
+                    args: vec![original],
+                },
+                IrType::Unit,
+            )));
+        }
+    }
+}
@@ -8,7 +8,9 @@
 //! These interfaces allow for future customization (e.g., dry-run, remote execution)
 //! without breaking the current test_runner behavior.
 
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 use thiserror::Error;
 
 /// Errors that occur during test operations
@@ -32,6 +34,9 @@ pub enum TestError {
     #[error("test execution failed: {0}")]
     Execution(String),
 
+    #[error("test execution timed out: {0}")]
+    Timeout(String),
+
     #[error("I/O error: {0}")]
     Io(#[from] std::io::Error),
 }
@@ -70,6 +75,25 @@ pub struct HarnessInput {
     pub source_file: PathBuf,
     pub test_function_name: String,
     pub source_code: String,
+    pub expected_outcome: ExpectedOutcome,
+    /// `#[ignore]`/`#[should_panic]` attributes to emit for `test_function_name`, parsed from its
+    /// decorators during discovery (see `crate::cli::test_runner::extract_test_markers`).
+    pub directives: crate::backend::ir::emit::TestDirectives,
+    /// Rust edition to generate the test's Cargo project with, if the test's `@edition(...)`
+    /// decorator requested one other than the project default.
+    pub edition: Option<String>,
+}
+
+/// What a test is expected to do once its generated Rust project is built.
+///
+/// `CompileFail` tests still go through `HarnessGenerator` like any other test - codegen itself
+/// is expected to succeed - but the project is expected to fail `cargo build`, with a specific,
+/// checked-in diagnostic (see `TestExecutor::check_compile_fail`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExpectedOutcome {
+    #[default]
+    Pass,
+    CompileFail,
 }
 
 pub struct HarnessOutput {
@@ -78,16 +102,72 @@ pub struct HarnessOutput {
     pub generated_at: std::time::SystemTime,
 }
 
+/// Result of batching many tests into one shared Cargo project (see `HarnessGenerator::generate_batch`).
+pub struct BatchHarnessOutput {
+    pub project_dir: PathBuf,
+    /// Maps each input's `test_function_name` to the fully-qualified path of the generated
+    /// `#[test]` function within the batch project (e.g. `my_module::test_adds_numbers`), since
+    /// several source files can each define a test with the same name.
+    pub test_functions: HashMap<String, String>,
+}
+
 pub trait HarnessGenerator {
     /// Generate Rust test harness and setup Cargo project.
     /// Returns the project directory and generated code.
     fn generate_harness(&self, input: &HarnessInput) -> Result<HarnessOutput, TestError>;
+
+    /// Generate a single shared Cargo project covering every test in `inputs`, so a whole suite
+    /// pays the dependency-compile cost once instead of once per test (see `BatchHarnessGenerator`).
+    ///
+    /// The default implementation reports that this generator doesn't support batching; only
+    /// generators built for it (like `BatchHarnessGenerator`) need to override it.
+    fn generate_batch(&self, _inputs: &[HarnessInput]) -> Result<BatchHarnessOutput, TestError> {
+        Err(TestError::ProjectGeneration(
+            "this harness generator does not support batched generation".to_string(),
+        ))
+    }
 }
 
 // ============================================================================
 // Test Executor Interface
 // ============================================================================
 
+/// Tunables for how a `TestExecutor` runs its child processes.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ExecutorConfig {
+    /// Kill the child (and its whole process group) if it runs longer than this. `None` means
+    /// no limit, the previous unconditional-block behavior.
+    pub timeout: Option<Duration>,
+    /// When set, a missing or mismatched expected-output fixture is written to disk and reported
+    /// instead of failing the test - the "bless" workflow trybuild and compiletest use to
+    /// maintain their expected-error files. `None` preserves the old check-only behavior.
+    pub update_mode: Option<UpdateMode>,
+}
+
+/// How to handle a missing or mismatched expected-output fixture when `ExecutorConfig.update_mode`
+/// is set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpdateMode {
+    /// Write the actual normalized output next to the fixture, as `<fixture>.wip`, for the
+    /// author to review and rename into place rather than overwriting the checked-in file.
+    Wip,
+    /// Overwrite the fixture file itself with the actual normalized output.
+    Overwrite,
+}
+
+/// Outcome of a single fixture-backed check (e.g. `TestExecutor::check_compile_fail`) once
+/// `update_mode` is taken into account.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CompileFailOutcome {
+    /// Actual output matched the existing fixture - nothing to do.
+    Matched,
+    /// No fixture existed yet and `update_mode` was set, so one was written fresh.
+    Created(PathBuf),
+    /// An existing fixture's contents differed and `update_mode` was set, so it was overwritten
+    /// (or a `.wip` sibling was written, per `UpdateMode`).
+    Updated(PathBuf),
+}
+
 /// Execute a compiled test and capture results.
 ///
 /// This trait separates the cargo invocation and output parsing from
@@ -101,6 +181,46 @@ pub trait TestExecutor {
         project_dir: &Path,
         test_name: &str,
     ) -> Result<(bool, String), TestError>;
+
+    /// Run `cargo build` in `project_dir`, expecting it to fail, and compare its normalized
+    /// stderr against the normalized contents of `expected_stderr_path`.
+    ///
+    /// Returns `Ok(CompileFailOutcome::Matched)` when the build fails and the normalized output
+    /// matches. When no `update_mode` is configured (see `ExecutorConfig`), a missing or
+    /// mismatched fixture returns `TestError::Execution` carrying a unified diff (empty on the
+    /// "expected" side when no fixture exists yet). When `update_mode` is set, a missing or
+    /// mismatched fixture is written to disk instead and reported as `Created`/`Updated` rather
+    /// than failing. An unexpectedly *successful* build is always an error, `update_mode` or not:
+    /// there's no fixture to bless when the test isn't actually failing to compile.
+    fn check_compile_fail(
+        &self,
+        project_dir: &Path,
+        expected_stderr_path: &Path,
+    ) -> Result<CompileFailOutcome, TestError>;
+
+    /// Run every `#[test]` in `project_dir` once and return a structured, per-test result by
+    /// parsing libtest's `--format json` event stream, instead of scraping combined stdout for a
+    /// single pass/fail - the only way to tell individual tests apart when a batch project (see
+    /// `BatchHarnessGenerator`) runs many of them together.
+    ///
+    /// The default implementation reports that this executor doesn't support structured results;
+    /// only executors built for it (like `DefaultTestExecutor`) need to override it.
+    fn execute_batch(&self, _project_dir: &Path) -> Result<Vec<TestCaseResult>, TestError> {
+        Err(TestError::Execution(
+            "this test executor does not support per-test JSON result capture".to_string(),
+        ))
+    }
+}
+
+/// One test's outcome as reported by a libtest `--format json` event stream.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TestCaseResult {
+    pub name: String,
+    pub passed: bool,
+    /// Wall-clock time libtest reported for the test, in seconds, if `--report-time` was enabled.
+    pub duration: Option<f64>,
+    /// Captured stdout/panic message libtest attached to a failed test, if any.
+    pub stdout: String,
 }
 
 // ============================================================================
@@ -138,6 +258,11 @@ impl HarnessGenerator for DefaultHarnessGenerator {
         let mut codegen = IrCodegen::new();
         codegen.set_test_mode(true);
         codegen.set_test_function(&input.test_function_name);
+        if input.directives.ignore.is_some() || input.directives.should_panic.is_some() {
+            let mut directives = HashMap::new();
+            directives.insert(input.test_function_name.clone(), input.directives.clone());
+            codegen.set_test_directives(directives);
+        }
 
         let rust_code = codegen
             .try_generate(&ast)
@@ -145,7 +270,10 @@ impl HarnessGenerator for DefaultHarnessGenerator {
 
         let project_dir = PathBuf::from(format!("target/incan_tests/{}", input.test_function_name));
 
-        let generator = ProjectGenerator::new(&project_dir, "test_runner", true);
+        let mut generator = ProjectGenerator::new(&project_dir, "test_runner", true);
+        if let Some(edition) = &input.edition {
+            generator.set_edition(edition);
+        }
         generator
             .generate(&rust_code)
             .map_err(|e| TestError::ProjectGeneration(e.to_string()))?;
@@ -158,8 +286,129 @@ impl HarnessGenerator for DefaultHarnessGenerator {
     }
 }
 
+/// Batches every test in a suite into a single Cargo project so they share one dependency
+/// compile and one `target/` directory, modeled on trybuild's shared-project approach: one
+/// generated package, one manifest aggregating all needed dependencies, one build.
+///
+/// Each distinct source file becomes its own module under `src/`, with every `test_`-prefixed
+/// function in that file kept as `#[test]` (unlike `DefaultHarnessGenerator`, which isolates a
+/// single test per project). `TestExecutor` then runs `cargo test` once against the whole
+/// project and demultiplexes libtest's per-test results.
+pub struct BatchHarnessGenerator;
+
+impl BatchHarnessGenerator {
+    /// Turn a file stem into a unique Rust module name of ASCII letters, digits and `_` that does
+    /// not start with a digit. Rust keywords (e.g. a stem of `type`) are not escaped.
+    fn module_name(file_stem: &str, used: &mut HashMap<String, usize>) -> String {
+        let mut name: String = file_stem
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_lowercase() } else { '_' })
+            .collect();
+        if name.is_empty() || name.chars().next().is_some_and(|c| c.is_ascii_digit()) {
+            name = format!("m_{}", name);
+        }
+
+        // Keep bumping the suffix until it names no earlier module, so a stem that already ends
+        // in `_<n>` cannot collide with a suffixed duplicate.
+        let mut candidate = name.clone();
+        while used.contains_key(&candidate) {
+            let count = used.entry(name.clone()).or_insert(0);
+            *count += 1;
+            candidate = format!("{}_{}", name, count);
+        }
+        used.insert(candidate.clone(), 0);
+        candidate
+    }
+}
+
+impl HarnessGenerator for BatchHarnessGenerator {
+    fn generate_harness(&self, input: &HarnessInput) -> Result<HarnessOutput, TestError> {
+        DefaultHarnessGenerator.generate_harness(input)
+    }
+
+    fn generate_batch(&self, inputs: &[HarnessInput]) -> Result<BatchHarnessOutput, TestError> {
+        use crate::backend::{IrCodegen, ProjectGenerator};
+        use crate::frontend::{lexer, parser};
+
+        // Group inputs by source file: every test in the same file is generated together, with
+        // every `test_`-prefixed function marked `#[test]`, instead of regenerating the file once
+        // per test the way `DefaultHarnessGenerator` does.
+        let mut files: Vec<(&PathBuf, &str, Vec<&str>)> = Vec::new();
+        for input in inputs {
+            if let Some(entry) = files.iter_mut().find(|(path, _, _)| *path == &input.source_file) {
+                entry.2.push(&input.test_function_name);
+            } else {
+                files.push((&input.source_file, &input.source_code, vec![&input.test_function_name]));
+            }
+        }
+
+        let mut modules: HashMap<String, String> = HashMap::new();
+        let mut test_functions: HashMap<String, String> = HashMap::new();
+        let mut used_names: HashMap<String, usize> = HashMap::new();
+
+        for (source_file, source_code, test_names) in files {
+            let tokens = lexer::lex(source_code).map_err(|e| TestError::Lexer(format!("{:?}", e)))?;
+            let ast = parser::parse(&tokens).map_err(|e| TestError::Parser(format!("{:?}", e)))?;
+
+            let mut codegen = IrCodegen::new();
+            codegen.set_test_mode(true);
+            // Deliberately leave `test_function` unset: with no single test singled out, the
+            // emitter marks every `test_`-prefixed function in this file as `#[test]`.
+
+            let rust_code = codegen.try_generate(&ast).map_err(|e| TestError::Codegen(format!("{}", e)))?;
+
+            let file_stem = source_file.file_stem().map(|s| s.to_string_lossy().to_string()).unwrap_or_default();
+            let module = Self::module_name(&file_stem, &mut used_names);
+
+            for test_name in test_names {
+                test_functions.insert(test_name.to_string(), format!("{}::{}", module, test_name));
+            }
+            modules.insert(module, rust_code);
+        }
+
+        let project_dir = PathBuf::from("target/incan_tests/_batch");
+        let generator = ProjectGenerator::new(&project_dir, "incan_batch_tests", true);
+        generator
+            .generate_multi("#![allow(dead_code)]\n", &modules)
+            .map_err(|e| TestError::ProjectGeneration(e.to_string()))?;
+
+        Ok(BatchHarnessOutput {
+            project_dir,
+            test_functions,
+        })
+    }
+}
+
 /// Cargo test execution with output capture (current behavior).
-pub struct DefaultTestExecutor;
+pub struct DefaultTestExecutor {
+    config: ExecutorConfig,
+}
+
+impl DefaultTestExecutor {
+    pub fn new() -> Self {
+        Self {
+            config: ExecutorConfig::default(),
+        }
+    }
+
+    /// Kill any child process (and its process group) that runs longer than `timeout`.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.config.timeout = Some(timeout);
+        self
+    }
+
+    /// Bless missing/mismatched fixtures instead of failing on them - see `UpdateMode`.
+    pub fn with_update_mode(mut self, mode: UpdateMode) -> Self {
+        self.config.update_mode = Some(mode);
+        self
+    }
+}
+
+impl Default for DefaultTestExecutor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 impl TestExecutor for DefaultTestExecutor {
     fn execute_test(
@@ -167,18 +416,429 @@ impl TestExecutor for DefaultTestExecutor {
         project_dir: &Path,
         _test_name: &str,
     ) -> Result<(bool, String), TestError> {
-        let output = std::process::Command::new("cargo")
+        let mut command = std::process::Command::new("cargo");
+        command.arg("test").arg("--").arg("--nocapture").current_dir(project_dir);
+        let (status, stdout, stderr) = run_with_timeout(&mut command, self.config.timeout)?;
+
+        let combined = format!("{}\n{}", String::from_utf8_lossy(&stdout), String::from_utf8_lossy(&stderr));
+
+        Ok((status.success(), combined))
+    }
+
+    fn check_compile_fail(
+        &self,
+        project_dir: &Path,
+        expected_stderr_path: &Path,
+    ) -> Result<CompileFailOutcome, TestError> {
+        let mut command = std::process::Command::new("cargo");
+        command.arg("build").current_dir(project_dir);
+        let (status, _stdout, stderr) = run_with_timeout(&mut command, self.config.timeout)?;
+
+        let existing_raw = std::fs::read_to_string(expected_stderr_path).ok();
+        let expected = existing_raw.as_deref().map(|raw| normalize_stderr(raw, project_dir));
+
+        if status.success() {
+            return Err(TestError::Execution(format!(
+                "expected compilation to fail, but `cargo build` succeeded\n\n{}",
+                unified_diff(expected.as_deref().unwrap_or(""), "")
+            )));
+        }
+
+        let actual_raw = String::from_utf8_lossy(&stderr);
+        let actual = normalize_stderr(&actual_raw, project_dir);
+
+        if expected.as_deref() == Some(actual.as_str()) {
+            return Ok(CompileFailOutcome::Matched);
+        }
+
+        match self.config.update_mode {
+            Some(mode) => {
+                let target = match mode {
+                    UpdateMode::Overwrite => expected_stderr_path.to_path_buf(),
+                    UpdateMode::Wip => {
+                        let mut wip = expected_stderr_path.as_os_str().to_os_string();
+                        wip.push(".wip");
+                        PathBuf::from(wip)
+                    }
+                };
+                std::fs::write(&target, &actual)?;
+                Ok(if expected.is_some() {
+                    CompileFailOutcome::Updated(target)
+                } else {
+                    CompileFailOutcome::Created(target)
+                })
+            }
+            None => match expected {
+                Some(expected) => Err(TestError::Execution(format!(
+                    "compile-fail output did not match '{}'\n\n{}",
+                    expected_stderr_path.display(),
+                    unified_diff(&expected, &actual)
+                ))),
+                None => Err(TestError::Execution(format!(
+                    "no expected output fixture at '{}' (rerun with an update mode to create one)\n\n{}",
+                    expected_stderr_path.display(),
+                    unified_diff("", &actual)
+                ))),
+            },
+        }
+    }
+
+    fn execute_batch(&self, project_dir: &Path) -> Result<Vec<TestCaseResult>, TestError> {
+        // `--format json` is unstable libtest output; `RUSTC_BOOTSTRAP=1` lets it run on a
+        // stable toolchain the same way compiletest and rustdoc's doctest harness do.
+        let mut command = std::process::Command::new("cargo");
+        command
             .arg("test")
             .arg("--")
-            .arg("--nocapture")
-            .current_dir(project_dir)
-            .output()
-            .map_err(|e| TestError::Execution(format!("Failed to run cargo test: {}", e)))?;
+            .arg("-Z")
+            .arg("unstable-options")
+            .arg("--format")
+            .arg("json")
+            .arg("--report-time")
+            .env("RUSTC_BOOTSTRAP", "1")
+            .current_dir(project_dir);
+        let (_status, stdout, _stderr) = run_with_timeout(&mut command, self.config.timeout)?;
+
+        Ok(parse_libtest_json_events(&String::from_utf8_lossy(&stdout)))
+    }
+}
+
+/// Spawn `command` with piped stdout/stderr, killing its whole process group if it doesn't exit
+/// within `timeout` (no limit when `timeout` is `None`). Returns whatever output was captured
+/// either way.
+///
+/// Killing the whole process group - not just `command`'s direct child - matters because `cargo
+/// test`/`cargo build` fork further children (the compiled test binary, `rustc`); killing only
+/// the `cargo` process would leave those running, which is exactly the runaway-process leak a
+/// timeout is meant to prevent.
+fn run_with_timeout(
+    command: &mut std::process::Command,
+    timeout: Option<Duration>,
+) -> Result<(std::process::ExitStatus, Vec<u8>, Vec<u8>), TestError> {
+    use std::io::Read;
+    use std::process::Stdio;
+
+    command.stdout(Stdio::piped()).stderr(Stdio::piped());
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        // Make the child its own process group leader so the whole group can be killed at once.
+        command.process_group(0);
+    }
+
+    let mut child = command
+        .spawn()
+        .map_err(|e| TestError::Execution(format!("failed to spawn process: {}", e)))?;
+
+    let (Some(mut stdout_pipe), Some(mut stderr_pipe)) = (child.stdout.take(), child.stderr.take()) else {
+        return Err(TestError::Execution("failed to capture process output".to_string()));
+    };
+    let stdout_thread = std::thread::spawn(move || {
+        let mut buf = Vec::new();
+        let _ = stdout_pipe.read_to_end(&mut buf);
+        buf
+    });
+    let stderr_thread = std::thread::spawn(move || {
+        let mut buf = Vec::new();
+        let _ = stderr_pipe.read_to_end(&mut buf);
+        buf
+    });
+
+    let deadline = timeout.map(|t| std::time::Instant::now() + t);
+    let status = loop {
+        if let Some(status) =
+            child.try_wait().map_err(|e| TestError::Execution(format!("failed to poll process: {}", e)))?
+        {
+            break Some(status);
+        }
+        if deadline.is_some_and(|d| std::time::Instant::now() >= d) {
+            break None;
+        }
+        std::thread::sleep(Duration::from_millis(50));
+    };
+
+    let status = match status {
+        Some(status) => status,
+        None => {
+            kill_process_group(child.id());
+            let _ = child.wait();
+            let stdout = stdout_thread.join().unwrap_or_default();
+            let stderr = stderr_thread.join().unwrap_or_default();
+            return Err(TestError::Timeout(format!(
+                "process timed out after {:?}\n\nstdout:\n{}\n\nstderr:\n{}",
+                timeout.unwrap_or_default(),
+                String::from_utf8_lossy(&stdout),
+                String::from_utf8_lossy(&stderr)
+            )));
+        }
+    };
+
+    let stdout = stdout_thread.join().unwrap_or_default();
+    let stderr = stderr_thread.join().unwrap_or_default();
+    Ok((status, stdout, stderr))
+}
+
+#[cfg(unix)]
+fn kill_process_group(pid: u32) {
+    // Negative pid targets the whole process group rather than just the `cargo` process itself.
+    let _ = std::process::Command::new("kill").arg("-9").arg(format!("-{}", pid)).output();
+}
+
+#[cfg(not(unix))]
+fn kill_process_group(pid: u32) {
+    // Best effort only: without process groups, this can only reach the `cargo` process itself,
+    // not whatever it forked (the compiled test binary, `rustc`).
+    let _ = std::process::Command::new("taskkill").arg("/F").arg("/PID").arg(pid.to_string()).output();
+}
+
+// ============================================================================
+// libtest JSON event parsing
+// ============================================================================
+
+/// Parse libtest's `--format json` event stream (one JSON object per line) into a per-test
+/// `TestCaseResult` list, keeping only `{"type":"test", ...}` events with a terminal `event` of
+/// `"ok"` or `"failed"` (`"started"` events and `{"type":"suite", ...}` summary lines are
+/// ignored - this only needs the per-test outcomes, not the aggregate libtest prints itself).
+///
+/// No JSON crate is available in this tree, so this is a small hand-rolled parser scoped to the
+/// flat, single-level object shape libtest actually emits - not a general JSON parser.
+fn parse_libtest_json_events(output: &str) -> Vec<TestCaseResult> {
+    let mut results = Vec::new();
+    for line in output.lines() {
+        let line = line.trim();
+        if !line.starts_with('{') || !line.ends_with('}') {
+            continue;
+        }
+        if json_string_field(line, "type").as_deref() != Some("test") {
+            continue;
+        }
+        let Some(event) = json_string_field(line, "event") else {
+            continue;
+        };
+        let passed = match event.as_str() {
+            "ok" => true,
+            "failed" => false,
+            _ => continue,
+        };
+        let Some(name) = json_string_field(line, "name") else {
+            continue;
+        };
+
+        results.push(TestCaseResult {
+            name,
+            passed,
+            duration: json_number_field(line, "exec_time"),
+            stdout: json_string_field(line, "stdout").unwrap_or_default(),
+        });
+    }
+    results
+}
+
+/// Find `"key":"value"` (with optional whitespace around the colon) in a flat JSON object line
+/// and return `value`, unescaping `\"`, `\\`, and `\n`.
+fn json_string_field(line: &str, key: &str) -> Option<String> {
+    let needle = format!("\"{}\"", key);
+    let key_pos = line.find(&needle)?;
+    let after_key = &line[key_pos + needle.len()..];
+    let colon_pos = after_key.find(':')?;
+    let after_colon = after_key[colon_pos + 1..].trim_start();
+    let value_start = after_colon.strip_prefix('"')?;
+
+    let mut out = String::new();
+    let mut chars = value_start.chars();
+    loop {
+        match chars.next()? {
+            '"' => return Some(out),
+            '\\' => match chars.next()? {
+                'n' => out.push('\n'),
+                't' => out.push('\t'),
+                '"' => out.push('"'),
+                '\\' => out.push('\\'),
+                other => out.push(other),
+            },
+            c => out.push(c),
+        }
+    }
+}
+
+/// Find `"key":<number>` in a flat JSON object line and return the number.
+fn json_number_field(line: &str, key: &str) -> Option<f64> {
+    let needle = format!("\"{}\"", key);
+    let key_pos = line.find(&needle)?;
+    let after_key = &line[key_pos + needle.len()..];
+    let colon_pos = after_key.find(':')?;
+    let after_colon = after_key[colon_pos + 1..].trim_start();
+
+    let end = after_colon
+        .find(|c: char| c == ',' || c == '}' || c.is_whitespace())
+        .unwrap_or(after_colon.len());
+    after_colon[..end].parse::<f64>().ok()
+}
+
+// ============================================================================
+// Compile-fail stderr normalization and diffing
+// ============================================================================
+
+/// Normalize captured `rustc`/`cargo` stderr so a `.stderr` snapshot stays portable across
+/// machines and toolchains: strip ANSI color codes, rewrite the generated project directory's
+/// absolute path to the stable placeholder `$DIR`, normalize `--> $DIR/...:LINE:COL` locations
+/// (the generated Rust is synthesized, so exact line/column is never worth pinning), collapse
+/// rustc-version-specific `= note: ...` blocks, and trim trailing whitespace and blank-line runs.
+fn normalize_stderr(stderr: &str, project_dir: &Path) -> String {
+    let no_ansi = strip_ansi_codes(stderr);
+    let no_abs_paths = rewrite_project_dir(&no_ansi, project_dir);
+    let no_locations = normalize_location_lines(&no_abs_paths);
+    let no_notes = collapse_note_blocks(&no_locations);
+    trim_and_collapse_blank_lines(&no_notes)
+}
+
+/// Strip `ESC [ ... <letter>` ANSI escape sequences (SGR color codes, the only kind `rustc`
+/// emits for `--color=always`/terminal output).
+fn strip_ansi_codes(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\u{1b}' && chars.peek() == Some(&'[') {
+            chars.next();
+            for next in chars.by_ref() {
+                if next.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+            continue;
+        }
+        out.push(c);
+    }
+    out
+}
 
-        let stdout = String::from_utf8_lossy(&output.stdout).to_string();
-        let stderr = String::from_utf8_lossy(&output.stderr).to_string();
-        let combined = format!("{}\n{}", stdout, stderr);
+/// Replace every occurrence of `project_dir`'s absolute path (canonicalized and as-given) with
+/// the stable placeholder `$DIR`.
+fn rewrite_project_dir(text: &str, project_dir: &Path) -> String {
+    let mut result = text.to_string();
+    if let Ok(canonical) = project_dir.canonicalize() {
+        result = result.replace(canonical.to_string_lossy().as_ref(), "$DIR");
+    }
+    result.replace(project_dir.to_string_lossy().as_ref(), "$DIR")
+}
+
+/// Replace the trailing `:LINE:COL` of every `--> path/to/file.rs:LINE:COL` location line with
+/// the stable placeholder `:LINE:COL`, since those point into codegen'd Rust whose exact position
+/// isn't meaningful to pin in a snapshot.
+fn normalize_location_lines(text: &str) -> String {
+    text.lines().map(normalize_location_line).collect::<Vec<_>>().join("\n")
+}
+
+fn normalize_location_line(line: &str) -> String {
+    let indent_len = line.len() - line.trim_start().len();
+    let (indent, rest) = line.split_at(indent_len);
+    let Some(path_and_position) = rest.strip_prefix("--> ") else {
+        return line.to_string();
+    };
+
+    let Some((before_col, col)) = path_and_position.rsplit_once(':') else {
+        return line.to_string();
+    };
+    if col.is_empty() || !col.chars().all(|c| c.is_ascii_digit()) {
+        return line.to_string();
+    }
+    let Some((path, line_num)) = before_col.rsplit_once(':') else {
+        return line.to_string();
+    };
+    if line_num.is_empty() || !line_num.chars().all(|c| c.is_ascii_digit()) {
+        return line.to_string();
+    }
 
-        Ok((output.status.success(), combined))
+    format!("{indent}--> {path}:LINE:COL")
+}
+
+/// Collapse every run of consecutive `= note: ...` lines into a single placeholder line, since
+/// their exact wording (std/rustc version info, layout notes, etc.) varies across toolchains.
+fn collapse_note_blocks(text: &str) -> String {
+    let mut out: Vec<&str> = Vec::new();
+    let mut in_note_block = false;
+    for line in text.lines() {
+        if line.trim_start().starts_with("= note:") {
+            if !in_note_block {
+                out.push("= note: (elided; version-specific)");
+                in_note_block = true;
+            }
+        } else {
+            in_note_block = false;
+            out.push(line);
+        }
+    }
+    out.join("\n")
+}
+
+/// Trim trailing whitespace from every line, collapse runs of blank lines to one, and drop
+/// trailing blank lines entirely.
+fn trim_and_collapse_blank_lines(text: &str) -> String {
+    let mut out: Vec<String> = Vec::new();
+    let mut blank_run = false;
+    for line in text.lines() {
+        let trimmed = line.trim_end();
+        if trimmed.is_empty() {
+            if blank_run {
+                continue;
+            }
+            blank_run = true;
+        } else {
+            blank_run = false;
+        }
+        out.push(trimmed.to_string());
+    }
+    while out.last().is_some_and(|l| l.is_empty()) {
+        out.pop();
+    }
+    out.join("\n")
+}
+
+/// A line-level unified diff between `expected` and `actual` (`-`/`+`/` ` prefixed lines, no hunk
+/// folding - compile-fail snapshots are short enough that full output reads better than headers).
+/// Uses a longest-common-subsequence alignment, which is cheap enough at this size.
+fn unified_diff(expected: &str, actual: &str) -> String {
+    let a: Vec<&str> = expected.lines().collect();
+    let b: Vec<&str> = actual.lines().collect();
+    let (n, m) = (a.len(), b.len());
+
+    let mut lcs = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if a[i] == b[j] { lcs[i + 1][j + 1] + 1 } else { lcs[i + 1][j].max(lcs[i][j + 1]) };
+        }
+    }
+
+    let mut out = String::new();
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if a[i] == b[j] {
+            out.push_str("  ");
+            out.push_str(a[i]);
+            out.push('\n');
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            out.push_str("- ");
+            out.push_str(a[i]);
+            out.push('\n');
+            i += 1;
+        } else {
+            out.push_str("+ ");
+            out.push_str(b[j]);
+            out.push('\n');
+            j += 1;
+        }
+    }
+    for line in &a[i..n] {
+        out.push_str("- ");
+        out.push_str(line);
+        out.push('\n');
+    }
+    for line in &b[j..m] {
+        out.push_str("+ ");
+        out.push_str(line);
+        out.push('\n');
     }
+    out
 }
@@ -21,13 +21,11 @@ use std::fs;
 use std::path::{Path, PathBuf};
 use std::time::{Duration, Instant};
 
-use crate::backend::{IrCodegen, ProjectGenerator};
 use crate::frontend::{lexer, parser};
 
-#[allow(unused_imports)]
 use super::test_interfaces::{
-    DefaultHarnessGenerator, DefaultTestDiscovery, DefaultTestExecutor, HarnessGenerator, HarnessInput, HarnessOutput,
-    TestDiscovery, TestExecutor,
+    CompileFailOutcome, DefaultHarnessGenerator, DefaultTestDiscovery, DefaultTestExecutor, ExpectedOutcome,
+    HarnessGenerator, HarnessInput, TestDiscovery, TestExecutor, UpdateMode,
 };
 use super::{CliError, CliResult, ExitCode};
 
@@ -178,6 +176,14 @@ pub enum TestMarker {
     XFail(String),
     Slow,
     Parametrize(String, Vec<String>),
+    /// Still generated and compile-checked, but not executed (mirrors Rust's `#[ignore]`).
+    Ignore(String),
+    /// Expected to panic, optionally with a specific expected message (mirrors `#[should_panic]`).
+    ShouldPanic(Option<String>),
+    /// Expected to fail to compile rather than run.
+    CompileFail,
+    /// Target a specific Rust edition when generating the test's Cargo project.
+    Edition(String),
 }
 
 /// Fixture scope determines lifecycle
@@ -221,16 +227,23 @@ pub struct DiscoveryResult {
 }
 
 /// Run all tests in the given path.
+///
+/// `bless` rewrites any mismatched/missing `compile_fail` expected-stderr fixture instead of
+/// failing the test on it (see `UpdateMode::Overwrite`).
 pub fn run_tests(
     path: &str,
     verbose: bool,
     stop_on_fail: bool,
     include_slow: bool,
     filter: Option<&str>,
+    bless: bool,
 ) -> CliResult<ExitCode> {
     let start_time = Instant::now();
 
-    let test_files = discover_test_files(Path::new(path));
+    let discovery = DefaultTestDiscovery;
+    let test_files = discovery
+        .discover_test_files(Path::new(path))
+        .map_err(|e| CliError::failure(e.to_string()))?;
 
     if test_files.is_empty() {
         return Err(CliError::failure(format!(
@@ -243,7 +256,7 @@ pub fn run_tests(
     let mut all_fixtures: HashMap<String, FixtureInfo> = HashMap::new();
 
     for file_path in &test_files {
-        match discover_tests_and_fixtures(file_path) {
+        match discovery.discover_tests_and_fixtures(file_path) {
             Ok(result) => {
                 all_tests.extend(result.tests);
                 for fixture in result.fixtures {
@@ -331,7 +344,7 @@ pub fn run_tests(
 
         let is_xfail = test.markers.iter().any(|m| matches!(m, TestMarker::XFail(_)));
 
-        let result = run_single_test(&test);
+        let result = run_single_test(&test, bless);
 
         let result = if is_xfail {
             match result {
@@ -691,6 +704,22 @@ fn extract_test_markers(
             "parametrize" => {
                 markers.push(TestMarker::Parametrize(String::new(), Vec::new()));
             }
+            "ignore" => {
+                let reason = extract_string_arg(&dec.node.args).unwrap_or_default();
+                markers.push(TestMarker::Ignore(reason));
+            }
+            "should_panic" => {
+                let expected = extract_named_string_arg(&dec.node.args, "expected");
+                markers.push(TestMarker::ShouldPanic(expected));
+            }
+            "compile_fail" => {
+                markers.push(TestMarker::CompileFail);
+            }
+            "edition" => {
+                if let Some(edition) = extract_string_arg(&dec.node.args) {
+                    markers.push(TestMarker::Edition(edition));
+                }
+            }
             _ => {}
         }
     }
@@ -707,7 +736,28 @@ fn extract_string_arg(args: &[crate::frontend::ast::DecoratorArg]) -> Option<Str
     None
 }
 
-fn run_single_test(test: &TestInfo) -> TestResult {
+fn extract_named_string_arg(args: &[crate::frontend::ast::DecoratorArg], name: &str) -> Option<String> {
+    for arg in args {
+        if let crate::frontend::ast::DecoratorArg::Named(arg_name, value) = arg {
+            if arg_name == name {
+                if let crate::frontend::ast::DecoratorArgValue::Expr(expr) = value {
+                    if let crate::frontend::ast::Expr::Literal(crate::frontend::ast::Literal::String(s)) = &expr.node
+                    {
+                        return Some(s.clone());
+                    }
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Run a single test through the `HarnessGenerator`/`TestExecutor` I/O boundary (see
+/// `test_interfaces`), rather than invoking `lexer`/`parser`/`IrCodegen`/`cargo` directly.
+///
+/// `bless` rewrites a mismatched/missing `compile_fail` expected-stderr fixture instead of
+/// failing the test on it.
+fn run_single_test(test: &TestInfo, bless: bool) -> TestResult {
     let start = Instant::now();
 
     let source = match fs::read_to_string(&test.file_path) {
@@ -717,64 +767,100 @@ fn run_single_test(test: &TestInfo) -> TestResult {
         }
     };
 
-    let tokens = match lexer::lex(&source) {
-        Ok(t) => t,
-        Err(e) => return TestResult::Failed(start.elapsed(), format!("Lexer error: {:?}", e)),
+    let ignore_reason = test.markers.iter().find_map(|m| match m {
+        TestMarker::Ignore(reason) => Some(reason.clone()),
+        _ => None,
+    });
+    let should_panic = test.markers.iter().find_map(|m| match m {
+        TestMarker::ShouldPanic(expected) => Some(expected.clone()),
+        _ => None,
+    });
+    let compile_fail = test.markers.iter().any(|m| matches!(m, TestMarker::CompileFail));
+    let edition = test.markers.iter().find_map(|m| match m {
+        TestMarker::Edition(edition) => Some(edition.clone()),
+        _ => None,
+    });
+
+    let harness_input = HarnessInput {
+        source_file: test.file_path.clone(),
+        test_function_name: test.function_name.clone(),
+        source_code: source,
+        expected_outcome: if compile_fail { ExpectedOutcome::CompileFail } else { ExpectedOutcome::Pass },
+        directives: crate::backend::ir::emit::TestDirectives {
+            ignore: ignore_reason.clone(),
+            // `should_panic` is already `Option<Option<String>>` (present-with-no-message vs
+            // present-with-an-expected-message vs absent) - don't re-wrap it in another `Some`.
+            should_panic: should_panic.clone(),
+        },
+        edition,
     };
 
-    let ast = match parser::parse(&tokens) {
-        Ok(a) => a,
-        Err(e) => return TestResult::Failed(start.elapsed(), format!("Parser error: {:?}", e)),
+    let harness = match DefaultHarnessGenerator.generate_harness(&harness_input) {
+        Ok(harness) => harness,
+        Err(e) => return TestResult::Failed(start.elapsed(), e.to_string()),
     };
 
-    let mut codegen = IrCodegen::new();
-    codegen.set_test_mode(true);
-    codegen.set_test_function(&test.function_name);
-
-    let rust_code = match codegen.try_generate(&ast) {
-        Ok(code) => code,
-        Err(e) => {
-            return TestResult::Failed(start.elapsed(), format!("Code generation error: {}", e));
-        }
+    let executor = if bless {
+        DefaultTestExecutor::new().with_update_mode(UpdateMode::Overwrite)
+    } else {
+        DefaultTestExecutor::new()
     };
 
-    let temp_dir = format!("target/incan_tests/{}", test.function_name);
-    let generator = ProjectGenerator::new(&temp_dir, "test_runner", true);
+    // `compile_fail` tests are expected to fail `cargo build` with a specific, checked-in
+    // diagnostic - there's no compiled test binary to execute at all if codegen did its job.
+    if compile_fail {
+        let expected_stderr_path = compile_fail_fixture_path(test);
+        return match executor.check_compile_fail(&harness.project_dir, &expected_stderr_path) {
+            Ok(CompileFailOutcome::Matched) => TestResult::Passed(start.elapsed()),
+            Ok(CompileFailOutcome::Created(path)) => {
+                eprintln!("  (bless) wrote new fixture {}", path.display());
+                TestResult::Passed(start.elapsed())
+            }
+            Ok(CompileFailOutcome::Updated(path)) => {
+                eprintln!("  (bless) updated fixture {}", path.display());
+                TestResult::Passed(start.elapsed())
+            }
+            Err(e) => TestResult::Failed(start.elapsed(), e.to_string()),
+        };
+    }
 
-    if let Err(e) = generator.generate(&rust_code) {
-        return TestResult::Failed(start.elapsed(), format!("Failed to generate project: {}", e));
+    // `ignore` tests are still compile-checked, just not executed.
+    if let Some(reason) = ignore_reason {
+        let output = std::process::Command::new("cargo").arg("build").current_dir(&harness.project_dir).output();
+        return match output {
+            Ok(output) if output.status.success() => TestResult::Skipped(reason),
+            Ok(output) => {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                TestResult::Failed(start.elapsed(), format!("Failed to compile: {}", stderr))
+            }
+            Err(e) => TestResult::Failed(start.elapsed(), format!("Failed to run build: {}", e)),
+        };
     }
 
-    let output = std::process::Command::new("cargo")
-        .arg("test")
-        .arg("--")
-        .arg("--nocapture")
-        .current_dir(&temp_dir)
-        .output();
-
-    match output {
-        Ok(output) => {
-            let duration = start.elapsed();
-            let stdout = String::from_utf8_lossy(&output.stdout);
-            let stderr = String::from_utf8_lossy(&output.stderr);
-
-            if output.status.success() {
-                TestResult::Passed(duration)
+    match executor.execute_test(&harness.project_dir, &test.function_name) {
+        Ok((true, _)) => TestResult::Passed(start.elapsed()),
+        Ok((false, output)) => {
+            let msg = if output.contains("assertion") {
+                extract_assertion_error(&output)
+            } else if output.contains("panicked") {
+                extract_panic_message(&output)
             } else {
-                let msg = if stderr.contains("assertion") {
-                    extract_assertion_error(&stderr)
-                } else if stdout.contains("panicked") {
-                    extract_panic_message(&stdout)
-                } else {
-                    format!("Test failed\n{}\n{}", stdout, stderr)
-                };
-                TestResult::Failed(duration, msg)
-            }
+                format!("Test failed\n{}", output)
+            };
+            TestResult::Failed(start.elapsed(), msg)
         }
-        Err(e) => TestResult::Failed(start.elapsed(), format!("Failed to run test: {}", e)),
+        Err(e) => TestResult::Failed(start.elapsed(), e.to_string()),
     }
 }
 
+/// Where a `compile_fail` test's checked-in expected-stderr fixture lives: alongside the source
+/// file, named after both the file and the specific test function so several `compile_fail` tests
+/// in the same file don't collide.
+fn compile_fail_fixture_path(test: &TestInfo) -> PathBuf {
+    let stem = test.file_path.file_stem().and_then(|s| s.to_str()).unwrap_or("test");
+    test.file_path.with_file_name(format!("{}.{}.stderr", stem, test.function_name))
+}
+
 fn extract_assertion_error(stderr: &str) -> String {
     for line in stderr.lines() {
         if line.contains("assertion") || line.contains("AssertionError") {
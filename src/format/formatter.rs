@@ -306,6 +306,11 @@ impl Formatter {
     }
     
     fn format_enum(&mut self, en: &EnumDecl) {
+        // Decorators
+        for dec in &en.decorators {
+            self.format_decorator(&dec.node);
+        }
+
         // enum Name[T]:
         self.writer.write("enum ");
         self.writer.write(&en.name);
@@ -340,6 +345,11 @@ impl Formatter {
     }
     
     fn format_newtype(&mut self, nt: &NewtypeDecl) {
+        // Decorators
+        for dec in &nt.decorators {
+            self.format_decorator(&dec.node);
+        }
+
         // type Name = newtype underlying
         self.writer.write("type ");
         self.writer.write(&nt.name);
@@ -554,6 +564,19 @@ impl Formatter {
             }
             Type::SelfType => self.writer.write("Self"),
             Type::Unit => self.writer.write("None"),
+            Type::Optional(inner) => {
+                self.writer.write("Optional[");
+                self.format_type(&inner.node);
+                self.writer.write("]");
+            }
+            Type::Union(members) => {
+                for (i, m) in members.iter().enumerate() {
+                    if i > 0 {
+                        self.writer.write(" | ");
+                    }
+                    self.format_type(&m.node);
+                }
+            }
         }
     }
     
@@ -868,14 +891,7 @@ impl Formatter {
             Expr::ListComp(comp) => {
                 self.writer.write("[");
                 self.format_expr(&comp.expr.node);
-                self.writer.write(" for ");
-                self.writer.write(&comp.var);
-                self.writer.write(" in ");
-                self.format_expr(&comp.iter.node);
-                if let Some(filter) = &comp.filter {
-                    self.writer.write(" if ");
-                    self.format_expr(&filter.node);
-                }
+                self.format_comp_clauses(&comp.clauses);
                 self.writer.write("]");
             }
             Expr::DictComp(comp) => {
@@ -883,16 +899,21 @@ impl Formatter {
                 self.format_expr(&comp.key.node);
                 self.writer.write(": ");
                 self.format_expr(&comp.value.node);
-                self.writer.write(" for ");
-                self.writer.write(&comp.var);
-                self.writer.write(" in ");
-                self.format_expr(&comp.iter.node);
-                if let Some(filter) = &comp.filter {
-                    self.writer.write(" if ");
-                    self.format_expr(&filter.node);
-                }
+                self.format_comp_clauses(&comp.clauses);
                 self.writer.write("}");
             }
+            Expr::SetComp(comp) => {
+                self.writer.write("{");
+                self.format_expr(&comp.expr.node);
+                self.format_comp_clauses(&comp.clauses);
+                self.writer.write("}");
+            }
+            Expr::GenExp(comp) => {
+                self.writer.write("(");
+                self.format_expr(&comp.expr.node);
+                self.format_comp_clauses(&comp.clauses);
+                self.writer.write(")");
+            }
             Expr::Yield(inner) => {
                 self.writer.write("yield");
                 if let Some(inner) = inner {
@@ -912,6 +933,23 @@ impl Formatter {
         }
     }
     
+    fn format_comp_clauses(&mut self, clauses: &[CompClause]) {
+        for clause in clauses {
+            match clause {
+                CompClause::For { var, iter } => {
+                    self.writer.write(" for ");
+                    self.writer.write(var);
+                    self.writer.write(" in ");
+                    self.format_expr(&iter.node);
+                }
+                CompClause::If(cond) => {
+                    self.writer.write(" if ");
+                    self.format_expr(&cond.node);
+                }
+            }
+        }
+    }
+
     fn format_literal(&mut self, lit: &Literal) {
         match lit {
             Literal::Int(n) => self.writer.write(&n.to_string()),
@@ -1009,9 +1047,9 @@ impl Formatter {
             Pattern::Wildcard => self.writer.write("_"),
             Pattern::Binding(name) => self.writer.write(name),
             Pattern::Literal(lit) => self.format_literal(lit),
-            Pattern::Constructor(name, patterns) => {
+            Pattern::Constructor(name, patterns, keyword_patterns) => {
                 self.writer.write(name);
-                if !patterns.is_empty() {
+                if !patterns.is_empty() || !keyword_patterns.is_empty() {
                     self.writer.write("(");
                     for (i, p) in patterns.iter().enumerate() {
                         if i > 0 {
@@ -1019,6 +1057,14 @@ impl Formatter {
                         }
                         self.format_pattern(&p.node);
                     }
+                    for (i, (field, p)) in keyword_patterns.iter().enumerate() {
+                        if i > 0 || !patterns.is_empty() {
+                            self.writer.write(", ");
+                        }
+                        self.writer.write(field);
+                        self.writer.write("=");
+                        self.format_pattern(&p.node);
+                    }
                     self.writer.write(")");
                 }
             }
@@ -1032,6 +1078,65 @@ impl Formatter {
                 }
                 self.writer.write(")");
             }
+            Pattern::Sequence(seq) => {
+                self.writer.write("[");
+                let mut first = true;
+                for p in &seq.prefix {
+                    if !first {
+                        self.writer.write(", ");
+                    }
+                    first = false;
+                    self.format_pattern(&p.node);
+                }
+                if let Some(rest) = &seq.rest {
+                    if !first {
+                        self.writer.write(", ");
+                    }
+                    first = false;
+                    self.writer.write("*");
+                    self.writer.write(rest.as_deref().unwrap_or("_"));
+                }
+                for p in &seq.suffix {
+                    if !first {
+                        self.writer.write(", ");
+                    }
+                    first = false;
+                    self.format_pattern(&p.node);
+                }
+                self.writer.write("]");
+            }
+            Pattern::Mapping(mapping) => {
+                self.writer.write("{");
+                for (i, (key, value)) in mapping.entries.iter().enumerate() {
+                    if i > 0 {
+                        self.writer.write(", ");
+                    }
+                    self.format_expr(&key.node);
+                    self.writer.write(": ");
+                    self.format_pattern(&value.node);
+                }
+                if let Some(rest) = &mapping.rest {
+                    if !mapping.entries.is_empty() {
+                        self.writer.write(", ");
+                    }
+                    self.writer.write("**");
+                    self.writer.write(rest);
+                }
+                self.writer.write("}");
+            }
+            Pattern::Or(patterns) => {
+                for (i, p) in patterns.iter().enumerate() {
+                    if i > 0 {
+                        self.writer.write(" | ");
+                    }
+                    self.format_pattern(&p.node);
+                }
+            }
+            Pattern::As(inner, name) => {
+                self.format_pattern(&inner.node);
+                self.writer.write(" as ");
+                self.writer.write(name);
+            }
         }
     }
 }
@@ -18,6 +18,17 @@ pub struct CompileError {
     pub kind: ErrorKind,
     pub notes: Vec<String>,
     pub hints: Vec<String>,
+    /// Stable, cross-referenceable identifier (e.g. `E0308`), if the catalog assigned one.
+    ///
+    /// Look codes up with [`explain`] or via `incan --explain <CODE>` for a longer writeup.
+    pub code: Option<&'static str>,
+    /// Structured, tooling-applyable fixes, as opposed to the free-text `hints` above.
+    pub suggestions: Vec<Suggestion>,
+    /// Fluent message identifier for [`locale`] lookup, for catalog entries that have been
+    /// migrated off hard-coded English. `message` remains the English fallback either way.
+    pub message_id: Option<&'static str>,
+    /// Named arguments for `message_id`'s Fluent template (e.g. `("type", "int")`).
+    pub message_args: Vec<(&'static str, String)>,
 }
 
 impl CompileError {
@@ -28,6 +39,10 @@ impl CompileError {
             kind: ErrorKind::Error,
             notes: Vec::new(),
             hints: Vec::new(),
+            code: None,
+            suggestions: Vec::new(),
+            message_id: None,
+            message_args: Vec::new(),
         }
     }
 
@@ -38,6 +53,10 @@ impl CompileError {
             kind: ErrorKind::Syntax,
             notes: Vec::new(),
             hints: Vec::new(),
+            code: None,
+            suggestions: Vec::new(),
+            message_id: None,
+            message_args: Vec::new(),
         }
     }
 
@@ -48,6 +67,10 @@ impl CompileError {
             kind: ErrorKind::Type,
             notes: Vec::new(),
             hints: Vec::new(),
+            code: None,
+            suggestions: Vec::new(),
+            message_id: None,
+            message_args: Vec::new(),
         }
     }
 
@@ -60,6 +83,73 @@ impl CompileError {
         self.hints.push(hint.into());
         self
     }
+
+    /// Attach a stable error code from the [`codes`] registry.
+    pub fn with_code(mut self, code: &'static str) -> Self {
+        self.code = Some(code);
+        self
+    }
+
+    /// Attach a structured, machine-applyable fix.
+    pub fn with_suggestion(mut self, suggestion: Suggestion) -> Self {
+        self.suggestions.push(suggestion);
+        self
+    }
+
+    /// Mark this error as localizable: `id` is looked up in the active [`locale`] bundle and
+    /// rendered with `args`, falling back to the already-English `message` when the bundle
+    /// has no entry for `id` in the active locale (including the compiled-in `en` bundle).
+    pub fn with_message_id(
+        mut self,
+        id: &'static str,
+        args: impl IntoIterator<Item = (&'static str, String)>,
+    ) -> Self {
+        self.message_id = Some(id);
+        self.message_args = args.into_iter().collect();
+        self
+    }
+}
+
+/// How confident the compiler is that a [`Suggestion`] is correct, mirroring rustc's
+/// `rustc_errors::Applicability`. Only `MachineApplicable` suggestions should be applied
+/// automatically by tooling like `incan --fix`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Applicability {
+    /// The suggestion is definitely what the user meant; safe to apply automatically.
+    MachineApplicable,
+    /// The suggestion is probably right, but could change semantics; ask before applying.
+    MaybeIncorrect,
+    /// The suggestion contains placeholder text that must be filled in by hand.
+    HasPlaceholders,
+    /// The suggestion's correctness hasn't been classified.
+    Unspecified,
+}
+
+/// A structured, machine-applyable code fix: replace the source text in `span` with
+/// `replacement`. Mirrors rustc's structured suggestions, turning free-text `hints` into
+/// edits that editors, LSP servers, or `incan --fix` can apply directly.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Suggestion {
+    pub span: Span,
+    pub replacement: String,
+    pub message: String,
+    pub applicability: Applicability,
+}
+
+impl Suggestion {
+    pub fn new(
+        message: impl Into<String>,
+        span: Span,
+        replacement: impl Into<String>,
+        applicability: Applicability,
+    ) -> Self {
+        Self {
+            span,
+            replacement: replacement.into(),
+            message: message.into(),
+            applicability,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -89,6 +179,7 @@ impl std::fmt::Display for ErrorKind {
 /// into a Result instead of printing immediately.
 pub fn format_error(file_name: &str, source: &str, error: &CompileError) -> String {
     let (line_num, col_num, line_text) = get_line_info(source, error.span.start);
+    let (end_line_num, _, _) = get_line_info(source, error.span.end.saturating_sub(1).max(error.span.start));
 
     // Color codes
     let red = "\x1b[31m";
@@ -105,11 +196,18 @@ pub fn format_error(file_name: &str, source: &str, error: &CompileError) -> Stri
     let mut out = String::new();
 
     // Header
-    out.push_str(&format!(
-        "{bold}{kind_color}{kind}{reset}{bold}: {message}{reset}\n",
-        kind = error.kind,
-        message = error.message,
-    ));
+    match error.code {
+        Some(code) => out.push_str(&format!(
+            "{bold}{kind_color}{kind}[{code}]{reset}{bold}: {message}{reset}\n",
+            kind = error.kind,
+            message = error.message,
+        )),
+        None => out.push_str(&format!(
+            "{bold}{kind_color}{kind}{reset}{bold}: {message}{reset}\n",
+            kind = error.kind,
+            message = error.message,
+        )),
+    }
 
     // Location
     out.push_str(&format!(
@@ -119,39 +217,102 @@ pub fn format_error(file_name: &str, source: &str, error: &CompileError) -> Stri
         col = col_num,
     ));
 
-    // Source line with line number
-    let line_num_width = format!("{}", line_num).len();
+    // Source line(s) with line numbers, and the caret/underline pointing to the error.
+    let line_num_width = format!("{}", end_line_num).len();
     out.push_str(&format!(
         "  {cyan}{:>width$} |{reset}\n",
         "",
         width = line_num_width
     ));
-    out.push_str(&format!(
-        "  {cyan}{:>width$} |{reset} {}\n",
-        line_num,
-        line_text,
-        width = line_num_width
-    ));
 
-    // Caret pointing to error
-    let underline_len = if error.span.end > error.span.start && col_num > 0 {
-        let start_offset = error.span.start.saturating_sub(col_num.saturating_sub(1));
-        let end_in_line = error.span.end.saturating_sub(start_offset);
-        end_in_line
-            .min(line_text.len())
-            .saturating_sub(col_num.saturating_sub(1))
-            .max(1)
+    if end_line_num <= line_num {
+        // Single-line fast path.
+        out.push_str(&format!(
+            "  {cyan}{:>width$} |{reset} {}\n",
+            line_num,
+            line_text,
+            width = line_num_width
+        ));
+
+        // `col_num` is already a display column (see `get_line_info`), so the underline
+        // width must be measured the same way over the highlighted char range rather
+        // than via byte lengths.
+        let underline_len = if error.span.end > error.span.start {
+            let line_start = source[..error.span.start.min(source.len())]
+                .rfind('\n')
+                .map(|i| i + 1)
+                .unwrap_or(0);
+            let highlight_start = error.span.start.saturating_sub(line_start).min(line_text.len());
+            let highlight_end = error
+                .span
+                .end
+                .saturating_sub(line_start)
+                .min(line_text.len());
+            if highlight_end > highlight_start {
+                display_width(&line_text[highlight_start..highlight_end], col_num - 1).max(1)
+            } else {
+                1
+            }
+        } else {
+            1
+        };
+
+        out.push_str(&format!(
+            "  {cyan}{:>width$} |{reset} {}{kind_color}{}{reset}\n",
+            "",
+            " ".repeat(col_num - 1),
+            "^".repeat(underline_len),
+            width = line_num_width
+        ));
     } else {
-        1
-    };
+        // Multi-line span: print each covered line with a continuous left gutter
+        // (rustc-style), capping at MAX_HIGHLIGHT_LINES and eliding the middle of
+        // larger spans instead of dumping the whole range.
+        let lines_to_show: Vec<usize> = if end_line_num - line_num + 1 <= MAX_HIGHLIGHT_LINES {
+            (line_num..=end_line_num).collect()
+        } else {
+            vec![line_num, end_line_num]
+        };
+        let elide = lines_to_show.len() == 2 && end_line_num - line_num + 1 > MAX_HIGHLIGHT_LINES;
+
+        for (i, &lnum) in lines_to_show.iter().enumerate() {
+            let text = if lnum == line_num {
+                line_text
+            } else {
+                nth_line(source, lnum)
+            };
+            let connector = if i == 0 { '/' } else { '|' };
+            out.push_str(&format!(
+                "  {cyan}{:>width$} |{reset} {connector} {text}\n",
+                lnum,
+                width = line_num_width
+            ));
+            if elide && i == 0 {
+                out.push_str(&format!(
+                    "  {cyan}{:>width$} |{reset} {connector} ...\n",
+                    "",
+                    width = line_num_width
+                ));
+            }
+        }
 
-    out.push_str(&format!(
-        "  {cyan}{:>width$} |{reset} {}{kind_color}{}{reset}\n",
-        "",
-        " ".repeat(col_num - 1),
-        "^".repeat(underline_len),
-        width = line_num_width
-    ));
+        // Closing connector row, with the caret under the end of the span on the last line.
+        // The source text sits two columns right of the connector, so `n` underscores put the
+        // caret under display column `n`.
+        let (span_end_line, span_end_col, _) = get_line_info(source, error.span.end);
+        let last_char_col = if span_end_line == end_line_num {
+            span_end_col.saturating_sub(1)
+        } else {
+            display_width(nth_line(source, end_line_num), 0)
+        }
+        .max(1);
+        out.push_str(&format!(
+            "  {cyan}{:>width$} |{reset} {kind_color}|{}^{reset}\n",
+            "",
+            "_".repeat(last_char_col),
+            width = line_num_width
+        ));
+    }
 
     // Notes
     for note in &error.notes {
@@ -171,7 +332,68 @@ pub fn print_error(file_name: &str, source: &str, error: &CompileError) {
     eprint!("{}", format_error(file_name, source, error));
 }
 
-/// Get line number, column number, and line text for a byte offset
+/// Maximum number of source lines to print in full for a multi-line span before
+/// eliding the middle with a `...` row, mirroring rustc's snippet emitter.
+const MAX_HIGHLIGHT_LINES: usize = 6;
+
+/// Fetch the text of the given 1-based line number, for multi-line span rendering.
+fn nth_line(source: &str, line_num: usize) -> &str {
+    source.lines().nth(line_num - 1).unwrap_or("")
+}
+
+/// Default tab stop used when expanding `\t` to display columns.
+const TAB_STOP: usize = 8;
+
+/// The display width of a single character, matching rustc's snippet renderer:
+/// tabs advance to the next tab stop, East-Asian-wide and emoji characters are
+/// 2 columns wide, everything else is 1.
+fn char_display_width(c: char, col: usize) -> usize {
+    if c == '\t' {
+        TAB_STOP - (col % TAB_STOP)
+    } else if is_wide_char(c) {
+        2
+    } else {
+        1
+    }
+}
+
+/// Rough East-Asian-wide / emoji detection, covering the common ranges rustc's
+/// own `unicode-width`-backed renderer treats as double-width. Not exhaustive,
+/// but good enough to keep carets aligned for CJK text and emoji.
+fn is_wide_char(c: char) -> bool {
+    let cp = c as u32;
+    matches!(cp,
+        0x1100..=0x115F   // Hangul Jamo
+        | 0x2E80..=0x303E // CJK Radicals, Kangxi, CJK Symbols and Punctuation
+        | 0x3041..=0x33FF // Hiragana, Katakana, CJK Compat
+        | 0x3400..=0x4DBF // CJK Unified Ideographs Extension A
+        | 0x4E00..=0x9FFF // CJK Unified Ideographs
+        | 0xA000..=0xA4CF // Yi Syllables
+        | 0xAC00..=0xD7A3 // Hangul Syllables
+        | 0xF900..=0xFAFF // CJK Compatibility Ideographs
+        | 0xFF00..=0xFF60 // Fullwidth Forms
+        | 0xFFE0..=0xFFE6
+        | 0x1F300..=0x1FAFF // Emoji / symbols
+        | 0x20000..=0x3FFFD // CJK Extension B and beyond
+    )
+}
+
+/// Compute the display-column width of `text`, expanding tabs and widening
+/// East-Asian/emoji characters, starting from display column `start_col` (0-based).
+fn display_width(text: &str, start_col: usize) -> usize {
+    let mut col = start_col;
+    for c in text.chars() {
+        col += char_display_width(c, col);
+    }
+    col - start_col
+}
+
+/// Get line number, display column, and line text for a byte offset.
+///
+/// The column is a 1-based *display* column, not a byte offset: tabs advance
+/// to the next multiple of [`TAB_STOP`] and East-Asian-wide/emoji characters
+/// count as 2 columns, matching how rustc maps byte positions to the columns
+/// shown in its snippets.
 fn get_line_info(source: &str, offset: usize) -> (usize, usize, &str) {
     let offset = offset.min(source.len());
     let mut line_num = 1;
@@ -193,11 +415,333 @@ fn get_line_info(source: &str, offset: usize) -> (usize, usize, &str) {
         .unwrap_or(source.len());
 
     let line_text = &source[line_start..line_end];
-    let col_num = offset - line_start + 1;
+    let prefix_end = offset.min(line_end) - line_start;
+    let col_num = display_width(&line_text[..prefix_end], 0) + 1;
 
     (line_num, col_num, line_text)
 }
 
+/// Suggest the closest in-scope candidate to `name`, mirroring rustc's "did you mean" hints.
+///
+/// Candidates are pruned to those within `max_dist = max(1, name.len() / 3)` (capped at 3) of
+/// `name`'s length before computing the full edit distance, so this stays cheap even with large
+/// candidate sets. Ties break on shortest candidate, then lexicographic order.
+fn suggest_name<'a>(name: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    let name_len = name.chars().count();
+    let max_dist = (name_len / 3).max(1).min(3);
+
+    candidates
+        .iter()
+        .copied()
+        .filter(|candidate| candidate.chars().count().abs_diff(name_len) <= max_dist)
+        .filter_map(|candidate| {
+            let dist = damerau_levenshtein(name, candidate);
+            (dist <= max_dist && dist > 0).then_some((dist, candidate))
+        })
+        .min_by(|(dist_a, a), (dist_b, b)| dist_a.cmp(dist_b).then(a.len().cmp(&b.len())).then(a.cmp(b)))
+        .map(|(_, candidate)| candidate)
+}
+
+/// Damerau-Levenshtein edit distance: insertion, deletion, and substitution each cost 1, and an
+/// adjacent transposition (swapping two neighboring characters) also costs 1.
+fn damerau_levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let (len_a, len_b) = (a.len(), b.len());
+
+    // `dist[i][j]` holds the edit distance between `a[..i]` and `b[..j]`.
+    let mut dist = vec![vec![0usize; len_b + 1]; len_a + 1];
+    for (i, row) in dist.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for j in 0..=len_b {
+        dist[0][j] = j;
+    }
+
+    for i in 1..=len_a {
+        for j in 1..=len_b {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            dist[i][j] = (dist[i - 1][j] + 1)
+                .min(dist[i][j - 1] + 1)
+                .min(dist[i - 1][j - 1] + cost);
+
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                dist[i][j] = dist[i][j].min(dist[i - 2][j - 2] + 1);
+            }
+        }
+    }
+
+    dist[len_a][len_b]
+}
+
+// ============================================================================
+// Stable error codes and the `--explain` catalog
+// ============================================================================
+
+/// Stable, rustc-style error code identifiers.
+///
+/// Each constant is assigned to exactly one catalog function in [`errors`] or [`lints`] below, so a
+/// code set with [`CompileError::with_code`] is a reliable way to look up a diagnostic's explanation
+/// (via [`explain`]) or to cross-reference it in bug reports, independent of the (free-form,
+/// may-change) message text. An error raised without a code is given a generic per-kind code by
+/// [`IncanDiagnostic::from_error`]; `E0001`, `E0002` and `W0001` there repeat the numbers of
+/// [`EXPECTED_TOKEN`], [`UNEXPECTED_TOKEN`] and [`UNUSED_VARIABLE`].
+pub mod codes {
+    pub const EXPECTED_TOKEN: &str = "E0001";
+    pub const UNEXPECTED_TOKEN: &str = "E0002";
+    pub const INVALID_RECEIVER: &str = "E0003";
+    pub const NON_EXHAUSTIVE_MATCH: &str = "E0004";
+    pub const TYPE_MISMATCH: &str = "E0308";
+    pub const FIELD_TYPE_MISMATCH: &str = "E0309";
+    pub const CANNOT_COMPARE: &str = "E0369";
+    pub const CANNOT_ORDER: &str = "E0370";
+    pub const NOT_HASHABLE: &str = "E0371";
+    pub const MISSING_TRAIT_METHOD: &str = "E0046";
+    pub const UNKNOWN_DERIVE: &str = "E0404";
+    pub const DERIVE_WRONG_KIND: &str = "E0405";
+    pub const MISSING_RETURN_TYPE: &str = "E0406";
+    pub const INCOMPATIBLE_ERROR_TYPE: &str = "E0407";
+    pub const DUPLICATE_DEFINITION: &str = "E0428";
+    pub const UNKNOWN_SYMBOL: &str = "E0412";
+    pub const MUTATION_WITHOUT_MUT: &str = "E0596";
+    pub const SELF_MUTATION_WITHOUT_MUT: &str = "E0595";
+    pub const REASSIGNMENT_WITHOUT_MUT: &str = "E0384";
+    pub const TRY_ON_NON_RESULT: &str = "E0701";
+    pub const TRAIT_CONFLICT: &str = "E0592";
+    pub const MISSING_FIELD: &str = "E0609";
+    pub const MISSING_METHOD: &str = "E0599";
+    pub const NOT_INDEXABLE: &str = "E0608";
+    pub const TUPLE_INDEX_REQUIRES_INT_LITERAL: &str = "E0610";
+    pub const TUPLE_INDEX_OUT_OF_BOUNDS: &str = "E0611";
+    pub const INDEX_TYPE_MISMATCH: &str = "E0612";
+    pub const INDEX_VALUE_TYPE_MISMATCH: &str = "E0613";
+    pub const MUTABLE_TUPLE: &str = "E0614";
+    pub const TUPLE_FIELD_ASSIGNMENT: &str = "E0615";
+    pub const ARGUMENT_MISMATCH: &str = "E0616";
+    pub const NOT_SLICEABLE: &str = "E0617";
+    pub const TRAIT_NOT_IMPLEMENTED: &str = "E0277";
+    pub const UNKNOWN_FORMAT: &str = "E0408";
+    pub const UNUSED_VARIABLE: &str = "W0001";
+    pub const UNUSED_IMPORT: &str = "W0002";
+    pub const WILDCARD_MATCH: &str = "W0003";
+}
+
+/// One catalog entry: a longer markdown explanation with a worked example, as surfaced by
+/// `incan --explain <CODE>`.
+struct Explanation {
+    code: &'static str,
+    /// One-line title, shown as the heading of the rendered explanation.
+    title: &'static str,
+    /// Why the error happens.
+    why: &'static str,
+    /// A minimal failing example, as an `incan` code block.
+    example: &'static str,
+    /// How to fix it.
+    fix: &'static str,
+}
+
+impl Explanation {
+    /// Render this entry as the long-form Markdown shown by `incan --explain <CODE>`.
+    fn render(&self) -> String {
+        format!(
+            "# {code}: {title}\n\n{why}\n\n```incan\n{example}\n```\n\n{fix}",
+            code = self.code,
+            title = self.title,
+            why = self.why,
+            example = self.example,
+            fix = self.fix,
+        )
+    }
+}
+
+/// The full `--explain` registry, indexed by [`codes`] constant.
+static EXPLANATIONS: &[Explanation] = &[
+    Explanation {
+        code: codes::TYPE_MISMATCH,
+        title: "mismatched types",
+        why: "A value of one type was used where a different type was expected.",
+        example: "def double(x: int) -> int:\n    return x * 2\n\ndouble(\"3\")  # expected `int`, found `str`",
+        fix: "Convert the value to the expected type explicitly (`int(...)`, `str(...)`, ...) or \
+              fix the call site to pass the right type.",
+    },
+    Explanation {
+        code: codes::UNKNOWN_SYMBOL,
+        title: "unresolved name",
+        why: "A name was used that isn't defined or imported in the current scope.",
+        example: "print(unknonwn_name)  # did you mean `unknown_name`?",
+        fix: "Check the spelling, or add the missing `def`/`import`.",
+    },
+    Explanation {
+        code: codes::MISSING_FIELD,
+        title: "no field on this type",
+        why: "A struct/model/class was accessed with a field name it doesn't have.",
+        example: "model User:\n    name: str\n\nu.nmae  # `User` has no field `nmae`",
+        fix: "Check the spelling, or add the field to the model/class declaration.",
+    },
+    Explanation {
+        code: codes::MISSING_METHOD,
+        title: "no method on this type",
+        why: "A method was called on a type that doesn't define it.",
+        example: "\"hi\".frobnicate()  # `str` has no method `frobnicate(...)`",
+        fix: "Check the method name, or implement it on your type.",
+    },
+    Explanation {
+        code: codes::NON_EXHAUSTIVE_MATCH,
+        title: "non-exhaustive match",
+        why: "A `match` over an enum, `Result`, or `Option` did not cover every case.",
+        example: "match maybe_value:\n    Some(x) => x\n    # missing: None => ...",
+        fix: "Add the missing arms, or a trailing `_` wildcard if you truly mean to ignore them.",
+    },
+    Explanation {
+        code: codes::TRAIT_NOT_IMPLEMENTED,
+        title: "trait not implemented",
+        why: "A type was used somewhere that requires a trait it doesn't implement (e.g. `==` \
+              requires `Eq`, `<` requires `Ord`).",
+        example: "class Point:\n    x: int\n    y: int\n\nPoint(1, 2) == Point(1, 2)  # Point is not Eq",
+        fix: "Add the matching `@derive(...)`, or implement the trait's methods by hand.",
+    },
+    Explanation {
+        code: codes::REASSIGNMENT_WITHOUT_MUT,
+        title: "reassignment of immutable binding",
+        why: "A binding was reassigned without being declared `mut`.",
+        example: "let x = 1\nx = 2  # cannot reassign `x` - variable is immutable",
+        fix: "Declare it `mut x = 1` if it needs to change, or bind a new name instead.",
+    },
+    Explanation {
+        code: codes::MUTATION_WITHOUT_MUT,
+        title: "mutation of immutable binding",
+        why: "A value was mutated through a binding that wasn't declared `mut`.",
+        example: "let items = []\nitems.append(1)  # cannot mutate `items` - variable is immutable",
+        fix: "Add `mut` to the declaration to allow mutation.",
+    },
+];
+
+/// Look up the longer, worked-example explanation for a stable error code.
+///
+/// Backs the `incan --explain <CODE>` CLI flag and is linked from `IncanDiagnostic::code`'s
+/// rendered output via [`Diagnostic::url`]. Returns `None` for codes with no catalog entry
+/// yet (codes without an entry here still render fine in diagnostics; they just can't be
+/// `--explain`ed).
+pub fn explain(code: &str) -> Option<String> {
+    EXPLANATIONS.iter().find(|e| e.code == code).map(Explanation::render)
+}
+
+// ============================================================================
+// Locale: optional Fluent-style translation of diagnostic messages
+// ============================================================================
+
+/// Resolves [`CompileError::message_id`]s to localized text, modeled on rustc's
+/// fallback-bundle loader: a `.ftl` bundle is read for the active locale, and any message
+/// (or argument) the active bundle doesn't have falls back to the compiled-in `en` bundle,
+/// which in turn is what ships when no locale directory is configured at all.
+///
+/// Bundles use a deliberately small subset of Fluent's syntax - one `id = text` pair per
+/// line, with `{$name}` placeholders substituted from a message's `message_args` - rather
+/// than pulling in a full Fluent implementation for a feature most builds never exercise.
+pub mod locale {
+    use std::collections::HashMap;
+    use std::env;
+    use std::fs;
+    use std::sync::OnceLock;
+
+    /// Message identifiers, one per [`super::CompileError`] migrated off hard-coded English.
+    /// Mirrors rustc's per-diagnostic Fluent slugs (e.g. `borrowck-move-out-of-borrow`).
+    pub mod ids {
+        pub const UNKNOWN_SYMBOL: &str = "unknown-symbol";
+        pub const TYPE_MISMATCH: &str = "type-mismatch";
+    }
+
+    /// The compiled-in English bundle. Always available, so a missing `INCAN_LOCALE_DIR` or
+    /// an incomplete translation never leaves a diagnostic without text.
+    const EN_FTL: &str = "\
+unknown-symbol = Unknown symbol '{$name}'
+type-mismatch = Type mismatch: expected '{$expected}', found '{$found}'
+";
+
+    /// A parsed bundle: message id -> template text with `{$name}` placeholders.
+    type Bundle = HashMap<String, String>;
+
+    /// Parse the tiny `id = text` subset of Fluent syntax described on [`locale`]. Blank
+    /// lines and lines starting with `#` (comments) are skipped.
+    fn parse_ftl(text: &str) -> Bundle {
+        text.lines()
+            .filter_map(|line| {
+                let line = line.trim();
+                if line.is_empty() || line.starts_with('#') {
+                    return None;
+                }
+                let (id, template) = line.split_once('=')?;
+                Some((id.trim().to_string(), template.trim().to_string()))
+            })
+            .collect()
+    }
+
+    fn en_bundle() -> &'static Bundle {
+        static EN: OnceLock<Bundle> = OnceLock::new();
+        EN.get_or_init(|| parse_ftl(EN_FTL))
+    }
+
+    /// Directory holding `<locale>.ftl` bundles, in the order rustc-alikes check: an explicit
+    /// override, then a development-tree-relative default.
+    fn locales_dir() -> Option<std::path::PathBuf> {
+        if let Ok(dir) = env::var("INCAN_LOCALE_DIR") {
+            return Some(std::path::PathBuf::from(dir));
+        }
+        let dev_dir = std::path::Path::new("locales");
+        dev_dir.is_dir().then(|| dev_dir.to_path_buf())
+    }
+
+    /// Load and parse the `.ftl` bundle for `locale`, if one is installed. Returns `None` for
+    /// `en` (already compiled in) and for any locale without a readable bundle file.
+    fn load_bundle(locale: &str) -> Option<Bundle> {
+        if locale == "en" {
+            return None;
+        }
+        let path = locales_dir()?.join(format!("{locale}.ftl"));
+        let text = fs::read_to_string(path).ok()?;
+        Some(parse_ftl(&text))
+    }
+
+    /// The active locale, from `INCAN_LOCALE` or `LANG`, normalized to just the language
+    /// subtag (`en_US.UTF-8` -> `en`). Defaults to `en` when neither is set or parseable.
+    pub fn active_locale() -> String {
+        let raw = env::var("INCAN_LOCALE")
+            .or_else(|_| env::var("LANG"))
+            .unwrap_or_else(|_| "en".to_string());
+        raw.split(|c| c == '.' || c == '_')
+            .next()
+            .filter(|s| !s.is_empty())
+            .unwrap_or("en")
+            .to_string()
+    }
+
+    /// Substitute `{$name}` placeholders in `template` from `args`, leaving any placeholder
+    /// with no matching argument untouched so a missing-arg bug is visible rather than silently
+    /// eaten.
+    fn render(template: &str, args: &[(&str, String)]) -> String {
+        let mut out = template.to_string();
+        for (name, value) in args {
+            out = out.replace(&format!("{{${name}}}"), value);
+        }
+        out
+    }
+
+    /// Resolve `id` in the active locale's bundle, falling back to the `en` bundle, and
+    /// finally to `fallback` (the diagnostic's already-English `message`) if `id` isn't in
+    /// either bundle.
+    pub fn resolve(id: &str, args: &[(&'static str, String)], fallback: &str) -> String {
+        let active = active_locale();
+        let template = load_bundle(&active)
+            .and_then(|bundle| bundle.get(id).cloned())
+            .or_else(|| en_bundle().get(id).cloned());
+
+        match template {
+            Some(template) => render(&template, args),
+            None => fallback.to_string(),
+        }
+    }
+}
+
 // ============================================================================
 // Error catalog: common errors with Python-friendly explanations
 // ============================================================================
@@ -206,15 +750,28 @@ fn get_line_info(source: &str, offset: usize) -> (usize, usize, &str) {
 pub mod errors {
     use super::*;
 
-    pub fn unknown_symbol(name: &str, span: Span) -> CompileError {
-        CompileError::type_error(format!("Unknown symbol '{}'", name), span)
-            .with_hint("Did you forget to import it or define it?")
+    pub fn unknown_symbol(name: &str, candidates: &[&str], span: Span) -> CompileError {
+        let error = CompileError::type_error(format!("Unknown symbol '{}'", name), span)
+            .with_code(codes::UNKNOWN_SYMBOL)
+            .with_message_id(locale::ids::UNKNOWN_SYMBOL, [("name", name.to_string())]);
+        match suggest_name(name, candidates) {
+            Some(candidate) => error.with_hint(format!("did you mean `{}`?", candidate)),
+            None => error.with_hint("Did you forget to import it or define it?"),
+        }
     }
 
     pub fn type_mismatch(expected: &str, found: &str, span: Span) -> CompileError {
         let mut error = CompileError::type_error(
             format!("Type mismatch: expected '{}', found '{}'", expected, found),
             span,
+        )
+        .with_code(codes::TYPE_MISMATCH)
+        .with_message_id(
+            locale::ids::TYPE_MISMATCH,
+            [
+                ("expected", expected.to_string()),
+                ("found", found.to_string()),
+            ],
         );
 
         // Add context-aware hints based on common patterns
@@ -304,6 +861,7 @@ pub mod errors {
         )
         .with_hint("Valid derives: Debug, Display, Eq, Ord, Hash, Clone, Copy, Default, Serialize, Deserialize")
         .with_hint("Hint: Use 'with TraitName' syntax for custom trait implementations")
+        .with_code(codes::UNKNOWN_DERIVE)
     }
 
     pub fn derive_wrong_kind(name: &str, kind: &str, span: Span) -> CompileError {
@@ -316,11 +874,22 @@ pub mod errors {
             "Did you mean: `with {}` to implement a trait?",
             name
         ))
+        .with_code(codes::DERIVE_WRONG_KIND)
+    }
+
+    pub fn unknown_format(name: &str, span: Span) -> CompileError {
+        CompileError::type_error(
+            format!("Unknown serialization format '{}'", name),
+            span,
+        )
+        .with_hint("Valid formats: yaml, toml, msgpack")
+        .with_code(codes::UNKNOWN_FORMAT)
     }
 
     pub fn missing_return_type(span: Span) -> CompileError {
         CompileError::type_error("Function is missing a return type".to_string(), span)
             .with_hint("Add a return type annotation: def name(...) -> Type:")
+            .with_code(codes::MISSING_RETURN_TYPE)
     }
 
     pub fn incompatible_error_type(expected: &str, found: &str, span: Span) -> CompileError {
@@ -332,6 +901,7 @@ pub mod errors {
             span,
         )
         .with_hint("Use map_err to convert the error type, or add a From implementation")
+        .with_code(codes::INCOMPATIBLE_ERROR_TYPE)
     }
 
     pub fn non_exhaustive_match(missing: &[String], span: Span) -> CompileError {
@@ -341,6 +911,7 @@ pub mod errors {
             span,
         )
         .with_hint("Add the missing cases or use '_' as a wildcard (use wildcards sparingly)")
+        .with_code(codes::NON_EXHAUSTIVE_MATCH)
     }
 
     pub fn mutation_without_mut(name: &str, span: Span) -> CompileError {
@@ -354,6 +925,7 @@ pub mod errors {
         ))
         .with_note("In Incan, variables are immutable by default for safety")
         .with_note("This prevents accidental modifications and makes code easier to reason about")
+        .with_code(codes::MUTATION_WITHOUT_MUT)
     }
 
     pub fn self_mutation_without_mut(span: Span) -> CompileError {
@@ -364,6 +936,7 @@ pub mod errors {
         .with_hint("Change the method signature to use 'mut self':")
         .with_hint("  def method(mut self) -> ReturnType:")
         .with_note("Methods that modify self must explicitly declare 'mut self'")
+        .with_code(codes::SELF_MUTATION_WITHOUT_MUT)
     }
 
     pub fn reassignment_without_mut(name: &str, span: Span) -> CompileError {
@@ -377,6 +950,7 @@ pub mod errors {
         ))
         .with_hint("Or use a new variable name with 'let'")
         .with_note("Reassignment requires the variable to be declared as mutable")
+        .with_code(codes::REASSIGNMENT_WITHOUT_MUT)
     }
 
     pub fn try_on_non_result(found: &str, span: Span) -> CompileError {
@@ -392,6 +966,7 @@ pub mod errors {
         } else {
             "If this operation can fail, the function should return Result[T, E]"
         })
+        .with_code(codes::TRY_ON_NON_RESULT)
     }
 
     pub fn trait_conflict(trait_a: &str, trait_b: &str, method: &str, span: Span) -> CompileError {
@@ -406,22 +981,134 @@ pub mod errors {
             "Resolve the conflict explicitly: {}.{}(self, ...)",
             trait_a, method
         ))
+        .with_code(codes::TRAIT_CONFLICT)
     }
 
-    pub fn missing_field(type_name: &str, field: &str, span: Span) -> CompileError {
-        CompileError::type_error(
+    pub fn missing_field(type_name: &str, field: &str, candidates: &[&str], span: Span) -> CompileError {
+        let error = CompileError::type_error(
             format!("Type '{}' has no field '{}'", type_name, field),
             span,
         )
+        .with_code(codes::MISSING_FIELD);
+        match suggest_name(field, candidates) {
+            Some(candidate) => error.with_hint(format!("did you mean `{}`?", candidate)),
+            None => error,
+        }
     }
 
-    pub fn missing_method(type_name: &str, method: &str, span: Span) -> CompileError {
-        CompileError::type_error(
+    pub fn missing_method(type_name: &str, method: &str, candidates: &[&str], span: Span) -> CompileError {
+        let error = CompileError::type_error(
             format!("Type '{}' has no method '{}(...)'", type_name, method),
             span,
         )
-        .with_hint("Check the method name spelling and receiver type")
-        .with_hint("If this is your type, implement the method on the class/model/newtype")
+        .with_code(codes::MISSING_METHOD);
+        match suggest_name(method, candidates) {
+            Some(candidate) => error.with_hint(format!("did you mean `{}`?", candidate)),
+            None => error
+                .with_hint("Check the method name spelling and receiver type")
+                .with_hint("If this is your type, implement the method on the class/model/newtype"),
+        }
+    }
+
+    /// What an argument-matrix pass (see `TypeChecker::check_arg_matrix`) found wrong
+    /// between a call's provided arguments and the callee's declared parameters.
+    pub enum ArgMismatch<'a> {
+        /// The provided argument count doesn't match the declared parameter count.
+        Count { expected: usize, found: usize },
+        /// Argument `index` (0-based) doesn't type-check against its parameter.
+        Incompatible { index: usize, param: &'a str, expected: String, found: String },
+        /// Arguments `first` and `second` (0-based indices with their param names) would
+        /// type-check if swapped.
+        Swapped { first: (usize, &'a str), second: (usize, &'a str) },
+        /// An argument for `param` (0-based `index`) appears to be missing.
+        Missing { index: usize, param: &'a str, expected: String },
+        /// Counts match, but three or more arguments are incompatible (or exactly two are and
+        /// swapping them wouldn't fix it) - too many independent mismatches to single out.
+        MultiIncompatible { args: Vec<IncompatibleArg<'a>> },
+    }
+
+    /// One mismatched argument within an `ArgMismatch::MultiIncompatible` diagnosis.
+    pub struct IncompatibleArg<'a> {
+        pub index: usize,
+        pub param: &'a str,
+        pub expected: String,
+        pub found: String,
+    }
+
+    pub fn argument_mismatch(owner: &str, callee: &str, mismatch: ArgMismatch, span: Span) -> CompileError {
+        let (message, hint) = match mismatch {
+            ArgMismatch::Count { expected, found } => (
+                format!(
+                    "'{}.{}' expects {} argument{}, but {} {} provided",
+                    owner,
+                    callee,
+                    expected,
+                    if expected == 1 { "" } else { "s" },
+                    found,
+                    if found == 1 { "was" } else { "were" }
+                ),
+                if found < expected {
+                    format!("Add the missing argument{}", if expected - found == 1 { "" } else { "s" })
+                } else {
+                    "Remove the extra argument(s)".to_string()
+                },
+            ),
+            ArgMismatch::Incompatible { index, param, expected, found } => (
+                format!(
+                    "Argument {} to '{}.{}' has type '{}', but parameter '{}' expects '{}'",
+                    index + 1,
+                    owner,
+                    callee,
+                    found,
+                    param,
+                    expected
+                ),
+                format!("Pass a value of type '{}' for '{}'", expected, param),
+            ),
+            ArgMismatch::Swapped { first, second } => (
+                format!(
+                    "Arguments {} and {} to '{}.{}' are in the wrong order",
+                    first.0 + 1,
+                    second.0 + 1,
+                    owner,
+                    callee
+                ),
+                format!("Swap arguments for '{}' and '{}'", first.1, second.1),
+            ),
+            ArgMismatch::Missing { index, param, expected } => (
+                format!(
+                    "'{}.{}' is missing argument {} ('{}: {}')",
+                    owner,
+                    callee,
+                    index + 1,
+                    param,
+                    expected
+                ),
+                format!("Add an argument for '{}'", param),
+            ),
+            ArgMismatch::MultiIncompatible { args } => (
+                format!(
+                    "'{}.{}' has {} incompatible arguments: {}",
+                    owner,
+                    callee,
+                    args.len(),
+                    args.iter()
+                        .map(|a| format!(
+                            "argument {} has type '{}', but parameter '{}' expects '{}'",
+                            a.index + 1,
+                            a.found,
+                            a.param,
+                            a.expected
+                        ))
+                        .collect::<Vec<_>>()
+                        .join("; ")
+                ),
+                "Check the types of each argument listed above".to_string(),
+            ),
+        };
+        CompileError::type_error(message, span)
+            .with_hint(hint)
+            .with_code(codes::ARGUMENT_MISMATCH)
     }
 
     pub fn field_type_mismatch(
@@ -441,11 +1128,19 @@ pub mod errors {
             "Field '{}' expects type '{}', but got '{}'",
             field, expected, found
         ))
+        .with_code(codes::FIELD_TYPE_MISMATCH)
     }
 
     pub fn not_indexable(type_name: &str, span: Span) -> CompileError {
         CompileError::type_error(format!("Type '{}' is not indexable", type_name), span)
             .with_hint("Only List, Dict, str, and Tuple types support indexing")
+            .with_code(codes::NOT_INDEXABLE)
+    }
+
+    pub fn not_sliceable(type_name: &str, span: Span) -> CompileError {
+        CompileError::type_error(format!("Type '{}' is not sliceable", type_name), span)
+            .with_hint("Only List, FrozenList, str, FrozenStr, and Tuple types support slicing")
+            .with_code(codes::NOT_SLICEABLE)
     }
 
     pub fn tuple_index_requires_int_literal(span: Span) -> CompileError {
@@ -454,6 +1149,7 @@ pub mod errors {
             span,
         )
         .with_hint("Use a literal index so the compiler can validate bounds")
+        .with_code(codes::TUPLE_INDEX_REQUIRES_INT_LITERAL)
     }
 
     pub fn tuple_index_out_of_bounds(idx: i64, len: usize, span: Span) -> CompileError {
@@ -465,6 +1161,7 @@ pub mod errors {
             span,
         )
         .with_hint("Tuple indices are checked at compile time")
+        .with_code(codes::TUPLE_INDEX_OUT_OF_BOUNDS)
     }
 
     pub fn index_type_mismatch(expected: &str, found: &str, span: Span) -> CompileError {
@@ -476,6 +1173,7 @@ pub mod errors {
             span,
         )
         .with_hint(format!("Use '{}' as the index type", expected))
+        .with_code(codes::INDEX_TYPE_MISMATCH)
     }
 
     pub fn index_value_type_mismatch(expected: &str, found: &str, span: Span) -> CompileError {
@@ -490,6 +1188,7 @@ pub mod errors {
             "Collection elements are of type '{}', but got '{}'",
             expected, found
         ))
+        .with_code(codes::INDEX_VALUE_TYPE_MISMATCH)
     }
 
     pub fn mutable_tuple(span: Span) -> CompileError {
@@ -498,6 +1197,7 @@ pub mod errors {
             span,
         )
         .with_hint("Remove 'mut' - tuples cannot be modified after creation")
+        .with_code(codes::MUTABLE_TUPLE)
     }
 
     pub fn tuple_field_assignment(span: Span) -> CompileError {
@@ -506,6 +1206,7 @@ pub mod errors {
             span,
         )
         .with_hint("Create a new tuple instead of modifying an existing one")
+        .with_code(codes::TUPLE_FIELD_ASSIGNMENT)
     }
 
     pub fn missing_trait_method(trait_name: &str, method: &str, span: Span) -> CompileError {
@@ -521,62 +1222,109 @@ pub mod errors {
             method
         ))
         .with_note("All required trait methods must be implemented")
+        .with_code(codes::MISSING_TRAIT_METHOD)
     }
 
-    pub fn trait_not_implemented(type_name: &str, trait_name: &str, span: Span) -> CompileError {
+    /// `trait_name` → hint/note templates, with `{type}`/`{trait}` placeholders, shown
+    /// when a type fails to implement a builtin trait. Mirrors rustc's
+    /// `#[rustc_on_unimplemented]` table of per-trait guidance.
+    const ON_UNIMPLEMENTED_TABLE: &[(&str, &[&str], &[&str])] = &[
+        (
+            "Eq",
+            &[
+                "Add @derive(Eq) to enable equality comparison (==, !=)",
+                "Or implement __eq__ manually for custom comparison logic",
+            ],
+            &[],
+        ),
+        (
+            "Ord",
+            &[
+                "Add @derive(Ord) to enable ordering comparison (<, >, <=, >=)",
+                "Or implement __lt__ manually for custom ordering",
+            ],
+            &[],
+        ),
+        (
+            "Hash",
+            &["Add @derive(Hash) to use this type in Set or as Dict key"],
+            &["Hash is required for Set membership and Dict keys"],
+        ),
+        ("Clone", &["Add @derive(Clone) to enable .clone() method"], &[]),
+        ("Debug", &["Add @derive(Debug) to enable {:?} formatting"], &[]),
+        (
+            "Display",
+            &[
+                "Implement __str__ method for string representation",
+                "Example: def __str__(self) -> str: return f\"{self.name}\"",
+            ],
+            &[],
+        ),
+        ("Default", &["Add @derive(Default) to enable Type.default()"], &[]),
+        (
+            "Serialize",
+            &["Add @derive({trait}) for JSON/serialization support"],
+            &[],
+        ),
+        (
+            "Deserialize",
+            &["Add @derive({trait}) for JSON/serialization support"],
+            &[],
+        ),
+        (
+            "Error",
+            &[
+                "Implement the Error trait with a message() method",
+                "Example: def message(self) -> str: return self.msg",
+            ],
+            &[],
+        ),
+    ];
+
+    /// Substitute the `{type}`/`{trait}` placeholders used by `@on_unimplemented`
+    /// templates and [`ON_UNIMPLEMENTED_TABLE`].
+    fn render_on_unimplemented(template: &str, type_name: &str, trait_name: &str) -> String {
+        template
+            .replace("{type}", type_name)
+            .replace("{trait}", trait_name)
+    }
+
+    /// `type_name` does not implement `trait_name`. If the trait was declared with a
+    /// custom `@on_unimplemented("...")` message (`custom_message`), that's used, with its
+    /// `{type}`/`{trait}` placeholders filled in, in place of this module's builtin table,
+    /// mirroring rustc's `#[rustc_on_unimplemented]`.
+    pub fn trait_not_implemented(
+        type_name: &str,
+        trait_name: &str,
+        custom_message: Option<&str>,
+        span: Span,
+    ) -> CompileError {
         let mut error = CompileError::type_error(
             format!(
                 "Type '{}' does not implement trait '{}'",
                 type_name, trait_name
             ),
             span,
-        );
-
-        // Add specific hints based on the trait
-        match trait_name {
-            "Eq" => {
-                error = error.with_hint("Add @derive(Eq) to enable equality comparison (==, !=)");
-                error = error.with_hint("Or implement __eq__ manually for custom comparison logic");
-            }
-            "Ord" => {
-                error = error
-                    .with_hint("Add @derive(Ord) to enable ordering comparison (<, >, <=, >=)");
-                error = error.with_hint("Or implement __lt__ manually for custom ordering");
-            }
-            "Hash" => {
-                error = error.with_hint("Add @derive(Hash) to use this type in Set or as Dict key");
-                error = error.with_note("Hash is required for Set membership and Dict keys");
-            }
-            "Clone" => {
-                error = error.with_hint("Add @derive(Clone) to enable .clone() method");
-            }
-            "Debug" => {
-                error = error.with_hint("Add @derive(Debug) to enable {:?} formatting");
-            }
-            "Display" => {
-                error = error.with_hint("Implement __str__ method for string representation");
-                error =
-                    error.with_hint("Example: def __str__(self) -> str: return f\"{self.name}\"");
-            }
-            "Default" => {
-                error = error.with_hint("Add @derive(Default) to enable Type.default()");
-            }
-            "Serialize" | "Deserialize" => {
-                error = error.with_hint(format!(
-                    "Add @derive({}) for JSON/serialization support",
-                    trait_name
-                ));
-            }
-            "Error" => {
-                error = error.with_hint("Implement the Error trait with a message() method");
-                error = error.with_hint("Example: def message(self) -> str: return self.msg");
+        )
+        .with_code(codes::TRAIT_NOT_IMPLEMENTED);
+
+        if let Some(custom) = custom_message {
+            error = error.with_hint(render_on_unimplemented(custom, type_name, trait_name));
+        } else if let Some((_, hints, notes)) = ON_UNIMPLEMENTED_TABLE
+            .iter()
+            .find(|(name, _, _)| *name == trait_name)
+        {
+            for hint in *hints {
+                error = error.with_hint(render_on_unimplemented(hint, type_name, trait_name));
             }
-            _ => {
-                error = error.with_hint(format!(
-                    "Implement the {} trait or add 'with {}'",
-                    trait_name, trait_name
-                ));
+            for note in *notes {
+                error = error.with_note(render_on_unimplemented(note, type_name, trait_name));
             }
+        } else {
+            error = error.with_hint(format!(
+                "Implement the {} trait or add 'with {}'",
+                trait_name, trait_name
+            ));
         }
 
         error
@@ -592,6 +1340,7 @@ pub mod errors {
         )
         .with_hint("Add @derive(Eq) to the type definition to enable comparison")
         .with_note("Comparison operators (==, !=) require the Eq trait")
+        .with_code(codes::CANNOT_COMPARE)
     }
 
     pub fn cannot_order(type_name: &str, span: Span) -> CompileError {
@@ -604,6 +1353,7 @@ pub mod errors {
         )
         .with_hint("Add @derive(Ord) to the type definition to enable ordering")
         .with_note("Ordering operators (<, >, <=, >=) require the Ord trait")
+        .with_code(codes::CANNOT_ORDER)
     }
 
     pub fn not_hashable(type_name: &str, span: Span) -> CompileError {
@@ -616,14 +1366,15 @@ pub mod errors {
         )
         .with_hint("Add @derive(Hash, Eq) to make this type hashable")
         .with_note("Both Hash and Eq are required for Set membership and Dict keys")
+        .with_code(codes::NOT_HASHABLE)
     }
 
     pub fn expected_token(expected: &str, found: &str, span: Span) -> CompileError {
-        CompileError::syntax(format!("Expected {}, found {}", expected, found), span)
+        CompileError::syntax(format!("Expected {}, found {}", expected, found), span).with_code(codes::EXPECTED_TOKEN)
     }
 
     pub fn unexpected_token(found: &str, span: Span) -> CompileError {
-        CompileError::syntax(format!("Unexpected token: {}", found), span)
+        CompileError::syntax(format!("Unexpected token: {}", found), span).with_code(codes::UNEXPECTED_TOKEN)
     }
 
     pub fn invalid_receiver(span: Span) -> CompileError {
@@ -631,10 +1382,11 @@ pub mod errors {
             "Invalid receiver - expected 'self' or 'mut self'".to_string(),
             span,
         )
+        .with_code(codes::INVALID_RECEIVER)
     }
 
     pub fn duplicate_definition(name: &str, span: Span) -> CompileError {
-        CompileError::type_error(format!("Duplicate definition of '{}'", name), span)
+        CompileError::type_error(format!("Duplicate definition of '{}'", name), span).with_code(codes::DUPLICATE_DEFINITION)
     }
 }
 
@@ -652,6 +1404,15 @@ pub mod lints {
             kind: ErrorKind::Lint,
             notes: vec![],
             hints: vec!["Prefix with underscore to silence: _{}".to_string() + name],
+            code: Some(codes::UNUSED_VARIABLE),
+            suggestions: vec![Suggestion::new(
+                format!("prefix `{}` with an underscore", name),
+                span,
+                format!("_{}", name),
+                Applicability::MachineApplicable,
+            )],
+            message_id: None,
+            message_args: Vec::new(),
         }
     }
 
@@ -662,6 +1423,15 @@ pub mod lints {
             kind: ErrorKind::Lint,
             notes: vec![],
             hints: vec!["Remove the import or use it".to_string()],
+            code: Some(codes::UNUSED_IMPORT),
+            suggestions: vec![Suggestion::new(
+                "remove the unused import",
+                span,
+                "",
+                Applicability::MachineApplicable,
+            )],
+            message_id: None,
+            message_args: Vec::new(),
         }
     }
 
@@ -673,6 +1443,10 @@ pub mod lints {
             kind: ErrorKind::Lint,
             notes: vec![],
             hints: vec![],
+            code: Some(codes::WILDCARD_MATCH),
+            suggestions: vec![],
+            message_id: None,
+            message_args: Vec::new(),
         }
     }
 }
@@ -701,6 +1475,12 @@ pub struct IncanDiagnostic {
     pub help: Option<String>,
     /// Related spans (for secondary labels)
     pub related: Vec<LabeledSpan>,
+    /// The original `CompileError` kind, used to derive [`Diagnostic::severity`]
+    pub kind: ErrorKind,
+    /// Chained diagnostics (e.g. a "defined here" origin for a conflicting definition)
+    pub chained: Vec<IncanDiagnostic>,
+    /// Structured, tooling-applyable fixes (see [`Suggestion`])
+    pub suggestions: Vec<Suggestion>,
 }
 
 impl std::fmt::Display for IncanDiagnostic {
@@ -725,6 +1505,13 @@ impl Diagnostic for IncanDiagnostic {
             self.span.len(),
         )];
         labels.extend(self.related.iter().cloned());
+        labels.extend(self.suggestions.iter().map(|s| {
+            LabeledSpan::new(
+                Some(format!("suggestion: {}", s.message)),
+                s.span.start,
+                (s.span.end - s.span.start).max(1),
+            )
+        }));
         Some(Box::new(labels.into_iter()))
     }
 
@@ -737,6 +1524,27 @@ impl Diagnostic for IncanDiagnostic {
     fn source_code(&self) -> Option<&dyn miette::SourceCode> {
         Some(&self.source)
     }
+
+    fn url<'a>(&'a self) -> Option<Box<dyn std::fmt::Display + 'a>> {
+        let code = self.code.as_ref()?;
+        explain(code)?;
+        Some(Box::new(format!("incan --explain {code}")) as Box<dyn std::fmt::Display>)
+    }
+
+    fn severity(&self) -> Option<miette::Severity> {
+        Some(match self.kind {
+            ErrorKind::Error | ErrorKind::Type | ErrorKind::Syntax => miette::Severity::Error,
+            ErrorKind::Warning | ErrorKind::Lint => miette::Severity::Warning,
+        })
+    }
+
+    fn related(&self) -> Option<Box<dyn Iterator<Item = &dyn Diagnostic> + '_>> {
+        if self.chained.is_empty() {
+            None
+        } else {
+            Some(Box::new(self.chained.iter().map(|d| d as &dyn Diagnostic)))
+        }
+    }
 }
 
 impl IncanDiagnostic {
@@ -745,8 +1553,9 @@ impl IncanDiagnostic {
         let span_start = error.span.start;
         let span_len = (error.span.end - error.span.start).max(1);
 
-        // Combine hints into help text
-        let help = if error.hints.is_empty() && error.notes.is_empty() {
+        // Combine hints and structured suggestions into help text
+        let help = if error.hints.is_empty() && error.notes.is_empty() && error.suggestions.is_empty()
+        {
             None
         } else {
             let mut help_text = String::new();
@@ -760,26 +1569,48 @@ impl IncanDiagnostic {
                 help_text.push_str(hint);
                 help_text.push('\n');
             }
+            for suggestion in &error.suggestions {
+                help_text.push_str("suggestion: ");
+                help_text.push_str(&suggestion.message);
+                if !suggestion.replacement.is_empty() {
+                    help_text.push_str(": `");
+                    help_text.push_str(&suggestion.replacement);
+                    help_text.push('`');
+                }
+                help_text.push('\n');
+            }
             Some(help_text.trim_end().to_string())
         };
 
-        // Generate error code based on kind
-        let code = match error.kind {
-            ErrorKind::Type => Some("E0001".to_string()),
-            ErrorKind::Syntax => Some("E0002".to_string()),
-            ErrorKind::Error => Some("E0000".to_string()),
-            ErrorKind::Warning => Some("W0001".to_string()),
-            ErrorKind::Lint => Some("L0001".to_string()),
+        // Prefer the catalog's stable code; fall back to a generic one bucketed by kind so
+        // every diagnostic still renders a `code()` for miette.
+        let code = error.code.map(str::to_string).or_else(|| {
+            match error.kind {
+                ErrorKind::Type => Some("E0001"),
+                ErrorKind::Syntax => Some("E0002"),
+                ErrorKind::Error => Some("E0000"),
+                ErrorKind::Warning => Some("W0001"),
+                ErrorKind::Lint => Some("L0001"),
+            }
+            .map(str::to_string)
+        });
+
+        let message = match error.message_id {
+            Some(id) => locale::resolve(id, &error.message_args, &error.message),
+            None => error.message.clone(),
         };
 
         Self {
-            message: error.message.clone(),
+            message,
             code,
             source: miette::NamedSource::new(file_name, source.to_string()),
             span: SourceSpan::new(span_start.into(), span_len),
             label: error.kind.to_string(),
             help,
             related: vec![],
+            kind: error.kind,
+            chained: vec![],
+            suggestions: error.suggestions.clone(),
         }
     }
 
@@ -789,6 +1620,13 @@ impl IncanDiagnostic {
             .push(LabeledSpan::new(Some(message.into()), start, len));
         self
     }
+
+    /// Chain another diagnostic as context (e.g. a "defined here" origin for a
+    /// conflicting definition), surfaced via [`Diagnostic::related`].
+    pub fn with_chained(mut self, origin: IncanDiagnostic) -> Self {
+        self.chained.push(origin);
+        self
+    }
 }
 
 /// Render a CompileError using miette's fancy reporter
@@ -797,17 +1635,433 @@ pub fn render_miette(error: &CompileError, file_name: &str, source: &str) -> Str
     format!("{:?}", miette::Report::new(diagnostic))
 }
 
-/// Format an error, using miette if INCAN_FANCY_ERRORS is set
+/// Escape a string for embedding in a JSON string literal.
+fn json_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Render a CompileError as a single line of structured JSON, in the spirit of rustc's
+/// `--error-format=json`, so editors/LSP servers/CI can consume diagnostics without
+/// scraping terminal text.
+pub fn render_json(error: &CompileError, file_name: &str, source: &str) -> String {
+    let diagnostic = IncanDiagnostic::from_error(error, file_name, source);
+
+    let (line_start, col_start, _) = get_line_info(source, error.span.start);
+    let (line_end, col_end_inclusive, line_text) =
+        get_line_info(source, error.span.end.saturating_sub(1).max(error.span.start));
+    let col_end = if error.span.end > error.span.start {
+        col_end_inclusive + 1
+    } else {
+        col_end_inclusive
+    };
+    let _ = line_text; // only needed to compute the column above
+
+    let level = match error.kind {
+        ErrorKind::Error | ErrorKind::Type | ErrorKind::Syntax => "error",
+        ErrorKind::Warning | ErrorKind::Lint => "warning",
+    };
+
+    let code_json = match &diagnostic.code {
+        Some(code) => format!("\"{}\"", json_escape(code)),
+        None => "null".to_string(),
+    };
+
+    let span = format!(
+        "{{\"file_name\":\"{file}\",\"start\":{start},\"end\":{end},\
+         \"line_start\":{line_start},\"col_start\":{col_start},\
+         \"line_end\":{line_end},\"col_end\":{col_end}}}",
+        file = json_escape(file_name),
+        start = error.span.start,
+        end = error.span.end,
+    );
+
+    let mut children: Vec<String> = Vec::new();
+    for note in &error.notes {
+        children.push(format!(
+            "{{\"message\":\"{}\",\"level\":\"note\"}}",
+            json_escape(note)
+        ));
+    }
+    for hint in &error.hints {
+        children.push(format!(
+            "{{\"message\":\"{}\",\"level\":\"help\"}}",
+            json_escape(hint)
+        ));
+    }
+
+    format!(
+        "{{\"message\":\"{message}\",\"code\":{code},\"level\":\"{level}\",\"spans\":[{span}],\"children\":[{children}]}}",
+        message = json_escape(&error.message),
+        code = code_json,
+        children = children.join(","),
+    )
+}
+
+/// Render an error's structured [`Suggestion`]s as a JSON array of edits, so an external
+/// `incan --fix` command can apply the `MachineApplicable` ones automatically without
+/// re-parsing free-text hints.
+pub fn render_fixes_json(error: &CompileError, file_name: &str) -> String {
+    let applicability_str = |a: Applicability| match a {
+        Applicability::MachineApplicable => "MachineApplicable",
+        Applicability::MaybeIncorrect => "MaybeIncorrect",
+        Applicability::HasPlaceholders => "HasPlaceholders",
+        Applicability::Unspecified => "Unspecified",
+    };
+
+    let fixes: Vec<String> = error
+        .suggestions
+        .iter()
+        .map(|s| {
+            format!(
+                "{{\"file_name\":\"{file}\",\"start\":{start},\"end\":{end},\
+                 \"replacement\":\"{replacement}\",\"message\":\"{message}\",\
+                 \"applicability\":\"{applicability}\"}}",
+                file = json_escape(file_name),
+                start = s.span.start,
+                end = s.span.end,
+                replacement = json_escape(&s.replacement),
+                message = json_escape(&s.message),
+                applicability = applicability_str(s.applicability),
+            )
+        })
+        .collect();
+
+    format!("[{}]", fixes.join(","))
+}
+
+// ============================================================================
+// annotate-snippet rendering backend
+// ============================================================================
+
+/// A minimal stand-in for the `annotate-snippet` crate's rendering model - a title, one
+/// `Slice` of source carrying byte-range `SourceAnnotation`s, and free-text footer lines -
+/// good enough to render Incan diagnostics in that crate's plain, ASCII-only style without
+/// pulling in the dependency, the same way [`render_json`] hand-rolls JSON instead of
+/// depending on `serde_json`.
+pub mod annotate_snippet {
+    /// How an annotation or footer line should be tagged when rendered.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum AnnotationType {
+        Error,
+        Warning,
+        Help,
+        Note,
+    }
+
+    /// One labeled byte range (relative to the containing [`Slice`]'s source) to underline.
+    pub struct SourceAnnotation {
+        pub range: (usize, usize),
+        pub label: String,
+        pub annotation_type: AnnotationType,
+    }
+
+    /// A window of source lines, with the annotations that fall inside it.
+    pub struct Slice<'a> {
+        pub source: &'a str,
+        pub line_start: usize,
+        pub origin: Option<&'a str>,
+        pub annotations: Vec<SourceAnnotation>,
+    }
+
+    /// A footer line below the slice (rendered as `= note: ...` / `= help: ...`).
+    pub struct Footer {
+        pub label: String,
+        pub annotation_type: AnnotationType,
+    }
+
+    /// The top-level unit the `annotate-snippet` crate renders: a title plus one slice and
+    /// its footer.
+    pub struct Snippet<'a> {
+        pub title: Option<String>,
+        pub slice: Slice<'a>,
+        pub footer: Vec<Footer>,
+    }
+
+    /// Render a [`Snippet`] as plain, box-drawing-free ASCII text.
+    pub fn render(snippet: &Snippet) -> String {
+        let mut out = String::new();
+
+        if let Some(title) = &snippet.title {
+            out.push_str(title);
+            out.push('\n');
+        }
+        if let Some(origin) = snippet.slice.origin {
+            out.push_str(&format!(" --> {origin}\n"));
+        }
+
+        let line_count = snippet.slice.source.lines().count().max(1);
+        let width = format!("{}", snippet.slice.line_start + line_count - 1).len();
+
+        out.push_str(&format!("{:>width$} |\n", "", width = width));
+
+        let mut offset = 0;
+        for (i, line) in snippet.slice.source.lines().enumerate() {
+            let line_num = snippet.slice.line_start + i;
+            out.push_str(&format!("{:>width$} | {}\n", line_num, line, width = width));
+
+            for ann in snippet
+                .slice
+                .annotations
+                .iter()
+                .filter(|a| a.range.0 >= offset && a.range.0 <= offset + line.len())
+            {
+                let start_col = ann.range.0 - offset;
+                let ann_len = ann.range.1.saturating_sub(ann.range.0).max(1);
+                let marker = match ann.annotation_type {
+                    AnnotationType::Error | AnnotationType::Warning => "^",
+                    AnnotationType::Help | AnnotationType::Note => "-",
+                };
+                out.push_str(&format!(
+                    "{:>width$} | {}{} {}\n",
+                    "",
+                    " ".repeat(start_col),
+                    marker.repeat(ann_len),
+                    ann.label,
+                    width = width
+                ));
+            }
+
+            offset += line.len() + 1; // +1 for the stripped '\n'
+        }
+
+        for footer in &snippet.footer {
+            let tag = match footer.annotation_type {
+                AnnotationType::Note => "note",
+                AnnotationType::Help => "help",
+                AnnotationType::Error | AnnotationType::Warning => "error",
+            };
+            out.push_str(&format!("  = {tag}: {}\n", footer.label));
+        }
+
+        out
+    }
+}
+
+/// Render a `CompileError` using the `annotate-snippet` model: a single [`annotate_snippet::Slice`]
+/// covering the error's line range, with the primary span and any in-range [`Suggestion`]s as
+/// [`annotate_snippet::SourceAnnotation`]s, and `notes`/`hints` as footer lines. A lighter-weight,
+/// ASCII-friendly alternative to [`render_miette`] for terminals where box-drawing characters
+/// render poorly.
+pub fn render_annotate_snippet(error: &CompileError, file_name: &str, source: &str) -> String {
+    let diagnostic = IncanDiagnostic::from_error(error, file_name, source);
+
+    let (line_start, _, _) = get_line_info(source, error.span.start);
+    let (line_end, _, _) =
+        get_line_info(source, error.span.end.saturating_sub(1).max(error.span.start));
+
+    let slice_source = (line_start..=line_end)
+        .map(|n| nth_line(source, n))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    // Byte offset of `slice_source`'s first line within `source`, so later spans can be
+    // rebased to be relative to the slice rather than the whole file.
+    let slice_offset = (1..line_start).map(|n| nth_line(source, n).len() + 1).sum::<usize>();
+
+    let severity = match error.kind {
+        ErrorKind::Warning | ErrorKind::Lint => annotate_snippet::AnnotationType::Warning,
+        ErrorKind::Error | ErrorKind::Syntax | ErrorKind::Type => annotate_snippet::AnnotationType::Error,
+    };
+
+    let mut annotations = vec![annotate_snippet::SourceAnnotation {
+        range: (
+            error.span.start.saturating_sub(slice_offset),
+            error.span.end.saturating_sub(slice_offset).max(
+                error.span.start.saturating_sub(slice_offset) + 1,
+            ),
+        ),
+        label: diagnostic.label.clone(),
+        annotation_type: severity,
+    }];
+
+    for suggestion in &error.suggestions {
+        if suggestion.span.start >= slice_offset
+            && suggestion.span.end <= slice_offset + slice_source.len()
+        {
+            annotations.push(annotate_snippet::SourceAnnotation {
+                range: (
+                    suggestion.span.start - slice_offset,
+                    (suggestion.span.end - slice_offset).max(suggestion.span.start - slice_offset + 1),
+                ),
+                label: format!("suggestion: {}", suggestion.message),
+                annotation_type: annotate_snippet::AnnotationType::Help,
+            });
+        }
+    }
+
+    for related in &diagnostic.related {
+        annotations.push(annotate_snippet::SourceAnnotation {
+            range: (related.offset(), related.offset() + related.len().max(1)),
+            label: related.label().unwrap_or_default().to_string(),
+            annotation_type: annotate_snippet::AnnotationType::Help,
+        });
+    }
+
+    let mut footer = Vec::new();
+    for note in &error.notes {
+        footer.push(annotate_snippet::Footer {
+            label: note.clone(),
+            annotation_type: annotate_snippet::AnnotationType::Note,
+        });
+    }
+    for hint in &error.hints {
+        footer.push(annotate_snippet::Footer {
+            label: hint.clone(),
+            annotation_type: annotate_snippet::AnnotationType::Help,
+        });
+    }
+
+    let title = format!(
+        "{kind}{code}: {message}",
+        kind = error.kind,
+        code = error.code.map(|c| format!("[{c}]")).unwrap_or_default(),
+        message = diagnostic.message,
+    );
+
+    annotate_snippet::render(&annotate_snippet::Snippet {
+        title: Some(title),
+        slice: annotate_snippet::Slice {
+            source: &slice_source,
+            line_start,
+            origin: Some(file_name),
+            annotations,
+        },
+        footer,
+    })
+}
+
+/// Format an error, using miette if `INCAN_FANCY_ERRORS` is set, structured JSON if
+/// `INCAN_ERROR_FORMAT=json` is set, or the `annotate-snippet`-style renderer if
+/// `INCAN_ERROR_FORMAT=annotate` is set.
 ///
 /// Set `INCAN_FANCY_ERRORS=1` to enable miette's fancy error output.
 pub fn format_error_smart(file_name: &str, source: &str, error: &CompileError) -> String {
-    if std::env::var("INCAN_FANCY_ERRORS").is_ok() {
+    if std::env::var("INCAN_ERROR_FORMAT").as_deref() == Ok("json") {
+        render_json(error, file_name, source)
+    } else if std::env::var("INCAN_ERROR_FORMAT").as_deref() == Ok("annotate") {
+        render_annotate_snippet(error, file_name, source)
+    } else if std::env::var("INCAN_FANCY_ERRORS").is_ok() {
         render_miette(error, file_name, source)
     } else {
         format_error(file_name, source, error)
     }
 }
 
+// ============================================================================
+// Diagnostic buffering: deterministic ordering, deduplication, and error limits
+// ============================================================================
+
+/// Which backend [`DiagnosticBuffer::render_all`] renders through.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenderBackend {
+    Miette,
+    Json,
+}
+
+/// Collects `CompileError`s across passes and emits them in a deterministic, deduplicated
+/// order, mirroring rustc's diagnostic buffering. Without this, errors print in whatever
+/// order passes happen to report them, and the same type error reported from more than one
+/// pass (a common occurrence - e.g. both the resolver and the typechecker balking at an
+/// unresolved symbol) shows up twice.
+#[derive(Debug, Default)]
+pub struct DiagnosticBuffer {
+    errors: Vec<CompileError>,
+}
+
+impl DiagnosticBuffer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Buffer a single error.
+    pub fn push(&mut self, error: CompileError) {
+        self.errors.push(error);
+    }
+
+    /// Buffer errors from, e.g., a pass's `Err(Vec<CompileError>)`.
+    pub fn extend(&mut self, errors: impl IntoIterator<Item = CompileError>) {
+        self.errors.extend(errors);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    /// Sort by primary span start, breaking ties by span end and then by the line/column
+    /// [`get_line_info`] reports against `source` (byte offsets alone can't distinguish
+    /// zero-width spans on different lines), and drop duplicate (message, span, code)
+    /// triples - the signature of the same error surfacing from more than one pass.
+    fn sorted_deduped(&self, source: &str) -> Vec<&CompileError> {
+        let mut indices: Vec<usize> = (0..self.errors.len()).collect();
+        indices.sort_by_key(|&i| {
+            let span = self.errors[i].span;
+            let (line, col, _) = get_line_info(source, span.start);
+            (span.start, span.end, line, col)
+        });
+
+        let mut seen = std::collections::HashSet::new();
+        indices
+            .into_iter()
+            .map(|i| &self.errors[i])
+            .filter(|error| {
+                seen.insert((error.message.clone(), error.span.start, error.span.end, error.code))
+            })
+            .collect()
+    }
+
+    /// Render the sorted, deduplicated set through `backend` in one pass.
+    ///
+    /// If `INCAN_ERROR_LIMIT` is set to a number, rendering stops after that many errors and
+    /// appends an "aborting due to N previous errors" summary, mirroring rustc's behavior
+    /// under `-Z error-limit` so a single bad file can't flood the terminal.
+    pub fn render_all(&self, backend: RenderBackend, file_name: &str, source: &str) -> String {
+        let deduped = self.sorted_deduped(source);
+        let total = deduped.len();
+
+        let limit = std::env::var("INCAN_ERROR_LIMIT")
+            .ok()
+            .and_then(|s| s.parse::<usize>().ok());
+        let shown = limit.map_or(total, |limit| total.min(limit));
+
+        let mut out = String::new();
+        for error in deduped.into_iter().take(shown) {
+            match backend {
+                RenderBackend::Miette => out.push_str(&render_miette(error, file_name, source)),
+                RenderBackend::Json => {
+                    out.push_str(&render_json(error, file_name, source));
+                    out.push('\n');
+                }
+            }
+        }
+
+        if shown < total {
+            let remaining = total - shown;
+            out.push_str(&format!(
+                "aborting due to {remaining} previous error{}\n",
+                if remaining == 1 { "" } else { "s" }
+            ));
+        }
+
+        out
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -831,4 +2085,42 @@ mod tests {
         assert_eq!(col, 4);
         assert_eq!(text, "line 2");
     }
+
+    #[test]
+    fn test_locale_resolve_falls_back_to_english_and_to_message() {
+        // No INCAN_LOCALE_DIR is configured in the test environment, so the compiled-in
+        // `en` bundle resolves a known id...
+        assert_eq!(
+            locale::resolve(
+                locale::ids::UNKNOWN_SYMBOL,
+                &[("name", "foo".to_string())],
+                "fallback message",
+            ),
+            "Unknown symbol 'foo'"
+        );
+
+        // ...and an unknown id falls back to the diagnostic's own English message.
+        assert_eq!(
+            locale::resolve("not-a-real-id", &[], "fallback message"),
+            "fallback message"
+        );
+    }
+
+    #[test]
+    fn test_diagnostic_buffer_sorts_and_dedupes() {
+        let source = "aaaa\nbbbb\ncccc\n";
+        let mut buffer = DiagnosticBuffer::new();
+        // Out of span order, with a duplicate of the second error.
+        buffer.push(CompileError::new("third".to_string(), Span::new(10, 11)));
+        buffer.push(CompileError::new("first".to_string(), Span::new(0, 1)));
+        buffer.push(CompileError::new("second".to_string(), Span::new(5, 6)));
+        buffer.push(CompileError::new("second".to_string(), Span::new(5, 6)));
+
+        let deduped = buffer.sorted_deduped(source);
+        assert_eq!(deduped.len(), 3);
+        assert_eq!(
+            deduped.iter().map(|e| e.message.as_str()).collect::<Vec<_>>(),
+            vec!["first", "second", "third"]
+        );
+    }
 }
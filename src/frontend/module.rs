@@ -348,6 +348,7 @@ mod tests {
     #[test]
     fn test_exported_symbols_enum_with_variants() {
         let enum_decl = EnumDecl {
+            decorators: vec![],
             name: "Color".to_string(),
             type_params: vec![],
             variants: vec![
@@ -391,6 +392,7 @@ mod tests {
     #[test]
     fn test_exported_symbols_newtype() {
         let newtype = NewtypeDecl {
+            decorators: vec![],
             name: "UserId".to_string(),
             underlying: make_spanned(Type::Simple("i64".to_string())),
             methods: vec![],
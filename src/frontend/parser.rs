@@ -1815,6 +1815,34 @@ impl<'a> Parser<'a> {
         ))
     }
 
+    /// Parse a comprehension's `for`/`if` clause chain: `for x in xs for y in ys if cond ...`.
+    ///
+    /// Requires at least one leading `for` clause; any number of further `for`/`if` clauses may
+    /// follow in any order, matching Python's comprehension grammar.
+    fn comp_clauses(&mut self) -> Result<Vec<CompClause>, CompileError> {
+        let mut clauses = Vec::new();
+        loop {
+            if self.match_token(&TokenKind::For) {
+                self.skip_newlines();
+                let var = self.identifier()?;
+                self.skip_newlines();
+                self.expect(&TokenKind::In, "Expected 'in' in comprehension")?;
+                self.skip_newlines();
+                let iter = self.expression()?;
+                self.skip_newlines();
+                clauses.push(CompClause::For { var, iter });
+            } else if self.match_token(&TokenKind::If) {
+                self.skip_newlines();
+                let cond = self.expression()?;
+                self.skip_newlines();
+                clauses.push(CompClause::If(cond));
+            } else {
+                break;
+            }
+        }
+        Ok(clauses)
+    }
+
     fn list_or_comp(&mut self, start: usize) -> Result<Spanned<Expr>, CompileError> {
         // Implicit line continuation: skip newlines after [
         self.skip_newlines();
@@ -1829,30 +1857,12 @@ impl<'a> Parser<'a> {
         self.skip_newlines();
 
         // Check for comprehension
-        if self.match_token(&TokenKind::For) {
-            self.skip_newlines();
-            let var = self.identifier()?;
-            self.skip_newlines();
-            self.expect(&TokenKind::In, "Expected 'in' in comprehension")?;
-            self.skip_newlines();
-            let iter = self.expression()?;
-            self.skip_newlines();
-            let filter = if self.match_token(&TokenKind::If) {
-                self.skip_newlines();
-                Some(self.expression()?)
-            } else {
-                None
-            };
-            self.skip_newlines();
+        if self.check(&TokenKind::For) {
+            let clauses = self.comp_clauses()?;
             self.expect(&TokenKind::RBracket, "Expected ']' after comprehension")?;
             let end = self.tokens[self.pos - 1].span.end;
             return Ok(Spanned::new(
-                Expr::ListComp(Box::new(ListComp {
-                    expr: first,
-                    var,
-                    iter,
-                    filter,
-                })),
+                Expr::ListComp(Box::new(ListComp { expr: first, clauses })),
                 Span::new(start, end),
             ));
         }
@@ -1893,30 +1903,15 @@ impl<'a> Parser<'a> {
             self.skip_newlines();
 
             // Check for comprehension
-            if self.match_token(&TokenKind::For) {
-                self.skip_newlines();
-                let var = self.identifier()?;
-                self.skip_newlines();
-                self.expect(&TokenKind::In, "Expected 'in' in comprehension")?;
-                self.skip_newlines();
-                let iter = self.expression()?;
-                self.skip_newlines();
-                let filter = if self.match_token(&TokenKind::If) {
-                    self.skip_newlines();
-                    Some(self.expression()?)
-                } else {
-                    None
-                };
-                self.skip_newlines();
+            if self.check(&TokenKind::For) {
+                let clauses = self.comp_clauses()?;
                 self.expect(&TokenKind::RBrace, "Expected '}' after comprehension")?;
                 let end = self.tokens[self.pos - 1].span.end;
                 return Ok(Spanned::new(
                     Expr::DictComp(Box::new(DictComp {
                         key: first,
                         value: first_value,
-                        var,
-                        iter,
-                        filter,
+                        clauses,
                     })),
                     Span::new(start, end),
                 ));
@@ -1940,6 +1935,15 @@ impl<'a> Parser<'a> {
             self.expect(&TokenKind::RBrace, "Expected '}' after dict")?;
             let end = self.tokens[self.pos - 1].span.end;
             Ok(Spanned::new(Expr::Dict(entries), Span::new(start, end)))
+        } else if self.check(&TokenKind::For) {
+            // Set comprehension: {expr for x in iter if cond}
+            let clauses = self.comp_clauses()?;
+            self.expect(&TokenKind::RBrace, "Expected '}' after comprehension")?;
+            let end = self.tokens[self.pos - 1].span.end;
+            Ok(Spanned::new(
+                Expr::SetComp(Box::new(SetComp { expr: first, clauses })),
+                Span::new(start, end),
+            ))
         } else {
             // It's a set literal: {expr, expr, ...}
             let mut elements = vec![first];
@@ -1977,6 +1981,17 @@ impl<'a> Parser<'a> {
         let first = self.expression()?;
         self.skip_newlines();
 
+        // Check for a generator expression: (expr for x in iter if cond)
+        if self.check(&TokenKind::For) {
+            let clauses = self.comp_clauses()?;
+            self.expect(&TokenKind::RParen, "Expected ')' after generator expression")?;
+            let end = self.tokens[self.pos - 1].span.end;
+            return Ok(Spanned::new(
+                Expr::GenExp(Box::new(GenExp { expr: first, clauses })),
+                Span::new(start, end),
+            ));
+        }
+
         // Check for tuple (needs comma)
         if self.match_token(&TokenKind::Comma) {
             self.skip_newlines();
@@ -73,6 +73,7 @@ impl SymbolTable {
                     type_params: vec![],
                     methods: HashMap::new(),
                     requires: vec![],
+                    on_unimplemented: None,
                 }),
                 span: Span::default(),
                 scope: 0,
@@ -298,6 +299,20 @@ impl SymbolTable {
         self.scopes[self.current_scope].symbols.get(name).copied()
     }
 
+    /// All symbol names visible from the current scope chain, for "did you mean" suggestions.
+    pub fn names_in_scope(&self) -> Vec<&str> {
+        let mut scope_idx = self.current_scope;
+        let mut names = Vec::new();
+        loop {
+            names.extend(self.scopes[scope_idx].symbols.keys().map(String::as_str));
+            match self.scopes[scope_idx].parent {
+                Some(parent) => scope_idx = parent,
+                None => break,
+            }
+        }
+        names
+    }
+
     /// Get a symbol by ID
     pub fn get(&self, id: SymbolId) -> Option<&Symbol> {
         self.symbols.get(id)
@@ -475,6 +490,8 @@ pub struct NewtypeInfo {
 pub struct EnumInfo {
     pub type_params: Vec<String>,
     pub variants: Vec<String>,
+    pub derives: Vec<String>,
+    pub formats: Vec<String>,
 }
 
 /// Trait information
@@ -483,6 +500,9 @@ pub struct TraitInfo {
     pub type_params: Vec<String>,
     pub methods: HashMap<String, MethodInfo>,
     pub requires: Vec<(String, ResolvedType)>, // Required fields
+    /// Custom `@on_unimplemented("...")` message template, with `{type}`/`{trait}`
+    /// placeholders, shown instead of the generic fallback hint.
+    pub on_unimplemented: Option<String>,
 }
 
 /// Module/import information
@@ -539,6 +559,10 @@ pub enum ResolvedType {
     TypeVar(String),
     /// Self type (resolved to the implementing type in traits)
     SelfType,
+    /// Optional type: `T | None` / `Optional[T]`
+    Optional(Box<ResolvedType>),
+    /// Union type (PEP 604): `int | str`
+    Union(Vec<ResolvedType>),
     /// Unknown/error type
     Unknown,
 }
@@ -627,6 +651,16 @@ impl std::fmt::Display for ResolvedType {
             }
             ResolvedType::TypeVar(name) => write!(f, "{}", name),
             ResolvedType::SelfType => write!(f, "Self"),
+            ResolvedType::Optional(inner) => write!(f, "Optional[{}]", inner),
+            ResolvedType::Union(members) => {
+                for (i, m) in members.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, " | ")?;
+                    }
+                    write!(f, "{}", m)?;
+                }
+                Ok(())
+            }
             ResolvedType::Unknown => write!(f, "?"),
         }
     }
@@ -693,6 +727,10 @@ pub fn resolve_type(ty: &Type, symbols: &SymbolTable) -> ResolvedType {
             ResolvedType::Tuple(resolved_elems)
         }
         Type::SelfType => ResolvedType::SelfType,
+        Type::Optional(inner) => ResolvedType::Optional(Box::new(resolve_type(&inner.node, symbols))),
+        Type::Union(members) => {
+            ResolvedType::Union(members.iter().map(|m| resolve_type(&m.node, symbols)).collect())
+        }
     }
 }
 
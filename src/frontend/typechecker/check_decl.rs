@@ -39,6 +39,7 @@ impl TypeChecker {
 
         // Validate @derive decorators
         self.validate_derives(&model.decorators);
+        self.validate_formats(&model.decorators);
 
         // Define type parameters
         for param in &model.type_params {
@@ -90,11 +91,14 @@ impl TypeChecker {
 
         // Validate @derive decorators
         self.validate_derives(&class.decorators);
+        self.validate_formats(&class.decorators);
 
         // Check base class exists
         if let Some(base) = &class.extends {
             if self.symbols.lookup(base).is_none() {
-                self.errors.push(errors::unknown_symbol(base, Span::default()));
+                let candidates = self.symbols.names_in_scope();
+                self.errors
+                    .push(errors::unknown_symbol(base, &candidates, Span::default()));
             }
         }
 
@@ -107,7 +111,9 @@ impl TypeChecker {
                     }
                 }
             } else {
-                self.errors.push(errors::unknown_symbol(trait_name, Span::default()));
+                let candidates = self.symbols.names_in_scope();
+                self.errors
+                    .push(errors::unknown_symbol(trait_name, &candidates, Span::default()));
             }
         }
 
@@ -146,12 +152,20 @@ impl TypeChecker {
     }
 
     fn check_trait_conformance(&mut self, class: &ClassDecl, trait_info: TraitInfo, trait_name: &str) {
+        let mut satisfied = true;
+
         // Check required fields
         for (field_name, _field_ty) in &trait_info.requires {
             let found = class.fields.iter().any(|f| &f.node.name == field_name);
             if !found {
-                self.errors
-                    .push(errors::missing_field(&class.name, field_name, Span::default()));
+                satisfied = false;
+                let candidates: Vec<&str> = class.fields.iter().map(|f| f.node.name.as_str()).collect();
+                self.errors.push(errors::missing_field(
+                    &class.name,
+                    field_name,
+                    &candidates,
+                    Span::default(),
+                ));
             }
         }
 
@@ -160,11 +174,24 @@ impl TypeChecker {
             if !method_info.has_body {
                 let found = class.methods.iter().any(|m| &m.node.name == method_name);
                 if !found {
+                    satisfied = false;
                     self.errors
                         .push(errors::missing_trait_method(trait_name, method_name, Span::default()));
                 }
             }
         }
+
+        // On top of the specific missing-field/method errors above, surface a tailored
+        // top-level hint: a user-defined `@on_unimplemented` message if the trait declared
+        // one, otherwise the builtin per-trait table in `errors::trait_not_implemented`.
+        if !satisfied {
+            self.errors.push(errors::trait_not_implemented(
+                &class.name,
+                trait_name,
+                trait_info.on_unimplemented.as_deref(),
+                Span::default(),
+            ));
+        }
     }
 
     fn check_trait(&mut self, tr: &TraitDecl) {
@@ -180,11 +207,17 @@ impl TypeChecker {
     }
 
     fn check_newtype(&mut self, nt: &NewtypeDecl) {
+        // Validate @derive decorators
+        self.validate_derives(&nt.decorators);
+        self.validate_formats(&nt.decorators);
+
         // Check underlying type exists
         let underlying = resolve_type(&nt.underlying.node, &self.symbols);
         if matches!(underlying, ResolvedType::Unknown) {
+            let candidates = self.symbols.names_in_scope();
             self.errors.push(errors::unknown_symbol(
                 &format!("{:?}", nt.underlying.node),
+                &candidates,
                 nt.underlying.span,
             ));
         }
@@ -227,13 +260,21 @@ impl TypeChecker {
     }
 
     fn check_enum(&mut self, en: &EnumDecl) {
+        // Validate @derive decorators
+        self.validate_derives(&en.decorators);
+        self.validate_formats(&en.decorators);
+
         // Check variant field types exist
         for variant in &en.variants {
             for field_ty in &variant.node.fields {
                 let resolved = resolve_type(&field_ty.node, &self.symbols);
                 if matches!(resolved, ResolvedType::Unknown) {
-                    self.errors
-                        .push(errors::unknown_symbol(&format!("{:?}", field_ty.node), field_ty.span));
+                    let candidates = self.symbols.names_in_scope();
+                    self.errors.push(errors::unknown_symbol(
+                        &format!("{:?}", field_ty.node),
+                        &candidates,
+                        field_ty.span,
+                    ));
                 }
             }
         }
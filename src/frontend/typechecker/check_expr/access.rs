@@ -3,12 +3,13 @@
 //! These helpers validate access patterns like `xs[i]`, `xs[a:b]`, `obj.field`, and
 //! `obj.method(...)`, emitting diagnostics for missing fields/methods and incompatible uses.
 
+use incan_core::lang::derives::{self, DeriveId};
+
 use crate::frontend::ast::*;
 use crate::frontend::diagnostics::errors;
 use crate::frontend::symbols::*;
 use crate::frontend::typechecker::helpers::{
-    DICT_TY_NAME, LIST_TY_NAME, SET_TY_NAME, is_frozen_bytes, is_frozen_str, is_intlike_for_index, list_ty, option_ty,
-    string_method_return,
+    DICT_TY_NAME, LIST_TY_NAME, SET_TY_NAME, is_frozen_bytes, is_frozen_str, is_intlike_for_index, string_method_return,
 };
 
 use super::TypeChecker;
@@ -28,19 +29,14 @@ impl TypeChecker {
             ResolvedType::Generic(name, args) => match name.as_str() {
                 "List" if !args.is_empty() => {
                     if !is_intlike_for_index(&index_ty) {
-                        self.errors
-                            .push(errors::index_type_mismatch("int", &index_ty.to_string(), index.span));
+                        self.push_index_mismatch(&ResolvedType::Int, &index_ty, index);
                     }
                     args[0].clone()
                 }
                 "Dict" if args.len() >= 2 => {
-                    let key_ty = &args[0];
-                    if !self.types_compatible(&index_ty, key_ty) {
-                        self.errors.push(errors::index_type_mismatch(
-                            &key_ty.to_string(),
-                            &index_ty.to_string(),
-                            index.span,
-                        ));
+                    let key_ty = args[0].clone();
+                    if !self.try_coerce(&index_ty, &key_ty) {
+                        self.push_index_mismatch(&key_ty, &index_ty, index);
                     }
                     args[1].clone()
                 }
@@ -67,8 +63,7 @@ impl TypeChecker {
             },
             ty if matches!(ty, ResolvedType::Str) || is_frozen_str(&ty) => {
                 if !is_intlike_for_index(&index_ty) {
-                    self.errors
-                        .push(errors::index_type_mismatch("int", &index_ty.to_string(), index.span));
+                    self.push_index_mismatch(&ResolvedType::Int, &index_ty, index);
                 }
                 ResolvedType::Str
             }
@@ -99,7 +94,7 @@ impl TypeChecker {
         &mut self,
         base: &Spanned<Expr>,
         slice: &SliceExpr,
-        _span: Span,
+        span: Span,
     ) -> ResolvedType {
         let base_ty = self.check_expr(base);
 
@@ -107,46 +102,141 @@ impl TypeChecker {
         let end_ty = slice.end.as_ref().map(|e| self.check_expr(e));
         let step_ty = slice.step.as_ref().map(|st| self.check_expr(st));
 
-        // Helper: validate that an already-computed type is int-like (or Unknown during inference).
-        let check_intlike_ty = |ty: &ResolvedType, span: Span, errors: &mut Vec<_>| {
-            if !is_intlike_for_index(ty) {
-                errors.push(errors::index_type_mismatch("int", &ty.to_string(), span));
-            }
-        };
-        // Helper: if a slice component exists, validate its already-computed type using the component span.
-        let check_component = |ty_opt: Option<&ResolvedType>, expr_opt: Option<&Spanned<Expr>>, errors: &mut Vec<_>| {
+        // Helper: if a slice component exists, validate its already-computed type using the
+        // component span, attaching a literal-rewrite suggestion when a whole-number float
+        // literal was used where an int is expected.
+        let check_component = |this: &mut Self, ty_opt: Option<&ResolvedType>, expr_opt: Option<&Spanned<Expr>>| {
             if let (Some(ty), Some(expr)) = (ty_opt, expr_opt) {
-                check_intlike_ty(ty, expr.span, errors);
+                if !is_intlike_for_index(ty) {
+                    this.push_index_mismatch(&ResolvedType::Int, ty, expr);
+                }
             }
         };
 
         match base_ty {
             ResolvedType::Generic(name, args) => match name.as_str() {
                 LIST_TY_NAME => ResolvedType::Generic(LIST_TY_NAME.to_string(), args),
+                DICT_TY_NAME => {
+                    self.errors
+                        .push(errors::not_sliceable(&ResolvedType::Generic(name, args).to_string(), span));
+                    ResolvedType::Unknown
+                }
                 _ => ResolvedType::Unknown,
             },
             ResolvedType::Str => {
                 // We typecheck each slice component once (above) and reuse the computed types here.
                 // This avoids re-walking the same expression multiple times and keeps error reporting
                 // anchored to the original component spans.
-                check_component(start_ty.as_ref(), slice.start.as_deref(), &mut self.errors);
-                check_component(end_ty.as_ref(), slice.end.as_deref(), &mut self.errors);
-                check_component(step_ty.as_ref(), slice.step.as_deref(), &mut self.errors);
+                check_component(self, start_ty.as_ref(), slice.start.as_deref());
+                check_component(self, end_ty.as_ref(), slice.end.as_deref());
+                check_component(self, step_ty.as_ref(), slice.step.as_deref());
                 ResolvedType::Str
             }
             ty if is_frozen_str(&ty) => {
                 // `FrozenStr` is the const-eval / deeply-immutable string type, but for indexing/slicing
                 // it behaves like `str`: indices must be int-like (or Unknown during inference).
                 // Reuse the exact same helper as `str` (the only difference is the receiver type).
-                check_component(start_ty.as_ref(), slice.start.as_deref(), &mut self.errors);
-                check_component(end_ty.as_ref(), slice.end.as_deref(), &mut self.errors);
-                check_component(step_ty.as_ref(), slice.step.as_deref(), &mut self.errors);
+                check_component(self, start_ty.as_ref(), slice.start.as_deref());
+                check_component(self, end_ty.as_ref(), slice.end.as_deref());
+                check_component(self, step_ty.as_ref(), slice.step.as_deref());
                 ResolvedType::Str
             }
+            ResolvedType::FrozenList(elem) => {
+                // Same shape as `str`/`FrozenStr`: only the components need validating, the
+                // receiver keeps its (frozen) element type.
+                check_component(self, start_ty.as_ref(), slice.start.as_deref());
+                check_component(self, end_ty.as_ref(), slice.end.as_deref());
+                check_component(self, step_ty.as_ref(), slice.step.as_deref());
+                ResolvedType::FrozenList(elem)
+            }
+            ResolvedType::Tuple(elems) => self.slice_tuple(&elems, slice),
             _ => ResolvedType::Unknown,
         }
     }
 
+    /// Narrow a tuple slice (`t[start:end:step]`) following Python's slicing semantics.
+    ///
+    /// When `start`/`end`/`step` are all either absent or integer literals, the exact surviving
+    /// positions are known at compile time, so the result is a precisely-typed sub-tuple. When
+    /// any bound isn't a literal, the surviving positions can't be known ahead of time; we fall
+    /// back to a same-arity tuple whose element type is widened to cover every original element
+    /// (a single type when the tuple is already homogeneous, otherwise their `Union`).
+    fn slice_tuple(&self, elems: &[ResolvedType], slice: &SliceExpr) -> ResolvedType {
+        // `None` means "absent" (use the Python default); `Some(None)` means "present but not a
+        // literal we can reason about precisely".
+        let literal_component = |component: &Option<Box<Spanned<Expr>>>| -> Option<Option<i64>> {
+            match component {
+                None => Some(None),
+                Some(expr) => match &expr.node {
+                    Expr::Literal(Literal::Int(n)) => Some(Some(*n)),
+                    _ => None,
+                },
+            }
+        };
+
+        let (start, end, step) = match (
+            literal_component(&slice.start),
+            literal_component(&slice.end),
+            literal_component(&slice.step),
+        ) {
+            (Some(start), Some(end), Some(step)) => (start, end, step),
+            _ => return Self::homogeneous_tuple(elems),
+        };
+
+        let len = elems.len() as i64;
+        let step = step.unwrap_or(1);
+        if step == 0 {
+            // Not constructible (Python raises `ValueError` here); give up precisely narrowing.
+            return Self::homogeneous_tuple(elems);
+        }
+
+        let normalize = |i: i64| -> i64 {
+            let i = if i < 0 { i + len } else { i };
+            if step > 0 { i.clamp(0, len) } else { i.clamp(-1, len - 1) }
+        };
+        let mut idx = start.map(normalize).unwrap_or(if step > 0 { 0 } else { len - 1 });
+        let stop = end.map(normalize).unwrap_or(if step > 0 { len } else { -1 });
+
+        let mut indices = Vec::new();
+        while (step > 0 && idx < stop) || (step < 0 && idx > stop) {
+            if idx >= 0 && idx < len {
+                indices.push(idx as usize);
+            }
+            idx += step;
+        }
+
+        ResolvedType::Tuple(indices.into_iter().map(|i| elems[i].clone()).collect())
+    }
+
+    /// A same-arity tuple whose element type has been widened to cover every element of
+    /// `elems`: the shared type when they're all equal, or their `Union` otherwise.
+    fn homogeneous_tuple(elems: &[ResolvedType]) -> ResolvedType {
+        let mut unique: Vec<ResolvedType> = Vec::new();
+        for elem in elems {
+            if !unique.contains(elem) {
+                unique.push(elem.clone());
+            }
+        }
+        let widened = match unique.len() {
+            0 => ResolvedType::Unknown,
+            1 => unique.into_iter().next().unwrap(),
+            _ => ResolvedType::Union(unique),
+        };
+        ResolvedType::Tuple(vec![widened; elems.len()])
+    }
+
+    /// Push an `index_type_mismatch` diagnostic, attaching a literal-rewrite suggestion when
+    /// the offending expression is a literal that would satisfy `expected` if rewritten (e.g.
+    /// `xs[2.0]` where `xs` is a list). Callers are expected to have already established that
+    /// `actual` doesn't satisfy `expected` (directly, or via [`Self::try_coerce`]).
+    fn push_index_mismatch(&mut self, expected: &ResolvedType, actual: &ResolvedType, expr: &Spanned<Expr>) {
+        let mut error = errors::index_type_mismatch(&expected.to_string(), &actual.to_string(), expr.span);
+        if let Some(suggestion) = Self::literal_coercion_suggestion(expr, expected) {
+            error = error.with_suggestion(suggestion);
+        }
+        self.errors.push(error);
+    }
+
     /// Type-check a field access (`base.field`) and return the field type.
     pub(in crate::frontend::typechecker::check_expr) fn check_field(
         &mut self,
@@ -171,51 +261,53 @@ impl TypeChecker {
             return ResolvedType::Unknown;
         }
 
-        match &base_ty {
-            ResolvedType::Tuple(elements) => {
-                if let Ok(idx) = field.parse::<usize>() {
-                    if idx < elements.len() {
-                        return elements[idx].clone();
-                    }
-                }
-                self.errors
-                    .push(errors::missing_field(&base_ty.to_string(), field, span));
-                ResolvedType::Unknown
-            }
-            ResolvedType::Named(type_name) => {
-                if let Some(type_info) = self.lookup_type_info(type_name) {
-                    match type_info {
-                        TypeInfo::Model(model) => {
-                            if let Some(field_info) = model.fields.get(field) {
-                                return field_info.ty.clone();
-                            }
-                        }
-                        TypeInfo::Class(class) => {
-                            if let Some(field_info) = class.fields.get(field) {
-                                return field_info.ty.clone();
-                            }
-                        }
-                        TypeInfo::Enum(enum_info) => {
-                            if enum_info.variants.contains(&field.to_string()) {
-                                return ResolvedType::Named(type_name.clone());
-                            }
-                        }
-                        TypeInfo::Newtype(nt) => {
-                            if field == "0" {
-                                return nt.underlying.clone();
-                            }
-                        }
-                        _ => {}
-                    }
-                }
-                self.errors.push(errors::missing_field(type_name, field, span));
-                ResolvedType::Unknown
-            }
-            _ => {
-                self.errors
-                    .push(errors::missing_field(&base_ty.to_string(), field, span));
-                ResolvedType::Unknown
-            }
+        // Try the receiver directly, then walk the autoderef chain (newtype wrappers,
+        // `Option`/`Result` payloads) before giving up.
+        if let Some((ty, _derefs)) = self.resolve_through_autoderef(&base_ty, |checker, t| checker.try_field(t, field)) {
+            return ty;
+        }
+
+        let candidates = self.field_candidates(&base_ty);
+        self.errors
+            .push(errors::missing_field(&base_ty.to_string(), field, &candidates, span));
+        ResolvedType::Unknown
+    }
+
+    /// Resolve `field` against `ty` itself, without reporting a diagnostic on failure. Used
+    /// both for the receiver's own type and for each step of the autoderef chain.
+    fn try_field(&self, ty: &ResolvedType, field: &str) -> Option<ResolvedType> {
+        match ty {
+            ResolvedType::Tuple(elements) => field
+                .parse::<usize>()
+                .ok()
+                .filter(|idx| *idx < elements.len())
+                .map(|idx| elements[idx].clone()),
+            ResolvedType::Named(type_name) => match self.lookup_type_info(type_name)? {
+                TypeInfo::Model(model) => model.fields.get(field).map(|f| f.ty.clone()),
+                TypeInfo::Class(class) => class.fields.get(field).map(|f| f.ty.clone()),
+                TypeInfo::Enum(enum_info) => enum_info
+                    .variants
+                    .contains(&field.to_string())
+                    .then(|| ResolvedType::Named(type_name.clone())),
+                TypeInfo::Newtype(nt) => (field == "0").then(|| nt.underlying.clone()),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Candidate field/variant names for a "no such field" suggestion, for the receiver's own
+    /// type (the autoderef chain isn't considered here — candidates stay scoped to what the
+    /// user actually wrote).
+    fn field_candidates<'a>(&'a self, ty: &ResolvedType) -> Vec<&'a str> {
+        let ResolvedType::Named(type_name) = ty else {
+            return Vec::new();
+        };
+        match self.lookup_type_info(type_name) {
+            Some(TypeInfo::Model(model)) => model.fields.keys().map(String::as_str).collect(),
+            Some(TypeInfo::Class(class)) => class.fields.keys().map(String::as_str).collect(),
+            Some(TypeInfo::Enum(enum_info)) => enum_info.variants.iter().map(String::as_str).collect(),
+            _ => Vec::new(),
         }
     }
 
@@ -245,8 +337,21 @@ impl TypeChecker {
         if let ResolvedType::Named(enum_name) = &base_ty {
             if let Some(TypeInfo::Enum(enum_info)) = self.lookup_type_info(enum_name) {
                 if enum_info.variants.iter().any(|v| v == method) {
-                    // Args were checked above; no strict arity enforcement here.
-                    let _ = &arg_types; // keep for potential future validation
+                    // Tuple-variant fields have no declared names; number them positionally,
+                    // the same convention `check_field` uses for newtype field 0.
+                    if let Some(id) = self.symbols.lookup(method) {
+                        if let Some(sym) = self.symbols.get(id) {
+                            if let SymbolKind::Variant(info) = &sym.kind {
+                                let params: Vec<(String, ResolvedType)> = info
+                                    .fields
+                                    .iter()
+                                    .enumerate()
+                                    .map(|(i, ty)| (i.to_string(), ty.clone()))
+                                    .collect();
+                                self.check_arg_matrix(enum_name, method, &params, args, &arg_types, span);
+                            }
+                        }
+                    }
                     return ResolvedType::Named(enum_name.clone());
                 }
             }
@@ -262,16 +367,12 @@ impl TypeChecker {
             }
         }
 
-        // Builtin methods for builtin types (so we don't report missing methods).
+        // Builtin methods for builtin types, driven by the `lang::surface::methods` registries
+        // (so we don't report missing methods, and method names/aliases stay in sync with the
+        // language surface instead of being re-listed here as string literals).
         if matches!(base_ty, ResolvedType::Float) {
-            match method {
-                // Math functions available on f64 in Rust
-                "sqrt" | "abs" | "floor" | "ceil" | "round" | "sin" | "cos" | "tan" | "exp" | "ln" | "log2"
-                | "log10" => return ResolvedType::Float,
-                "is_nan" | "is_infinite" | "is_finite" => return ResolvedType::Bool,
-                "powi" => return ResolvedType::Float, // float.powi(int) -> float
-                "powf" => return ResolvedType::Float, // float.powf(float) -> float
-                _ => {}
+            if let Some(ret) = self.try_float_method(method, args, &arg_types, span) {
+                return ret;
             }
         }
 
@@ -287,72 +388,52 @@ impl TypeChecker {
             }
         }
         if is_frozen_bytes(&base_ty) {
-            match method {
-                "len" => return ResolvedType::Int,
-                "is_empty" => return ResolvedType::Bool,
-                _ => {}
+            if let Some(ret) = self.try_frozen_bytes_method(method, args, &arg_types, span) {
+                return ret;
             }
         }
 
         match &base_ty {
-            ResolvedType::FrozenList(_) => match method {
-                "len" => return ResolvedType::Int,
-                "is_empty" => return ResolvedType::Bool,
-                _ => {}
-            },
-            ResolvedType::FrozenSet(_) => match method {
-                "len" => return ResolvedType::Int,
-                "is_empty" => return ResolvedType::Bool,
-                "contains" => return ResolvedType::Bool,
-                _ => {}
-            },
-            ResolvedType::FrozenDict(_, _) => match method {
-                "len" => return ResolvedType::Int,
-                "is_empty" => return ResolvedType::Bool,
-                "contains_key" => return ResolvedType::Bool,
-                _ => {}
-            },
+            ResolvedType::FrozenList(_) => {
+                if let Some(ret) = self.try_frozen_list_method(method, args, &arg_types, span) {
+                    return ret;
+                }
+            }
+            ResolvedType::FrozenSet(elem) => {
+                let elem = (**elem).clone();
+                if let Some(ret) = self.try_frozen_set_method(method, &elem, args, &arg_types, span) {
+                    return ret;
+                }
+            }
+            ResolvedType::FrozenDict(key, _) => {
+                let key = (**key).clone();
+                if let Some(ret) = self.try_frozen_dict_method(method, &key, args, &arg_types, span) {
+                    return ret;
+                }
+            }
             _ => {}
         }
 
         if let ResolvedType::Generic(name, type_args) = &base_ty {
+            let owner = base_ty.to_string();
             if name == LIST_TY_NAME {
                 let elem = type_args.first().cloned().unwrap_or(ResolvedType::Unknown);
-                match method {
-                    "append" => {
-                        if let Some(arg0) = arg_types.first() {
-                            if !self.types_compatible(arg0, &elem) {
-                                self.errors
-                                    .push(errors::type_mismatch(&elem.to_string(), &arg0.to_string(), span));
-                            }
-                        }
-                        return ResolvedType::Unit;
-                    }
-                    "pop" => return elem,
-                    "contains" => return ResolvedType::Bool,
-                    "swap" => return ResolvedType::Unit,
-                    "reserve" => return ResolvedType::Unit,
-                    "reserve_exact" => return ResolvedType::Unit,
-                    "remove" => return ResolvedType::Unit,
-                    "count" => return ResolvedType::Int,
-                    "index" => return ResolvedType::Int,
-                    _ => {}
+                if let Some(ret) = self.try_list_method(&owner, method, &elem, args, &arg_types, span) {
+                    return ret;
                 }
             }
             if name == DICT_TY_NAME {
                 let key = type_args.first().cloned().unwrap_or(ResolvedType::Unknown);
                 let val = type_args.get(1).cloned().unwrap_or(ResolvedType::Unknown);
-                match method {
-                    "keys" => return list_ty(key),
-                    "values" => return list_ty(val),
-                    // Allow get/insert helpers to match examples; keep return types simple.
-                    "get" => return option_ty(val.clone()),
-                    "insert" => return ResolvedType::Unit,
-                    _ => {}
+                if let Some(ret) = self.try_dict_method(&owner, method, &key, &val, args, &arg_types, span) {
+                    return ret;
                 }
             }
-            if name == SET_TY_NAME && method == "contains" {
-                return ResolvedType::Bool;
+            if name == SET_TY_NAME {
+                let elem = type_args.first().cloned().unwrap_or(ResolvedType::Unknown);
+                if let Some(ret) = self.try_set_method(&owner, method, &elem, args, &arg_types, span) {
+                    return ret;
+                }
             }
         }
 
@@ -360,46 +441,57 @@ impl TypeChecker {
         // If the symbol doesn't exist or isn't a type (e.g., Module/RustModule placeholder),
         // treat it as external and be permissive.
         if let ResolvedType::Named(type_name) = &base_ty {
-            match self.lookup_type_info(type_name) {
-                None => {
-                    // Symbol not found or not a Type - treat as external, be permissive.
-                    return ResolvedType::Unknown;
+            if self.lookup_type_info(type_name).is_none() {
+                // Symbol not found or not a Type - treat as external, be permissive.
+                return ResolvedType::Unknown;
+            }
+
+            // Be permissive for common error/display helpers on enums.
+            if method == "message" && matches!(self.lookup_type_info(type_name), Some(TypeInfo::Enum(_))) {
+                return ResolvedType::Str;
+            }
+
+            // Enums have no user-facing methods map, so to_json/from_json (injected by codegen
+            // when Serialize/Deserialize are derived) are resolved the same permissive way.
+            if let Some(TypeInfo::Enum(enum_info)) = self.lookup_type_info(type_name) {
+                let has_derive =
+                    |id: DeriveId| enum_info.derives.iter().any(|d| derives::from_str(d.as_str()) == Some(id));
+                if method == "to_json" && has_derive(DeriveId::Serialize) {
+                    return ResolvedType::Str;
                 }
-                Some(type_info) => match type_info {
-                    TypeInfo::Model(model) => {
-                        if let Some(method_info) = model.methods.get(method) {
-                            return method_info.return_type.clone();
-                        }
-                    }
-                    TypeInfo::Class(class) => {
-                        if let Some(method_info) = class.methods.get(method) {
-                            return method_info.return_type.clone();
-                        }
-                        for trait_name in &class.traits {
-                            if let Some(tid) = self.symbols.lookup(trait_name) {
-                                if let Some(tsym) = self.symbols.get(tid) {
-                                    if let SymbolKind::Trait(trait_info) = &tsym.kind {
-                                        if let Some(method_info) = trait_info.methods.get(method) {
-                                            return method_info.return_type.clone();
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                    }
-                    TypeInfo::Enum(_enum_info) => {
-                        // Be permissive for common error/display helpers on enums
-                        if method == "message" {
-                            return ResolvedType::Str;
-                        }
+                if method == "from_json" && has_derive(DeriveId::Deserialize) {
+                    return ResolvedType::Generic(
+                        "Result".to_string(),
+                        vec![ResolvedType::Named(type_name.clone()), ResolvedType::Str],
+                    );
+                }
+
+                // Same treatment for `@formats(...)` methods.
+                for format in &enum_info.formats {
+                    let payload_ty = match format.as_str() {
+                        "yaml" | "toml" => ResolvedType::Str,
+                        "msgpack" => ResolvedType::Bytes,
+                        _ => continue,
+                    };
+                    if method == format!("to_{format}") && has_derive(DeriveId::Serialize) {
+                        return payload_ty;
                     }
-                    TypeInfo::Newtype(nt) => {
-                        if let Some(method_info) = nt.methods.get(method) {
-                            return method_info.return_type.clone();
-                        }
+                    if method == format!("from_{format}") && has_derive(DeriveId::Deserialize) {
+                        return ResolvedType::Generic(
+                            "Result".to_string(),
+                            vec![ResolvedType::Named(type_name.clone()), ResolvedType::Str],
+                        );
                     }
-                    _ => {}
-                },
+                }
+            }
+
+            // Try the receiver directly, then walk the autoderef chain (newtype wrappers,
+            // `Option`/`Result` payloads) before giving up.
+            if let Some(((owner, method_info), _derefs)) =
+                self.resolve_through_autoderef(&base_ty, |checker, t| checker.try_method_info(t, method))
+            {
+                self.check_arg_matrix(&owner, method, &method_info.params, args, &arg_types, span);
+                return method_info.return_type;
             }
         }
 
@@ -429,9 +521,51 @@ impl TypeChecker {
         if !(matches!(base_ty, ResolvedType::Named(ref n) if self.symbols.lookup(n).is_none())
             || skip_error_for_known_runtime)
         {
+            let candidates: Vec<&str> = match &base_ty {
+                ResolvedType::Named(type_name) => match self.lookup_type_info(type_name) {
+                    Some(TypeInfo::Model(model)) => model.methods.keys().map(String::as_str).collect(),
+                    Some(TypeInfo::Class(class)) => class.methods.keys().map(String::as_str).collect(),
+                    Some(TypeInfo::Newtype(nt)) => nt.methods.keys().map(String::as_str).collect(),
+                    _ => Vec::new(),
+                },
+                _ => Vec::new(),
+            };
             self.errors
-                .push(errors::missing_method(&base_ty.to_string(), method, span));
+                .push(errors::missing_method(&base_ty.to_string(), method, &candidates, span));
         }
         ResolvedType::Unknown
     }
+
+    /// Resolve `method` against `ty` itself (a `Model`/`Class`/`Newtype`, including trait
+    /// methods inherited by a `Class`), without reporting a diagnostic on failure. Returns the
+    /// owning type's name alongside the method's signature, since after autoderef that name
+    /// may differ from the original receiver's. Used both for the receiver's own type and for
+    /// each step of the autoderef chain.
+    fn try_method_info(&self, ty: &ResolvedType, method: &str) -> Option<(String, MethodInfo)> {
+        let ResolvedType::Named(type_name) = ty else {
+            return None;
+        };
+        match self.lookup_type_info(type_name)? {
+            TypeInfo::Model(model) => model.methods.get(method).map(|m| (type_name.clone(), m.clone())),
+            TypeInfo::Class(class) => {
+                if let Some(m) = class.methods.get(method) {
+                    return Some((type_name.clone(), m.clone()));
+                }
+                for trait_name in &class.traits {
+                    if let Some(tid) = self.symbols.lookup(trait_name) {
+                        if let Some(tsym) = self.symbols.get(tid) {
+                            if let SymbolKind::Trait(trait_info) = &tsym.kind {
+                                if let Some(m) = trait_info.methods.get(method) {
+                                    return Some((type_name.clone(), m.clone()));
+                                }
+                            }
+                        }
+                    }
+                }
+                None
+            }
+            TypeInfo::Newtype(nt) => nt.methods.get(method).map(|m| (type_name.clone(), m.clone())),
+            _ => None,
+        }
+    }
 }
@@ -0,0 +1,149 @@
+//! Argument arity and type matching for method/constructor calls.
+//!
+//! Builds a 2-D compatibility matrix (provided arguments x declared parameters) and runs a
+//! minimal-edit diagnosis over it, modeled on rustc's `fn_ctxt/arg_matrix.rs`: too few/too
+//! many arguments, a single incompatible argument, two arguments that would line up if
+//! swapped, or one argument missing in the middle of an otherwise-aligned list. Exactly one
+//! consolidated diagnostic is pushed per call; a fully compatible matrix pushes nothing.
+
+use crate::frontend::ast::*;
+use crate::frontend::diagnostics::errors::{self, ArgMismatch};
+use crate::frontend::symbols::ResolvedType;
+
+use super::TypeChecker;
+
+/// One already type-checked call argument, paired with the span it came from.
+struct ProvidedArg {
+    ty: ResolvedType,
+    span: Span,
+}
+
+/// The single diagnosis chosen for a mismatched argument matrix.
+enum Diagnosis {
+    Count,
+    Incompatible(usize),
+    Swapped(usize, usize),
+    Missing(usize),
+    /// Counts match but three or more arguments are incompatible, or exactly two are and
+    /// swapping them wouldn't fix it - too many independent mismatches to call out a single
+    /// culprit (or pair) the way `Incompatible`/`Swapped` do.
+    MultiIncompatible(Vec<usize>),
+}
+
+impl TypeChecker {
+    /// Validate `args` (whose types have already been computed into `arg_types`) against a
+    /// method/constructor's declared `params`, pushing at most one consolidated diagnostic.
+    ///
+    /// Callers with an `Unknown` receiver should not call this at all; this is only for
+    /// receivers whose member's signature is fully known (`TypeInfo::Model`/`Class`/`Newtype`
+    /// methods and enum-variant constructors).
+    pub(in crate::frontend::typechecker::check_expr) fn check_arg_matrix(
+        &mut self,
+        owner: &str,
+        callee: &str,
+        params: &[(String, ResolvedType)],
+        args: &[CallArg],
+        arg_types: &[ResolvedType],
+        call_span: Span,
+    ) {
+        let provided: Vec<ProvidedArg> = args
+            .iter()
+            .zip(arg_types)
+            .map(|(arg, ty)| {
+                let expr = match arg {
+                    CallArg::Positional(e) | CallArg::Named(_, e) => e,
+                };
+                ProvidedArg {
+                    ty: ty.clone(),
+                    span: expr.span,
+                }
+            })
+            .collect();
+
+        // Row i, column j: whether provided[i] could fill params[j].
+        let matrix: Vec<Vec<bool>> = provided
+            .iter()
+            .map(|p| params.iter().map(|(_, ty)| self.types_compatible(&p.ty, ty)).collect())
+            .collect();
+
+        let Some(diagnosis) = Self::diagnose(&matrix, provided.len(), params.len()) else {
+            return;
+        };
+
+        let span = match &diagnosis {
+            Diagnosis::Incompatible(i) => provided[*i].span,
+            Diagnosis::Swapped(i, j) => provided[*i].span.merge(provided[*j].span),
+            Diagnosis::MultiIncompatible(indices) => indices
+                .iter()
+                .skip(1)
+                .fold(provided[indices[0]].span, |span, &i| span.merge(provided[i].span)),
+            Diagnosis::Count | Diagnosis::Missing(_) => call_span,
+        };
+
+        let mismatch = match diagnosis {
+            Diagnosis::Count => ArgMismatch::Count {
+                expected: params.len(),
+                found: provided.len(),
+            },
+            Diagnosis::Incompatible(i) => ArgMismatch::Incompatible {
+                index: i,
+                param: &params[i].0,
+                expected: params[i].1.to_string(),
+                found: provided[i].ty.to_string(),
+            },
+            Diagnosis::Swapped(i, j) => ArgMismatch::Swapped {
+                first: (i, &params[i].0),
+                second: (j, &params[j].0),
+            },
+            Diagnosis::Missing(i) => ArgMismatch::Missing {
+                index: i,
+                param: &params[i].0,
+                expected: params[i].1.to_string(),
+            },
+            Diagnosis::MultiIncompatible(indices) => ArgMismatch::MultiIncompatible {
+                args: indices
+                    .into_iter()
+                    .map(|i| errors::IncompatibleArg {
+                        index: i,
+                        param: &params[i].0,
+                        expected: params[i].1.to_string(),
+                        found: provided[i].ty.to_string(),
+                    })
+                    .collect(),
+            },
+        };
+
+        self.errors.push(errors::argument_mismatch(owner, callee, mismatch, span));
+    }
+
+    /// Run the minimal-edit diagnosis over a provided/declared compatibility `matrix`.
+    /// Returns `None` when every provided argument matches its positional parameter.
+    fn diagnose(matrix: &[Vec<bool>], n_provided: usize, n_params: usize) -> Option<Diagnosis> {
+        if n_provided == n_params {
+            let mismatches: Vec<usize> = (0..n_provided).filter(|&i| !matrix[i][i]).collect();
+            return match mismatches.as_slice() {
+                [] => None,
+                [i] => Some(Diagnosis::Incompatible(*i)),
+                [i, j] if matrix[*i][*j] && matrix[*j][*i] => Some(Diagnosis::Swapped(*i, *j)),
+                // Counts already match, so this is never actually a count problem - either a
+                // swap wouldn't fix the two mismatches, or there are three or more of them.
+                _ => Some(Diagnosis::MultiIncompatible(mismatches)),
+            };
+        }
+
+        if n_provided + 1 == n_params {
+            // Look for a single shift point where everything before it lines up on the
+            // diagonal and everything from it on lines up one column to the right, i.e. a
+            // missing argument at that position.
+            for missing_at in 0..=n_provided {
+                let left_ok = (0..missing_at).all(|i| matrix[i][i]);
+                let right_ok = (missing_at..n_provided).all(|i| matrix[i][i + 1]);
+                if left_ok && right_ok {
+                    return Some(Diagnosis::Missing(missing_at));
+                }
+            }
+        }
+
+        Some(Diagnosis::Count)
+    }
+}
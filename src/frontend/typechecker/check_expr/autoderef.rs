@@ -0,0 +1,59 @@
+//! Autoderef chain for field access and method resolution.
+//!
+//! Mirrors rustc's `autoderef.rs`: before `check_field`/`check_method_call` give up and
+//! report a missing field/method, walk a bounded chain of "transparent" layers (a user
+//! `Newtype`'s underlying type, or an `Option`/`Result`'s success payload) retrying the
+//! lookup at each step. The chain is cycle-guarded (by tracking visited type names) and
+//! capped so a newtype that wraps itself can't loop forever.
+
+use crate::frontend::symbols::{ResolvedType, TypeInfo};
+
+use super::TypeChecker;
+
+/// Deref chains longer than this are almost certainly a cycle; bail out rather than loop.
+const MAX_DEREF_STEPS: usize = 8;
+
+impl TypeChecker {
+    /// Peel one transparent layer off `ty`, if any: a user `Newtype`'s underlying type, or
+    /// an `Option`/`Result`'s success payload.
+    fn deref_step(&self, ty: &ResolvedType) -> Option<ResolvedType> {
+        match ty {
+            ResolvedType::Named(name) => match self.lookup_type_info(name) {
+                Some(TypeInfo::Newtype(nt)) => Some(nt.underlying.clone()),
+                _ => None,
+            },
+            ResolvedType::Optional(inner) => Some((**inner).clone()),
+            _ => ty.option_inner_type().or_else(|| ty.result_ok_type()).cloned(),
+        }
+    }
+
+    /// Try `resolve` against `ty`, then against each type reachable by repeatedly applying
+    /// [`Self::deref_step`] (cycle-guarded, capped at [`MAX_DEREF_STEPS`]). Returns the first
+    /// successful resolution along with how many derefs it took to reach it (`0` if `ty`
+    /// itself already resolved).
+    pub(in crate::frontend::typechecker::check_expr) fn resolve_through_autoderef<T>(
+        &self,
+        ty: &ResolvedType,
+        mut resolve: impl FnMut(&Self, &ResolvedType) -> Option<T>,
+    ) -> Option<(T, usize)> {
+        let mut current = ty.clone();
+        let mut seen = vec![current.to_string()];
+        let mut derefs = 0;
+        loop {
+            if let Some(found) = resolve(self, &current) {
+                return Some((found, derefs));
+            }
+            if derefs >= MAX_DEREF_STEPS {
+                return None;
+            }
+            let next = self.deref_step(&current)?;
+            let key = next.to_string();
+            if seen.contains(&key) {
+                return None;
+            }
+            seen.push(key);
+            current = next;
+            derefs += 1;
+        }
+    }
+}
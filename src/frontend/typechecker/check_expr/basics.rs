@@ -41,7 +41,9 @@ impl TypeChecker {
                 ResolvedType::Unknown
             }
         } else {
-            self.errors.push(errors::unknown_symbol(name, span));
+            let candidates = self.symbols.names_in_scope();
+            self.errors
+                .push(errors::unknown_symbol(name, &candidates, span));
             ResolvedType::Unknown
         }
     }
@@ -75,7 +77,9 @@ impl TypeChecker {
                 }
             }
         }
-        self.errors.push(errors::unknown_symbol("self", span));
+        let candidates = self.symbols.names_in_scope();
+        self.errors
+            .push(errors::unknown_symbol("self", &candidates, span));
         ResolvedType::Unknown
     }
 }
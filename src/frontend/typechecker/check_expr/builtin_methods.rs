@@ -0,0 +1,235 @@
+//! Builtin method tables, driven by the `lang::surface::methods` registries.
+//!
+//! `float`/`list`/`dict`/`set`/the frozen collections all expose a small, fixed set of builtin
+//! methods. Rather than matching on method-name string literals (which drift out of sync with
+//! the names/aliases the language surface actually declares), resolve the method against its
+//! registry first, then look up the declared parameter/return types locally (the registries only
+//! carry names, aliases, and docs — not types) and validate the call through
+//! [`TypeChecker::check_arg_matrix`], the same path `Model`/`Class` methods get.
+
+use incan_core::lang::surface::methods::dict_methods::{self, DictMethodId};
+use incan_core::lang::surface::methods::float_methods::{self, FloatMethodId};
+use incan_core::lang::surface::methods::frozen_bytes_methods::{self, FrozenBytesMethodId};
+use incan_core::lang::surface::methods::frozen_dict_methods::{self, FrozenDictMethodId};
+use incan_core::lang::surface::methods::frozen_list_methods::{self, FrozenListMethodId};
+use incan_core::lang::surface::methods::frozen_set_methods::{self, FrozenSetMethodId};
+use incan_core::lang::surface::methods::list_methods::{self, ListMethodId};
+use incan_core::lang::surface::methods::set_methods::{self, SetMethodId};
+
+use crate::frontend::ast::{CallArg, Span};
+use crate::frontend::diagnostics::errors;
+use crate::frontend::symbols::ResolvedType;
+use crate::frontend::typechecker::helpers::{list_ty, option_ty};
+
+use super::TypeChecker;
+
+impl TypeChecker {
+    /// Try `method` as a builtin `float` method.
+    pub(in crate::frontend::typechecker::check_expr) fn try_float_method(
+        &mut self,
+        method: &str,
+        args: &[CallArg],
+        arg_types: &[ResolvedType],
+        span: Span,
+    ) -> Option<ResolvedType> {
+        let id = float_methods::from_str(method)?;
+        let (params, ret): (Vec<(String, ResolvedType)>, ResolvedType) = match id {
+            FloatMethodId::Sqrt
+            | FloatMethodId::Abs
+            | FloatMethodId::Floor
+            | FloatMethodId::Ceil
+            | FloatMethodId::Round
+            | FloatMethodId::Sin
+            | FloatMethodId::Cos
+            | FloatMethodId::Tan
+            | FloatMethodId::Exp
+            | FloatMethodId::Ln
+            | FloatMethodId::Log2
+            | FloatMethodId::Log10 => (vec![], ResolvedType::Float),
+            FloatMethodId::IsNan | FloatMethodId::IsInfinite | FloatMethodId::IsFinite => (vec![], ResolvedType::Bool),
+            FloatMethodId::Powi => (vec![("exp".to_string(), ResolvedType::Int)], ResolvedType::Float),
+            FloatMethodId::Powf => (vec![("exp".to_string(), ResolvedType::Float)], ResolvedType::Float),
+        };
+        self.check_arg_matrix("float", float_methods::as_str(id), &params, args, arg_types, span);
+        Some(ret)
+    }
+
+    /// Try `method` as a builtin `FrozenBytes` method (all zero-arity today).
+    pub(in crate::frontend::typechecker::check_expr) fn try_frozen_bytes_method(
+        &mut self,
+        method: &str,
+        args: &[CallArg],
+        arg_types: &[ResolvedType],
+        span: Span,
+    ) -> Option<ResolvedType> {
+        let id = frozen_bytes_methods::from_str(method)?;
+        let ret = match id {
+            FrozenBytesMethodId::Len => ResolvedType::Int,
+            FrozenBytesMethodId::IsEmpty => ResolvedType::Bool,
+        };
+        self.check_arg_matrix("bytes", frozen_bytes_methods::as_str(id), &[], args, arg_types, span);
+        Some(ret)
+    }
+
+    /// Try `method` as a builtin `FrozenList[T]` method.
+    pub(in crate::frontend::typechecker::check_expr) fn try_frozen_list_method(
+        &mut self,
+        method: &str,
+        args: &[CallArg],
+        arg_types: &[ResolvedType],
+        span: Span,
+    ) -> Option<ResolvedType> {
+        let id = frozen_list_methods::from_str(method)?;
+        let ret = match id {
+            FrozenListMethodId::Len => ResolvedType::Int,
+            FrozenListMethodId::IsEmpty => ResolvedType::Bool,
+        };
+        self.check_arg_matrix(
+            "FrozenList",
+            frozen_list_methods::as_str(id),
+            &[],
+            args,
+            arg_types,
+            span,
+        );
+        Some(ret)
+    }
+
+    /// Try `method` as a builtin `FrozenSet[T]` method.
+    pub(in crate::frontend::typechecker::check_expr) fn try_frozen_set_method(
+        &mut self,
+        method: &str,
+        elem: &ResolvedType,
+        args: &[CallArg],
+        arg_types: &[ResolvedType],
+        span: Span,
+    ) -> Option<ResolvedType> {
+        let id = frozen_set_methods::from_str(method)?;
+        let (params, ret) = match id {
+            FrozenSetMethodId::Len => (vec![], ResolvedType::Int),
+            FrozenSetMethodId::IsEmpty => (vec![], ResolvedType::Bool),
+            FrozenSetMethodId::Contains => (vec![("value".to_string(), elem.clone())], ResolvedType::Bool),
+        };
+        self.check_arg_matrix("FrozenSet", frozen_set_methods::as_str(id), &params, args, arg_types, span);
+        Some(ret)
+    }
+
+    /// Try `method` as a builtin `FrozenDict[K, V]` method.
+    pub(in crate::frontend::typechecker::check_expr) fn try_frozen_dict_method(
+        &mut self,
+        method: &str,
+        key: &ResolvedType,
+        args: &[CallArg],
+        arg_types: &[ResolvedType],
+        span: Span,
+    ) -> Option<ResolvedType> {
+        let id = frozen_dict_methods::from_str(method)?;
+        let (params, ret) = match id {
+            FrozenDictMethodId::Len => (vec![], ResolvedType::Int),
+            FrozenDictMethodId::IsEmpty => (vec![], ResolvedType::Bool),
+            FrozenDictMethodId::ContainsKey => (vec![("key".to_string(), key.clone())], ResolvedType::Bool),
+        };
+        self.check_arg_matrix(
+            "FrozenDict",
+            frozen_dict_methods::as_str(id),
+            &params,
+            args,
+            arg_types,
+            span,
+        );
+        Some(ret)
+    }
+
+    /// Try `method` as a builtin `List[T]` method.
+    ///
+    /// `append` keeps its own coercion-aware check (int-to-float widening, a suggested literal
+    /// rewrite; see [`TypeChecker::try_coerce`]) rather than going through the plain
+    /// `check_arg_matrix` path every other list method uses.
+    pub(in crate::frontend::typechecker::check_expr) fn try_list_method(
+        &mut self,
+        owner: &str,
+        method: &str,
+        elem: &ResolvedType,
+        args: &[CallArg],
+        arg_types: &[ResolvedType],
+        span: Span,
+    ) -> Option<ResolvedType> {
+        let id = list_methods::from_str(method)?;
+        if id == ListMethodId::Append {
+            if let (Some(arg0), Some(arg0_expr)) = (arg_types.first(), args.first()) {
+                if !self.try_coerce(arg0, elem) {
+                    let expr = match arg0_expr {
+                        CallArg::Positional(e) | CallArg::Named(_, e) => e,
+                    };
+                    let mut error = errors::type_mismatch(&elem.to_string(), &arg0.to_string(), expr.span);
+                    if let Some(suggestion) = Self::literal_coercion_suggestion(expr, elem) {
+                        error = error.with_suggestion(suggestion);
+                    }
+                    self.errors.push(error);
+                }
+            }
+            return Some(ResolvedType::Unit);
+        }
+
+        let (params, ret) = match id {
+            ListMethodId::Append => unreachable!("handled above"),
+            ListMethodId::Pop => (vec![], elem.clone()),
+            ListMethodId::Contains => (vec![("value".to_string(), elem.clone())], ResolvedType::Bool),
+            ListMethodId::Swap => (
+                vec![("a".to_string(), ResolvedType::Int), ("b".to_string(), ResolvedType::Int)],
+                ResolvedType::Unit,
+            ),
+            ListMethodId::Reserve | ListMethodId::ReserveExact => {
+                (vec![("additional".to_string(), ResolvedType::Int)], ResolvedType::Unit)
+            }
+            ListMethodId::Remove => (vec![("index".to_string(), ResolvedType::Int)], ResolvedType::Unit),
+            ListMethodId::Count => (vec![("value".to_string(), elem.clone())], ResolvedType::Int),
+            ListMethodId::Index => (vec![("value".to_string(), elem.clone())], ResolvedType::Int),
+        };
+        self.check_arg_matrix(owner, list_methods::as_str(id), &params, args, arg_types, span);
+        Some(ret)
+    }
+
+    /// Try `method` as a builtin `Dict[K, V]` method.
+    pub(in crate::frontend::typechecker::check_expr) fn try_dict_method(
+        &mut self,
+        owner: &str,
+        method: &str,
+        key: &ResolvedType,
+        val: &ResolvedType,
+        args: &[CallArg],
+        arg_types: &[ResolvedType],
+        span: Span,
+    ) -> Option<ResolvedType> {
+        let id = dict_methods::from_str(method)?;
+        let (params, ret) = match id {
+            DictMethodId::Keys => (vec![], list_ty(key.clone())),
+            DictMethodId::Values => (vec![], list_ty(val.clone())),
+            DictMethodId::Get => (vec![("key".to_string(), key.clone())], option_ty(val.clone())),
+            DictMethodId::Insert => (
+                vec![("key".to_string(), key.clone()), ("value".to_string(), val.clone())],
+                ResolvedType::Unit,
+            ),
+        };
+        self.check_arg_matrix(owner, dict_methods::as_str(id), &params, args, arg_types, span);
+        Some(ret)
+    }
+
+    /// Try `method` as a builtin `Set[T]` method.
+    pub(in crate::frontend::typechecker::check_expr) fn try_set_method(
+        &mut self,
+        owner: &str,
+        method: &str,
+        elem: &ResolvedType,
+        args: &[CallArg],
+        arg_types: &[ResolvedType],
+        span: Span,
+    ) -> Option<ResolvedType> {
+        let id = set_methods::from_str(method)?;
+        let params = match id {
+            SetMethodId::Contains => vec![("value".to_string(), elem.clone())],
+        };
+        self.check_arg_matrix(owner, set_methods::as_str(id), &params, args, arg_types, span);
+        Some(ResolvedType::Bool)
+    }
+}
@@ -179,6 +179,42 @@ impl TypeChecker {
                     }
                     Some(ResolvedType::Unit)
                 }
+                BuiltinFnId::Min | BuiltinFnId::Max => {
+                    // `min`/`max` accept either one iterable or two-or-more scalars; both shapes
+                    // resolve to the element/scalar type of the first argument.
+                    let arg_types = self.check_call_arg_types(args);
+                    match arg_types.first() {
+                        Some(ResolvedType::Generic(name, type_args))
+                            if (name == surface_types::as_str(SurfaceTypeId::Vec)
+                                || matches!(
+                                    collection_type_id(name.as_str()),
+                                    Some(CollectionTypeId::List | CollectionTypeId::FrozenList)
+                                ))
+                                && !type_args.is_empty() =>
+                        {
+                            Some(type_args[0].clone())
+                        }
+                        Some(ty) => Some(ty.clone()),
+                        None => Some(ResolvedType::Unknown),
+                    }
+                }
+                BuiltinFnId::Sorted | BuiltinFnId::Reversed => {
+                    let arg_types = self.check_call_arg_types(args);
+                    Some(arg_types.into_iter().next().unwrap_or(ResolvedType::Unknown))
+                }
+                BuiltinFnId::Round => {
+                    self.check_call_args(args);
+                    Some(ResolvedType::Int)
+                }
+                BuiltinFnId::Map | BuiltinFnId::Filter => {
+                    // `map(f, xs)`/`filter(f, xs)` -> same list shape as their `xs` argument.
+                    let arg_types = self.check_call_arg_types(args);
+                    Some(arg_types.into_iter().nth(1).unwrap_or(ResolvedType::Unknown))
+                }
+                BuiltinFnId::Any | BuiltinFnId::All => {
+                    self.check_call_args(args);
+                    Some(ResolvedType::Bool)
+                }
             };
         }
 
@@ -475,7 +511,8 @@ impl TypeChecker {
                 ResolvedType::Unknown
             }
         } else {
-            self.errors.push(errors::unknown_symbol(name, span));
+            let candidates = self.symbols.names_in_scope();
+            self.errors.push(errors::unknown_symbol(name, &candidates, span));
             ResolvedType::Unknown
         }
     }
@@ -0,0 +1,63 @@
+//! Coercions consulted before reporting an index/slice/append type mismatch.
+//!
+//! Modeled on rustc's `demand.rs`/`coercion.rs`: rather than reporting every type mismatch
+//! verbatim, first try a small set of narrow, read-position coercions (Python-like int-to-float
+//! widening, and `FrozenList`/`List` differing only by mutability), and when neither applies,
+//! attach a suggested literal rewrite to the diagnostic where one is obviously available. This
+//! is deliberately narrower than [`super::super::TypeChecker::types_compatible`]: it only backs
+//! the three call sites that opt into it (`check_index`, `check_slice`, the `append` check in
+//! `check_method_call`), so it can't change behavior anywhere else in the checker.
+
+use crate::frontend::ast::{Expr, Literal, Spanned};
+use crate::frontend::diagnostics::{Applicability, Suggestion};
+use crate::frontend::symbols::ResolvedType;
+use crate::frontend::typechecker::helpers::LIST_TY_NAME;
+
+use super::TypeChecker;
+
+impl TypeChecker {
+    /// Check whether `actual` can be used where `expected` is required, allowing a few narrow
+    /// coercions beyond [`Self::types_compatible`]: an `int` widens to `float`, and a
+    /// `FrozenList[T]` is accepted where the equivalent mutable `List[T]` is expected (they
+    /// differ only by mutability, and read positions don't care).
+    pub(in crate::frontend::typechecker::check_expr) fn try_coerce(
+        &self,
+        actual: &ResolvedType,
+        expected: &ResolvedType,
+    ) -> bool {
+        if self.types_compatible(actual, expected) {
+            return true;
+        }
+        match (actual, expected) {
+            (ResolvedType::Int, ResolvedType::Float) => true,
+            (ResolvedType::FrozenList(elem), ResolvedType::Generic(name, args))
+                if name == LIST_TY_NAME && args.len() == 1 =>
+            {
+                self.types_compatible(elem, &args[0])
+            }
+            _ => false,
+        }
+    }
+
+    /// When `expr` is a literal that would satisfy `expected` if rewritten (e.g. `3.0` where an
+    /// `int` is expected), produce a suggested replacement for it. Returns `None` when no such
+    /// rewrite is obvious.
+    pub(in crate::frontend::typechecker::check_expr) fn literal_coercion_suggestion(
+        expr: &Spanned<Expr>,
+        expected: &ResolvedType,
+    ) -> Option<Suggestion> {
+        let Expr::Literal(Literal::Float(value)) = &expr.node else {
+            return None;
+        };
+        if !matches!(expected, ResolvedType::Int) || value.fract() != 0.0 {
+            return None;
+        }
+        let rewritten = *value as i64;
+        Some(Suggestion::new(
+            format!("use the integer literal `{rewritten}`"),
+            expr.span,
+            rewritten.to_string(),
+            Applicability::MaybeIncorrect,
+        ))
+    }
+}
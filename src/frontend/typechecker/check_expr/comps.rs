@@ -5,36 +5,46 @@
 
 use crate::frontend::ast::*;
 use crate::frontend::symbols::*;
-use crate::frontend::typechecker::helpers::{dict_ty, list_ty};
+use crate::frontend::typechecker::helpers::{dict_ty, iterator_ty, list_ty, set_ty};
 
 use super::TypeChecker;
 
 impl TypeChecker {
+    /// Check a comprehension's `for`/`if` clauses in order, binding each `for`'s loop variable
+    /// into the (already-entered) current scope so later clauses and the element/value
+    /// expression can see it.
+    fn check_comp_clauses(&mut self, clauses: &[CompClause]) {
+        for clause in clauses {
+            match clause {
+                CompClause::For { var, iter } => {
+                    let iter_ty = self.check_expr(iter);
+                    let elem_ty = self.infer_iterator_element_type(&iter_ty);
+                    self.symbols.define(Symbol {
+                        name: var.clone(),
+                        kind: SymbolKind::Variable(VariableInfo {
+                            ty: elem_ty,
+                            is_mutable: false,
+                            is_used: false,
+                        }),
+                        span: iter.span,
+                        scope: 0,
+                    });
+                }
+                CompClause::If(cond) => {
+                    self.check_expr(cond);
+                }
+            }
+        }
+    }
+
     /// Type-check a list comprehension and return `List[T]`.
     pub(in crate::frontend::typechecker::check_expr) fn check_list_comp(
         &mut self,
         comp: &ListComp,
         _span: Span,
     ) -> ResolvedType {
-        let iter_ty = self.check_expr(&comp.iter);
-        let elem_ty = self.infer_iterator_element_type(&iter_ty);
-
         self.symbols.enter_scope(ScopeKind::Block);
-        self.symbols.define(Symbol {
-            name: comp.var.clone(),
-            kind: SymbolKind::Variable(VariableInfo {
-                ty: elem_ty,
-                is_mutable: false,
-                is_used: false,
-            }),
-            span: comp.iter.span,
-            scope: 0,
-        });
-
-        if let Some(filter) = &comp.filter {
-            self.check_expr(filter);
-        }
-
+        self.check_comp_clauses(&comp.clauses);
         let result_elem_ty = self.check_expr(&comp.expr);
         self.symbols.exit_scope();
 
@@ -47,25 +57,8 @@ impl TypeChecker {
         comp: &DictComp,
         _span: Span,
     ) -> ResolvedType {
-        let iter_ty = self.check_expr(&comp.iter);
-        let elem_ty = self.infer_iterator_element_type(&iter_ty);
-
         self.symbols.enter_scope(ScopeKind::Block);
-        self.symbols.define(Symbol {
-            name: comp.var.clone(),
-            kind: SymbolKind::Variable(VariableInfo {
-                ty: elem_ty,
-                is_mutable: false,
-                is_used: false,
-            }),
-            span: comp.iter.span,
-            scope: 0,
-        });
-
-        if let Some(filter) = &comp.filter {
-            self.check_expr(filter);
-        }
-
+        self.check_comp_clauses(&comp.clauses);
         let key_ty = self.check_expr(&comp.key);
         let val_ty = self.check_expr(&comp.value);
         self.symbols.exit_scope();
@@ -73,6 +66,37 @@ impl TypeChecker {
         dict_ty(key_ty, val_ty)
     }
 
+    /// Type-check a set comprehension and return `Set[T]`.
+    pub(in crate::frontend::typechecker::check_expr) fn check_set_comp(
+        &mut self,
+        comp: &SetComp,
+        _span: Span,
+    ) -> ResolvedType {
+        self.symbols.enter_scope(ScopeKind::Block);
+        self.check_comp_clauses(&comp.clauses);
+        let result_elem_ty = self.check_expr(&comp.expr);
+        self.symbols.exit_scope();
+
+        set_ty(result_elem_ty)
+    }
+
+    /// Type-check a generator expression and return `Iterator[T]`.
+    ///
+    /// Shares its scoping with [`Self::check_list_comp`]; unlike comprehensions it never
+    /// collects, so the emitter keeps its adapter chain lazy.
+    pub(in crate::frontend::typechecker::check_expr) fn check_gen_exp(
+        &mut self,
+        comp: &GenExp,
+        _span: Span,
+    ) -> ResolvedType {
+        self.symbols.enter_scope(ScopeKind::Block);
+        self.check_comp_clauses(&comp.clauses);
+        let result_elem_ty = self.check_expr(&comp.expr);
+        self.symbols.exit_scope();
+
+        iterator_ty(result_elem_ty)
+    }
+
     /// Type-check a closure expression and return a function type.
     pub(in crate::frontend::typechecker::check_expr) fn check_closure(
         &mut self,
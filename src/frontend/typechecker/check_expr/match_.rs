@@ -53,20 +53,35 @@ impl TypeChecker {
     fn check_pattern(&mut self, pattern: &Spanned<Pattern>, expected_ty: &ResolvedType) {
         match &pattern.node {
             Pattern::Wildcard => {}
-            Pattern::Binding(name) => {
-                self.symbols.define(Symbol {
-                    name: name.clone(),
-                    kind: SymbolKind::Variable(VariableInfo {
-                        ty: expected_ty.clone(),
-                        is_mutable: false,
-                        is_used: false,
-                    }),
-                    span: pattern.span,
-                    scope: 0,
-                });
-            }
+            Pattern::Binding(name) => self.bind_name(name, expected_ty.clone(), pattern.span),
             Pattern::Literal(_) => {}
-            Pattern::Constructor(name, sub_patterns) => {
+            Pattern::Constructor(name, sub_patterns, keyword_patterns) if !keyword_patterns.is_empty() => {
+                // Class pattern with keyword sub-patterns, e.g. `Point(x=0, y=y)`: each keyword
+                // checks against the matching struct/model field's type.
+                let field_types: Option<std::collections::HashMap<String, ResolvedType>> =
+                    self.symbols.lookup(name.as_str()).and_then(|id| self.symbols.get(id)).and_then(|sym| {
+                        match &sym.kind {
+                            SymbolKind::Type(TypeInfo::Model(info)) => {
+                                Some(info.fields.iter().map(|(n, f)| (n.clone(), f.ty.clone())).collect())
+                            }
+                            SymbolKind::Type(TypeInfo::Class(info)) => {
+                                Some(info.fields.iter().map(|(n, f)| (n.clone(), f.ty.clone())).collect())
+                            }
+                            _ => None,
+                        }
+                    });
+
+                for (field_name, pat) in keyword_patterns {
+                    let field_ty = field_types.as_ref().and_then(|fields| fields.get(field_name));
+                    if let Some(field_ty) = field_ty {
+                        self.check_pattern(pat, field_ty);
+                    } else {
+                        self.check_pattern(pat, &ResolvedType::Unknown);
+                    }
+                }
+                debug_assert!(sub_patterns.is_empty(), "parser never mixes positional and keyword args");
+            }
+            Pattern::Constructor(name, sub_patterns, _) => {
                 if let Some(cid) = constructors::from_str(name.as_str()) {
                     match cid {
                         ConstructorId::Ok => {
@@ -136,9 +151,61 @@ impl TypeChecker {
                     }
                 }
             }
+            Pattern::Sequence(seq) => {
+                let elem_ty = match expected_ty {
+                    ResolvedType::Generic(type_name, args) if type_name == collections::as_str(CollectionTypeId::List) => {
+                        args.first().cloned().unwrap_or(ResolvedType::Unknown)
+                    }
+                    _ => ResolvedType::Unknown,
+                };
+                for pat in seq.prefix.iter().chain(seq.suffix.iter()) {
+                    self.check_pattern(pat, &elem_ty);
+                }
+                if let Some(Some(rest_name)) = &seq.rest {
+                    self.bind_name(rest_name, expected_ty.clone(), pattern.span);
+                }
+            }
+            Pattern::Mapping(mapping) => {
+                let value_ty = match expected_ty {
+                    ResolvedType::Generic(type_name, args)
+                        if type_name == collections::as_str(CollectionTypeId::Dict) && args.len() >= 2 =>
+                    {
+                        args[1].clone()
+                    }
+                    _ => ResolvedType::Unknown,
+                };
+                for (_, value_pat) in &mapping.entries {
+                    self.check_pattern(value_pat, &value_ty);
+                }
+                if let Some(rest_name) = &mapping.rest {
+                    self.bind_name(rest_name, expected_ty.clone(), pattern.span);
+                }
+            }
+            Pattern::Or(alts) => {
+                // Every alternative checks against the same expected type; PEP 634 requires each
+                // to bind the same names with compatible types (the IR lowering relies on this
+                // too, since a single Rust `|` pattern shares one set of bindings).
+                for alt in alts {
+                    self.check_pattern(alt, expected_ty);
+                }
+            }
+            Pattern::As(inner, name) => {
+                self.check_pattern(inner, expected_ty);
+                self.bind_name(name, expected_ty.clone(), pattern.span);
+            }
         }
     }
 
+    /// Define a pattern-bound name in the current scope.
+    fn bind_name(&mut self, name: &str, ty: ResolvedType, span: Span) {
+        self.symbols.define(Symbol {
+            name: name.to_string(),
+            kind: SymbolKind::Variable(VariableInfo { ty, is_mutable: false, is_used: false }),
+            span,
+            scope: 0,
+        });
+    }
+
     /// Check that a match expression covers all possible cases.
     ///
     /// For enums, `Result`, and `Option`, verifies every variant is handled. Wildcards
@@ -187,7 +254,7 @@ impl TypeChecker {
                     Pattern::Literal(Literal::None) if subject_ty.is_option() => {
                         covered.insert(constructors::as_str(ConstructorId::None).to_string());
                     }
-                    Pattern::Constructor(name, _) => {
+                    Pattern::Constructor(name, _, _) => {
                         let variant_name = if name.contains("::") {
                             name.split("::").last().unwrap_or(name).to_string()
                         } else {
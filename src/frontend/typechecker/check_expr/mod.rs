@@ -13,8 +13,12 @@ use crate::frontend::symbols::ResolvedType;
 use super::TypeChecker;
 
 mod access;
+mod arg_matching;
+mod autoderef;
 mod basics;
+mod builtin_methods;
 mod calls;
+mod coercion;
 mod collections;
 mod comps;
 mod control_flow;
@@ -49,6 +53,8 @@ impl TypeChecker {
             Expr::If(if_expr) => self.check_if_expr(if_expr, expr.span),
             Expr::ListComp(comp) => self.check_list_comp(comp, expr.span),
             Expr::DictComp(comp) => self.check_dict_comp(comp, expr.span),
+            Expr::SetComp(comp) => self.check_set_comp(comp, expr.span),
+            Expr::GenExp(comp) => self.check_gen_exp(comp, expr.span),
             Expr::Closure(params, body) => self.check_closure(params, body, expr.span),
             Expr::Tuple(elems) => self.check_tuple(elems),
             Expr::List(elems) => self.check_list(elems),
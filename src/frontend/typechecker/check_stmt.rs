@@ -91,7 +91,9 @@ impl TypeChecker {
                         ));
                     }
                 } else {
-                    self.errors.push(errors::unknown_symbol(&compound.name, stmt.span));
+                    let candidates = self.symbols.names_in_scope();
+                    self.errors
+                        .push(errors::unknown_symbol(&compound.name, &candidates, stmt.span));
                 }
             }
             Statement::TupleUnpack(unpack) => {
@@ -270,7 +272,13 @@ impl TypeChecker {
                                 }
                                 None => {
                                     // Field doesn't exist
-                                    self.errors.push(errors::missing_field(type_name, field, span));
+                                    let candidates: Vec<&str> = match type_info {
+                                        TypeInfo::Model(model) => model.fields.keys().map(String::as_str).collect(),
+                                        TypeInfo::Class(class) => class.fields.keys().map(String::as_str).collect(),
+                                        _ => Vec::new(),
+                                    };
+                                    self.errors
+                                        .push(errors::missing_field(type_name, field, &candidates, span));
                                 }
                             }
                         }
@@ -284,7 +292,7 @@ impl TypeChecker {
             _ => {
                 // Cannot assign fields to primitive types
                 self.errors
-                    .push(errors::missing_field(&obj_ty.to_string(), field, span));
+                    .push(errors::missing_field(&obj_ty.to_string(), field, &[], span));
             }
         }
     }
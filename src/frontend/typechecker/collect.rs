@@ -111,6 +111,54 @@ fn testing_import_function_info(name: &str) -> Option<FunctionInfo> {
     }
 }
 
+/// Inject to_<fmt>/from_<fmt> methods for each `@formats(...)` entry, gated on the same
+/// Serialize/Deserialize derives that gate `to_json`/`from_json`.
+fn inject_format_methods(methods: &mut HashMap<String, MethodInfo>, type_name: &str, derives: &[String], formats: &[String]) {
+    let has_serialize = derives
+        .iter()
+        .any(|d| derives::from_str(d.as_str()) == Some(DeriveId::Serialize));
+    let has_deserialize = derives
+        .iter()
+        .any(|d| derives::from_str(d.as_str()) == Some(DeriveId::Deserialize));
+
+    for format in formats {
+        let (to_name, from_name, payload_ty) = match format.as_str() {
+            "yaml" => ("to_yaml", "from_yaml", ResolvedType::Str),
+            "toml" => ("to_toml", "from_toml", ResolvedType::Str),
+            "msgpack" => ("to_msgpack", "from_msgpack", ResolvedType::Bytes),
+            _ => continue,
+        };
+
+        if has_serialize {
+            methods.insert(
+                to_name.to_string(),
+                MethodInfo {
+                    receiver: Some(Receiver::Immutable),
+                    params: vec![],
+                    return_type: payload_ty.clone(),
+                    is_async: false,
+                    has_body: true,
+                },
+            );
+        }
+        if has_deserialize {
+            methods.insert(
+                from_name.to_string(),
+                MethodInfo {
+                    receiver: None, // Static method
+                    params: vec![("payload".to_string(), payload_ty)],
+                    return_type: ResolvedType::Generic(
+                        "Result".to_string(),
+                        vec![ResolvedType::Named(type_name.to_string()), ResolvedType::Str],
+                    ),
+                    is_async: false,
+                    has_body: true,
+                },
+            );
+        }
+    }
+}
+
 /// Inject to_json/from_json methods based on Serialize/Deserialize derives.
 fn inject_json_methods(methods: &mut HashMap<String, MethodInfo>, type_name: &str, derives: &[String]) {
     if derives
@@ -434,6 +482,7 @@ impl TypeChecker {
         // Inject JSON methods based on derives
         let derives = Self::extract_derive_names(&model.decorators);
         inject_json_methods(&mut methods, &model.name, &derives);
+        inject_format_methods(&mut methods, &model.name, &derives, &Self::extract_format_names(&model.decorators));
         let field_order: Vec<Ident> = model.fields.iter().map(|f| f.node.name.clone()).collect();
         inject_validate_methods(&mut methods, &model.name, &fields, &field_order, &derives);
 
@@ -464,6 +513,7 @@ impl TypeChecker {
         // Inject JSON methods based on derives
         let derives = Self::extract_derive_names(&class.decorators);
         inject_json_methods(&mut methods, &class.name, &derives);
+        inject_format_methods(&mut methods, &class.name, &derives, &Self::extract_format_names(&class.decorators));
 
         self.symbols.define(Symbol {
             name: class.name.clone(),
@@ -506,6 +556,7 @@ impl TypeChecker {
     fn collect_trait(&mut self, tr: &TraitDecl, span: Span) {
         let methods = collect_methods(&tr.methods, &self.symbols);
         let requires = self.extract_requires(&tr.decorators);
+        let on_unimplemented = Self::extract_on_unimplemented(&tr.decorators);
 
         self.symbols.define(Symbol {
             name: tr.name.clone(),
@@ -513,6 +564,7 @@ impl TypeChecker {
                 type_params: tr.type_params.clone(),
                 methods,
                 requires,
+                on_unimplemented,
             }),
             span,
             scope: 0,
@@ -564,6 +616,20 @@ impl TypeChecker {
         }
     }
 
+    /// Supported `@formats(...)` entries, kept in sync with the backend's `SUPPORTED_FORMATS`.
+    const SUPPORTED_FORMATS: &'static [&'static str] = &["yaml", "toml", "msgpack"];
+
+    /// Validate `@formats(...)` decorator entries against the supported format list.
+    pub(crate) fn validate_formats(&mut self, decorators: &[Spanned<Decorator>]) {
+        for dec in decorators_named(decorators, "formats") {
+            for (name, span) in positional_idents(&dec.node.args) {
+                if !Self::SUPPORTED_FORMATS.contains(&name) {
+                    self.errors.push(errors::unknown_format(name, span));
+                }
+            }
+        }
+    }
+
     /// Look up what kind of symbol a name refers to, if any.
     fn lookup_symbol_kind(&self, name: &str) -> Option<&'static str> {
         let sym_id = self.symbols.lookup(name)?;
@@ -593,6 +659,20 @@ impl TypeChecker {
             .collect()
     }
 
+    /// Extract a trait's `@on_unimplemented("message with {type}/{trait} placeholders")`
+    /// decorator, mirroring rustc's `#[rustc_on_unimplemented]` for domain-specific guidance.
+    fn extract_on_unimplemented(decorators: &[Spanned<Decorator>]) -> Option<String> {
+        decorators_named(decorators, "on_unimplemented")
+            .flat_map(|dec| dec.node.args.iter())
+            .find_map(|arg| match arg {
+                DecoratorArg::Positional(expr) => match &expr.node {
+                    Expr::Literal(Literal::String(s)) => Some(s.clone()),
+                    _ => None,
+                },
+                _ => None,
+            })
+    }
+
     /// Extract derive names from @derive decorators.
     pub(crate) fn extract_derive_names(decorators: &[Spanned<Decorator>]) -> Vec<String> {
         decorators_named(decorators, "derive")
@@ -601,10 +681,22 @@ impl TypeChecker {
             .collect()
     }
 
+    /// Extract format names from @formats decorators.
+    pub(crate) fn extract_format_names(decorators: &[Spanned<Decorator>]) -> Vec<String> {
+        decorators_named(decorators, "formats")
+            .flat_map(|dec| positional_idents(&dec.node.args))
+            .map(|(name, _)| name.to_string())
+            .collect()
+    }
+
     /// Register a newtype declaration with its underlying type and methods.
     fn collect_newtype(&mut self, nt: &NewtypeDecl, span: Span) {
         let underlying = resolve_type(&nt.underlying.node, &self.symbols);
-        let methods = collect_methods(&nt.methods, &self.symbols);
+        let mut methods = collect_methods(&nt.methods, &self.symbols);
+
+        let derives = Self::extract_derive_names(&nt.decorators);
+        inject_json_methods(&mut methods, &nt.name, &derives);
+        inject_format_methods(&mut methods, &nt.name, &derives, &Self::extract_format_names(&nt.decorators));
 
         self.symbols.define(Symbol {
             name: nt.name.clone(),
@@ -623,6 +715,8 @@ impl TypeChecker {
             kind: SymbolKind::Type(TypeInfo::Enum(EnumInfo {
                 type_params: en.type_params.clone(),
                 variants: variants.clone(),
+                derives: Self::extract_derive_names(&en.decorators),
+                formats: Self::extract_format_names(&en.decorators),
             })),
             span,
             scope: 0,
@@ -149,7 +149,9 @@ impl TypeChecker {
         }
 
         let Some((decl, decl_span)) = self.const_decls.get(name).cloned() else {
-            self.errors.push(errors::unknown_symbol(name, Span::default()));
+            let candidates: Vec<&str> = self.const_decls.keys().map(String::as_str).collect();
+            self.errors
+                .push(errors::unknown_symbol(name, &candidates, Span::default()));
             return None;
         };
 
@@ -646,6 +648,8 @@ impl TypeChecker {
             | Expr::MethodCall(_, _, _)
             | Expr::ListComp(_)
             | Expr::DictComp(_)
+            | Expr::SetComp(_)
+            | Expr::GenExp(_)
             | Expr::Await(_)
             | Expr::Match(_, _)
             | Expr::If(_)
@@ -43,12 +43,26 @@ pub fn string_method_return(method: &str, include_len: bool) -> Option<ResolvedT
     match id {
         StringMethodId::Upper
         | StringMethodId::Lower
+        | StringMethodId::Title
+        | StringMethodId::Capitalize
+        | StringMethodId::Casefold
         | StringMethodId::Strip
+        | StringMethodId::Lstrip
+        | StringMethodId::Rstrip
         | StringMethodId::Replace
         | StringMethodId::Join
         | StringMethodId::ToString => Some(ResolvedType::Str),
-        StringMethodId::SplitWhitespace | StringMethodId::Split => Some(list_ty(ResolvedType::Str)),
+        StringMethodId::SplitWhitespace
+        | StringMethodId::Split
+        | StringMethodId::Rsplit
+        | StringMethodId::Splitlines => Some(list_ty(ResolvedType::Str)),
+        StringMethodId::Partition | StringMethodId::Rpartition => {
+            Some(ResolvedType::Tuple(vec![ResolvedType::Str, ResolvedType::Str, ResolvedType::Str]))
+        }
         StringMethodId::Contains | StringMethodId::StartsWith | StringMethodId::EndsWith => Some(ResolvedType::Bool),
+        StringMethodId::Find | StringMethodId::Rfind | StringMethodId::Index | StringMethodId::Rindex | StringMethodId::Count => {
+            Some(ResolvedType::Int)
+        }
         StringMethodId::Len if include_len => Some(ResolvedType::Int),
         StringMethodId::IsEmpty if include_len => Some(ResolvedType::Bool),
         _ => None,
@@ -46,6 +46,11 @@ pub fn set_ty(elem: ResolvedType) -> ResolvedType {
     ResolvedType::Generic(collection_name(CollectionTypeId::Set).to_string(), vec![elem])
 }
 
+/// Construct an `Iterator[T]` type, the result of a generator expression.
+pub fn iterator_ty(elem: ResolvedType) -> ResolvedType {
+    ResolvedType::Generic(collection_name(CollectionTypeId::Iterator).to_string(), vec![elem])
+}
+
 /// Construct an `Option[T]` type.
 ///
 /// ## Parameters
@@ -830,3 +830,81 @@ def foo() -> int:
     let errs = result.err().unwrap();
     assert!(errs.iter().any(|e| e.message.contains("has no method")));
 }
+
+// ========================================
+// Argument matrix diagnosis (TypeChecker::check_arg_matrix)
+// ========================================
+
+#[test]
+fn test_arg_matrix_reports_swapped_arguments() {
+    let source = r#"
+class Pair:
+  tag: int
+
+  def mix(self, a: str, b: int) -> int:
+    return b
+
+def foo(p: Pair) -> int:
+  return p.mix(1, "x")
+"#;
+    let result = check_str(source);
+    assert!(result.is_err());
+    let errs = result.err().unwrap();
+    assert!(errs.iter().any(|e| e.message.contains("wrong order")));
+}
+
+#[test]
+fn test_arg_matrix_reports_missing_argument() {
+    let source = r#"
+class Pair:
+  tag: int
+
+  def mix(self, a: int, b: str) -> int:
+    return a
+
+def foo(p: Pair) -> int:
+  return p.mix(1)
+"#;
+    let result = check_str(source);
+    assert!(result.is_err());
+    let errs = result.err().unwrap();
+    assert!(errs.iter().any(|e| e.message.contains("is missing argument")));
+}
+
+#[test]
+fn test_arg_matrix_reports_multi_incompatible_not_just_a_count_error() {
+    let source = r#"
+class Triple:
+  tag: int
+
+  def mix(self, a: int, b: str, c: bool) -> int:
+    return a
+
+def foo(t: Triple) -> int:
+  return t.mix(true, 1, "x")
+"#;
+    let result = check_str(source);
+    assert!(result.is_err());
+    let errs = result.err().unwrap();
+    // Counts already match (3 provided, 3 declared) - this must not be reported as the
+    // self-contradictory "expects 3 arguments, but 3 were provided".
+    assert!(errs.iter().any(|e| e.message.contains("incompatible arguments") && !e.message.contains("expects 3")));
+}
+
+#[test]
+fn test_arg_matrix_reports_two_non_swappable_mismatches_as_incompatible() {
+    let source = r#"
+class Pair:
+  tag: int
+
+  def mix(self, a: int, b: str) -> int:
+    return a
+
+def foo(p: Pair) -> int:
+  return p.mix(true, 3.14)
+"#;
+    let result = check_str(source);
+    assert!(result.is_err());
+    let errs = result.err().unwrap();
+    assert!(errs.iter().any(|e| e.message.contains("incompatible arguments")));
+}
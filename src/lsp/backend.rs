@@ -3,17 +3,74 @@
 use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::RwLock;
+use tokio_util::sync::CancellationToken;
 
 use tower_lsp::jsonrpc::Result;
 use tower_lsp::lsp_types::*;
 use tower_lsp::{Client, LanguageServer};
 
-use crate::frontend::ast::{Declaration, Program, Span, Type};
+use crate::frontend::ast::{
+    Declaration, FieldDecl, FunctionDecl, ImportKind, MethodDecl, Program, Receiver, Span, Spanned, Type,
+};
 use crate::frontend::module::resolve_import_path;
 use crate::frontend::{lexer, parser, typechecker};
-use crate::lsp::diagnostics::{compile_error_to_diagnostic, position_to_offset, span_to_range};
+use crate::lsp::diagnostics::{compile_error_to_diagnostic, offset_to_position, position_to_offset, span_to_range};
+
+/// Reserved words and literals: never valid rename/completion targets for user identifiers.
+const KEYWORDS: &[&str] = &[
+    "def", "async", "await", "return", "if", "elif", "else", "match", "case", "for", "in", "while", "let", "mut",
+    "model", "class", "trait", "enum", "newtype", "import", "from", "as", "with", "extends", "pub", "const", "True",
+    "False", "None", "Ok", "Err", "Some", "Result", "Option",
+];
+
+/// Diagnostic code for an import whose target file doesn't exist on disk. The matching
+/// diagnostic's `data` carries `{"path": ...}`, the resolved location a quick-fix would create.
+const MISSING_DEPENDENCY_CODE: &str = "missing-dependency";
+
+/// How long `did_change` waits for the edit to settle before analyzing, so a burst of keystrokes
+/// under FULL sync triggers one lex+parse+typecheck pass instead of one per character.
+const ANALYSIS_DEBOUNCE: Duration = Duration::from_millis(250);
+
+/// Semantic token type legend. Index into this array is the packed `token_type` field of every
+/// emitted `SemanticToken`, so the `TOK_*` constants below must track these positions exactly.
+const SEMANTIC_TOKEN_TYPES: &[SemanticTokenType] = &[
+    SemanticTokenType::TYPE,
+    SemanticTokenType::CLASS,
+    SemanticTokenType::ENUM,
+    SemanticTokenType::INTERFACE,
+    SemanticTokenType::STRUCT,
+    SemanticTokenType::PARAMETER,
+    SemanticTokenType::PROPERTY,
+    SemanticTokenType::ENUM_MEMBER,
+    SemanticTokenType::FUNCTION,
+    SemanticTokenType::METHOD,
+    SemanticTokenType::VARIABLE,
+];
+const TOK_TYPE: u32 = 0;
+const TOK_CLASS: u32 = 1;
+const TOK_ENUM: u32 = 2;
+const TOK_INTERFACE: u32 = 3;
+const TOK_STRUCT: u32 = 4;
+const TOK_PARAMETER: u32 = 5;
+const TOK_PROPERTY: u32 = 6;
+const TOK_ENUM_MEMBER: u32 = 7;
+const TOK_FUNCTION: u32 = 8;
+const TOK_METHOD: u32 = 9;
+/// `const` bindings are reported as `VARIABLE` with the `READONLY` modifier set, mirroring how
+/// rust-analyzer distinguishes `const`/`static` from `let` — the LSP spec has no dedicated
+/// "constant" token type.
+const TOK_CONST_AS_VARIABLE: u32 = 10;
+
+/// Semantic token modifier legend. Bit position in this array is the bit set in every emitted
+/// `SemanticToken::token_modifiers_bitset`.
+const SEMANTIC_TOKEN_MODIFIERS: &[SemanticTokenModifier] =
+    &[SemanticTokenModifier::DECLARATION, SemanticTokenModifier::READONLY];
+const MOD_DECLARATION: u32 = 1 << 0;
+const MOD_READONLY: u32 = 1 << 1;
 
 /// Document state stored by the LSP
 #[derive(Debug, Clone)]
@@ -29,9 +86,26 @@ pub struct DocumentState {
 }
 
 /// Incan Language Server
+///
+/// Cheap to `Clone`: every field is a `Client` (already clone-on-send) or an `Arc`, so a clone
+/// shares the same underlying state. Background analysis tasks clone the server to get a
+/// `'static` handle they can run on, without ceremony around partial borrows.
+#[derive(Clone)]
 pub struct IncanLanguageServer {
     client: Client,
     documents: Arc<RwLock<HashMap<Url, DocumentState>>>,
+    /// The `CancellationToken` for each document's most recently scheduled analysis. A new
+    /// `did_change` cancels whatever's here before installing its own token, so at most one
+    /// analysis per document is ever actually running.
+    pending_analyses: Arc<RwLock<HashMap<Url, CancellationToken>>>,
+    /// Reverse edges of the import graph: a dependency file's canonical path to every open
+    /// document that transitively imports it. Populated by `collect_dependency_modules` and
+    /// consulted by `did_change_watched_files` to know who to re-analyze when a file changes on
+    /// disk instead of through the editor.
+    reverse_deps: Arc<RwLock<HashMap<PathBuf, HashSet<Url>>>>,
+    /// Whether the client advertised `completion.completionItem.snippetSupport` at `initialize`.
+    /// Clients without it get a plain identifier back instead of a tab-stop snippet.
+    snippet_support: Arc<AtomicBool>,
 }
 
 impl IncanLanguageServer {
@@ -39,17 +113,64 @@ impl IncanLanguageServer {
         Self {
             client,
             documents: Arc::new(RwLock::new(HashMap::new())),
+            pending_analyses: Arc::new(RwLock::new(HashMap::new())),
+            reverse_deps: Arc::new(RwLock::new(HashMap::new())),
+            snippet_support: Arc::new(AtomicBool::new(false)),
         }
     }
 
-    /// Analyze a document and publish diagnostics
-    async fn analyze_document(&self, uri: &Url, source: &str, version: i32) {
+    /// Cancel any in-flight analysis for `uri` and schedule a fresh one after `ANALYSIS_DEBOUNCE`,
+    /// dropping its results if it's cancelled or superseded before it finishes. Background
+    /// counterpart to calling `analyze_document` directly, for the high-frequency `did_change`
+    /// and `did_change_watched_files` paths (as opposed to `did_open`, which wants its one
+    /// analysis to run immediately).
+    async fn schedule_analysis(&self, uri: Url, source: String, version: i32) {
+        let token = CancellationToken::new();
+        {
+            let mut pending = self.pending_analyses.write().await;
+            if let Some(previous) = pending.insert(uri.clone(), token.clone()) {
+                previous.cancel();
+            }
+        }
+
+        let server = self.clone();
+        tokio::spawn(async move {
+            tokio::select! {
+                _ = token.cancelled() => {}
+                _ = tokio::time::sleep(ANALYSIS_DEBOUNCE) => {
+                    server.analyze_document(&uri, &source, version, &token).await;
+                }
+            }
+        });
+    }
+
+    /// Record that `entry_uri` (transitively) depends on the file at `dep_path`, so a
+    /// `did_change_watched_files` notification for that path knows which open documents to
+    /// re-analyze. Recorded even for dependencies that don't currently exist on disk, so a
+    /// later `workspace/didChangeWatchedFiles` create event reanalyzes the importer too.
+    async fn record_reverse_dependency(&self, dep_path: &Path, entry_uri: &Url) {
+        let mut reverse_deps = self.reverse_deps.write().await;
+        reverse_deps.entry(dep_path.to_path_buf()).or_default().insert(entry_uri.clone());
+    }
+
+    /// Analyze a document and publish diagnostics.
+    ///
+    /// `cancel` is checked before each expensive step and again before the results are stored or
+    /// published, so a stale pass (cancelled by a newer edit) never overwrites newer results.
+    async fn analyze_document(&self, uri: &Url, source: &str, version: i32, cancel: &CancellationToken) {
+        if cancel.is_cancelled() {
+            return;
+        }
+
         let mut diagnostics = Vec::new();
 
         // Step 1: Lex
         let tokens = match lexer::lex(source) {
             Ok(tokens) => tokens,
             Err(errors) => {
+                if cancel.is_cancelled() {
+                    return;
+                }
                 // Convert all lexer errors to diagnostics
                 for error in &errors {
                     diagnostics.push(compile_error_to_diagnostic(error, source, uri));
@@ -65,6 +186,9 @@ impl IncanLanguageServer {
         let ast = match parser::parse(&tokens) {
             Ok(ast) => ast,
             Err(errors) => {
+                if cancel.is_cancelled() {
+                    return;
+                }
                 // Convert all parse errors to diagnostics
                 for error in &errors {
                     diagnostics.push(compile_error_to_diagnostic(error, source, uri));
@@ -76,11 +200,19 @@ impl IncanLanguageServer {
             }
         };
 
+        if cancel.is_cancelled() {
+            return;
+        }
+
         // Step 3: Type check (with multi-file import resolution)
         let mut checker = typechecker::TypeChecker::new();
         let (deps, mut dep_summary_diags) = self.collect_dependency_modules(uri, &ast, source, version).await;
         let dep_refs: Vec<(&str, &Program)> = deps.iter().map(|(name, program)| (name.as_str(), program)).collect();
 
+        if cancel.is_cancelled() {
+            return;
+        }
+
         if let Err(errors) = checker.check_with_imports(&ast, &dep_refs) {
             for error in &errors {
                 diagnostics.push(compile_error_to_diagnostic(error, source, uri));
@@ -102,6 +234,10 @@ impl IncanLanguageServer {
             }
         }
 
+        if cancel.is_cancelled() {
+            return;
+        }
+
         // Store AST for hover/goto
         {
             let mut docs = self.documents.write().await;
@@ -160,6 +296,7 @@ impl IncanLanguageServer {
             if !seen.insert(canonical.clone()) {
                 continue;
             }
+            self.record_reverse_dependency(&canonical, uri).await;
 
             // Prefer in-memory source if this file is open.
             let dep_uri = Url::from_file_path(&canonical).ok();
@@ -169,14 +306,31 @@ impl IncanLanguageServer {
                 .or_else(|| fs::read_to_string(&canonical).ok());
 
             let Some(dep_source) = dep_source else {
-                // If we can't read it, we can't typecheck it; skip.
+                // Unresolved import: `resolve_import_path` found where the module should live, but
+                // nothing's there. Carry that path in `data` so a code action can offer to create
+                // it without re-deriving it from the import statement.
+                let range = span_to_range(entry_source, import_span.start, import_span.end);
+                entry_diags.push(Diagnostic {
+                    range,
+                    severity: Some(DiagnosticSeverity::ERROR),
+                    code: Some(NumberOrString::String(MISSING_DEPENDENCY_CODE.to_string())),
+                    code_description: None,
+                    source: Some("incan".to_string()),
+                    message: format!("Dependency '{}' not found", canonical.display()),
+                    related_information: None,
+                    tags: None,
+                    data: Some(serde_json::json!({ "path": canonical.to_string_lossy() })),
+                });
                 continue;
             };
 
             let dep_tokens = match lexer::lex(&dep_source) {
                 Ok(t) => t,
                 Err(errors) => {
-                    // Guardrail: surface dependency lex errors.
+                    // Guardrail: surface dependency lex errors, and link the entry file's summary
+                    // diagnostic to each one's true location so an editor can jump straight there
+                    // instead of only "open that file and look".
+                    let related = dependency_related_information(dep_uri.as_ref(), &dep_source, &errors);
                     if let Some(u) = dep_uri.clone() {
                         let mut diags = Vec::new();
                         for e in &errors {
@@ -191,14 +345,14 @@ impl IncanLanguageServer {
                     entry_diags.push(Diagnostic {
                         range,
                         severity: Some(DiagnosticSeverity::ERROR),
-                        code: None,
+                        code: Some(NumberOrString::String("dependency-lex-error".to_string())),
                         code_description: None,
                         source: Some("incan".to_string()),
                         message: format!(
                             "Failed to lex dependency '{}'; open that file for details",
                             canonical.display()
                         ),
-                        related_information: None,
+                        related_information: related,
                         tags: None,
                         data: None,
                     });
@@ -208,7 +362,9 @@ impl IncanLanguageServer {
             let dep_ast = match parser::parse(&dep_tokens) {
                 Ok(a) => a,
                 Err(errors) => {
-                    // Guardrail: surface dependency parse errors.
+                    // Guardrail: surface dependency parse errors, linked the same way as lex
+                    // errors above.
+                    let related = dependency_related_information(dep_uri.as_ref(), &dep_source, &errors);
                     if let Some(u) = dep_uri.clone() {
                         let mut diags = Vec::new();
                         for e in &errors {
@@ -222,14 +378,14 @@ impl IncanLanguageServer {
                     entry_diags.push(Diagnostic {
                         range,
                         severity: Some(DiagnosticSeverity::ERROR),
-                        code: None,
+                        code: Some(NumberOrString::String("dependency-parse-error".to_string())),
                         code_description: None,
                         source: Some("incan".to_string()),
                         message: format!(
                             "Failed to parse dependency '{}'; open that file for details",
                             canonical.display()
                         ),
-                        related_information: None,
+                        related_information: related,
                         tags: None,
                         data: None,
                     });
@@ -264,6 +420,73 @@ impl IncanLanguageServer {
         (result, entry_diags)
     }
 
+    /// Collect every Incan file transitively reachable from `uri`'s imports, including the entry
+    /// file itself, keeping each file's `Url`, raw source text, and parsed `Program`.
+    ///
+    /// This mirrors `collect_dependency_modules`'s traversal (in-memory source preferred over
+    /// disk, canonicalized-path dedup) but keeps the data rename needs to build per-file edits,
+    /// and doesn't publish diagnostics for unparsable dependencies (a rename should just skip them).
+    async fn collect_all_files(&self, uri: &Url, ast: &Program, source: &str) -> Vec<(Url, String, Program)> {
+        let mut result = vec![(uri.clone(), source.to_string(), ast.clone())];
+
+        let Ok(entry_path) = uri.to_file_path() else {
+            return result;
+        };
+        let entry_base = entry_path.parent().unwrap_or(Path::new(".")).to_path_buf();
+
+        let docs = self.documents.read().await;
+
+        let mut seen: HashSet<PathBuf> = HashSet::new();
+        if let Ok(canonical_entry) = entry_path.canonicalize() {
+            seen.insert(canonical_entry);
+        }
+        let mut stack: Vec<(PathBuf, PathBuf)> = Vec::new();
+        for decl in &ast.declarations {
+            if let Declaration::Import(import) = &decl.node {
+                if let Some(dep_path) = resolve_import_path(&entry_base, import) {
+                    let base = dep_path.parent().unwrap_or(&entry_base).to_path_buf();
+                    stack.push((dep_path, base));
+                }
+            }
+        }
+
+        while let Some((path, base_dir)) = stack.pop() {
+            let canonical = path.canonicalize().unwrap_or(path.clone());
+            if !seen.insert(canonical.clone()) {
+                continue;
+            }
+
+            let dep_uri = Url::from_file_path(&canonical).ok();
+            let dep_doc = dep_uri.as_ref().and_then(|u| docs.get(u));
+            let dep_source = dep_doc.map(|d| d.source.clone()).or_else(|| fs::read_to_string(&canonical).ok());
+
+            let Some(dep_source) = dep_source else {
+                continue;
+            };
+            let Ok(dep_tokens) = lexer::lex(&dep_source) else {
+                continue;
+            };
+            let Ok(dep_ast) = parser::parse(&dep_tokens) else {
+                continue;
+            };
+
+            for decl in &dep_ast.declarations {
+                if let Declaration::Import(import) = &decl.node {
+                    if let Some(nested_path) = resolve_import_path(&base_dir, import) {
+                        let nested_base = nested_path.parent().unwrap_or(&base_dir).to_path_buf();
+                        stack.push((nested_path, nested_base));
+                    }
+                }
+            }
+
+            if let Some(u) = dep_uri {
+                result.push((u, dep_source, dep_ast));
+            }
+        }
+
+        result
+    }
+
     /// Find the symbol at a position in the AST
     fn find_symbol_at_position(&self, ast: &Program, source: &str, position: Position) -> Option<SymbolInfo> {
         let offset = position_to_offset(source, position)?;
@@ -391,6 +614,749 @@ impl IncanLanguageServer {
         }
         None
     }
+
+    /// Find a standalone function declaration by name, for signature help. Unlike
+    /// [`Self::find_definition`] this returns the declaration itself (not just its span), since
+    /// signature help needs the parameter list and return type.
+    fn find_function_decl<'a>(&self, ast: &'a Program, name: &str) -> Option<&'a FunctionDecl> {
+        ast.declarations.iter().find_map(|decl| match &decl.node {
+            Declaration::Function(func) if func.name == name => Some(func),
+            _ => None,
+        })
+    }
+
+    /// Build a `DocumentSymbol` for a single top-level declaration, including nested children
+    /// (model fields, class/trait/newtype methods, enum variants) for the outline/breadcrumb view.
+    ///
+    /// Reuses the same span accounting as [`Self::find_in_declaration`]: the declaration's own
+    /// span fills both `range` and `selection_range`, since individual name spans aren't tracked.
+    fn document_symbol_for_declaration(&self, decl: &Declaration, span: Span, source: &str) -> Option<DocumentSymbol> {
+        let range = span_to_range(source, span.start, span.end);
+
+        let (name, kind, detail, children): (String, SymbolKind, String, Vec<DocumentSymbol>) = match decl {
+            Declaration::Const(konst) => (
+                konst.name.clone(),
+                SymbolKind::CONSTANT,
+                if let Some(ty) = &konst.ty {
+                    format!("const {}: {}", konst.name, format_type(&ty.node))
+                } else {
+                    format!("const {}", konst.name)
+                },
+                Vec::new(),
+            ),
+            Declaration::Function(func) => {
+                (func.name.clone(), SymbolKind::FUNCTION, format_function_signature(func), Vec::new())
+            }
+            Declaration::Model(model) => (
+                model.name.clone(),
+                SymbolKind::STRUCT,
+                format!("model {}", model.name),
+                self.field_and_method_symbols(&model.fields, &model.methods, source),
+            ),
+            Declaration::Class(class) => (
+                class.name.clone(),
+                SymbolKind::CLASS,
+                format!("class {}", class.name),
+                self.field_and_method_symbols(&class.fields, &class.methods, source),
+            ),
+            Declaration::Trait(tr) => (
+                tr.name.clone(),
+                SymbolKind::INTERFACE,
+                format!("trait {}", tr.name),
+                self.method_symbols(&tr.methods, source),
+            ),
+            Declaration::Newtype(nt) => (
+                nt.name.clone(),
+                SymbolKind::STRUCT,
+                format!("newtype {} = {}", nt.name, format_type(&nt.underlying.node)),
+                self.method_symbols(&nt.methods, source),
+            ),
+            Declaration::Enum(en) => (
+                en.name.clone(),
+                SymbolKind::ENUM,
+                format!("enum {}", en.name),
+                en.variants.iter().map(|v| variant_symbol(v, source)).collect(),
+            ),
+            Declaration::Import(_) | Declaration::Docstring(_) => return None,
+        };
+
+        #[allow(deprecated)]
+        Some(DocumentSymbol {
+            name,
+            detail: Some(detail),
+            kind,
+            tags: None,
+            deprecated: None,
+            range,
+            selection_range: range,
+            children: if children.is_empty() { None } else { Some(children) },
+        })
+    }
+
+    /// Build child symbols for a model/class's fields followed by its methods.
+    fn field_and_method_symbols(
+        &self,
+        fields: &[Spanned<FieldDecl>],
+        methods: &[Spanned<MethodDecl>],
+        source: &str,
+    ) -> Vec<DocumentSymbol> {
+        let mut children: Vec<DocumentSymbol> = fields.iter().map(|f| field_symbol(f, source)).collect();
+        children.extend(self.method_symbols(methods, source));
+        children
+    }
+
+    /// Build child symbols for a trait/class/newtype's methods.
+    fn method_symbols(&self, methods: &[Spanned<MethodDecl>], source: &str) -> Vec<DocumentSymbol> {
+        methods.iter().map(|m| method_symbol(m, source)).collect()
+    }
+
+    /// Build an EXTRACT_CONSTANT refactor action for the selected expression in `range`, if any.
+    ///
+    /// Generates a `WorkspaceEdit` that inserts `const NAME = <selection>` just above the
+    /// top-level declaration containing the selection, and replaces the selection itself with
+    /// `NAME`. Returns `None` when the selection is empty or doesn't fall inside a declaration.
+    async fn extract_constant_action(&self, uri: &Url, range: Range) -> Option<CodeAction> {
+        if range.start == range.end {
+            return None;
+        }
+
+        let docs = self.documents.read().await;
+        let doc = docs.get(uri)?;
+        let ast = doc.ast.as_ref()?;
+        let source = &doc.source;
+
+        let start = position_to_offset(source, range.start)?;
+        let end = position_to_offset(source, range.end)?;
+        if start >= end {
+            return None;
+        }
+        let selected = source.get(start..end)?.trim();
+        if selected.is_empty() {
+            return None;
+        }
+
+        let decl_start = ast
+            .declarations
+            .iter()
+            .find(|decl| decl.span.start <= start && end <= decl.span.end)?
+            .span
+            .start;
+
+        let existing_names: HashSet<&str> =
+            ast.declarations.iter().filter_map(|decl| declaration_name(&decl.node)).collect();
+        let mut name = "EXTRACTED".to_string();
+        let mut suffix = 1;
+        while existing_names.contains(name.as_str()) {
+            name = format!("EXTRACTED_{suffix}");
+            suffix += 1;
+        }
+
+        let insert_pos = offset_to_position(source, decl_start);
+        let edits = vec![
+            TextEdit {
+                range: Range::new(insert_pos, insert_pos),
+                new_text: format!("const {name} = {selected}\n\n"),
+            },
+            TextEdit {
+                range,
+                new_text: name.clone(),
+            },
+        ];
+
+        let mut changes = HashMap::new();
+        changes.insert(uri.clone(), edits);
+
+        Some(CodeAction {
+            title: format!("Extract constant '{name}'"),
+            kind: Some(CodeActionKind::REFACTOR_EXTRACT),
+            diagnostics: None,
+            edit: Some(WorkspaceEdit {
+                changes: Some(changes),
+                document_changes: None,
+                change_annotations: None,
+            }),
+            command: None,
+            is_preferred: Some(false),
+            disabled: None,
+            data: None,
+        })
+    }
+}
+
+/// Build a `DocumentSymbol` for a model/class field.
+#[allow(deprecated)]
+fn field_symbol(field: &Spanned<FieldDecl>, source: &str) -> DocumentSymbol {
+    let range = span_to_range(source, field.span.start, field.span.end);
+    DocumentSymbol {
+        name: field.node.name.clone(),
+        detail: Some(format!("{}: {}", field.node.name, format_type(&field.node.ty.node))),
+        kind: SymbolKind::FIELD,
+        tags: None,
+        deprecated: None,
+        range,
+        selection_range: range,
+        children: None,
+    }
+}
+
+/// Build a `DocumentSymbol` for a class/trait/newtype method.
+#[allow(deprecated)]
+fn method_symbol(method: &Spanned<MethodDecl>, source: &str) -> DocumentSymbol {
+    let range = span_to_range(source, method.span.start, method.span.end);
+    DocumentSymbol {
+        name: method.node.name.clone(),
+        detail: Some(format_method_signature(&method.node)),
+        kind: SymbolKind::METHOD,
+        tags: None,
+        deprecated: None,
+        range,
+        selection_range: range,
+        children: None,
+    }
+}
+
+/// Build a `DocumentSymbol` for an enum variant.
+#[allow(deprecated)]
+fn variant_symbol(variant: &Spanned<crate::frontend::ast::VariantDecl>, source: &str) -> DocumentSymbol {
+    let range = span_to_range(source, variant.span.start, variant.span.end);
+    let detail = if variant.node.fields.is_empty() {
+        variant.node.name.clone()
+    } else {
+        let field_types: Vec<String> = variant.node.fields.iter().map(|f| format_type(&f.node)).collect();
+        format!("{}({})", variant.node.name, field_types.join(", "))
+    };
+    DocumentSymbol {
+        name: variant.node.name.clone(),
+        detail: Some(detail),
+        kind: SymbolKind::ENUM_MEMBER,
+        tags: None,
+        deprecated: None,
+        range,
+        selection_range: range,
+        children: None,
+    }
+}
+
+/// Find the identifier word under `offset` in `source`, if any.
+///
+/// Expands left/right from `offset` over identifier bytes (`[A-Za-z0-9_]`). Returns `None` if
+/// `offset` doesn't sit inside a word, or the word starts with a digit (a numeric literal, not
+/// an identifier).
+fn identifier_at_offset(source: &str, offset: usize) -> Option<(String, Span)> {
+    let bytes = source.as_bytes();
+    if offset > bytes.len() {
+        return None;
+    }
+
+    let mut start = offset;
+    while start > 0 && is_ident_byte(bytes[start - 1]) {
+        start -= 1;
+    }
+    let mut end = offset;
+    while end < bytes.len() && is_ident_byte(bytes[end]) {
+        end += 1;
+    }
+    if start == end {
+        return None;
+    }
+
+    let word = &source[start..end];
+    if word.starts_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+
+    Some((word.to_string(), Span::new(start, end)))
+}
+
+fn is_ident_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_'
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Find the call expression enclosing `offset`, if any: the called function's name and which
+/// 0-based parameter position the cursor currently sits in.
+///
+/// Scans backward from `offset`, tracking a combined `()`/`[]`/`{}` depth so nested calls and
+/// collection literals don't confuse comma-counting, until it finds the unmatched `(` that opens
+/// the enclosing call. The identifier immediately before that paren (skipping whitespace) is the
+/// function name.
+fn active_call_at_offset(source: &str, offset: usize) -> Option<(String, u32)> {
+    let bytes = source.as_bytes();
+    let offset = offset.min(bytes.len());
+
+    let mut depth: i32 = 0;
+    let mut active_parameter = 0u32;
+    let mut i = offset;
+    let open_paren = loop {
+        if i == 0 {
+            return None;
+        }
+        i -= 1;
+        match bytes[i] {
+            b')' | b']' | b'}' => depth += 1,
+            b'(' if depth == 0 => break i,
+            b'(' | b'[' | b'{' => depth -= 1,
+            b',' if depth == 0 => active_parameter += 1,
+            _ => {}
+        }
+    };
+
+    let mut name_end = open_paren;
+    while name_end > 0 && bytes[name_end - 1].is_ascii_whitespace() {
+        name_end -= 1;
+    }
+    let mut name_start = name_end;
+    while name_start > 0 && is_ident_byte(bytes[name_start - 1]) {
+        name_start -= 1;
+    }
+    if name_start == name_end {
+        return None;
+    }
+
+    let name = &source[name_start..name_end];
+    if KEYWORDS.contains(&name) {
+        return None;
+    }
+
+    Some((name.to_string(), active_parameter))
+}
+
+/// Find every whole-word occurrence of `word` within `span`'s byte range of `source`.
+///
+/// A "whole word" match requires the surrounding bytes (if any) not be identifier characters, so
+/// e.g. searching for `foo` does not match inside `foobar`.
+fn all_word_occurrences(source: &str, span: Span, word: &str) -> Vec<Span> {
+    let Some(window) = source.get(span.start..span.end) else {
+        return Vec::new();
+    };
+
+    let mut matches = Vec::new();
+    let mut search_from = 0;
+    while search_from <= window.len() {
+        let Some(rel) = window[search_from..].find(word) else {
+            break;
+        };
+        let start = search_from + rel;
+        let end = start + word.len();
+        let before_ok = window[..start].chars().next_back().is_none_or(|c| !is_ident_char(c));
+        let after_ok = window[end..].chars().next().is_none_or(|c| !is_ident_char(c));
+        if before_ok && after_ok {
+            matches.push(Span::new(span.start + start, span.start + end));
+        }
+        search_from = start + 1;
+    }
+    matches
+}
+
+/// Find the first whole-word occurrence of `word` within `span`'s byte range of `source`.
+fn first_word_occurrence(source: &str, span: Span, word: &str) -> Option<Span> {
+    all_word_occurrences(source, span, word).into_iter().next()
+}
+
+/// Walk a type annotation, collecting the span of every `Type::Simple`/`Type::Generic` name that
+/// matches `name`. The span covers just the name itself (not generic parameters), computed from
+/// the type's own span plus `name`'s byte length, since Incan's `Name[Params]` syntax always puts
+/// the name at the very start of the type's span.
+fn collect_type_name_refs(ty: &Spanned<Type>, name: &str, out: &mut Vec<Span>) {
+    match &ty.node {
+        Type::Simple(n) => {
+            if n == name {
+                out.push(Span::new(ty.span.start, ty.span.start + n.len()));
+            }
+        }
+        Type::Generic(n, params) => {
+            if n == name {
+                out.push(Span::new(ty.span.start, ty.span.start + n.len()));
+            }
+            for p in params {
+                collect_type_name_refs(p, name, out);
+            }
+        }
+        Type::Function(params, ret) => {
+            for p in params {
+                collect_type_name_refs(p, name, out);
+            }
+            collect_type_name_refs(ret, name, out);
+        }
+        Type::Tuple(items) | Type::Union(items) => {
+            for it in items {
+                collect_type_name_refs(it, name, out);
+            }
+        }
+        Type::Optional(inner) => collect_type_name_refs(inner, name, out),
+        Type::Unit | Type::SelfType => {}
+    }
+}
+
+/// The name a top-level declaration introduces into scope, if any (imports and module
+/// docstrings don't introduce a name).
+fn declaration_name(decl: &Declaration) -> Option<&str> {
+    match decl {
+        Declaration::Const(konst) => Some(&konst.name),
+        Declaration::Function(func) => Some(&func.name),
+        Declaration::Model(model) => Some(&model.name),
+        Declaration::Class(class) => Some(&class.name),
+        Declaration::Trait(tr) => Some(&tr.name),
+        Declaration::Newtype(nt) => Some(&nt.name),
+        Declaration::Enum(en) => Some(&en.name),
+        Declaration::Import(_) | Declaration::Docstring(_) => None,
+    }
+}
+
+/// Build `DiagnosticRelatedInformation` pointing an entry-file summary diagnostic (e.g.
+/// "Failed to lex dependency '...'") at each underlying error's true location in the dependency
+/// file, so editors can jump straight to the cause instead of only naming the file.
+///
+/// Returns `None` if `dep_uri` is `None` (the dependency's path couldn't be turned into a `Url`)
+/// or `errors` is empty, matching the other `Diagnostic` fields' convention of `None` over `Some(vec![])`.
+fn dependency_related_information(
+    dep_uri: Option<&Url>,
+    dep_source: &str,
+    errors: &[crate::frontend::diagnostics::CompileError],
+) -> Option<Vec<DiagnosticRelatedInformation>> {
+    let dep_uri = dep_uri?;
+    if errors.is_empty() {
+        return None;
+    }
+    Some(
+        errors
+            .iter()
+            .map(|error| DiagnosticRelatedInformation {
+                location: Location {
+                    uri: dep_uri.clone(),
+                    range: span_to_range(dep_source, error.span.start, error.span.end),
+                },
+                message: error.message.clone(),
+            })
+            .collect(),
+    )
+}
+
+/// Build the "create missing dependency file" quick-fix for a `missing-dependency` diagnostic,
+/// using the path it carries in `data` to target the file `resolve_import_path` expected.
+fn missing_dependency_quick_fix(diagnostic: &Diagnostic) -> Option<CodeAction> {
+    let path = diagnostic.data.as_ref()?.get("path")?.as_str()?;
+    let file_uri = Url::from_file_path(path).ok()?;
+    let module_name = Path::new(path).file_stem().and_then(|s| s.to_str()).unwrap_or("module");
+
+    let document_changes = DocumentChanges::Operations(vec![
+        DocumentChangeOperation::Op(ResourceOp::Create(CreateFile {
+            uri: file_uri.clone(),
+            options: None,
+            annotation_id: None,
+        })),
+        DocumentChangeOperation::Edit(TextDocumentEdit {
+            text_document: OptionalVersionedTextDocumentIdentifier { uri: file_uri, version: None },
+            edits: vec![OneOf::Left(TextEdit {
+                range: Range::new(Position::new(0, 0), Position::new(0, 0)),
+                new_text: format!("# Auto-generated stub for missing dependency '{module_name}'\n"),
+            })],
+        }),
+    ]);
+
+    Some(CodeAction {
+        title: format!("Create missing dependency file '{module_name}'"),
+        kind: Some(CodeActionKind::QUICKFIX),
+        diagnostics: Some(vec![diagnostic.clone()]),
+        edit: Some(WorkspaceEdit {
+            changes: None,
+            document_changes: Some(document_changes),
+            change_annotations: None,
+        }),
+        command: None,
+        is_preferred: Some(true),
+        disabled: None,
+        data: None,
+    })
+}
+
+/// Check whether an import statement names `name` as an imported item (or alias).
+fn import_references_name(import: &crate::frontend::ast::ImportDecl, name: &str) -> bool {
+    match &import.kind {
+        ImportKind::From { items, .. } | ImportKind::RustFrom { items, .. } => {
+            items.iter().any(|item| item.name == name || item.alias.as_deref() == Some(name))
+        }
+        _ => false,
+    }
+}
+
+/// Collect every reference span to `name` within a single declaration's own definition site
+/// (found via a whole-word text search near its header), nested field/method/variant definition
+/// sites, type annotations, and import items.
+///
+/// This does not walk into statement bodies — renaming in-body type annotations and expression
+/// references is out of scope for this pass.
+fn collect_decl_name_refs(decl: &Declaration, decl_span: Span, name: &str, source: &str, out: &mut Vec<Span>) {
+    let mut push_own_name = |own_name: &str, span: Span| {
+        if own_name == name {
+            if let Some(found) = first_word_occurrence(source, span, name) {
+                out.push(found);
+            }
+        }
+    };
+
+    match decl {
+        Declaration::Const(konst) => {
+            push_own_name(&konst.name, decl_span);
+            if let Some(ty) = &konst.ty {
+                collect_type_name_refs(ty, name, out);
+            }
+        }
+        Declaration::Function(func) => {
+            push_own_name(&func.name, decl_span);
+            for p in &func.params {
+                collect_type_name_refs(&p.node.ty, name, out);
+            }
+            collect_type_name_refs(&func.return_type, name, out);
+        }
+        Declaration::Model(model) => {
+            push_own_name(&model.name, decl_span);
+            for f in &model.fields {
+                push_own_name(&f.node.name, f.span);
+                collect_type_name_refs(&f.node.ty, name, out);
+            }
+            for m in &model.methods {
+                collect_method_name_refs(m, name, source, out);
+            }
+        }
+        Declaration::Class(class) => {
+            push_own_name(&class.name, decl_span);
+            for f in &class.fields {
+                push_own_name(&f.node.name, f.span);
+                collect_type_name_refs(&f.node.ty, name, out);
+            }
+            for m in &class.methods {
+                collect_method_name_refs(m, name, source, out);
+            }
+        }
+        Declaration::Trait(tr) => {
+            push_own_name(&tr.name, decl_span);
+            for m in &tr.methods {
+                collect_method_name_refs(m, name, source, out);
+            }
+        }
+        Declaration::Newtype(nt) => {
+            push_own_name(&nt.name, decl_span);
+            collect_type_name_refs(&nt.underlying, name, out);
+            for m in &nt.methods {
+                collect_method_name_refs(m, name, source, out);
+            }
+        }
+        Declaration::Enum(en) => {
+            push_own_name(&en.name, decl_span);
+            for v in &en.variants {
+                push_own_name(&v.node.name, v.span);
+                for f in &v.node.fields {
+                    collect_type_name_refs(f, name, out);
+                }
+            }
+        }
+        Declaration::Import(import) => {
+            if import_references_name(import, name) {
+                out.extend(all_word_occurrences(source, decl_span, name));
+            }
+        }
+        Declaration::Docstring(_) => {}
+    }
+}
+
+/// Collect reference spans to `name` within a single method: its own definition site and its
+/// parameter/return type annotations.
+fn collect_method_name_refs(method: &Spanned<MethodDecl>, name: &str, source: &str, out: &mut Vec<Span>) {
+    if method.node.name == name {
+        if let Some(span) = first_word_occurrence(source, method.span, name) {
+            out.push(span);
+        }
+    }
+    for p in &method.node.params {
+        collect_type_name_refs(&p.node.ty, name, out);
+    }
+    collect_type_name_refs(&method.node.return_type, name, out);
+}
+
+/// A single semantic token before delta-encoding: an absolute line/column, a byte length (counted
+/// in chars, matching this file's other position conversions), a token-type index into
+/// [`SEMANTIC_TOKEN_TYPES`], and a modifier bitset over [`SEMANTIC_TOKEN_MODIFIERS`].
+struct RawToken {
+    line: u32,
+    start_col: u32,
+    length: u32,
+    token_type: u32,
+    modifiers: u32,
+}
+
+/// Split a `(span, token_type, modifiers)` triple into one [`RawToken`] per source line it
+/// touches, since LSP semantic tokens cannot cross line boundaries.
+fn split_token_by_line(source: &str, span: Span, token_type: u32, modifiers: u32) -> Vec<RawToken> {
+    let Some(text) = source.get(span.start..span.end) else {
+        return Vec::new();
+    };
+
+    let start = offset_to_position(source, span.start);
+    let mut line = start.line;
+    let mut col = start.character;
+    let mut tokens = Vec::new();
+
+    for (i, part) in text.split('\n').enumerate() {
+        if i > 0 {
+            line += 1;
+            col = 0;
+        }
+        if !part.is_empty() {
+            tokens.push(RawToken {
+                line,
+                start_col: col,
+                length: part.chars().count() as u32,
+                token_type,
+                modifiers,
+            });
+        }
+        col += part.chars().count() as u32;
+    }
+
+    tokens
+}
+
+/// Push a token for the whole-word occurrence of `name` within `search_span`, split across lines
+/// if needed. Used for declaration/field/parameter/method name sites, which this file tracks only
+/// via their enclosing span (see [`collect_decl_name_refs`] for the same pattern).
+fn push_name_token(source: &str, search_span: Span, name: &str, token_type: u32, modifiers: u32, out: &mut Vec<RawToken>) {
+    if let Some(span) = first_word_occurrence(source, search_span, name) {
+        out.extend(split_token_by_line(source, span, token_type, modifiers));
+    }
+}
+
+/// Walk a type annotation, collecting the span of every `Type::Simple`/`Type::Generic` name
+/// (unconditionally, unlike [`collect_type_name_refs`] which filters by name) as a `TOK_TYPE`
+/// token.
+fn push_type_tokens(ty: &Spanned<Type>, source: &str, out: &mut Vec<RawToken>) {
+    match &ty.node {
+        Type::Simple(n) => {
+            out.extend(split_token_by_line(source, Span::new(ty.span.start, ty.span.start + n.len()), TOK_TYPE, 0));
+        }
+        Type::Generic(n, params) => {
+            out.extend(split_token_by_line(source, Span::new(ty.span.start, ty.span.start + n.len()), TOK_TYPE, 0));
+            for p in params {
+                push_type_tokens(p, source, out);
+            }
+        }
+        Type::Function(params, ret) => {
+            for p in params {
+                push_type_tokens(p, source, out);
+            }
+            push_type_tokens(ret, source, out);
+        }
+        Type::Tuple(items) | Type::Union(items) => {
+            for it in items {
+                push_type_tokens(it, source, out);
+            }
+        }
+        Type::Optional(inner) => push_type_tokens(inner, source, out),
+        Type::Unit | Type::SelfType => {}
+    }
+}
+
+/// Collect semantic tokens for a single method: its name, then its parameters and return type.
+fn collect_method_semantic_tokens(method: &Spanned<MethodDecl>, source: &str, out: &mut Vec<RawToken>) {
+    push_name_token(source, method.span, &method.node.name, TOK_METHOD, MOD_DECLARATION, out);
+    for p in &method.node.params {
+        push_name_token(source, p.span, &p.node.name, TOK_PARAMETER, 0, out);
+        push_type_tokens(&p.node.ty, source, out);
+    }
+    push_type_tokens(&method.node.return_type, source, out);
+}
+
+/// Collect semantic tokens for a single top-level declaration: its own name, nested
+/// field/method/variant names, and every type annotation reachable from it.
+fn collect_decl_semantic_tokens(decl: &Declaration, decl_span: Span, source: &str, out: &mut Vec<RawToken>) {
+    match decl {
+        Declaration::Const(konst) => {
+            push_name_token(source, decl_span, &konst.name, TOK_CONST_AS_VARIABLE, MOD_DECLARATION | MOD_READONLY, out);
+            if let Some(ty) = &konst.ty {
+                push_type_tokens(ty, source, out);
+            }
+        }
+        Declaration::Function(func) => {
+            push_name_token(source, decl_span, &func.name, TOK_FUNCTION, MOD_DECLARATION, out);
+            for p in &func.params {
+                push_name_token(source, p.span, &p.node.name, TOK_PARAMETER, 0, out);
+                push_type_tokens(&p.node.ty, source, out);
+            }
+            push_type_tokens(&func.return_type, source, out);
+        }
+        Declaration::Model(model) => {
+            push_name_token(source, decl_span, &model.name, TOK_STRUCT, MOD_DECLARATION, out);
+            for f in &model.fields {
+                push_name_token(source, f.span, &f.node.name, TOK_PROPERTY, MOD_DECLARATION, out);
+                push_type_tokens(&f.node.ty, source, out);
+            }
+            for m in &model.methods {
+                collect_method_semantic_tokens(m, source, out);
+            }
+        }
+        Declaration::Class(class) => {
+            push_name_token(source, decl_span, &class.name, TOK_CLASS, MOD_DECLARATION, out);
+            for f in &class.fields {
+                push_name_token(source, f.span, &f.node.name, TOK_PROPERTY, MOD_DECLARATION, out);
+                push_type_tokens(&f.node.ty, source, out);
+            }
+            for m in &class.methods {
+                collect_method_semantic_tokens(m, source, out);
+            }
+        }
+        Declaration::Trait(tr) => {
+            push_name_token(source, decl_span, &tr.name, TOK_INTERFACE, MOD_DECLARATION, out);
+            for m in &tr.methods {
+                collect_method_semantic_tokens(m, source, out);
+            }
+        }
+        Declaration::Newtype(nt) => {
+            push_name_token(source, decl_span, &nt.name, TOK_STRUCT, MOD_DECLARATION, out);
+            push_type_tokens(&nt.underlying, source, out);
+            for m in &nt.methods {
+                collect_method_semantic_tokens(m, source, out);
+            }
+        }
+        Declaration::Enum(en) => {
+            push_name_token(source, decl_span, &en.name, TOK_ENUM, MOD_DECLARATION, out);
+            for v in &en.variants {
+                push_name_token(source, v.span, &v.node.name, TOK_ENUM_MEMBER, MOD_DECLARATION, out);
+                for f in &v.node.fields {
+                    push_type_tokens(f, source, out);
+                }
+            }
+        }
+        Declaration::Import(_) | Declaration::Docstring(_) => {}
+    }
+}
+
+/// Sort raw tokens by position and delta-encode them into the flat integer array LSP's
+/// `semanticTokens/full` response requires: each token contributes `(deltaLine, deltaStartChar,
+/// length, tokenType, tokenModifiers)`, where `deltaStartChar` is relative to the previous
+/// token's start column only when both tokens share a line, and the very first token is absolute.
+fn encode_semantic_tokens(mut raw: Vec<RawToken>) -> Vec<SemanticToken> {
+    raw.sort_by_key(|t| (t.line, t.start_col));
+
+    let mut encoded = Vec::with_capacity(raw.len());
+    let mut prev_line = 0u32;
+    let mut prev_col = 0u32;
+    for (i, tok) in raw.into_iter().enumerate() {
+        let delta_line = if i == 0 { tok.line } else { tok.line - prev_line };
+        let delta_start = if delta_line == 0 && i != 0 { tok.start_col - prev_col } else { tok.start_col };
+        encoded.push(SemanticToken {
+            delta_line,
+            delta_start,
+            length: tok.length,
+            token_type: tok.token_type,
+            token_modifiers_bitset: tok.modifiers,
+        });
+        prev_line = tok.line;
+        prev_col = tok.start_col;
+    }
+    encoded
 }
 
 /// Symbol information for hover/goto
@@ -402,8 +1368,476 @@ pub struct SymbolInfo {
     pub span: Span,
 }
 
+/// If `offset` sits right after `TypeName.` (optionally with a partial member name already typed
+/// between the dot and the cursor, e.g. `TypeName.fo`), return `TypeName`. Returns `None` for a
+/// bare identifier, a dot with nothing identifier-like before it, or no dot at all.
+fn member_completion_prefix(source: &str, offset: usize) -> Option<String> {
+    let bytes = source.as_bytes();
+    let mut i = offset.min(bytes.len());
+
+    // Skip the partial member name currently being typed, if any.
+    while i > 0 && is_ident_byte(bytes[i - 1]) {
+        i -= 1;
+    }
+    if i == 0 || bytes[i - 1] != b'.' {
+        return None;
+    }
+    let dot = i - 1;
+
+    let mut start = dot;
+    while start > 0 && is_ident_byte(bytes[start - 1]) {
+        start -= 1;
+    }
+    if start == dot {
+        return None;
+    }
+
+    Some(source[start..dot].to_string())
+}
+
+/// Build completion items for `type_name`'s members: one `ENUM_MEMBER` per variant (detail is
+/// its tuple fields, parenthesized and comma-separated like a constructor call, or omitted for a
+/// unit variant) if it's an enum, or one `FIELD` per field (detail is the field's type) if it's a
+/// model or class. Returns `None` if no declaration in `ast` matches `type_name`.
+fn member_completion_items(ast: &Program, source: &str, type_name: &str) -> Option<Vec<CompletionItem>> {
+    for decl in &ast.declarations {
+        match &decl.node {
+            Declaration::Enum(en) if en.name == type_name => {
+                return Some(
+                    en.variants
+                        .iter()
+                        .map(|variant| {
+                            let field_types: Vec<String> =
+                                variant.node.fields.iter().map(|ty| format_type(&ty.node)).collect();
+                            CompletionItem {
+                                label: variant.node.name.clone(),
+                                kind: Some(CompletionItemKind::ENUM_MEMBER),
+                                detail: (!field_types.is_empty()).then(|| format!("({})", field_types.join(", "))),
+                                documentation: leading_doc_comment(source, variant.span.start),
+                                ..Default::default()
+                            }
+                        })
+                        .collect(),
+                );
+            }
+            Declaration::Model(model) if model.name == type_name => {
+                return Some(field_completion_items(&model.fields, source));
+            }
+            Declaration::Class(class) if class.name == type_name => {
+                return Some(field_completion_items(&class.fields, source));
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Shared `Model`/`Class` field-completion body: one `FIELD` item per field, typed in `detail`.
+fn field_completion_items(fields: &[Spanned<FieldDecl>], source: &str) -> Vec<CompletionItem> {
+    fields
+        .iter()
+        .map(|field| CompletionItem {
+            label: field.node.name.clone(),
+            kind: Some(CompletionItemKind::FIELD),
+            detail: Some(format_type(&field.node.ty.node)),
+            documentation: leading_doc_comment(source, field.span.start),
+            ..Default::default()
+        })
+        .collect()
+}
+
+/// The identifier fragment ending exactly at `offset`, e.g. `"Fo"` for `Fo|o` or `Foo|`. Unlike
+/// `identifier_at_offset`, this only looks backward, so it reflects what's been typed so far
+/// rather than the whole word the cursor happens to sit inside.
+fn word_before_offset(source: &str, offset: usize) -> String {
+    let bytes = source.as_bytes();
+    let mut start = offset.min(bytes.len());
+    while start > 0 && is_ident_byte(bytes[start - 1]) {
+        start -= 1;
+    }
+    source[start..offset.min(bytes.len())].to_string()
+}
+
+/// Auto-import completions: walk every `.incn`/`.incan` file under the project's `src/` root
+/// (preferring an open document's in-memory source over disk, like `collect_all_files`),
+/// case-insensitive-prefix-match their top-level declarations against `prefix`, and attach the
+/// `from crate::...` import statement each match needs as an `additional_text_edits` edit so
+/// accepting one both inserts the name and brings it into scope.
+///
+/// Skips symbols already declared in `ast` or already named by one of its `from ... import`
+/// items, and skips the entry file itself (its own declarations are offered separately, by the
+/// caller's local-symbol loop).
+fn foreign_symbol_completions(
+    docs: &HashMap<Url, DocumentState>,
+    uri: &Url,
+    ast: &Program,
+    source: &str,
+    prefix: &str,
+    snippet_support: bool,
+) -> Vec<CompletionItem> {
+    let Ok(entry_path) = uri.to_file_path() else {
+        return Vec::new();
+    };
+    let entry_canonical = entry_path.canonicalize().unwrap_or_else(|_| entry_path.clone());
+    let entry_base = entry_path.parent().unwrap_or(Path::new(".")).to_path_buf();
+    let src_root = find_project_src_root(&entry_base);
+
+    let local_names: HashSet<&str> =
+        ast.declarations.iter().filter_map(|decl| declaration_name(&decl.node)).collect();
+    let in_scope_names: HashSet<&str> = ast
+        .declarations
+        .iter()
+        .filter_map(|decl| match &decl.node {
+            Declaration::Import(import) => match &import.kind {
+                ImportKind::From { items, .. } => {
+                    Some(items.iter().map(|item| item.alias.as_deref().unwrap_or(&item.name)))
+                }
+                _ => None,
+            },
+            _ => None,
+        })
+        .flatten()
+        .collect();
+
+    let prefix_lower = prefix.to_lowercase();
+    let mut items = Vec::new();
+
+    for file in collect_project_files(&src_root) {
+        let canonical = file.canonicalize().unwrap_or_else(|_| file.clone());
+        if canonical == entry_canonical {
+            continue;
+        }
+        let Some(module_path) = module_path_segments(&canonical, &src_root) else {
+            continue;
+        };
+
+        let file_uri = Url::from_file_path(&canonical).ok();
+        let dep_source = file_uri
+            .as_ref()
+            .and_then(|u| docs.get(u))
+            .map(|d| d.source.clone())
+            .or_else(|| fs::read_to_string(&canonical).ok());
+        let Some(dep_source) = dep_source else { continue };
+        let Ok(dep_tokens) = lexer::lex(&dep_source) else { continue };
+        let Ok(dep_ast) = parser::parse(&dep_tokens) else { continue };
+
+        let module_label = module_path.join("::");
+        for decl in &dep_ast.declarations {
+            let Some(name) = declaration_name(&decl.node) else { continue };
+            if !name.to_lowercase().starts_with(&prefix_lower) {
+                continue;
+            }
+            if local_names.contains(name) || in_scope_names.contains(name) {
+                continue;
+            }
+
+            let import_stmt = format!("from crate::{module_label} import {name}");
+            let import_edit = import_insertion_edit(ast, source, &import_stmt);
+            if let Some(item) =
+                foreign_completion_item(&decl.node, &module_label, &dep_source, decl.span.start, import_edit, snippet_support)
+            {
+                items.push(item);
+            }
+        }
+    }
+
+    items
+}
+
+/// Walk upward from `start_dir` to the project root (first ancestor with a `Cargo.toml` or a
+/// `src/` directory), then into `src/` if one exists there. Mirrors the project-root search
+/// `resolve_import_path` does for an absolute `crate::...` import; duplicated here since that
+/// search isn't exposed as a standalone helper there.
+fn find_project_src_root(start_dir: &Path) -> PathBuf {
+    let mut project_root = start_dir.to_path_buf();
+    while !project_root.join("Cargo.toml").exists() && !project_root.join("src").exists() {
+        if !project_root.pop() {
+            break;
+        }
+    }
+    if project_root.join("src").exists() {
+        project_root.join("src")
+    } else {
+        project_root
+    }
+}
+
+/// Recursively collect every `.incn`/`.incan` file under `root`.
+fn collect_project_files(root: &Path) -> Vec<PathBuf> {
+    let mut result = Vec::new();
+    let mut stack = vec![root.to_path_buf()];
+    while let Some(dir) = stack.pop() {
+        let Ok(entries) = fs::read_dir(&dir) else { continue };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                stack.push(path);
+            } else if path.extension().is_some_and(|ext| ext == "incn" || ext == "incan") {
+                result.push(path);
+            }
+        }
+    }
+    result
+}
+
+/// Translate a project file's path into the dotted segments an absolute `crate::` import would
+/// use to reach it: its path components under `src_root` with the extension stripped, and a
+/// trailing `mod` segment (the directory-module convention `resolve_import_path` also handles)
+/// dropped so `models/mod.incn` reads as `models` rather than `models::mod`.
+fn module_path_segments(file: &Path, src_root: &Path) -> Option<Vec<String>> {
+    let rel = file.strip_prefix(src_root).ok()?;
+    let mut segments: Vec<String> = rel
+        .with_extension("")
+        .components()
+        .filter_map(|c| match c {
+            std::path::Component::Normal(s) => Some(s.to_string_lossy().into_owned()),
+            _ => None,
+        })
+        .collect();
+    if segments.last().map(String::as_str) == Some("mod") {
+        segments.pop();
+    }
+    (!segments.is_empty()).then_some(segments)
+}
+
+/// Build the `TextEdit` that inserts `statement` at the correct import location: right after the
+/// last existing `import`/`from ... import` declaration, or at the top of the file if there are
+/// none.
+fn import_insertion_edit(ast: &Program, source: &str, statement: &str) -> TextEdit {
+    let last_import_end = ast
+        .declarations
+        .iter()
+        .filter(|decl| matches!(decl.node, Declaration::Import(_)))
+        .map(|decl| decl.span.end)
+        .max();
+
+    match last_import_end {
+        Some(end) => {
+            let pos = offset_to_position(source, end);
+            TextEdit { range: Range::new(pos, pos), new_text: format!("\n{statement}") }
+        }
+        None => {
+            let pos = Position::new(0, 0);
+            TextEdit { range: Range::new(pos, pos), new_text: format!("{statement}\n\n") }
+        }
+    }
+}
+
+/// Build the `CompletionItem` for a cross-module match: the same `kind`/`detail` shape as the
+/// local-document arms in `completion`, with the originating module path appended to `detail` so
+/// two same-named symbols from different modules read as distinct entries, and `import_edit`
+/// attached so accepting the item also brings the symbol into scope. Returns `None` for imports
+/// and docstrings, neither of which names a completable symbol.
+fn foreign_completion_item(
+    decl: &Declaration,
+    module_label: &str,
+    dep_source: &str,
+    decl_start: usize,
+    import_edit: TextEdit,
+    snippet_support: bool,
+) -> Option<CompletionItem> {
+    let (kind, detail, insert_text, insert_text_format) = match decl {
+        Declaration::Const(konst) => (
+            CompletionItemKind::CONSTANT,
+            if let Some(ty) = &konst.ty {
+                format!("const {}: {}", konst.name, format_type(&ty.node))
+            } else {
+                format!("const {}", konst.name)
+            },
+            None,
+            None,
+        ),
+        Declaration::Function(func) => {
+            let (insert_text, insert_text_format) = if snippet_support {
+                (Some(function_call_snippet(func)), Some(InsertTextFormat::SNIPPET))
+            } else {
+                (None, None)
+            };
+            (CompletionItemKind::FUNCTION, format_function_signature(func), insert_text, insert_text_format)
+        }
+        Declaration::Model(model) => (CompletionItemKind::STRUCT, format!("model {}", model.name), None, None),
+        Declaration::Class(class) => (CompletionItemKind::CLASS, format!("class {}", class.name), None, None),
+        Declaration::Trait(tr) => (CompletionItemKind::INTERFACE, format!("trait {}", tr.name), None, None),
+        Declaration::Enum(en) => (CompletionItemKind::ENUM, format!("enum {}", en.name), None, None),
+        Declaration::Newtype(nt) => (
+            CompletionItemKind::STRUCT,
+            format!("newtype {} = {}", nt.name, format_type(&nt.underlying.node)),
+            None,
+            None,
+        ),
+        Declaration::Import(_) | Declaration::Docstring(_) => return None,
+    };
+
+    Some(CompletionItem {
+        label: declaration_name(decl)?.to_string(),
+        kind: Some(kind),
+        detail: Some(format!("{detail} — {module_label}")),
+        documentation: leading_doc_comment(dep_source, decl_start),
+        insert_text,
+        insert_text_format,
+        additional_text_edits: Some(vec![import_edit]),
+        ..Default::default()
+    })
+}
+
+/// Inside a `Class` body that declares `traits`, complete each implemented trait's still-
+/// unimplemented required methods (those with no default body) as ready-to-fill snippet stubs,
+/// mirroring rust-analyzer's `trait_impl` completion. Methods the trait already gives a default
+/// body to are left out, since overriding one is optional rather than required boilerplate.
+/// Returns nothing outside a class body, or once every required method is already present.
+fn trait_stub_completions(ast: &Program, source: &str, offset: usize, snippet_support: bool) -> Vec<CompletionItem> {
+    let Some(class) = ast.declarations.iter().find_map(|decl| match &decl.node {
+        Declaration::Class(class) if decl.span.start <= offset && offset < decl.span.end => Some(class),
+        _ => None,
+    }) else {
+        return Vec::new();
+    };
+
+    let implemented: HashSet<&str> = class.methods.iter().map(|m| m.node.name.as_str()).collect();
+    let indent = current_line_indent(source, offset);
+
+    class
+        .traits
+        .iter()
+        .filter_map(|trait_name| {
+            ast.declarations.iter().find_map(|decl| match &decl.node {
+                Declaration::Trait(tr) if &tr.name == trait_name => Some(tr),
+                _ => None,
+            })
+        })
+        .flat_map(|tr| {
+            let indent = indent.clone();
+            tr.methods.iter().filter_map(move |method| {
+                if method.node.body.is_some() || implemented.contains(method.node.name.as_str()) {
+                    return None;
+                }
+
+                let signature = format_method_signature(&method.node);
+                let insert_text = if snippet_support {
+                    format!("{signature}:\n{indent}    $0")
+                } else {
+                    format!("{signature}:\n{indent}    pass")
+                };
+
+                Some(CompletionItem {
+                    label: method.node.name.clone(),
+                    kind: Some(CompletionItemKind::METHOD),
+                    detail: Some(format!("from trait {}", tr.name)),
+                    documentation: leading_doc_comment(source, method.span.start),
+                    insert_text: Some(insert_text),
+                    insert_text_format: snippet_support.then_some(InsertTextFormat::SNIPPET),
+                    ..Default::default()
+                })
+            })
+        })
+        .collect()
+}
+
+/// The current line's leading horizontal whitespace up to `offset` - stops at the first
+/// non-space/tab character, e.g. whatever identifier prefix has already been typed - which is
+/// the indent a multi-line snippet inserted at `offset` should continue from.
+fn current_line_indent(source: &str, offset: usize) -> String {
+    let offset = offset.min(source.len());
+    let line_start = source[..offset].rfind('\n').map(|i| i + 1).unwrap_or(0);
+    source[line_start..offset].chars().take_while(|c| *c == ' ' || *c == '\t').collect()
+}
+
+/// Find the Markdown documentation for the item whose declaration starts at byte `item_start`,
+/// by collecting the contiguous run of comment lines immediately above it - either consecutive
+/// `///` lines or a single `/** ... */` block - and stripping the comment markers and whatever
+/// leading whitespace is common to every line. Returns `None` if there's no comment directly
+/// above (a blank line or other code breaks the run), mirroring how rustc/rust-analyzer only
+/// attach doc comments that touch the item with no gap.
+fn leading_doc_comment(source: &str, item_start: usize) -> Option<Documentation> {
+    let before = &source[..item_start.min(source.len())];
+    let mut above: Vec<&str> = before.lines().collect();
+    if !before.ends_with('\n') {
+        // The item starts mid-line; that partial line isn't a comment line above it.
+        above.pop();
+    }
+
+    let mut comment_lines: Vec<&str> = Vec::new();
+    for line in above.iter().rev() {
+        if line.trim().is_empty() {
+            break;
+        }
+        comment_lines.push(line);
+        if line.trim_start().starts_with("/**") {
+            break;
+        }
+    }
+    comment_lines.reverse();
+
+    let stripped = if comment_lines.first()?.trim_start().starts_with("/**") {
+        strip_block_comment(&comment_lines)
+    } else if comment_lines.iter().all(|line| line.trim_start().starts_with("///")) {
+        Some(comment_lines.iter().map(|line| line.trim_start().trim_start_matches("///")).collect())
+    } else {
+        None
+    };
+
+    let value = dedent(stripped?);
+    if value.trim().is_empty() {
+        return None;
+    }
+
+    Some(Documentation::MarkupContent(MarkupContent { kind: MarkupKind::Markdown, value }))
+}
+
+/// Strip a `/** ... */` block comment's delimiters and per-line leading `*` gutters, joining
+/// what's left with newlines. `lines` must be non-empty and start with `/**`.
+fn strip_block_comment(lines: &[&str]) -> Option<Vec<String>> {
+    let last = lines.len() - 1;
+    let mut inner = Vec::with_capacity(lines.len());
+    for (i, line) in lines.iter().enumerate() {
+        let mut text = line.trim();
+        if i == 0 {
+            text = text.strip_prefix("/**")?;
+        }
+        if i == last {
+            text = text.trim_end().strip_suffix("*/").unwrap_or(text);
+        }
+        inner.push(text.trim_start_matches('*').to_string());
+    }
+    while inner.first().is_some_and(|line: &String| line.trim().is_empty()) {
+        inner.remove(0);
+    }
+    while inner.last().is_some_and(|line: &String| line.trim().is_empty()) {
+        inner.pop();
+    }
+    if inner.is_empty() { None } else { Some(inner) }
+}
+
+/// Remove whatever leading whitespace is shared by every non-blank line, then join with newlines.
+fn dedent(lines: Vec<String>) -> String {
+    let indent = lines
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+
+    lines
+        .into_iter()
+        .map(|line| if line.len() >= indent { line[indent..].to_string() } else { line.trim_start().to_string() })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Build a tab-stop completion snippet for calling `func`: `name(${1:a}, ${2:b})$0`, one tabstop
+/// per declared parameter named after it, collapsing to `name()$0` when it takes none.
+fn function_call_snippet(func: &FunctionDecl) -> String {
+    if func.params.is_empty() {
+        return format!("{}()$0", func.name);
+    }
+
+    let args: Vec<String> =
+        func.params.iter().enumerate().map(|(i, param)| format!("${{{}:{}}}", i + 1, param.node.name)).collect();
+    format!("{}({})$0", func.name, args.join(", "))
+}
+
 /// Format a function signature for display
-fn format_function_signature(func: &crate::frontend::ast::FunctionDecl) -> String {
+fn format_function_signature(func: &FunctionDecl) -> String {
     let mut sig = String::new();
 
     if func.is_async {
@@ -429,6 +1863,80 @@ fn format_function_signature(func: &crate::frontend::ast::FunctionDecl) -> Strin
     sig
 }
 
+/// Build `SignatureInformation` for signature help, with one `ParameterInformation` per
+/// parameter whose `LabelOffsets` point at that parameter's `name: Type` substring in `label`.
+///
+/// This renders the same signature shape as [`format_function_signature`], but builds it
+/// char-by-char (matching this file's other offset conventions) so each parameter's offsets into
+/// the final label can be recorded as they're written, rather than re-deriving them afterward.
+fn build_signature_information(func: &FunctionDecl) -> SignatureInformation {
+    let mut label = String::new();
+    if func.is_async {
+        label.push_str("async ");
+    }
+    label.push_str("def ");
+    label.push_str(&func.name);
+    label.push('(');
+
+    let mut parameters = Vec::with_capacity(func.params.len());
+    for (i, p) in func.params.iter().enumerate() {
+        if i > 0 {
+            label.push_str(", ");
+        }
+        let start = label.chars().count() as u32;
+        label.push_str(&format!("{}: {}", p.node.name, format_type(&p.node.ty.node)));
+        let end = label.chars().count() as u32;
+        parameters.push(ParameterInformation {
+            label: ParameterLabel::LabelOffsets([start, end]),
+            documentation: None,
+        });
+    }
+
+    label.push(')');
+    label.push_str(" -> ");
+    label.push_str(&format_type(&func.return_type.node));
+
+    SignatureInformation {
+        label,
+        documentation: None,
+        parameters: Some(parameters),
+        active_parameter: None,
+    }
+}
+
+/// Format a method signature for display
+fn format_method_signature(method: &MethodDecl) -> String {
+    let mut sig = String::new();
+
+    if method.is_async {
+        sig.push_str("async ");
+    }
+
+    sig.push_str("def ");
+    sig.push_str(&method.name);
+    sig.push('(');
+
+    let mut parts = Vec::new();
+    if let Some(receiver) = method.receiver {
+        parts.push(
+            match receiver {
+                Receiver::Immutable => "self",
+                Receiver::Mutable => "mut self",
+            }
+            .to_string(),
+        );
+    }
+    parts.extend(method.params.iter().map(|p| format!("{}: {}", p.node.name, format_type(&p.node.ty.node))));
+
+    sig.push_str(&parts.join(", "));
+    sig.push(')');
+
+    sig.push_str(" -> ");
+    sig.push_str(&format_type(&method.return_type.node));
+
+    sig
+}
+
 /// Format a Type for display
 fn format_type(ty: &Type) -> String {
     match ty {
@@ -447,12 +1955,27 @@ fn format_type(ty: &Type) -> String {
         }
         Type::Unit => "()".to_string(),
         Type::SelfType => "Self".to_string(),
+        Type::Optional(inner) => format!("Optional[{}]", format_type(&inner.node)),
+        Type::Union(members) => {
+            let members_str: Vec<String> = members.iter().map(|m| format_type(&m.node)).collect();
+            members_str.join(" | ")
+        }
     }
 }
 
 #[tower_lsp::async_trait]
 impl LanguageServer for IncanLanguageServer {
-    async fn initialize(&self, _: InitializeParams) -> Result<InitializeResult> {
+    async fn initialize(&self, params: InitializeParams) -> Result<InitializeResult> {
+        let snippet_support = params
+            .capabilities
+            .text_document
+            .as_ref()
+            .and_then(|td| td.completion.as_ref())
+            .and_then(|c| c.completion_item.as_ref())
+            .and_then(|ci| ci.snippet_support)
+            .unwrap_or(false);
+        self.snippet_support.store(snippet_support, Ordering::Relaxed);
+
         Ok(InitializeResult {
             capabilities: ServerCapabilities {
                 // Real-time diagnostics via text sync
@@ -461,11 +1984,42 @@ impl LanguageServer for IncanLanguageServer {
                 hover_provider: Some(HoverProviderCapability::Simple(true)),
                 // Go-to-definition
                 definition_provider: Some(OneOf::Left(true)),
+                // Hierarchical outline / breadcrumbs
+                document_symbol_provider: Some(OneOf::Left(true)),
+                // Cross-file rename, with prepareRename validation
+                rename_provider: Some(OneOf::Right(RenameOptions {
+                    prepare_provider: Some(true),
+                    work_done_progress_options: WorkDoneProgressOptions::default(),
+                })),
+                // Full-document semantic highlighting, built from the AST rather than the lexer
+                semantic_tokens_provider: Some(SemanticTokensServerCapabilities::SemanticTokensOptions(
+                    SemanticTokensOptions {
+                        work_done_progress_options: WorkDoneProgressOptions::default(),
+                        legend: SemanticTokensLegend {
+                            token_types: SEMANTIC_TOKEN_TYPES.to_vec(),
+                            token_modifiers: SEMANTIC_TOKEN_MODIFIERS.to_vec(),
+                        },
+                        range: None,
+                        full: Some(SemanticTokensFullOptions::Bool(true)),
+                    },
+                )),
                 // Completions (basic)
                 completion_provider: Some(CompletionOptions {
                     trigger_characters: Some(vec![".".to_string(), ":".to_string()]),
                     ..Default::default()
                 }),
+                // Per-argument hints while typing a call
+                signature_help_provider: Some(SignatureHelpOptions {
+                    trigger_characters: Some(vec!["(".to_string(), ",".to_string()]),
+                    retrigger_characters: None,
+                    work_done_progress_options: WorkDoneProgressOptions::default(),
+                }),
+                // Extract-constant refactor and the missing-dependency quick-fix
+                code_action_provider: Some(CodeActionProviderCapability::Options(CodeActionOptions {
+                    code_action_kinds: Some(vec![CodeActionKind::REFACTOR_EXTRACT, CodeActionKind::QUICKFIX]),
+                    resolve_provider: None,
+                    work_done_progress_options: WorkDoneProgressOptions::default(),
+                })),
                 ..Default::default()
             },
             server_info: Some(ServerInfo {
@@ -479,6 +2033,25 @@ impl LanguageServer for IncanLanguageServer {
         self.client
             .log_message(MessageType::INFO, "Incan LSP initialized")
             .await;
+
+        // Watch Incan source files on disk so dependency edits made outside the editor (a
+        // teammate's file, a generator, `git checkout`) still refresh diagnostics for whoever has
+        // the importing file open. `did_change_watched_files` uses `reverse_deps` to target only
+        // the documents actually affected.
+        let watchers = vec![
+            FileSystemWatcher { glob_pattern: GlobPattern::String("**/*.incn".to_string()), kind: None },
+            FileSystemWatcher { glob_pattern: GlobPattern::String("**/*.incan".to_string()), kind: None },
+        ];
+        let registration = Registration {
+            id: "incan-dependency-watch".to_string(),
+            method: "workspace/didChangeWatchedFiles".to_string(),
+            register_options: serde_json::to_value(DidChangeWatchedFilesRegistrationOptions { watchers }).ok(),
+        };
+        if let Err(err) = self.client.register_capability(vec![registration]).await {
+            self.client
+                .log_message(MessageType::WARNING, format!("Failed to register file watcher: {err}"))
+                .await;
+        }
     }
 
     async fn shutdown(&self) -> Result<()> {
@@ -490,7 +2063,8 @@ impl LanguageServer for IncanLanguageServer {
         let source = params.text_document.text;
         let version = params.text_document.version;
 
-        self.analyze_document(&uri, &source, version).await;
+        // Opening a file wants its diagnostics immediately, not after a debounce.
+        self.analyze_document(&uri, &source, version, &CancellationToken::new()).await;
     }
 
     async fn did_change(&self, params: DidChangeTextDocumentParams) {
@@ -499,13 +2073,43 @@ impl LanguageServer for IncanLanguageServer {
 
         // We use FULL sync, so there's only one change with the full content
         if let Some(change) = params.content_changes.into_iter().next() {
-            self.analyze_document(&uri, &change.text, version).await;
+            self.schedule_analysis(uri, change.text, version).await;
+        }
+    }
+
+    async fn did_change_watched_files(&self, params: DidChangeWatchedFilesParams) {
+        for change in params.changes {
+            let Ok(changed_path) = change.uri.to_file_path() else {
+                continue;
+            };
+            let canonical = changed_path.canonicalize().unwrap_or(changed_path);
+
+            let dependents: Vec<Url> = {
+                let reverse_deps = self.reverse_deps.read().await;
+                reverse_deps.get(&canonical).map(|deps| deps.iter().cloned().collect()).unwrap_or_default()
+            };
+
+            for dependent_uri in dependents {
+                let doc_state = {
+                    let docs = self.documents.read().await;
+                    docs.get(&dependent_uri).cloned()
+                };
+                // Only re-analyze documents still open; a closed one has nothing to refresh.
+                if let Some(doc_state) = doc_state {
+                    self.schedule_analysis(dependent_uri, doc_state.source, doc_state.version).await;
+                }
+            }
         }
     }
 
     async fn did_close(&self, params: DidCloseTextDocumentParams) {
         let uri = params.text_document.uri;
 
+        // Drop any analysis still in flight for this document; it has no document left to update.
+        if let Some(token) = self.pending_analyses.write().await.remove(&uri) {
+            token.cancel();
+        }
+
         // Remove document from cache
         let mut docs = self.documents.write().await;
         docs.remove(&uri);
@@ -585,8 +2189,194 @@ impl LanguageServer for IncanLanguageServer {
         Ok(None)
     }
 
+    async fn document_symbol(&self, params: DocumentSymbolParams) -> Result<Option<DocumentSymbolResponse>> {
+        let uri = &params.text_document.uri;
+
+        let docs = self.documents.read().await;
+        let doc = match docs.get(uri) {
+            Some(doc) => doc,
+            None => return Ok(None),
+        };
+
+        let ast = match &doc.ast {
+            Some(ast) => ast,
+            None => return Ok(None),
+        };
+
+        let symbols: Vec<DocumentSymbol> = ast
+            .declarations
+            .iter()
+            .filter_map(|decl| self.document_symbol_for_declaration(&decl.node, decl.span, &doc.source))
+            .collect();
+
+        Ok(Some(DocumentSymbolResponse::Nested(symbols)))
+    }
+
+    async fn semantic_tokens_full(&self, params: SemanticTokensParams) -> Result<Option<SemanticTokensResult>> {
+        let uri = &params.text_document.uri;
+
+        let docs = self.documents.read().await;
+        let Some(doc) = docs.get(uri) else {
+            return Ok(None);
+        };
+        let Some(ast) = &doc.ast else {
+            return Ok(None);
+        };
+
+        let mut raw = Vec::new();
+        for decl in &ast.declarations {
+            collect_decl_semantic_tokens(&decl.node, decl.span, &doc.source, &mut raw);
+        }
+
+        Ok(Some(SemanticTokensResult::Tokens(SemanticTokens {
+            result_id: None,
+            data: encode_semantic_tokens(raw),
+        })))
+    }
+
+    async fn prepare_rename(&self, params: TextDocumentPositionParams) -> Result<Option<PrepareRenameResponse>> {
+        let uri = &params.text_document.uri;
+        let position = params.position;
+
+        let docs = self.documents.read().await;
+        let Some(doc) = docs.get(uri) else {
+            return Ok(None);
+        };
+
+        let Some(offset) = position_to_offset(&doc.source, position) else {
+            return Ok(None);
+        };
+        let Some((word, span)) = identifier_at_offset(&doc.source, offset) else {
+            return Ok(None);
+        };
+        if KEYWORDS.contains(&word.as_str()) {
+            return Ok(None);
+        }
+
+        Ok(Some(PrepareRenameResponse::Range(span_to_range(&doc.source, span.start, span.end))))
+    }
+
+    async fn rename(&self, params: RenameParams) -> Result<Option<WorkspaceEdit>> {
+        let uri = &params.text_document_position.text_document.uri;
+        let position = params.text_document_position.position;
+        let new_name = params.new_name;
+
+        let (source, ast) = {
+            let docs = self.documents.read().await;
+            let Some(doc) = docs.get(uri) else {
+                return Ok(None);
+            };
+            let Some(ast) = &doc.ast else {
+                return Ok(None);
+            };
+            (doc.source.clone(), ast.clone())
+        };
+
+        let Some(offset) = position_to_offset(&source, position) else {
+            return Ok(None);
+        };
+        let Some((old_name, _)) = identifier_at_offset(&source, offset) else {
+            return Ok(None);
+        };
+        if KEYWORDS.contains(&old_name.as_str()) {
+            return Ok(None);
+        }
+
+        let files = self.collect_all_files(uri, &ast, &source).await;
+
+        let mut changes: HashMap<Url, Vec<TextEdit>> = HashMap::new();
+        for (file_uri, file_source, file_ast) in &files {
+            let mut spans: Vec<Span> = Vec::new();
+            for decl in &file_ast.declarations {
+                collect_decl_name_refs(&decl.node, decl.span, &old_name, file_source, &mut spans);
+            }
+            if spans.is_empty() {
+                continue;
+            }
+            let edits: Vec<TextEdit> = spans
+                .into_iter()
+                .map(|span| TextEdit {
+                    range: span_to_range(file_source, span.start, span.end),
+                    new_text: new_name.clone(),
+                })
+                .collect();
+            changes.insert(file_uri.clone(), edits);
+        }
+
+        if changes.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(WorkspaceEdit {
+            changes: Some(changes),
+            document_changes: None,
+            change_annotations: None,
+        }))
+    }
+
+    async fn signature_help(&self, params: SignatureHelpParams) -> Result<Option<SignatureHelp>> {
+        let uri = &params.text_document_position_params.text_document.uri;
+        let position = params.text_document_position_params.position;
+
+        let (source, ast, version) = {
+            let docs = self.documents.read().await;
+            let Some(doc) = docs.get(uri) else {
+                return Ok(None);
+            };
+            let Some(ast) = &doc.ast else {
+                return Ok(None);
+            };
+            (doc.source.clone(), ast.clone(), doc.version)
+        };
+
+        let Some(offset) = position_to_offset(&source, position) else {
+            return Ok(None);
+        };
+        let Some((func_name, active_parameter)) = active_call_at_offset(&source, offset) else {
+            return Ok(None);
+        };
+
+        let mut func = self.find_function_decl(&ast, &func_name).cloned();
+        if func.is_none() {
+            let (deps, _) = self.collect_dependency_modules(uri, &ast, &source, version).await;
+            func = deps.iter().find_map(|(_, dep_ast)| self.find_function_decl(dep_ast, &func_name).cloned());
+        }
+        let Some(func) = func else {
+            return Ok(None);
+        };
+
+        let mut signature = build_signature_information(&func);
+        signature.active_parameter = Some(active_parameter);
+
+        Ok(Some(SignatureHelp {
+            signatures: vec![signature],
+            active_signature: Some(0),
+            active_parameter: Some(active_parameter),
+        }))
+    }
+
+    async fn code_action(&self, params: CodeActionParams) -> Result<Option<CodeActionResponse>> {
+        let uri = &params.text_document.uri;
+        let mut actions: CodeActionResponse = Vec::new();
+
+        for diagnostic in &params.context.diagnostics {
+            if diagnostic.code == Some(NumberOrString::String(MISSING_DEPENDENCY_CODE.to_string())) {
+                if let Some(action) = missing_dependency_quick_fix(diagnostic) {
+                    actions.push(CodeActionOrCommand::CodeAction(action));
+                }
+            }
+        }
+
+        if let Some(action) = self.extract_constant_action(uri, params.range).await {
+            actions.push(CodeActionOrCommand::CodeAction(action));
+        }
+
+        if actions.is_empty() { Ok(None) } else { Ok(Some(actions)) }
+    }
+
     async fn completion(&self, params: CompletionParams) -> Result<Option<CompletionResponse>> {
         let uri = &params.text_document_position.text_document.uri;
+        let position = params.text_document_position.position;
 
         let docs = self.documents.read().await;
         let doc = match docs.get(uri) {
@@ -594,16 +2384,24 @@ impl LanguageServer for IncanLanguageServer {
             None => return Ok(None),
         };
 
+        let offset = position_to_offset(&doc.source, position);
+
+        // `EnumName.`/`Model.`/`Class.` completion: list that type's variants/fields instead of
+        // the flat top-level symbol list below.
+        if let Some(offset) = offset {
+            if let Some(prefix) = member_completion_prefix(&doc.source, offset) {
+                if let Some(ast) = &doc.ast {
+                    if let Some(items) = member_completion_items(ast, &doc.source, &prefix) {
+                        return Ok(Some(CompletionResponse::Array(items)));
+                    }
+                }
+            }
+        }
+
         let mut items = Vec::new();
 
         // Add keywords
-        let keywords = [
-            "def", "async", "await", "return", "if", "elif", "else", "match", "case", "for", "in", "while", "let",
-            "mut", "model", "class", "trait", "enum", "newtype", "import", "from", "as", "with", "extends", "pub",
-            "const", "True", "False", "None", "Ok", "Err", "Some", "Result", "Option",
-        ];
-
-        for kw in keywords {
+        for kw in KEYWORDS.iter().copied() {
             items.push(CompletionItem {
                 label: kw.to_string(),
                 kind: Some(CompletionItemKind::KEYWORD),
@@ -628,10 +2426,18 @@ impl LanguageServer for IncanLanguageServer {
                         });
                     }
                     Declaration::Function(func) => {
+                        let (insert_text, insert_text_format) = if self.snippet_support.load(Ordering::Relaxed) {
+                            (Some(function_call_snippet(func)), Some(InsertTextFormat::SNIPPET))
+                        } else {
+                            (None, None)
+                        };
                         items.push(CompletionItem {
                             label: func.name.clone(),
                             kind: Some(CompletionItemKind::FUNCTION),
                             detail: Some(format_function_signature(func)),
+                            documentation: leading_doc_comment(&doc.source, decl.span.start),
+                            insert_text,
+                            insert_text_format,
                             ..Default::default()
                         });
                     }
@@ -640,6 +2446,7 @@ impl LanguageServer for IncanLanguageServer {
                             label: model.name.clone(),
                             kind: Some(CompletionItemKind::STRUCT),
                             detail: Some(format!("model {}", model.name)),
+                            documentation: leading_doc_comment(&doc.source, decl.span.start),
                             ..Default::default()
                         });
                     }
@@ -648,6 +2455,7 @@ impl LanguageServer for IncanLanguageServer {
                             label: class.name.clone(),
                             kind: Some(CompletionItemKind::CLASS),
                             detail: Some(format!("class {}", class.name)),
+                            documentation: leading_doc_comment(&doc.source, decl.span.start),
                             ..Default::default()
                         });
                     }
@@ -656,6 +2464,7 @@ impl LanguageServer for IncanLanguageServer {
                             label: tr.name.clone(),
                             kind: Some(CompletionItemKind::INTERFACE),
                             detail: Some(format!("trait {}", tr.name)),
+                            documentation: leading_doc_comment(&doc.source, decl.span.start),
                             ..Default::default()
                         });
                     }
@@ -664,12 +2473,53 @@ impl LanguageServer for IncanLanguageServer {
                             label: en.name.clone(),
                             kind: Some(CompletionItemKind::ENUM),
                             detail: Some(format!("enum {}", en.name)),
+                            documentation: leading_doc_comment(&doc.source, decl.span.start),
                             ..Default::default()
                         });
                     }
-                    _ => {}
+                    Declaration::Newtype(nt) => {
+                        items.push(CompletionItem {
+                            label: nt.name.clone(),
+                            kind: Some(CompletionItemKind::STRUCT),
+                            detail: Some(format!("newtype {} = {}", nt.name, format_type(&nt.underlying.node))),
+                            documentation: leading_doc_comment(&doc.source, decl.span.start),
+                            ..Default::default()
+                        });
+                    }
+                    // Neither names a completable symbol: an import brings other declarations'
+                    // names into scope rather than declaring one of its own, and a docstring is
+                    // a bare string literal, not an identifier.
+                    Declaration::Import(_) | Declaration::Docstring(_) => {}
                 }
             }
+
+            // Auto-import (flyimport): surface declarations from other files in the project
+            // that match what's been typed so far but aren't yet in scope, with the import
+            // statement they'd need pre-attached as an additional edit.
+            if let Some(offset) = offset {
+                let word = word_before_offset(&doc.source, offset);
+                if !word.is_empty() {
+                    items.extend(foreign_symbol_completions(
+                        &docs,
+                        uri,
+                        ast,
+                        &doc.source,
+                        &word,
+                        self.snippet_support.load(Ordering::Relaxed),
+                    ));
+                }
+            }
+
+            // Inside a class body that implements a trait: offer stub completions for that
+            // trait's still-unimplemented required methods.
+            if let Some(offset) = offset {
+                items.extend(trait_stub_completions(
+                    ast,
+                    &doc.source,
+                    offset,
+                    self.snippet_support.load(Ordering::Relaxed),
+                ));
+            }
         }
 
         Ok(Some(CompletionResponse::Array(items)))
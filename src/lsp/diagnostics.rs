@@ -10,7 +10,7 @@
 //! not bytes. LSP positions are 0-based (line 0, character 0 is the first).
 
 use tower_lsp::lsp_types::{
-    Diagnostic, DiagnosticRelatedInformation, DiagnosticSeverity, Location, Position, Range, Url,
+    Diagnostic, DiagnosticRelatedInformation, DiagnosticSeverity, Location, NumberOrString, Position, Range, Url,
 };
 
 use crate::frontend::diagnostics::{CompileError, ErrorKind};
@@ -142,7 +142,10 @@ pub fn compile_error_to_diagnostic(error: &CompileError, source: &str, uri: &Url
     Diagnostic {
         range,
         severity: Some(severity),
-        code: None,
+        // Stable, cross-referenceable code from the compiler's `codes` catalog (e.g. `E0308`),
+        // when the error was raised with one. `code_description` is left unset: there's no
+        // hosted docs page to link to yet, just the local `incan --explain <CODE>` catalog.
+        code: error.code.map(|code| NumberOrString::String(code.to_string())),
         code_description: None,
         source: Some("incan".to_string()),
         message,
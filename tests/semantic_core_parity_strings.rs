@@ -4,7 +4,11 @@
 use incan::frontend::typechecker::{ConstValue, TypeCheckInfo};
 use incan::frontend::{lexer, parser, typechecker};
 use incan_core::errors::{STRING_INDEX_OUT_OF_RANGE_MSG, STRING_SLICE_STEP_ZERO_MSG};
-use incan_core::strings::{str_char_at, str_concat, str_contains, str_slice};
+use incan_core::strings::{
+    str_capitalize, str_casefold, str_char_at, str_cmp_ignore_case, str_concat, str_contains, str_count,
+    str_encode, str_eq_ignore_case, str_find, str_from_utf8, str_from_utf8_lossy, str_lstrip, str_partition,
+    str_rfind, str_rpartition, str_rsplit, str_rstrip, str_slice, str_split, str_splitlines, str_strip, str_title,
+};
 use incan_stdlib::strings::{str_concat as rt_str_concat, str_index as rt_str_index, str_slice as rt_str_slice};
 
 fn run_const_eval_with_info(src: &str) -> Result<TypeCheckInfo, Vec<String>> {
@@ -60,10 +64,10 @@ fn semantics_vs_runtime_index_and_slice() {
     // Methods parity
     assert_eq!(incan_stdlib::strings::str_upper("héllo"), "HÉLLO");
     assert_eq!(incan_stdlib::strings::str_lower("HÉLLO"), "héllo");
-    assert_eq!(incan_stdlib::strings::str_strip("  hi  "), "hi");
+    assert_eq!(incan_stdlib::strings::str_strip("  hi  ", None::<&str>), "hi");
     assert_eq!(incan_stdlib::strings::str_replace("abcabc", "ab", "xy"), "xycxyc");
     assert_eq!(
-        incan_stdlib::strings::str_split("a,b,c", Some(",")),
+        incan_stdlib::strings::str_split("a,b,c", Some(","), None),
         vec!["a".to_string(), "b".to_string(), "c".to_string()]
     );
     assert_eq!(
@@ -74,6 +78,165 @@ fn semantics_vs_runtime_index_and_slice() {
     assert!(incan_stdlib::strings::str_ends_with("hello", "lo"));
 }
 
+#[test]
+fn semantics_vs_runtime_find_rfind_count() {
+    let s = "héllo héllo";
+
+    assert_eq!(incan_stdlib::strings::str_find(s, "éll", None, None), str_find(s, "éll", None, None));
+    assert_eq!(str_find(s, "éll", None, None), 1);
+    assert_eq!(str_find(s, "zz", None, None), -1);
+
+    assert_eq!(incan_stdlib::strings::str_rfind(s, "h", None, None), str_rfind(s, "h", None, None));
+    assert_eq!(str_rfind(s, "h", None, None), 6);
+
+    // Scalar start/end bounds (not byte offsets): the second "héllo" begins at scalar index 6.
+    assert_eq!(str_find(s, "h", Some(2), None), 6);
+    assert_eq!(str_find(s, "éll", None, Some(1)), -1);
+
+    assert_eq!(incan_stdlib::strings::str_count(s, "h", None, None), str_count(s, "h", None, None) as i64);
+    assert_eq!(str_count(s, "h", None, None), 2);
+    // Python semantics: an empty needle has one "occurrence" per scalar position, plus one past the end.
+    assert_eq!(str_count(s, "", None, None), s.chars().count() + 1);
+}
+
+#[test]
+fn semantics_vs_runtime_split_family() {
+    assert_eq!(
+        incan_stdlib::strings::str_split("a,b,c,d", Some(","), Some(1)),
+        str_split("a,b,c,d", Some(","), Some(1))
+    );
+    assert_eq!(str_split("a,b,c,d", Some(","), Some(1)), vec!["a".to_string(), "b,c,d".to_string()]);
+    assert_eq!(str_split("a,b,c,d", Some(","), Some(0)), vec!["a,b,c,d".to_string()]);
+
+    assert_eq!(
+        incan_stdlib::strings::str_rsplit("a,b,c,d", Some(","), Some(1)),
+        str_rsplit("a,b,c,d", Some(","), Some(1))
+    );
+    assert_eq!(str_rsplit("a,b,c,d", Some(","), Some(1)), vec!["a,b,c".to_string(), "d".to_string()]);
+
+    let multi = "one\ntwo\r\nthree\rfour";
+    assert_eq!(
+        incan_stdlib::strings::str_splitlines(multi, false),
+        str_splitlines(multi, false)
+    );
+    assert_eq!(
+        str_splitlines(multi, false),
+        vec!["one".to_string(), "two".to_string(), "three".to_string(), "four".to_string()]
+    );
+    assert_eq!(
+        str_splitlines("a\n", true),
+        vec!["a\n".to_string()],
+        "a trailing boundary must not produce an extra empty line"
+    );
+
+    assert_eq!(
+        incan_stdlib::strings::str_partition("key=value", "="),
+        str_partition("key=value", "=")
+    );
+    assert_eq!(
+        str_partition("key=value", "="),
+        ("key".to_string(), "=".to_string(), "value".to_string())
+    );
+    assert_eq!(str_partition("noequals", "="), ("noequals".to_string(), String::new(), String::new()));
+
+    assert_eq!(
+        incan_stdlib::strings::str_rpartition("a=b=c", "="),
+        str_rpartition("a=b=c", "=")
+    );
+    assert_eq!(str_rpartition("a=b=c", "="), ("a=b".to_string(), "=".to_string(), "c".to_string()));
+    assert_eq!(str_rpartition("noequals", "="), (String::new(), String::new(), "noequals".to_string()));
+}
+
+#[test]
+fn semantics_vs_runtime_strip_variants() {
+    assert_eq!(
+        incan_stdlib::strings::str_strip("xxabcxx", Some("x")),
+        str_strip("xxabcxx", Some("x"))
+    );
+    assert_eq!(str_strip("xxabcxx", Some("x")), "abc");
+    assert_eq!(str_strip("  hi  ", None), "hi");
+
+    assert_eq!(
+        incan_stdlib::strings::str_lstrip("xxabcxx", Some("x")),
+        str_lstrip("xxabcxx", Some("x"))
+    );
+    assert_eq!(str_lstrip("xxabcxx", Some("x")), "abcxx");
+
+    assert_eq!(
+        incan_stdlib::strings::str_rstrip("xxabcxx", Some("x")),
+        str_rstrip("xxabcxx", Some("x"))
+    );
+    assert_eq!(str_rstrip("xxabcxx", Some("x")), "xxabc");
+
+    // A multi-character set strips any member, in any order, from either end.
+    assert_eq!(str_strip("-+abc+-", Some("+-")), "abc");
+}
+
+#[test]
+fn semantics_vs_runtime_case_methods() {
+    assert_eq!(incan_stdlib::strings::str_title("hello world"), str_title("hello world"));
+    assert_eq!(str_title("hello world"), "Hello World");
+    assert_eq!(str_title("they're bill's"), "They'Re Bill'S");
+
+    assert_eq!(incan_stdlib::strings::str_capitalize("hELLO"), str_capitalize("hELLO"));
+    assert_eq!(str_capitalize("hELLO"), "Hello");
+    assert_eq!(str_capitalize(""), "");
+
+    assert_eq!(incan_stdlib::strings::str_casefold("HÉLLO"), str_casefold("HÉLLO"));
+    assert_eq!(str_casefold("HÉLLO"), "héllo");
+
+    assert_eq!(
+        incan_stdlib::strings::str_eq_ignore_case("Hello", "hello"),
+        str_eq_ignore_case("Hello", "hello")
+    );
+    assert!(str_eq_ignore_case("Hello", "HELLO"));
+    assert!(!str_eq_ignore_case("Hello", "World"));
+
+    assert_eq!(
+        incan_stdlib::strings::str_cmp_ignore_case("abc", "ABD"),
+        str_cmp_ignore_case("abc", "ABD")
+    );
+    assert!(str_cmp_ignore_case("abc", "ABD").is_lt());
+}
+
+#[test]
+fn semantics_vs_runtime_bytes_interop() {
+    assert_eq!(incan_stdlib::strings::str_encode("héllo"), str_encode("héllo"));
+    assert_eq!(str_encode("ab"), b"ab".to_vec());
+
+    let bytes = str_encode("héllo");
+    assert_eq!(incan_stdlib::strings::str_from_utf8(&bytes), "héllo");
+    assert_eq!(str_from_utf8(&bytes).unwrap(), "héllo");
+
+    // An isolated invalid byte is replaced with a single U+FFFD, matching Python's `errors="replace"`.
+    let mut mixed = b"ab".to_vec();
+    mixed.push(0xff);
+    mixed.extend_from_slice(b"cd");
+    assert_eq!(
+        incan_stdlib::strings::str_from_utf8_lossy(&mixed),
+        str_from_utf8_lossy(&mixed)
+    );
+    assert_eq!(str_from_utf8_lossy(&mixed), "ab\u{FFFD}cd");
+}
+
+#[test]
+#[should_panic(expected = "UnicodeDecodeError: invalid utf-8 sequence")]
+fn runtime_from_utf8_panics_on_invalid_bytes() {
+    let _ = incan_stdlib::strings::str_from_utf8(&[0xff, 0xfe]);
+}
+
+#[test]
+#[should_panic(expected = "ValueError: substring not found")]
+fn runtime_index_of_panics_when_absent() {
+    let _ = incan_stdlib::strings::str_index_of("abc", "z", None, None);
+}
+
+#[test]
+fn runtime_index_of_matches_find_when_present() {
+    assert_eq!(incan_stdlib::strings::str_index_of("abc", "b", None, None), 1);
+    assert_eq!(incan_stdlib::strings::str_rindex_of("abcabc", "b", None, None), 4);
+}
+
 #[test]
 #[should_panic(expected = "IndexError: string index out of range")]
 fn runtime_index_panics_on_oob() {